@@ -13,6 +13,14 @@ pub struct UnknownOriginName(String);
 #[error("no origin with NAIF ID `{0}` is known")]
 pub struct UnknownOriginId(i32);
 
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum UnknownBodyError {
+    #[error(transparent)]
+    Name(#[from] UnknownOriginName),
+    #[error(transparent)]
+    Id(#[from] UnknownOriginId),
+}
+
 #[derive(
     Debug, Copy, Clone, Default, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive, PartialOrd, Ord,
 )]
@@ -427,6 +435,36 @@ impl Origin for DynOrigin {
     }
 }
 
+impl DynOrigin {
+    /// Looks up an origin by name, e.g. the `CENTER_NAME` of an OEM.
+    ///
+    /// The lookup ignores case and surrounding whitespace, treats underscores like spaces, and
+    /// accepts common aliases such as "Luna" for the Moon or "EMB" for the Earth-Moon barycenter.
+    pub fn from_name(name: &str) -> Result<Self, UnknownBodyError> {
+        let normalized = name
+            .split(|c: char| c.is_whitespace() || c == '_')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let origin = match normalized.as_str() {
+            "sol" => Ok(DynOrigin::Sun),
+            "luna" => Ok(DynOrigin::Moon),
+            "solar system barycentre" => Ok(DynOrigin::SolarSystemBarycenter),
+            "emb" | "earth-moon barycenter" | "earth moon barycenter" | "earth-moon barycentre" => {
+                Ok(DynOrigin::EarthBarycenter)
+            }
+            _ => DynOrigin::from_str(&normalized),
+        };
+        origin.map_err(|_| UnknownOriginName(name.to_owned()).into())
+    }
+
+    /// Looks up an origin by its NAIF ID.
+    pub fn try_from_naif_id(id: i32) -> Result<Self, UnknownBodyError> {
+        Ok(DynOrigin::try_from(id)?)
+    }
+}
+
 impl Display for DynOrigin {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
@@ -659,6 +697,12 @@ impl FromStr for DynOrigin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        Earth, Io, Jupiter, Moon, RotationalElements, Saturn, Sun, TriaxialEllipsoid,
+        TryRotationalElements, TryVolumetricRadius, TryZonalHarmonics, VolumetricRadius,
+        ZonalHarmonics,
+    };
+    use float_eq::assert_float_eq;
     use rstest::rstest;
 
     #[rstest]
@@ -869,6 +913,123 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("Jupiter", DynOrigin::Jupiter)]
+    #[case("JUPITER", DynOrigin::Jupiter)]
+    #[case("  jupiter ", DynOrigin::Jupiter)]
+    #[case("Luna", DynOrigin::Moon)]
+    #[case("MOON", DynOrigin::Moon)]
+    #[case("MARS BARYCENTER", DynOrigin::MarsBarycenter)]
+    #[case("Mars_Barycenter", DynOrigin::MarsBarycenter)]
+    #[case("EARTH-MOON BARYCENTER", DynOrigin::EarthBarycenter)]
+    #[case("SSB", DynOrigin::SolarSystemBarycenter)]
+    #[case("Wilson-Harrington", DynOrigin::WilsonHarrington)]
+    fn test_dyn_origin_from_name(#[case] name: &str, #[case] exp: DynOrigin) {
+        assert_eq!(DynOrigin::from_name(name), Ok(exp));
+    }
+
+    #[test]
+    fn test_dyn_origin_from_name_unknown() {
+        let err = DynOrigin::from_name("Rupert ").unwrap_err();
+        assert_eq!(
+            err,
+            UnknownBodyError::Name(UnknownOriginName("Rupert ".to_string()))
+        );
+        assert_eq!(err.to_string(), "no origin with name `Rupert ` is known");
+    }
+
+    #[test]
+    fn test_dyn_origin_try_from_naif_id() {
+        assert_eq!(DynOrigin::try_from_naif_id(599), Ok(DynOrigin::Jupiter));
+        let err = DynOrigin::try_from_naif_id(666).unwrap_err();
+        assert_eq!(err, UnknownBodyError::Id(UnknownOriginId(666)));
+        assert_eq!(err.to_string(), "no origin with NAIF ID `666` is known");
+    }
+
+    #[rstest]
+    #[case(DynOrigin::Sun, &Sun)]
+    #[case(DynOrigin::Earth, &Earth)]
+    #[case(DynOrigin::Moon, &Moon)]
+    #[case(DynOrigin::Jupiter, &Jupiter)]
+    #[case(DynOrigin::Io, &Io)]
+    #[case(DynOrigin::Saturn, &Saturn)]
+    fn test_dyn_origin_rotational_elements(
+        #[case] origin: DynOrigin,
+        #[case] body: &dyn RotationalElements,
+    ) {
+        for t in [-1e9, 0.0, 86400.0, 1e9] {
+            assert_eq!(
+                origin.try_rotational_elements(t),
+                Ok(body.rotational_elements(t))
+            );
+            assert_eq!(
+                origin.try_rotational_element_rates(t),
+                Ok(body.rotational_element_rates(t))
+            );
+        }
+    }
+
+    #[test]
+    fn test_io_prime_meridian_nut_prec_terms() {
+        // Io's prime meridian from pck00011.tpc, where J3 and J4 are the phase angles shared by
+        // the Jovian system (`BODY5_NUT_PREC_ANGLES`)
+        for t in [-1e9, 0.0, 86400.0, 1e9] {
+            let d: f64 = t / 86400.0;
+            let centuries = d / 36525.0;
+            let j3 = (283.90 + 4850.7 * centuries).to_radians();
+            let j4 = (355.80 + 1191.3 * centuries).to_radians();
+            let exp = (200.39 + 203.4889538 * d - 0.085 * j3.sin() - 0.022 * j4.sin()).to_radians();
+            let (_, _, pm) = Io.rotational_elements(t);
+            assert_float_eq!(pm, exp, rel <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dyn_origin_rotational_elements_undefined() {
+        let err = DynOrigin::JupiterBarycenter
+            .try_rotational_elements(0.0)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "undefined property 'rotational elements' for origin 'Jupiter Barycenter'"
+        );
+        assert!(DynOrigin::JupiterBarycenter
+            .try_rotational_element_rates(0.0)
+            .is_err());
+    }
+
+    #[rstest]
+    #[case(DynOrigin::Earth, &Earth)]
+    #[case(DynOrigin::Moon, &Moon)]
+    #[case(DynOrigin::Jupiter, &Jupiter)]
+    #[case(DynOrigin::Io, &Io)]
+    fn test_dyn_origin_volumetric_radius(#[case] origin: DynOrigin, #[case] body: &dyn Body) {
+        let (a, b, c) = body.radii();
+        let exp = (a * b * c).cbrt();
+        assert_eq!(body.volumetric_radius(), exp);
+        assert_eq!(origin.try_volumetric_radius(), Ok(exp));
+    }
+
+    #[test]
+    fn test_dyn_origin_volumetric_radius_undefined() {
+        assert!(DynOrigin::JupiterBarycenter
+            .try_volumetric_radius()
+            .is_err());
+    }
+
+    #[test]
+    fn test_zonal_harmonics() {
+        assert!((Earth.j2() - 0.00108263).abs() < 1e-8);
+        assert_eq!(DynOrigin::Earth.try_j2(), Ok(Earth.j2()));
+        assert_eq!(DynOrigin::Earth.try_j3(), Ok(Earth.j3()));
+        assert_eq!(DynOrigin::Earth.try_j4(), Ok(Earth.j4()));
+        assert!(DynOrigin::Moon.try_j2().is_err());
+    }
+
+    trait Body: TriaxialEllipsoid + VolumetricRadius {}
+
+    impl<T: TriaxialEllipsoid + VolumetricRadius> Body for T {}
+
     #[test]
     fn test_dyn_origin_unknown_id() {
         assert_eq!(DynOrigin::try_from(666), Err(UnknownOriginId(666)))
@@ -0,0 +1,141 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `ellipsoids` exposes [ReferenceEllipsoid], an ellipsoid of revolution serving as the
+    reference surface for geodetic coordinates, together with the [WGS84] and [GRS80] Earth
+    ellipsoids.
+
+    The shapes of the bodies in this crate are based on the IAU radii from the PCK, which differ
+    from the geodetic ellipsoids, e.g. both radii of the Earth differ from WGS84 by about 0.4 m.
+    Data referenced to a geodetic datum, such as GNSS positions, should therefore be converted
+    with the corresponding [ReferenceEllipsoid].
+*/
+
+use crate::{Spheroid, TrySpheroid, UndefinedOriginPropertyError};
+
+/// The World Geodetic System 1984 ellipsoid as defined by NIMA TR8350.2.
+pub const WGS84: ReferenceEllipsoid = ReferenceEllipsoid {
+    semi_major: 6378.137,
+    flattening: 1.0 / 298.257223563,
+};
+
+/// The Geodetic Reference System 1980 ellipsoid as adopted by the IUGG.
+pub const GRS80: ReferenceEllipsoid = ReferenceEllipsoid {
+    semi_major: 6378.137,
+    flattening: 1.0 / 298.257222101,
+};
+
+/// An oblate ellipsoid of revolution defined by its semi-major axis in km and its flattening.
+///
+/// All derived quantities are computed from these two defining parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReferenceEllipsoid {
+    semi_major: f64,
+    flattening: f64,
+}
+
+impl ReferenceEllipsoid {
+    pub const fn new(semi_major: f64, flattening: f64) -> Self {
+        Self {
+            semi_major,
+            flattening,
+        }
+    }
+
+    /// Creates a reference ellipsoid from the semi-major axis in km and the inverse flattening
+    /// `1/f`, the form in which geodetic ellipsoids are usually defined.
+    pub fn from_inverse_flattening(semi_major: f64, inverse_flattening: f64) -> Self {
+        Self::new(semi_major, 1.0 / inverse_flattening)
+    }
+
+    /// Creates the reference ellipsoid of `body` from its PCK radii.
+    pub fn from_spheroid(body: &impl Spheroid) -> Self {
+        Self::new(body.equatorial_radius(), body.flattening())
+    }
+
+    /// Creates the reference ellipsoid of `body` from its PCK radii, if they are defined.
+    pub fn try_from_spheroid(
+        body: &impl TrySpheroid,
+    ) -> Result<Self, UndefinedOriginPropertyError> {
+        Ok(Self::new(
+            body.try_equatorial_radius()?,
+            body.try_flattening()?,
+        ))
+    }
+
+    /// The equatorial radius `a` in km.
+    pub fn semi_major(&self) -> f64 {
+        self.semi_major
+    }
+
+    /// The flattening `f = (a - b) / a`.
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// The polar radius `b = a (1 - f)` in km.
+    pub fn semi_minor(&self) -> f64 {
+        self.semi_major * (1.0 - self.flattening)
+    }
+
+    /// The square of the first eccentricity `e² = f (2 - f)`.
+    pub fn eccentricity_sq(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// The arithmetic mean radius `(2a + b) / 3` in km as defined by the IUGG.
+    pub fn mean_radius(&self) -> f64 {
+        (2.0 * self.semi_major + self.semi_minor()) / 3.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use crate::{DynOrigin, Earth};
+
+    use super::*;
+
+    // Reference values from NIMA TR8350.2 and Moritz (2000), "Geodetic Reference System 1980".
+    #[rstest]
+    #[case::wgs84(WGS84, 6356.7523142, 0.00669437999014, 6371.0087714)]
+    #[case::grs80(GRS80, 6356.7523141, 0.00669438002290, 6371.0087714)]
+    fn test_reference_ellipsoid(
+        #[case] ellipsoid: ReferenceEllipsoid,
+        #[case] semi_minor: f64,
+        #[case] eccentricity_sq: f64,
+        #[case] mean_radius: f64,
+    ) {
+        assert_eq!(ellipsoid.semi_major(), 6378.137);
+        assert_float_eq!(ellipsoid.semi_minor(), semi_minor, abs <= 1e-7);
+        assert_float_eq!(ellipsoid.eccentricity_sq(), eccentricity_sq, abs <= 1e-14);
+        assert_float_eq!(ellipsoid.mean_radius(), mean_radius, abs <= 1e-7);
+    }
+
+    #[test]
+    fn test_reference_ellipsoid_from_inverse_flattening() {
+        let ellipsoid = ReferenceEllipsoid::from_inverse_flattening(6378.137, 298.257223563);
+        assert_eq!(ellipsoid, WGS84);
+    }
+
+    #[test]
+    fn test_reference_ellipsoid_from_spheroid() {
+        let ellipsoid = ReferenceEllipsoid::from_spheroid(&Earth);
+        assert_eq!(ellipsoid.semi_major(), Earth.equatorial_radius());
+        assert_float_eq!(ellipsoid.semi_minor(), Earth.polar_radius(), abs <= 1e-12);
+        assert_ne!(ellipsoid, WGS84);
+        assert_eq!(
+            ReferenceEllipsoid::try_from_spheroid(&DynOrigin::Earth),
+            Ok(ellipsoid)
+        );
+        assert!(ReferenceEllipsoid::try_from_spheroid(&DynOrigin::SolarSystemBarycenter).is_err());
+    }
+}
@@ -25,7 +25,11 @@ use crate::TryPointMass;
 use crate::TryRotationalElements;
 use crate::TrySpheroid;
 use crate::TryTriaxialEllipsoid;
+use crate::TryVolumetricRadius;
+use crate::TryZonalHarmonics;
 use crate::UndefinedOriginPropertyError;
+use crate::VolumetricRadius;
+use crate::ZonalHarmonics;
 use std::fmt::Display;
 use std::fmt::Formatter;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -54,6 +58,11 @@ impl TriaxialEllipsoid for Sun {
     }
 }
 impl Spheroid for Sun {}
+impl VolumetricRadius for Sun {
+    fn volumetric_radius(&self) -> f64 {
+        695700f64
+    }
+}
 const RIGHT_ASCENSION_SUN: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.993910588731375f64,
@@ -128,6 +137,11 @@ impl TriaxialEllipsoid for Mercury {
     }
 }
 impl Spheroid for Mercury {}
+impl VolumetricRadius for Mercury {
+    fn volumetric_radius(&self) -> f64 {
+        2439.773098613628f64
+    }
+}
 const RIGHT_ASCENSION_MERCURY: RotationalElement<5usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.904554967017021f64,
@@ -244,6 +258,11 @@ impl TriaxialEllipsoid for Venus {
     }
 }
 impl Spheroid for Venus {}
+impl VolumetricRadius for Venus {
+    fn volumetric_radius(&self) -> f64 {
+        6051.8f64
+    }
+}
 const RIGHT_ASCENSION_VENUS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.760560067739733f64,
@@ -318,6 +337,22 @@ impl TriaxialEllipsoid for Earth {
     }
 }
 impl Spheroid for Earth {}
+impl VolumetricRadius for Earth {
+    fn volumetric_radius(&self) -> f64 {
+        6371.000385249621f64
+    }
+}
+impl ZonalHarmonics for Earth {
+    fn j2(&self) -> f64 {
+        0.0010826261738522227f64
+    }
+    fn j3(&self) -> f64 {
+        -0.0000025324105185677225f64
+    }
+    fn j4(&self) -> f64 {
+        -0.0000016198975999169731f64
+    }
+}
 const RIGHT_ASCENSION_EARTH: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0f64,
@@ -392,6 +427,11 @@ impl TriaxialEllipsoid for Mars {
     }
 }
 impl Spheroid for Mars {}
+impl VolumetricRadius for Mars {
+    fn volumetric_radius(&self) -> f64 {
+        3389.5135502710814f64
+    }
+}
 const RIGHT_ASCENSION_MARS: RotationalElement<15usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.5373921900749785f64,
@@ -658,6 +698,11 @@ impl TriaxialEllipsoid for Jupiter {
     }
 }
 impl Spheroid for Jupiter {}
+impl VolumetricRadius for Jupiter {
+    fn volumetric_radius(&self) -> f64 {
+        69911.30840807082f64
+    }
+}
 const RIGHT_ASCENSION_JUPITER: RotationalElement<15usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.6784701644349695f64,
@@ -862,6 +907,11 @@ impl TriaxialEllipsoid for Saturn {
     }
 }
 impl Spheroid for Saturn {}
+impl VolumetricRadius for Saturn {
+    fn volumetric_radius(&self) -> f64 {
+        58231.993022318304f64
+    }
+}
 const RIGHT_ASCENSION_SATURN: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7084116900919784f64,
@@ -936,6 +986,11 @@ impl TriaxialEllipsoid for Uranus {
     }
 }
 impl Spheroid for Uranus {}
+impl VolumetricRadius for Uranus {
+    fn volumetric_radius(&self) -> f64 {
+        25362.154531444983f64
+    }
+}
 const RIGHT_ASCENSION_URANUS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.4909241515991285f64,
@@ -1010,6 +1065,11 @@ impl TriaxialEllipsoid for Neptune {
     }
 }
 impl Spheroid for Neptune {}
+impl VolumetricRadius for Neptune {
+    fn volumetric_radius(&self) -> f64 {
+        24622.189475123607f64
+    }
+}
 const RIGHT_ASCENSION_NEPTUNE: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -1165,6 +1225,11 @@ impl TriaxialEllipsoid for Pluto {
     }
 }
 impl Spheroid for Pluto {}
+impl VolumetricRadius for Pluto {
+    fn volumetric_radius(&self) -> f64 {
+        1188.3f64
+    }
+}
 const RIGHT_ASCENSION_PLUTO: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 2.3211657321048187f64,
@@ -1439,6 +1504,11 @@ impl TriaxialEllipsoid for Moon {
     }
 }
 impl Spheroid for Moon {}
+impl VolumetricRadius for Moon {
+    fn volumetric_radius(&self) -> f64 {
+        1737.4f64
+    }
+}
 const RIGHT_ASCENSION_MOON: RotationalElement<13usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.712299968592838f64,
@@ -1638,6 +1708,11 @@ impl TriaxialEllipsoid for Phobos {
         (13f64, 11.4f64, 9.1f64)
     }
 }
+impl VolumetricRadius for Phobos {
+    fn volumetric_radius(&self) -> f64 {
+        11.04832731313149f64
+    }
+}
 const RIGHT_ASCENSION_PHOBOS: RotationalElement<4usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.544399941316208f64,
@@ -1759,6 +1834,11 @@ impl TriaxialEllipsoid for Deimos {
         (7.8f64, 6f64, 5.1f64)
     }
 }
+impl VolumetricRadius for Deimos {
+    fn volumetric_radius(&self) -> f64 {
+        6.203050874487247f64
+    }
+}
 const RIGHT_ASCENSION_DEIMOS: RotationalElement<10usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.526708263174914f64,
@@ -1931,6 +2011,11 @@ impl TriaxialEllipsoid for Io {
         (1829.4f64, 1819.4f64, 1815.7f64)
     }
 }
+impl VolumetricRadius for Io {
+    fn volumetric_radius(&self) -> f64 {
+        1821.4908175629503f64
+    }
+}
 const RIGHT_ASCENSION_IO: RotationalElement<4usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678355059970801f64,
@@ -2049,6 +2134,11 @@ impl TriaxialEllipsoid for Europa {
         (1562.6f64, 1560.3f64, 1559.5f64)
     }
 }
+impl VolumetricRadius for Europa {
+    fn volumetric_radius(&self) -> f64 {
+        1560.7994470247518f64
+    }
+}
 const RIGHT_ASCENSION_EUROPA: RotationalElement<7usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678878658746398f64,
@@ -2195,6 +2285,11 @@ impl TriaxialEllipsoid for Ganymede {
     }
 }
 impl Spheroid for Ganymede {}
+impl VolumetricRadius for Ganymede {
+    fn volumetric_radius(&self) -> f64 {
+        2631.2f64
+    }
+}
 const RIGHT_ASCENSION_GANYMEDE: RotationalElement<6usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.680973053848792f64,
@@ -2332,6 +2427,11 @@ impl TriaxialEllipsoid for Callisto {
     }
 }
 impl Spheroid for Callisto {}
+impl VolumetricRadius for Callisto {
+    fn volumetric_radius(&self) -> f64 {
+        2410.3f64
+    }
+}
 const RIGHT_ASCENSION_CALLISTO: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.690048765959163f64,
@@ -2486,6 +2586,11 @@ impl TriaxialEllipsoid for Amalthea {
         (125f64, 73f64, 64f64)
     }
 }
+impl VolumetricRadius for Amalthea {
+    fn volumetric_radius(&self) -> f64 {
+        83.58678392762464f64
+    }
+}
 const RIGHT_ASCENSION_AMALTHEA: RotationalElement<10usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678355059970801f64,
@@ -2659,6 +2764,11 @@ impl TriaxialEllipsoid for Himalia {
     }
 }
 impl Spheroid for Himalia {}
+impl VolumetricRadius for Himalia {
+    fn volumetric_radius(&self) -> f64 {
+        85f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Elara;
 impl Origin for Elara {
@@ -2685,6 +2795,11 @@ impl TriaxialEllipsoid for Elara {
     }
 }
 impl Spheroid for Elara {}
+impl VolumetricRadius for Elara {
+    fn volumetric_radius(&self) -> f64 {
+        40f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pasiphae;
 impl Origin for Pasiphae {
@@ -2711,6 +2826,11 @@ impl TriaxialEllipsoid for Pasiphae {
     }
 }
 impl Spheroid for Pasiphae {}
+impl VolumetricRadius for Pasiphae {
+    fn volumetric_radius(&self) -> f64 {
+        18f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Sinope;
 impl Origin for Sinope {
@@ -2737,6 +2857,11 @@ impl TriaxialEllipsoid for Sinope {
     }
 }
 impl Spheroid for Sinope {}
+impl VolumetricRadius for Sinope {
+    fn volumetric_radius(&self) -> f64 {
+        14f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Lysithea;
 impl Origin for Lysithea {
@@ -2763,6 +2888,11 @@ impl TriaxialEllipsoid for Lysithea {
     }
 }
 impl Spheroid for Lysithea {}
+impl VolumetricRadius for Lysithea {
+    fn volumetric_radius(&self) -> f64 {
+        12f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Carme;
 impl Origin for Carme {
@@ -2789,6 +2919,11 @@ impl TriaxialEllipsoid for Carme {
     }
 }
 impl Spheroid for Carme {}
+impl VolumetricRadius for Carme {
+    fn volumetric_radius(&self) -> f64 {
+        15f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ananke;
 impl Origin for Ananke {
@@ -2815,6 +2950,11 @@ impl TriaxialEllipsoid for Ananke {
     }
 }
 impl Spheroid for Ananke {}
+impl VolumetricRadius for Ananke {
+    fn volumetric_radius(&self) -> f64 {
+        10f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Leda;
 impl Origin for Leda {
@@ -2841,6 +2981,11 @@ impl TriaxialEllipsoid for Leda {
     }
 }
 impl Spheroid for Leda {}
+impl VolumetricRadius for Leda {
+    fn volumetric_radius(&self) -> f64 {
+        5f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Thebe;
 impl Origin for Thebe {
@@ -2871,6 +3016,11 @@ impl TriaxialEllipsoid for Thebe {
         (58f64, 49f64, 42f64)
     }
 }
+impl VolumetricRadius for Thebe {
+    fn volumetric_radius(&self) -> f64 {
+        49.23694759198364f64
+    }
+}
 const RIGHT_ASCENSION_THEBE: RotationalElement<10usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678355059970801f64,
@@ -3043,6 +3193,11 @@ impl TriaxialEllipsoid for Adrastea {
         (10f64, 8f64, 7f64)
     }
 }
+impl VolumetricRadius for Adrastea {
+    fn volumetric_radius(&self) -> f64 {
+        8.242570599617114f64
+    }
+}
 const RIGHT_ASCENSION_ADRASTEA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678355059970801f64,
@@ -3116,6 +3271,11 @@ impl TriaxialEllipsoid for Metis {
         (30f64, 20f64, 17f64)
     }
 }
+impl VolumetricRadius for Metis {
+    fn volumetric_radius(&self) -> f64 {
+        21.687028852501967f64
+    }
+}
 const RIGHT_ASCENSION_METIS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.678355059970801f64,
@@ -3714,6 +3874,11 @@ impl TriaxialEllipsoid for Mimas {
         (207.8f64, 196.7f64, 190.6f64)
     }
 }
+impl VolumetricRadius for Mimas {
+    fn volumetric_radius(&self) -> f64 {
+        198.23983755148876f64
+    }
+}
 const RIGHT_ASCENSION_MIMAS: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7096508738608943f64,
@@ -3868,6 +4033,11 @@ impl TriaxialEllipsoid for Enceladus {
         (256.6f64, 251.4f64, 248.3f64)
     }
 }
+impl VolumetricRadius for Enceladus {
+    fn volumetric_radius(&self) -> f64 {
+        252.07680267996935f64
+    }
+}
 const RIGHT_ASCENSION_ENCELADUS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7096508738608943f64,
@@ -3941,6 +4111,11 @@ impl TriaxialEllipsoid for Tethys {
         (538.4f64, 528.3f64, 526.3f64)
     }
 }
+impl VolumetricRadius for Tethys {
+    fn volumetric_radius(&self) -> f64 {
+        530.9737004221522f64
+    }
+}
 const RIGHT_ASCENSION_TETHYS: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7096508738608943f64,
@@ -4095,6 +4270,11 @@ impl TriaxialEllipsoid for Dione {
         (563.4f64, 561.3f64, 559.6f64)
     }
 }
+impl VolumetricRadius for Dione {
+    fn volumetric_radius(&self) -> f64 {
+        561.4311825935979f64
+    }
+}
 const RIGHT_ASCENSION_DIONE: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7096508738608943f64,
@@ -4168,6 +4348,11 @@ impl TriaxialEllipsoid for Rhea {
         (765f64, 763.1f64, 762.4f64)
     }
 }
+impl VolumetricRadius for Rhea {
+    fn volumetric_radius(&self) -> f64 {
+        763.4992101561094f64
+    }
+}
 const RIGHT_ASCENSION_RHEA: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7047639519553103f64,
@@ -4322,6 +4507,11 @@ impl TriaxialEllipsoid for Titan {
         (2575.15f64, 2574.78f64, 2574.47f64)
     }
 }
+impl VolumetricRadius for Titan {
+    fn volumetric_radius(&self) -> f64 {
+        2574.7999849957146f64
+    }
+}
 const RIGHT_ASCENSION_TITAN: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.6891031125771652f64,
@@ -4449,6 +4639,11 @@ impl TriaxialEllipsoid for Hyperion {
         (180.1f64, 133f64, 102.7f64)
     }
 }
+impl VolumetricRadius for Hyperion {
+    fn volumetric_radius(&self) -> f64 {
+        134.99321246166784f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Iapetus;
 impl Origin for Iapetus {
@@ -4480,6 +4675,11 @@ impl TriaxialEllipsoid for Iapetus {
     }
 }
 impl Spheroid for Iapetus {}
+impl VolumetricRadius for Iapetus {
+    fn volumetric_radius(&self) -> f64 {
+        734.3274404996205f64
+    }
+}
 const RIGHT_ASCENSION_IAPETUS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.552939548145159f64,
@@ -4553,6 +4753,11 @@ impl TriaxialEllipsoid for Phoebe {
         (109.4f64, 108.5f64, 101.8f64)
     }
 }
+impl VolumetricRadius for Phoebe {
+    fn volumetric_radius(&self) -> f64 {
+        106.51193618192615f64
+    }
+}
 const RIGHT_ASCENSION_PHOEBE: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 6.229080100367762f64,
@@ -4626,6 +4831,11 @@ impl TriaxialEllipsoid for Janus {
         (101.7f64, 93f64, 76.3f64)
     }
 }
+impl VolumetricRadius for Janus {
+    fn volumetric_radius(&self) -> f64 {
+        89.69663413953857f64
+    }
+}
 const RIGHT_ASCENSION_JANUS: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7082546104592989f64,
@@ -4780,6 +4990,11 @@ impl TriaxialEllipsoid for Epimetheus {
         (64.9f64, 57.3f64, 53f64)
     }
 }
+impl VolumetricRadius for Epimetheus {
+    fn volumetric_radius(&self) -> f64 {
+        58.195811628066906f64
+    }
+}
 const RIGHT_ASCENSION_EPIMETHEUS: RotationalElement<8usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7082546104592989f64,
@@ -4934,6 +5149,11 @@ impl TriaxialEllipsoid for Helene {
         (22.5f64, 19.6f64, 13.3f64)
     }
 }
+impl VolumetricRadius for Helene {
+    fn volumetric_radius(&self) -> f64 {
+        18.03419425993639f64
+    }
+}
 const RIGHT_ASCENSION_HELENE: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7129669994396837f64,
@@ -5002,6 +5222,11 @@ impl TriaxialEllipsoid for Telesto {
         (16.3f64, 11.8f64, 9.8f64)
     }
 }
+impl VolumetricRadius for Telesto {
+    fn volumetric_radius(&self) -> f64 {
+        12.352794856654658f64
+    }
+}
 const RIGHT_ASCENSION_TELESTO: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.8815658051823358f64,
@@ -5070,6 +5295,11 @@ impl TriaxialEllipsoid for Calypso {
         (15.3f64, 9.3f64, 6.3f64)
     }
 }
+impl VolumetricRadius for Calypso {
+    fn volumetric_radius(&self) -> f64 {
+        9.642100258052654f64
+    }
+}
 const RIGHT_ASCENSION_CALYPSO: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.6354743806511354f64,
@@ -5143,6 +5373,11 @@ impl TriaxialEllipsoid for Atlas {
         (20.5f64, 17.8f64, 9.4f64)
     }
 }
+impl VolumetricRadius for Atlas {
+    fn volumetric_radius(&self) -> f64 {
+        15.081130765820243f64
+    }
+}
 const RIGHT_ASCENSION_ATLAS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7082546104592989f64,
@@ -5216,6 +5451,11 @@ impl TriaxialEllipsoid for Prometheus {
         (68.2f64, 41.6f64, 28.2f64)
     }
 }
+impl VolumetricRadius for Prometheus {
+    fn volumetric_radius(&self) -> f64 {
+        43.08991173995601f64
+    }
+}
 const RIGHT_ASCENSION_PROMETHEUS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7082546104592989f64,
@@ -5289,6 +5529,11 @@ impl TriaxialEllipsoid for Pandora {
         (52.2f64, 40.8f64, 31.5f64)
     }
 }
+impl VolumetricRadius for Pandora {
+    fn volumetric_radius(&self) -> f64 {
+        40.63314206825375f64
+    }
+}
 const RIGHT_ASCENSION_PANDORA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7082546104592989f64,
@@ -5357,6 +5602,11 @@ impl TriaxialEllipsoid for Pan {
         (17.2f64, 15.4f64, 10.4f64)
     }
 }
+impl VolumetricRadius for Pan {
+    fn volumetric_radius(&self) -> f64 {
+        14.0182618827594f64
+    }
+}
 const RIGHT_ASCENSION_PAN: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.7086036763096978f64,
@@ -5620,6 +5870,11 @@ impl TriaxialEllipsoid for Methone {
         (1.94f64, 1.29f64, 1.21f64)
     }
 }
+impl VolumetricRadius for Methone {
+    fn volumetric_radius(&self) -> f64 {
+        1.4467459330188372f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pallene;
 impl Origin for Pallene {
@@ -5645,6 +5900,11 @@ impl TriaxialEllipsoid for Pallene {
         (2.88f64, 2.08f64, 1.8f64)
     }
 }
+impl VolumetricRadius for Pallene {
+    fn volumetric_radius(&self) -> f64 {
+        2.2092393798038135f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Polydeuces;
 impl Origin for Polydeuces {
@@ -5670,6 +5930,11 @@ impl TriaxialEllipsoid for Polydeuces {
         (1.5f64, 1.2f64, 1f64)
     }
 }
+impl VolumetricRadius for Polydeuces {
+    fn volumetric_radius(&self) -> f64 {
+        1.21644039911468f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Daphnis;
 impl Origin for Daphnis {
@@ -5695,6 +5960,11 @@ impl TriaxialEllipsoid for Daphnis {
         (4.6f64, 4.5f64, 2.8f64)
     }
 }
+impl VolumetricRadius for Daphnis {
+    fn volumetric_radius(&self) -> f64 {
+        3.86998657928563f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Aegir;
 impl Origin for Aegir {
@@ -5916,6 +6186,11 @@ impl TriaxialEllipsoid for Anthe {
     }
 }
 impl Spheroid for Anthe {}
+impl VolumetricRadius for Anthe {
+    fn volumetric_radius(&self) -> f64 {
+        0.5f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Jarnsaxa;
 impl Origin for Jarnsaxa {
@@ -5986,6 +6261,11 @@ impl TriaxialEllipsoid for Aegaeon {
         (0.7f64, 0.25f64, 0.2f64)
     }
 }
+impl VolumetricRadius for Aegaeon {
+    fn volumetric_radius(&self) -> f64 {
+        0.32710663101885895f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ariel;
 impl Origin for Ariel {
@@ -6016,6 +6296,11 @@ impl TriaxialEllipsoid for Ariel {
         (581.1f64, 577.9f64, 577.7f64)
     }
 }
+impl VolumetricRadius for Ariel {
+    fn volumetric_radius(&self) -> f64 {
+        578.8979066892539f64
+    }
+}
 const RIGHT_ASCENSION_ARIEL: RotationalElement<13usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.493001093409003f64,
@@ -6216,6 +6501,11 @@ impl TriaxialEllipsoid for Umbriel {
     }
 }
 impl Spheroid for Umbriel {}
+impl VolumetricRadius for Umbriel {
+    fn volumetric_radius(&self) -> f64 {
+        584.7f64
+    }
+}
 const RIGHT_ASCENSION_UMBRIEL: RotationalElement<14usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.493001093409003f64,
@@ -6425,6 +6715,11 @@ impl TriaxialEllipsoid for Titania {
     }
 }
 impl Spheroid for Titania {}
+impl VolumetricRadius for Titania {
+    fn volumetric_radius(&self) -> f64 {
+        788.9f64
+    }
+}
 const RIGHT_ASCENSION_TITANIA: RotationalElement<15usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.493001093409003f64,
@@ -6643,6 +6938,11 @@ impl TriaxialEllipsoid for Oberon {
     }
 }
 impl Spheroid for Oberon {}
+impl VolumetricRadius for Oberon {
+    fn volumetric_radius(&self) -> f64 {
+        761.4f64
+    }
+}
 const RIGHT_ASCENSION_OBERON: RotationalElement<16usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.493001093409003f64,
@@ -6869,6 +7169,11 @@ impl TriaxialEllipsoid for Miranda {
         (240.4f64, 234.2f64, 232.9f64)
     }
 }
+impl VolumetricRadius for Miranda {
+    fn volumetric_radius(&self) -> f64 {
+        235.81075794484337f64
+    }
+}
 const RIGHT_ASCENSION_MIRANDA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.493001093409003f64,
@@ -7109,6 +7414,11 @@ impl TriaxialEllipsoid for Cordelia {
     }
 }
 impl Spheroid for Cordelia {}
+impl VolumetricRadius for Cordelia {
+    fn volumetric_radius(&self) -> f64 {
+        13f64
+    }
+}
 const RIGHT_ASCENSION_CORDELIA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -7349,6 +7659,11 @@ impl TriaxialEllipsoid for Ophelia {
     }
 }
 impl Spheroid for Ophelia {}
+impl VolumetricRadius for Ophelia {
+    fn volumetric_radius(&self) -> f64 {
+        15f64
+    }
+}
 const RIGHT_ASCENSION_OPHELIA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -7589,6 +7904,11 @@ impl TriaxialEllipsoid for Bianca {
     }
 }
 impl Spheroid for Bianca {}
+impl VolumetricRadius for Bianca {
+    fn volumetric_radius(&self) -> f64 {
+        21f64
+    }
+}
 const RIGHT_ASCENSION_BIANCA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -7829,6 +8149,11 @@ impl TriaxialEllipsoid for Cressida {
     }
 }
 impl Spheroid for Cressida {}
+impl VolumetricRadius for Cressida {
+    fn volumetric_radius(&self) -> f64 {
+        31f64
+    }
+}
 const RIGHT_ASCENSION_CRESSIDA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -8069,6 +8394,11 @@ impl TriaxialEllipsoid for Desdemona {
     }
 }
 impl Spheroid for Desdemona {}
+impl VolumetricRadius for Desdemona {
+    fn volumetric_radius(&self) -> f64 {
+        27f64
+    }
+}
 const RIGHT_ASCENSION_DESDEMONA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -8309,6 +8639,11 @@ impl TriaxialEllipsoid for Juliet {
     }
 }
 impl Spheroid for Juliet {}
+impl VolumetricRadius for Juliet {
+    fn volumetric_radius(&self) -> f64 {
+        42f64
+    }
+}
 const RIGHT_ASCENSION_JULIET: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -8549,6 +8884,11 @@ impl TriaxialEllipsoid for Portia {
     }
 }
 impl Spheroid for Portia {}
+impl VolumetricRadius for Portia {
+    fn volumetric_radius(&self) -> f64 {
+        54f64
+    }
+}
 const RIGHT_ASCENSION_PORTIA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -8789,6 +9129,11 @@ impl TriaxialEllipsoid for Rosalind {
     }
 }
 impl Spheroid for Rosalind {}
+impl VolumetricRadius for Rosalind {
+    fn volumetric_radius(&self) -> f64 {
+        27f64
+    }
+}
 const RIGHT_ASCENSION_ROSALIND: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -9029,6 +9374,11 @@ impl TriaxialEllipsoid for Belinda {
     }
 }
 impl Spheroid for Belinda {}
+impl VolumetricRadius for Belinda {
+    fn volumetric_radius(&self) -> f64 {
+        33f64
+    }
+}
 const RIGHT_ASCENSION_BELINDA: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -9269,6 +9619,11 @@ impl TriaxialEllipsoid for Puck {
     }
 }
 impl Spheroid for Puck {}
+impl VolumetricRadius for Puck {
+    fn volumetric_radius(&self) -> f64 {
+        77f64
+    }
+}
 const RIGHT_ASCENSION_PUCK: RotationalElement<18usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 4.49090669830661f64,
@@ -9694,6 +10049,11 @@ impl TriaxialEllipsoid for Triton {
     }
 }
 impl Spheroid for Triton {}
+impl VolumetricRadius for Triton {
+    fn volumetric_radius(&self) -> f64 {
+        1352.6f64
+    }
+}
 const RIGHT_ASCENSION_TRITON: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -9925,6 +10285,11 @@ impl TriaxialEllipsoid for Nereid {
     }
 }
 impl Spheroid for Nereid {}
+impl VolumetricRadius for Nereid {
+    fn volumetric_radius(&self) -> f64 {
+        170f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Naiad;
 impl Origin for Naiad {
@@ -9956,6 +10321,11 @@ impl TriaxialEllipsoid for Naiad {
     }
 }
 impl Spheroid for Naiad {}
+impl VolumetricRadius for Naiad {
+    fn volumetric_radius(&self) -> f64 {
+        29f64
+    }
+}
 const RIGHT_ASCENSION_NAIAD: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -10192,6 +10562,11 @@ impl TriaxialEllipsoid for Thalassa {
     }
 }
 impl Spheroid for Thalassa {}
+impl VolumetricRadius for Thalassa {
+    fn volumetric_radius(&self) -> f64 {
+        40f64
+    }
+}
 const RIGHT_ASCENSION_THALASSA: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -10428,6 +10803,11 @@ impl TriaxialEllipsoid for Despina {
     }
 }
 impl Spheroid for Despina {}
+impl VolumetricRadius for Despina {
+    fn volumetric_radius(&self) -> f64 {
+        74f64
+    }
+}
 const RIGHT_ASCENSION_DESPINA: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -10664,6 +11044,11 @@ impl TriaxialEllipsoid for Galatea {
     }
 }
 impl Spheroid for Galatea {}
+impl VolumetricRadius for Galatea {
+    fn volumetric_radius(&self) -> f64 {
+        79f64
+    }
+}
 const RIGHT_ASCENSION_GALATEA: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -10900,6 +11285,11 @@ impl TriaxialEllipsoid for Larissa {
     }
 }
 impl Spheroid for Larissa {}
+impl VolumetricRadius for Larissa {
+    fn volumetric_radius(&self) -> f64 {
+        96f64
+    }
+}
 const RIGHT_ASCENSION_LARISSA: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.224817648770225f64,
@@ -11135,6 +11525,11 @@ impl TriaxialEllipsoid for Proteus {
         (218f64, 208f64, 201f64)
     }
 }
+impl VolumetricRadius for Proteus {
+    fn volumetric_radius(&self) -> f64 {
+        208.88405769890488f64
+    }
+}
 const RIGHT_ASCENSION_PROTEUS: RotationalElement<17usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.22324685244343f64,
@@ -11446,6 +11841,11 @@ impl TriaxialEllipsoid for Charon {
     }
 }
 impl Spheroid for Charon {}
+impl VolumetricRadius for Charon {
+    fn volumetric_radius(&self) -> f64 {
+        606f64
+    }
+}
 const RIGHT_ASCENSION_CHARON: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 2.3211657321048187f64,
@@ -11594,6 +11994,11 @@ impl TriaxialEllipsoid for Gaspra {
         (9.1f64, 5.2f64, 4.4f64)
     }
 }
+impl VolumetricRadius for Gaspra {
+    fn volumetric_radius(&self) -> f64 {
+        5.926966476226717f64
+    }
+}
 const RIGHT_ASCENSION_GASPRA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.16528268016386302f64,
@@ -11662,6 +12067,11 @@ impl TriaxialEllipsoid for Ida {
         (26.8f64, 12f64, 7.6f64)
     }
 }
+impl VolumetricRadius for Ida {
+    fn volumetric_radius(&self) -> f64 {
+        13.47027754471227f64
+    }
+}
 const RIGHT_ASCENSION_IDA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 2.9454176456656302f64,
@@ -11751,6 +12161,11 @@ impl TriaxialEllipsoid for Ceres {
     }
 }
 impl Spheroid for Ceres {}
+impl VolumetricRadius for Ceres {
+    fn volumetric_radius(&self) -> f64 {
+        473.12499671081065f64
+    }
+}
 const RIGHT_ASCENSION_CERES: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.0862035995768355f64,
@@ -11882,6 +12297,11 @@ impl TriaxialEllipsoid for Vesta {
         (289f64, 280f64, 229f64)
     }
 }
+impl VolumetricRadius for Vesta {
+    fn volumetric_radius(&self) -> f64 {
+        264.6247447667379f64
+    }
+}
 const RIGHT_ASCENSION_VESTA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.393608440730596f64,
@@ -11955,6 +12375,11 @@ impl TriaxialEllipsoid for Psyche {
         (139.5f64, 116f64, 94.5f64)
     }
 }
+impl VolumetricRadius for Psyche {
+    fn volumetric_radius(&self) -> f64 {
+        115.20942305732265f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Lutetia;
 impl Origin for Lutetia {
@@ -11980,6 +12405,11 @@ impl TriaxialEllipsoid for Lutetia {
         (62f64, 50.5f64, 46.5f64)
     }
 }
+impl VolumetricRadius for Lutetia {
+    fn volumetric_radius(&self) -> f64 {
+        52.60721852481162f64
+    }
+}
 const RIGHT_ASCENSION_LUTETIA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.9075712110370514f64,
@@ -12068,6 +12498,11 @@ impl TriaxialEllipsoid for Eros {
         (17f64, 5.5f64, 5.5f64)
     }
 }
+impl VolumetricRadius for Eros {
+    fn volumetric_radius(&self) -> f64 {
+        8.011701625649433f64
+    }
+}
 const RIGHT_ASCENSION_EROS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 0.1980948701013564f64,
@@ -12141,6 +12576,11 @@ impl TriaxialEllipsoid for Davida {
         (180f64, 147f64, 127f64)
     }
 }
+impl VolumetricRadius for Davida {
+    fn volumetric_radius(&self) -> f64 {
+        149.78368821134725f64
+    }
+}
 const RIGHT_ASCENSION_DAVIDA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 5.183627878423159f64,
@@ -12209,6 +12649,11 @@ impl TriaxialEllipsoid for Mathilde {
         (33f64, 24f64, 23f64)
     }
 }
+impl VolumetricRadius for Mathilde {
+    fn volumetric_radius(&self) -> f64 {
+        26.311827052523057f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Steins;
 impl Origin for Steins {
@@ -12234,6 +12679,11 @@ impl TriaxialEllipsoid for Steins {
         (3.24f64, 2.73f64, 2.04f64)
     }
 }
+impl VolumetricRadius for Steins {
+    fn volumetric_radius(&self) -> f64 {
+        2.62288515376248f64
+    }
+}
 const RIGHT_ASCENSION_STEINS: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 1.5882496193148399f64,
@@ -12327,6 +12777,11 @@ impl TriaxialEllipsoid for Toutatis {
         (2.13f64, 1.015f64, 0.85f64)
     }
 }
+impl VolumetricRadius for Toutatis {
+    fn volumetric_radius(&self) -> f64 {
+        1.224864902497028f64
+    }
+}
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Itokawa;
 impl Origin for Itokawa {
@@ -12347,6 +12802,11 @@ impl TriaxialEllipsoid for Itokawa {
         (0.268f64, 0.147f64, 0.104f64)
     }
 }
+impl VolumetricRadius for Itokawa {
+    fn volumetric_radius(&self) -> f64 {
+        0.1600154151814451f64
+    }
+}
 const RIGHT_ASCENSION_ITOKAWA: RotationalElement<0usize> = RotationalElement {
     typ: RotationalElementType::RightAscension,
     c0: 1.5800465718304666f64,
@@ -12680,6 +13140,134 @@ impl TryTriaxialEllipsoid for DynOrigin {
     }
 }
 impl TrySpheroid for DynOrigin {}
+impl TryVolumetricRadius for DynOrigin {
+    fn try_volumetric_radius(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        match self {
+            DynOrigin::Sun => Ok(695700f64),
+            DynOrigin::Mercury => Ok(2439.773098613628f64),
+            DynOrigin::Venus => Ok(6051.8f64),
+            DynOrigin::Earth => Ok(6371.000385249621f64),
+            DynOrigin::Mars => Ok(3389.5135502710814f64),
+            DynOrigin::Jupiter => Ok(69911.30840807082f64),
+            DynOrigin::Saturn => Ok(58231.993022318304f64),
+            DynOrigin::Uranus => Ok(25362.154531444983f64),
+            DynOrigin::Neptune => Ok(24622.189475123607f64),
+            DynOrigin::Pluto => Ok(1188.3f64),
+            DynOrigin::Moon => Ok(1737.4f64),
+            DynOrigin::Phobos => Ok(11.04832731313149f64),
+            DynOrigin::Deimos => Ok(6.203050874487247f64),
+            DynOrigin::Io => Ok(1821.4908175629503f64),
+            DynOrigin::Europa => Ok(1560.7994470247518f64),
+            DynOrigin::Ganymede => Ok(2631.2f64),
+            DynOrigin::Callisto => Ok(2410.3f64),
+            DynOrigin::Amalthea => Ok(83.58678392762464f64),
+            DynOrigin::Himalia => Ok(85f64),
+            DynOrigin::Elara => Ok(40f64),
+            DynOrigin::Pasiphae => Ok(18f64),
+            DynOrigin::Sinope => Ok(14f64),
+            DynOrigin::Lysithea => Ok(12f64),
+            DynOrigin::Carme => Ok(15f64),
+            DynOrigin::Ananke => Ok(10f64),
+            DynOrigin::Leda => Ok(5f64),
+            DynOrigin::Thebe => Ok(49.23694759198364f64),
+            DynOrigin::Adrastea => Ok(8.242570599617114f64),
+            DynOrigin::Metis => Ok(21.687028852501967f64),
+            DynOrigin::Mimas => Ok(198.23983755148876f64),
+            DynOrigin::Enceladus => Ok(252.07680267996935f64),
+            DynOrigin::Tethys => Ok(530.9737004221522f64),
+            DynOrigin::Dione => Ok(561.4311825935979f64),
+            DynOrigin::Rhea => Ok(763.4992101561094f64),
+            DynOrigin::Titan => Ok(2574.7999849957146f64),
+            DynOrigin::Hyperion => Ok(134.99321246166784f64),
+            DynOrigin::Iapetus => Ok(734.3274404996205f64),
+            DynOrigin::Phoebe => Ok(106.51193618192615f64),
+            DynOrigin::Janus => Ok(89.69663413953857f64),
+            DynOrigin::Epimetheus => Ok(58.195811628066906f64),
+            DynOrigin::Helene => Ok(18.03419425993639f64),
+            DynOrigin::Telesto => Ok(12.352794856654658f64),
+            DynOrigin::Calypso => Ok(9.642100258052654f64),
+            DynOrigin::Atlas => Ok(15.081130765820243f64),
+            DynOrigin::Prometheus => Ok(43.08991173995601f64),
+            DynOrigin::Pandora => Ok(40.63314206825375f64),
+            DynOrigin::Pan => Ok(14.0182618827594f64),
+            DynOrigin::Methone => Ok(1.4467459330188372f64),
+            DynOrigin::Pallene => Ok(2.2092393798038135f64),
+            DynOrigin::Polydeuces => Ok(1.21644039911468f64),
+            DynOrigin::Daphnis => Ok(3.86998657928563f64),
+            DynOrigin::Anthe => Ok(0.5f64),
+            DynOrigin::Aegaeon => Ok(0.32710663101885895f64),
+            DynOrigin::Ariel => Ok(578.8979066892539f64),
+            DynOrigin::Umbriel => Ok(584.7f64),
+            DynOrigin::Titania => Ok(788.9f64),
+            DynOrigin::Oberon => Ok(761.4f64),
+            DynOrigin::Miranda => Ok(235.81075794484337f64),
+            DynOrigin::Cordelia => Ok(13f64),
+            DynOrigin::Ophelia => Ok(15f64),
+            DynOrigin::Bianca => Ok(21f64),
+            DynOrigin::Cressida => Ok(31f64),
+            DynOrigin::Desdemona => Ok(27f64),
+            DynOrigin::Juliet => Ok(42f64),
+            DynOrigin::Portia => Ok(54f64),
+            DynOrigin::Rosalind => Ok(27f64),
+            DynOrigin::Belinda => Ok(33f64),
+            DynOrigin::Puck => Ok(77f64),
+            DynOrigin::Triton => Ok(1352.6f64),
+            DynOrigin::Nereid => Ok(170f64),
+            DynOrigin::Naiad => Ok(29f64),
+            DynOrigin::Thalassa => Ok(40f64),
+            DynOrigin::Despina => Ok(74f64),
+            DynOrigin::Galatea => Ok(79f64),
+            DynOrigin::Larissa => Ok(96f64),
+            DynOrigin::Proteus => Ok(208.88405769890488f64),
+            DynOrigin::Charon => Ok(606f64),
+            DynOrigin::Gaspra => Ok(5.926966476226717f64),
+            DynOrigin::Ida => Ok(13.47027754471227f64),
+            DynOrigin::Ceres => Ok(473.12499671081065f64),
+            DynOrigin::Vesta => Ok(264.6247447667379f64),
+            DynOrigin::Psyche => Ok(115.20942305732265f64),
+            DynOrigin::Lutetia => Ok(52.60721852481162f64),
+            DynOrigin::Eros => Ok(8.011701625649433f64),
+            DynOrigin::Davida => Ok(149.78368821134725f64),
+            DynOrigin::Mathilde => Ok(26.311827052523057f64),
+            DynOrigin::Steins => Ok(2.62288515376248f64),
+            DynOrigin::Toutatis => Ok(1.224864902497028f64),
+            DynOrigin::Itokawa => Ok(0.1600154151814451f64),
+            _ => Err(UndefinedOriginPropertyError {
+                origin: self.to_string(),
+                prop: "volumetric radius".to_string(),
+            }),
+        }
+    }
+}
+impl TryZonalHarmonics for DynOrigin {
+    fn try_j2(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        match self {
+            DynOrigin::Earth => Ok(0.0010826261738522227f64),
+            _ => Err(UndefinedOriginPropertyError {
+                origin: self.to_string(),
+                prop: "J2".to_string(),
+            }),
+        }
+    }
+    fn try_j3(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        match self {
+            DynOrigin::Earth => Ok(-0.0000025324105185677225f64),
+            _ => Err(UndefinedOriginPropertyError {
+                origin: self.to_string(),
+                prop: "J3".to_string(),
+            }),
+        }
+    }
+    fn try_j4(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        match self {
+            DynOrigin::Earth => Ok(-0.0000016198975999169731f64),
+            _ => Err(UndefinedOriginPropertyError {
+                origin: self.to_string(),
+                prop: "J4".to_string(),
+            }),
+        }
+    }
+}
 impl TryRotationalElements for DynOrigin {
     fn try_rotational_elements(&self, t: f64) -> Result<Elements, UndefinedOriginPropertyError> {
         match self {
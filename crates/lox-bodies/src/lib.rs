@@ -13,6 +13,7 @@ use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
 pub mod dynamic;
+pub mod ellipsoids;
 pub mod fundamental;
 #[allow(clippy::approx_constant)]
 mod generated;
@@ -48,6 +49,10 @@ pub trait TryTriaxialEllipsoid: Origin {
     fn try_radii(&self) -> Result<Radii, UndefinedOriginPropertyError>;
 }
 
+/// Bodies with a known shape.
+///
+/// The radii are taken from the PCK. For bodies for which the PCK only provides a mean radius, the
+/// shape is approximated as a sphere with all radii equal to the mean radius.
 pub trait TriaxialEllipsoid: Origin {
     fn radii(&self) -> Radii;
 }
@@ -118,6 +123,22 @@ impl<T: MeanRadius> TryMeanRadius for T {
     }
 }
 
+/// The volumetric mean radius `(a * b * c)^(1/3)`, i.e. the radius of the sphere with the same
+/// volume as the body's triaxial ellipsoid.
+pub trait TryVolumetricRadius: Origin {
+    fn try_volumetric_radius(&self) -> Result<f64, UndefinedOriginPropertyError>;
+}
+
+pub trait VolumetricRadius: Origin {
+    fn volumetric_radius(&self) -> f64;
+}
+
+impl<T: VolumetricRadius> TryVolumetricRadius for T {
+    fn try_volumetric_radius(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        Ok(self.volumetric_radius())
+    }
+}
+
 pub trait PointMass: Origin {
     fn gravitational_parameter(&self) -> f64;
 }
@@ -132,6 +153,40 @@ impl<T: PointMass> TryPointMass for T {
     }
 }
 
+/// Unnormalized low-order zonal harmonic coefficients of the gravity field.
+///
+/// The coefficients refer to the reference radius of the underlying gravity field model, which is
+/// close to but not necessarily equal to the equatorial radius of the body.
+pub trait ZonalHarmonics: Origin {
+    fn j2(&self) -> f64;
+
+    fn j3(&self) -> f64;
+
+    fn j4(&self) -> f64;
+}
+
+pub trait TryZonalHarmonics: Origin {
+    fn try_j2(&self) -> Result<f64, UndefinedOriginPropertyError>;
+
+    fn try_j3(&self) -> Result<f64, UndefinedOriginPropertyError>;
+
+    fn try_j4(&self) -> Result<f64, UndefinedOriginPropertyError>;
+}
+
+impl<T: ZonalHarmonics> TryZonalHarmonics for T {
+    fn try_j2(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        Ok(self.j2())
+    }
+
+    fn try_j3(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        Ok(self.j3())
+    }
+
+    fn try_j4(&self) -> Result<f64, UndefinedOriginPropertyError> {
+        Ok(self.j4())
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum RotationalElementType {
     RightAscension,
@@ -8,7 +8,7 @@
 use crate::dynamic::{DynOrigin, UnknownOriginId, UnknownOriginName};
 use crate::{
     Elements, Origin, TryMeanRadius, TryPointMass, TryRotationalElements, TrySpheroid,
-    TryTriaxialEllipsoid,
+    TryTriaxialEllipsoid, TryVolumetricRadius,
 };
 use crate::{Radii, UndefinedOriginPropertyError as RsUndefinedPropertyError};
 use lox_math::types::units::Seconds;
@@ -84,6 +84,10 @@ impl PyOrigin {
         Ok(self.0.try_mean_radius()?)
     }
 
+    pub fn volumetric_radius(&self) -> PyResult<f64> {
+        Ok(self.0.try_volumetric_radius()?)
+    }
+
     pub fn radii(&self) -> PyResult<Radii> {
         Ok(self.0.try_radii()?)
     }
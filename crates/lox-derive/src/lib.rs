@@ -16,7 +16,8 @@ fn generate_call_to_deserializer_for_covariance_matrix_kvn_type(
     quote! {
         match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
             None => Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                keyword: #expected_kvn_name.to_string()
+                keyword: #expected_kvn_name.to_string(),
+                line: lines.line_number(),
             }),
             Some(next_line) => {
                 let result = crate::ndm::kvn::parser::parse_kvn_covariance_matrix(
@@ -27,11 +28,9 @@ fn generate_call_to_deserializer_for_covariance_matrix_kvn_type(
                         // vector iterator to stop the iteration.
                         found: "".to_string(),
                         expected: "".to_string(),
+                        line: lines.line_number(),
                     },
-                    crate::ndm::kvn::KvnDeserializerErr::UnexpectedEndOfInput { keyword } => crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                        keyword
-                    },
-                    e => e,
+                    e => e.with_line(lines.line_number()),
                 })?;
 
                 Ok(result)
@@ -60,19 +59,19 @@ fn generate_call_to_deserializer_for_kvn_type(
                 "String" => quote! {
                     crate::ndm::kvn::parser::parse_kvn_string_line(
                         next_line
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x).with_line(line))?
                 },
                 "f64" | "NonNegativeDouble" | "NegativeDouble" | "PositiveDouble" => quote! {
                     crate::ndm::kvn::parser::parse_kvn_numeric_line(
                         next_line,
                         true, //@TODO
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x).with_line(line))?
                 },
                 "i32" | "u64" => quote! {
                     crate::ndm::kvn::parser::parse_kvn_integer_line(
                         next_line,
                         true, //@TODO
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x).with_line(line))?
                 },
                 // Assumes the match list here exhaustively matches the one from above
                 _ => unreachable!(),
@@ -82,7 +81,8 @@ fn generate_call_to_deserializer_for_kvn_type(
                 Ok(quote! {
                     match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
                         None => Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                            keyword: #expected_kvn_name.to_string()
+                            keyword: #expected_kvn_name.to_string(),
+                            line: lines.line_number(),
                         }),
                         Some(next_line) => {
                             let line_matches = crate::ndm::kvn::parser::kvn_line_matches_key(
@@ -91,6 +91,7 @@ fn generate_call_to_deserializer_for_kvn_type(
                             )?;
 
                             let result = if line_matches {
+                                let line = lines.line_number();
                                 let next_line = lines.next().unwrap();
 
                                 Ok(#parser #unpack_insert)
@@ -98,6 +99,7 @@ fn generate_call_to_deserializer_for_kvn_type(
                                 Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                                     found: next_line.to_string(),
                                     expected: #expected_kvn_name.to_string(),
+                                    line: lines.line_number(),
                                 })
                             };
 
@@ -108,6 +110,7 @@ fn generate_call_to_deserializer_for_kvn_type(
             } else {
                 Ok(quote! {
                    {
+                      let line = lines.line_number();
                       let next_line = lines.next().unwrap();
                       #parser #unpack_insert
                    }
@@ -117,7 +120,8 @@ fn generate_call_to_deserializer_for_kvn_type(
         "common::StateVectorAccType" => Ok(quote! {
             match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
                 None => Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                    keyword: #expected_kvn_name.to_string()
+                    keyword: #expected_kvn_name.to_string(),
+                    line: lines.line_number(),
                 }),
                 Some(next_line) => {
                     let result = crate::ndm::kvn::parser::parse_kvn_state_vector(
@@ -128,8 +132,37 @@ fn generate_call_to_deserializer_for_kvn_type(
                             // vector iterator to stop the iteration.
                             found: "".to_string(),
                             expected: "".to_string(),
+                            line: lines.line_number(),
+                        },
+                        e => e.with_line(lines.line_number()),
+                    }).map(|x| x.into());
+
+                    if result.is_ok() {
+                        let _ = lines.next().unwrap();
+                    }
+
+                    result
+                }
+            }
+        }),
+        "TdmObservation" => Ok(quote! {
+            match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
+                None => Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
+                    keyword: #expected_kvn_name.to_string(),
+                    line: lines.line_number(),
+                }),
+                Some(next_line) => {
+                    let result = crate::ndm::kvn::parser::parse_kvn_tdm_observation(
+                        next_line,
+                    ).map_err(|x| match crate::ndm::kvn::KvnDeserializerErr::from(x) {
+                        crate::ndm::kvn::KvnDeserializerErr::InvalidTdmObservationFormat { .. } => crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
+                            // This is empty because we just want to tell the
+                            // vector iterator to stop the iteration.
+                            found: "".to_string(),
+                            expected: "".to_string(),
+                            line: lines.line_number(),
                         },
-                        e => e,
+                        e => e.with_line(lines.line_number()),
                     }).map(|x| x.into());
 
                     if result.is_ok() {
@@ -148,7 +181,8 @@ fn generate_call_to_deserializer_for_kvn_type(
                     #type_name_new::deserialize(lines)
                 } else {
                     Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                          keyword: #expected_kvn_name.to_string()
+                          keyword: #expected_kvn_name.to_string(),
+                          line: lines.line_number(),
                     })
                 };
 
@@ -565,7 +599,8 @@ fn deserializer_for_struct_with_named_fields(
                         quote! {
                             match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
                                 None => Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                                    keyword: #expected_kvn_name.to_string()
+                                    keyword: #expected_kvn_name.to_string(),
+                                    line: lines.line_number(),
                                 })?,
                                 Some(next_line) => {
                                     let line_matches = crate::ndm::kvn::parser::kvn_line_matches_key(
@@ -579,6 +614,7 @@ fn deserializer_for_struct_with_named_fields(
                                         Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                                             found: next_line.to_string(),
                                             expected: #expected_kvn_name.to_string(),
+                                            line: lines.line_number(),
                                         })?
                                     }
                                 }
@@ -638,13 +674,15 @@ fn deserializer_for_struct_with_named_fields(
             ] {
                 let field_ident = syn::Ident::new(field, Span::call_site());
                 let type_ident = syn::Ident::new(field_type, Span::call_site());
+                let units_ident =
+                    syn::Ident::new(&field_type.replace("Type", "Units"), Span::call_site());
 
                 field_deserializers.push((
                     quote! {},
                     quote! {
                         #field_ident: #type_ident {
-                            base: covariance_matrix.#field_ident,
-                            units: None,
+                            base: covariance_matrix.#field_ident.value,
+                            units: covariance_matrix.#field_ident.unit.map(#units_ident),
                         },
                     },
                 ));
@@ -682,6 +720,21 @@ fn add_prefix_and_postfix_keyword_checks(
     match prefix_and_postfix_keyword {
         None => parser_to_wrap,
         Some((prefix_keyword, postfix_keyword)) => {
+            // Optional blocks are omitted at the end of the input as well, e.g. the covariance
+            // matrices of the last OEM segment
+            let end_of_input_handler = if is_field {
+                quote! { Default::default() }
+            } else {
+                quote! {
+                    Err(
+                        crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
+                            keyword: #prefix_keyword.to_string(),
+                            line: lines.line_number(),
+                        },
+                    )?
+                }
+            };
+
             let mismatch_handler = if is_field {
                 quote! { Default::default() }
             } else {
@@ -690,6 +743,7 @@ fn add_prefix_and_postfix_keyword_checks(
                         crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                             found: next_line.to_string(),
                             expected: #prefix_keyword.to_string(),
+                            line: lines.line_number(),
                         },
                     )?
                 }
@@ -698,11 +752,7 @@ fn add_prefix_and_postfix_keyword_checks(
             quote! {
 
                 match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
-                    None => Err(
-                        crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                            keyword: #prefix_keyword.to_string(),
-                        },
-                    )?,
+                    None => #end_of_input_handler,
 
                     Some(next_line) => {
                         let line_matches = crate::ndm::kvn::parser::kvn_line_matches_key(
@@ -719,6 +769,7 @@ fn add_prefix_and_postfix_keyword_checks(
                                 None =>  Err(
                                     crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
                                         keyword: #postfix_keyword.to_string(),
+                                        line: lines.line_number(),
                                     },
                                 )?,
 
@@ -735,6 +786,7 @@ fn add_prefix_and_postfix_keyword_checks(
                                             crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                                                 found: next_line.to_string(),
                                                 expected: #postfix_keyword.to_string(),
+                                                line: lines.line_number(),
                                             },
                                         )?
                                     }
@@ -762,10 +814,11 @@ fn deserializers_for_struct_with_unnamed_fields(
 
     if &type_name.to_string() == "EpochType" {
         return quote! {
+            let line = lines.line_number();
             Ok(#type_name (
                 crate::ndm::kvn::parser::parse_kvn_datetime_line(
                     lines.next().unwrap()
-                ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))
+                ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x).with_line(line))
                 .map(|x| x)?.full_value
             ))
         };
@@ -841,7 +894,7 @@ pub fn derive_kvn_deserialize(item: proc_macro::TokenStream) -> proc_macro::Toke
         impl #impl_generics crate::ndm::kvn::KvnDeserializer for #type_name #type_generics
         #where_clause
         {
-            fn deserialize<'a>(lines: &mut ::std::iter::Peekable<impl Iterator<Item = &'a str>>)
+            fn deserialize(lines: &mut crate::ndm::kvn::parser::KvnLines<'_>)
             -> Result<#type_name, crate::ndm::kvn::KvnDeserializerErr<String>> {
                 #struct_deserializer
             }
@@ -8,4 +8,36 @@
 
 //! Module cio exposes functions for calculating the Celestial Intermediate Origin (CIO) locator, s.
 
+use glam::DVec2;
+use lox_math::types::units::Radians;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::Tt;
+use lox_time::Time;
+
 pub mod s06;
+
+/// Calculates the Celestial Intermediate Origin (CIO) locator s in radians at `time`, given the
+/// (X, Y) coordinates of the Celestial Intermediate Pole (CIP), using the IAU 2006 precession and
+/// IAU 2000A nutation models.
+pub fn cio_locator_s(time: Time<Tt>, x: Radians, y: Radians) -> Radians {
+    s06::s(time.centuries_since_j2000(), DVec2::new(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_time::time;
+
+    use crate::cip::cip_xy;
+
+    use super::*;
+
+    #[test]
+    fn test_cio_locator_s_sofa() {
+        // Reference value from SOFA's `t_s06` at MJD 53736.0 TT.
+        let time = time!(Tt, 2006, 1, 1).unwrap();
+        let (x, y) = cip_xy(time, None);
+        let s = cio_locator_s(time, x, y);
+        assert_float_eq!(s, -1.220032213076463e-08, abs <= 1e-18);
+    }
+}
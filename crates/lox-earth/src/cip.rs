@@ -9,4 +9,48 @@
 //! Module cip exposes functions for calculating the position of the
 //! Celestial Intermediate Pole (CIP).
 
+use glam::DVec2;
+use lox_math::types::units::Radians;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::Tt;
+use lox_time::Time;
+
 pub mod xy06;
+
+/// Calculates the (X, Y) coordinates of the Celestial Intermediate Pole (CIP) in radians at `time`
+/// using the IAU 2006 precession and IAU 2000A nutation models.
+///
+/// If provided, the celestial pole offsets (dX, dY) published by the IERS are added to the modelled
+/// coordinates to account for the unmodelled free core nutation and model deficiencies.
+pub fn cip_xy(time: Time<Tt>, celestial_pole_offsets: Option<DVec2>) -> (Radians, Radians) {
+    let xy = xy06::xy(time.centuries_since_j2000()) + celestial_pole_offsets.unwrap_or_default();
+    (xy.x, xy.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_math::math::arcsec_to_rad;
+    use lox_time::time;
+
+    use super::*;
+
+    #[test]
+    fn test_cip_xy_sofa() {
+        // Reference values from SOFA's `t_xy06` at MJD 53736.0 TT.
+        let time = time!(Tt, 2006, 1, 1).unwrap();
+        let (x, y) = cip_xy(time, None);
+        assert_float_eq!(x, 0.0005791308486706011, abs <= 1e-15);
+        assert_float_eq!(y, 4.020579816732958e-05, abs <= 1e-16);
+    }
+
+    #[test]
+    fn test_cip_xy_celestial_pole_offsets() {
+        let time = Time::j2000(Tt);
+        let (x0, y0) = cip_xy(time, None);
+        let offsets = DVec2::new(arcsec_to_rad(0.3e-3), arcsec_to_rad(-0.1e-3));
+        let (x, y) = cip_xy(time, Some(offsets));
+        assert_float_eq!(x - x0, offsets.x, abs <= 1e-18);
+        assert_float_eq!(y - y0, offsets.y, abs <= 1e-18);
+    }
+}
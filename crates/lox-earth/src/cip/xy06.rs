@@ -18,10 +18,7 @@ use lox_bodies::{Earth, Jupiter, Mars, Mercury, Moon, Neptune, Saturn, Sun, Uran
 use lox_math::math::arcsec_to_rad;
 use lox_math::types::units::{JulianCenturies, Radians};
 
-mod amplitudes;
-mod luni_solar;
-mod planetary;
-mod polynomial;
+mod generated;
 
 const MAX_POWER_OF_T: usize = 5;
 
@@ -79,8 +76,8 @@ fn fundamental_args(centuries_since_j2000_tdb: JulianCenturies) -> FundamentalAr
 fn polynomial_components(powers_of_t: &PowersOfT) -> DVec2 {
     let mut result = DVec2::default();
     for (i, power_of_t) in powers_of_t.iter().enumerate().rev() {
-        result[0] += polynomial::COEFFICIENTS.x[i] * power_of_t;
-        result[1] += polynomial::COEFFICIENTS.y[i] * power_of_t;
+        result[0] += generated::POLYNOMIAL_X[i] * power_of_t;
+        result[1] += generated::POLYNOMIAL_Y[i] * power_of_t;
     }
     result
 }
@@ -96,10 +93,14 @@ fn nutation_components(
     let mut sin_cos = [0.0; 2];
 
     // The last amplitude chunk to be processed.
-    let mut last_amplitude_chunk_index = amplitudes::COEFFICIENTS.len();
+    let mut last_amplitude_chunk_index = generated::AMPLITUDES.len();
 
     // Calculate planetary nutation components.
-    for (freq_list_idx, freq_list) in planetary::FREQUENCY_LISTS.iter().enumerate().rev() {
+    for (freq_list_idx, freq_list) in generated::PLANETARY_FREQUENCY_LISTS
+        .iter()
+        .enumerate()
+        .rev()
+    {
         // Calculate argument functions.
         let mut arg = 0.0;
         for (i, freq) in freq_list.iter().enumerate() {
@@ -111,26 +112,30 @@ fn nutation_components(
         // The list of indices into the amplitudes array contains both luni-solar and planetary
         // indices. We offset by the number of luni-solar frequency lists to get the correct
         // planetary index.
-        let amplitude_indices_idx = freq_list_idx + luni_solar::N_FREQUENCY_LISTS;
-        let current_amplitude_chunk_idx = amplitudes::INDICES[amplitude_indices_idx];
+        let amplitude_indices_idx = freq_list_idx + generated::N_LUNI_SOLAR_FREQUENCY_LISTS;
+        let current_amplitude_chunk_idx = generated::AMPLITUDE_INDICES[amplitude_indices_idx];
 
         // Iterate backwards through the amplitudes of the current frequency chunk.
         for i in (current_amplitude_chunk_idx..=last_amplitude_chunk_index).rev() {
             // The index of the current amplitude within the chunk.
             let relative_amplitude_idx = i - current_amplitude_chunk_idx;
-            let axis = amplitudes::USAGE_XY[relative_amplitude_idx];
-            let trig_func = amplitudes::USAGE_SIN_COS[relative_amplitude_idx];
-            let power_of_t = amplitudes::USAGE_POWER_OF_T[relative_amplitude_idx];
+            let axis = generated::USAGE_XY[relative_amplitude_idx];
+            let trig_func = generated::USAGE_SIN_COS[relative_amplitude_idx];
+            let power_of_t = generated::USAGE_POWER_OF_T[relative_amplitude_idx];
 
             // Accumulate the component.
             result.planetary[axis] +=
-                amplitudes::COEFFICIENTS[i - 1] * sin_cos[trig_func] * powers_of_t[power_of_t];
+                generated::AMPLITUDES[i - 1] * sin_cos[trig_func] * powers_of_t[power_of_t];
         }
         last_amplitude_chunk_index = current_amplitude_chunk_idx - 1;
     }
 
     // Calculate luni-solar nutation components.
-    for (freq_list_idx, freq_list) in luni_solar::FREQUENCY_LISTS.iter().enumerate().rev() {
+    for (freq_list_idx, freq_list) in generated::LUNI_SOLAR_FREQUENCY_LISTS
+        .iter()
+        .enumerate()
+        .rev()
+    {
         // Calculate argument functions.
         let mut arg = 0.0;
         for (i, freq) in freq_list.iter().enumerate() {
@@ -143,19 +148,19 @@ fn nutation_components(
         // indices. We offset by the number of luni-solar frequency lists to get the correct
         // luni-solar index.
         let amplitude_indices_idx = freq_list_idx;
-        let current_amplitude_chunk_idx = amplitudes::INDICES[amplitude_indices_idx];
+        let current_amplitude_chunk_idx = generated::AMPLITUDE_INDICES[amplitude_indices_idx];
 
         // Iterate backwards through the amplitudes of the current frequency chunk.
         for i in (current_amplitude_chunk_idx..=last_amplitude_chunk_index).rev() {
             // The index of the current amplitude within the chunk.
             let relative_amplitude_idx = i - current_amplitude_chunk_idx;
-            let axis = amplitudes::USAGE_XY[relative_amplitude_idx];
-            let trig_func = amplitudes::USAGE_SIN_COS[relative_amplitude_idx];
-            let power_of_t = amplitudes::USAGE_POWER_OF_T[relative_amplitude_idx];
+            let axis = generated::USAGE_XY[relative_amplitude_idx];
+            let trig_func = generated::USAGE_SIN_COS[relative_amplitude_idx];
+            let power_of_t = generated::USAGE_POWER_OF_T[relative_amplitude_idx];
 
             // Accumulate the component.
             result.luni_solar[axis] +=
-                amplitudes::COEFFICIENTS[i - 1] * sin_cos[trig_func] * powers_of_t[power_of_t];
+                generated::AMPLITUDES[i - 1] * sin_cos[trig_func] * powers_of_t[power_of_t];
         }
         last_amplitude_chunk_index = current_amplitude_chunk_idx - 1;
     }
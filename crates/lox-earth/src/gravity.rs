@@ -0,0 +1,472 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module gravity provides the evaluation of spherical harmonic gravity fields.
+
+use glam::DVec3;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum GravityError {
+    #[error("degree {degree} exceeds the maximum degree {max_degree} of the gravity field")]
+    DegreeTooHigh { degree: usize, max_degree: usize },
+    #[error("order {order} exceeds the degree {degree}")]
+    OrderTooHigh { order: usize, degree: usize },
+}
+
+fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+/// A gravity field defined by fully normalised spherical harmonic coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GravityField {
+    gravitational_parameter: f64,
+    reference_radius: f64,
+    max_degree: usize,
+    c: Vec<f64>,
+    s: Vec<f64>,
+}
+
+impl GravityField {
+    /// Creates a point-mass gravity field which can hold coefficients up to `max_degree`.
+    pub fn new(gravitational_parameter: f64, reference_radius: f64, max_degree: usize) -> Self {
+        let len = index(max_degree, max_degree) + 1;
+        let mut c = vec![0.0; len];
+        c[0] = 1.0;
+        Self {
+            gravitational_parameter,
+            reference_radius,
+            max_degree,
+            c,
+            s: vec![0.0; len],
+        }
+    }
+
+    /// Sets the fully normalised coefficients `C_nm` and `S_nm`.
+    pub fn with_coefficients(
+        mut self,
+        degree: usize,
+        order: usize,
+        c: f64,
+        s: f64,
+    ) -> Result<Self, GravityError> {
+        check_degree_and_order(degree, order, self.max_degree)?;
+        let i = index(degree, order);
+        self.c[i] = c;
+        self.s[i] = s;
+        Ok(self)
+    }
+
+    /// Sets the unnormalised zonal coefficient `J_n = -C_n0`.
+    pub fn with_zonal(self, degree: usize, j: f64) -> Result<Self, GravityError> {
+        let c = -j / ((2 * degree + 1) as f64).sqrt();
+        self.with_coefficients(degree, 0, c, 0.0)
+    }
+
+    pub fn gravitational_parameter(&self) -> f64 {
+        self.gravitational_parameter
+    }
+
+    pub fn reference_radius(&self) -> f64 {
+        self.reference_radius
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Returns a builder for a [GravityEvaluator] which by default uses all coefficients.
+    pub fn evaluator(&self) -> GravityEvaluatorBuilder<'_> {
+        GravityEvaluatorBuilder::new(self)
+    }
+}
+
+fn check_degree_and_order(
+    degree: usize,
+    order: usize,
+    max_degree: usize,
+) -> Result<(), GravityError> {
+    if degree > max_degree {
+        return Err(GravityError::DegreeTooHigh { degree, max_degree });
+    }
+    if order > degree {
+        return Err(GravityError::OrderTooHigh { order, degree });
+    }
+    Ok(())
+}
+
+/// Builder for a [GravityEvaluator] truncated at a given degree and order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GravityEvaluatorBuilder<'a> {
+    field: &'a GravityField,
+    degree: usize,
+    order: Option<usize>,
+}
+
+impl<'a> GravityEvaluatorBuilder<'a> {
+    pub fn new(field: &'a GravityField) -> Self {
+        Self {
+            field,
+            degree: field.max_degree,
+            order: None,
+        }
+    }
+
+    pub fn with_degree(self, degree: usize) -> Self {
+        Self { degree, ..self }
+    }
+
+    /// Sets the maximum order, which defaults to the degree.
+    pub fn with_order(self, order: usize) -> Self {
+        Self {
+            order: Some(order),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<GravityEvaluator, GravityError> {
+        let degree = self.degree;
+        let order = self.order.unwrap_or(degree);
+        check_degree_and_order(degree, order, self.field.max_degree)?;
+        let len = index(degree, degree) + 1;
+        Ok(GravityEvaluator {
+            gravitational_parameter: self.field.gravitational_parameter,
+            reference_radius: self.field.reference_radius,
+            degree,
+            order,
+            c: self.field.c[..len].to_vec(),
+            s: self.field.s[..len].to_vec(),
+            cache: Cache::new(degree, order),
+        })
+    }
+}
+
+/// Intermediate results which only depend on a single spherical coordinate and are reused while
+/// that coordinate does not change between evaluations.
+#[derive(Clone, Debug, PartialEq)]
+struct Cache {
+    /// Key for `radius_powers`.
+    radius: f64,
+    /// `(R/r)^n`
+    radius_powers: Vec<f64>,
+    /// Key for `p` and `dp`.
+    sin_latitude: f64,
+    /// Fully normalised associated Legendre functions of the sine of the latitude.
+    p: Vec<f64>,
+    /// Derivatives of `p` with respect to the latitude.
+    dp: Vec<f64>,
+    /// `p` divided by the cosine of the latitude for orders `m >= 1`, which remains finite on the
+    /// polar axis because the tesseral and sectoral functions contain the factor `cos^m`.
+    p_sec: Vec<f64>,
+    /// Key for `cos_m` and `sin_m`.
+    longitude: f64,
+    /// `cos(m * longitude)`
+    cos_m: Vec<f64>,
+    /// `sin(m * longitude)`
+    sin_m: Vec<f64>,
+}
+
+impl Cache {
+    fn new(degree: usize, order: usize) -> Self {
+        let len = index(degree, degree) + 1;
+        Self {
+            radius: f64::NAN,
+            radius_powers: vec![0.0; degree + 1],
+            sin_latitude: f64::NAN,
+            p: vec![0.0; len],
+            dp: vec![0.0; len],
+            p_sec: vec![0.0; len],
+            longitude: f64::NAN,
+            cos_m: vec![0.0; order + 1],
+            sin_m: vec![0.0; order + 1],
+        }
+    }
+
+    fn update_radius(&mut self, radius: f64, reference_radius: f64) {
+        if radius == self.radius {
+            return;
+        }
+        self.radius = radius;
+        let ratio = reference_radius / radius;
+        let mut power = 1.0;
+        for p in self.radius_powers.iter_mut() {
+            *p = power;
+            power *= ratio;
+        }
+    }
+
+    fn update_latitude(&mut self, sin_latitude: f64, cos_latitude: f64, degree: usize) {
+        if sin_latitude == self.sin_latitude {
+            return;
+        }
+        self.sin_latitude = sin_latitude;
+        let (t, u) = (sin_latitude, cos_latitude);
+        let (p, dp, p_sec) = (&mut self.p, &mut self.dp, &mut self.p_sec);
+        p[0] = 1.0;
+        dp[0] = 0.0;
+        for n in 1..=degree {
+            let nf = n as f64;
+            // Sectoral terms
+            let i = index(n, n);
+            let j = index(n - 1, n - 1);
+            let k = if n == 1 {
+                3f64.sqrt()
+            } else {
+                ((2.0 * nf + 1.0) / (2.0 * nf)).sqrt()
+            };
+            p[i] = k * u * p[j];
+            dp[i] = k * (u * dp[j] - t * p[j]);
+            p_sec[i] = if n == 1 { k } else { k * u * p_sec[j] };
+            // Remaining terms
+            for m in 0..n {
+                let mf = m as f64;
+                let i = index(n, m);
+                let i1 = index(n - 1, m);
+                let a = ((2.0 * nf - 1.0) * (2.0 * nf + 1.0) / ((nf - mf) * (nf + mf))).sqrt();
+                let mut pnm = a * t * p[i1];
+                let mut dpnm = a * (u * p[i1] + t * dp[i1]);
+                let mut pnm_sec = a * t * p_sec[i1];
+                if n >= m + 2 {
+                    let i2 = index(n - 2, m);
+                    let b = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0)
+                        / ((nf - mf) * (nf + mf) * (2.0 * nf - 3.0)))
+                        .sqrt();
+                    pnm -= b * p[i2];
+                    dpnm -= b * dp[i2];
+                    pnm_sec -= b * p_sec[i2];
+                }
+                p[i] = pnm;
+                dp[i] = dpnm;
+                // The recursion is linear, so it also holds for the divided functions. They are
+                // only needed for m >= 1 where the sectoral seed above is exact.
+                p_sec[i] = if m == 0 { 0.0 } else { pnm_sec };
+            }
+        }
+    }
+
+    fn update_longitude(&mut self, longitude: f64) {
+        if longitude == self.longitude {
+            return;
+        }
+        self.longitude = longitude;
+        for (m, (cos_m, sin_m)) in self.cos_m.iter_mut().zip(&mut self.sin_m).enumerate() {
+            (*sin_m, *cos_m) = (m as f64 * longitude).sin_cos();
+        }
+    }
+}
+
+/// Evaluates the acceleration due to a [GravityField] truncated at a given degree and order.
+///
+/// The Legendre functions, powers of the radius ratio, and multiples of the longitude are cached
+/// and only recomputed when the corresponding spherical coordinate changes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GravityEvaluator {
+    gravitational_parameter: f64,
+    reference_radius: f64,
+    degree: usize,
+    order: usize,
+    c: Vec<f64>,
+    s: Vec<f64>,
+    cache: Cache,
+}
+
+impl GravityEvaluator {
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Computes the acceleration in km/s² at the given body-fixed position in km.
+    ///
+    /// The longitudinal derivative is evaluated with Legendre functions divided by the cosine of
+    /// the latitude, so that the acceleration is also well-defined on the polar axis.
+    pub fn acceleration(&mut self, position: DVec3) -> DVec3 {
+        let r = position.length();
+        let rho = (position.x.powi(2) + position.y.powi(2)).sqrt();
+        let sin_latitude = position.z / r;
+        let cos_latitude = rho / r;
+        let longitude = position.y.atan2(position.x);
+
+        let cache = &mut self.cache;
+        cache.update_radius(r, self.reference_radius);
+        cache.update_latitude(sin_latitude, cos_latitude, self.degree);
+        cache.update_longitude(longitude);
+
+        let mut du_dr = 0.0;
+        let mut du_dlat = 0.0;
+        let mut du_dlon = 0.0;
+        for n in 0..=self.degree {
+            let power = cache.radius_powers[n];
+            let mut sum_r = 0.0;
+            let mut sum_lat = 0.0;
+            let mut sum_lon = 0.0;
+            for m in 0..=n.min(self.order) {
+                let i = index(n, m);
+                let (cos_m, sin_m) = (cache.cos_m[m], cache.sin_m[m]);
+                let a = self.c[i] * cos_m + self.s[i] * sin_m;
+                sum_r += cache.p[i] * a;
+                sum_lat += cache.dp[i] * a;
+                sum_lon += m as f64 * cache.p_sec[i] * (self.s[i] * cos_m - self.c[i] * sin_m);
+            }
+            du_dr -= (n + 1) as f64 * power * sum_r;
+            du_dlat += power * sum_lat;
+            du_dlon += power * sum_lon;
+        }
+        let mu_r = self.gravitational_parameter / r;
+        du_dr *= mu_r / r;
+        du_dlat *= mu_r;
+        du_dlon *= mu_r;
+
+        // `du_dlon` is the derivative with respect to the longitude divided by the cosine of the
+        // latitude, i.e. the east component of the gradient times `r`
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+        let north = position.z / r.powi(2) * du_dlat;
+        du_dr / r * position
+            + DVec3::new(
+                -north * cos_lon - du_dlon / r * sin_lon,
+                -north * sin_lon + du_dlon / r * cos_lon,
+                rho / r.powi(2) * du_dlat,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::*;
+
+    const MU: f64 = 398600.4418;
+    const R: f64 = 6378.137;
+    const J2: f64 = 1.08262668e-3;
+
+    fn field() -> GravityField {
+        GravityField::new(MU, R, 4)
+            .with_zonal(2, J2)
+            .unwrap()
+            .with_coefficients(2, 2, 2.43914352398e-6, -1.40016683654e-6)
+            .unwrap()
+            .with_coefficients(3, 0, 9.57161207093e-7, 0.0)
+            .unwrap()
+            .with_coefficients(3, 1, 2.03046201047e-6, 2.48200415856e-7)
+            .unwrap()
+            .with_coefficients(4, 0, 5.39965866638e-7, 0.0)
+            .unwrap()
+    }
+
+    fn j2_acceleration(r: DVec3) -> DVec3 {
+        let rn = r.length();
+        let k = -1.5 * J2 * MU * R.powi(2) / rn.powi(5);
+        let z2 = 5.0 * r.z.powi(2) / rn.powi(2);
+        -MU * r / rn.powi(3) + k * DVec3::new(r.x * (1.0 - z2), r.y * (1.0 - z2), r.z * (3.0 - z2))
+    }
+
+    #[test]
+    fn test_gravity_j2() {
+        let mut evaluator = field()
+            .evaluator()
+            .with_degree(2)
+            .with_order(0)
+            .build()
+            .unwrap();
+        for r in [
+            DVec3::new(7000.0, 0.0, 0.0),
+            DVec3::new(-1200.0, 6400.0, 3100.0),
+            DVec3::new(300.0, -500.0, -7100.0),
+        ] {
+            let act = evaluator.acceleration(r);
+            let exp = j2_acceleration(r);
+            assert_float_eq!(act.to_array(), exp.to_array(), abs_all <= 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_gravity_polar_axis() {
+        let field = field();
+        let mut j2 = field
+            .evaluator()
+            .with_degree(2)
+            .with_order(0)
+            .build()
+            .unwrap();
+        let mut full = field.evaluator().build().unwrap();
+        for z in [7000.0, -7000.0] {
+            let r = DVec3::new(0.0, 0.0, z);
+            assert_float_eq!(
+                j2.acceleration(r).to_array(),
+                j2_acceleration(r).to_array(),
+                abs_all <= 1e-15
+            );
+            // The tesseral terms cause a horizontal acceleration on the polar axis, which must
+            // be the limit of the acceleration close to the axis from any direction
+            let act = full.acceleration(r);
+            assert!(act.x != 0.0 && act.y != 0.0);
+            for offset in [DVec3::new(1e-9, 0.0, 0.0), DVec3::new(-1e-9, -1e-9, 0.0)] {
+                let exp = full.acceleration(r + offset);
+                assert_float_eq!(act.to_array(), exp.to_array(), abs_all <= 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gravity_point_mass() {
+        let mut evaluator = field().evaluator().with_degree(0).build().unwrap();
+        let r = DVec3::new(4000.0, 4000.0, 3000.0);
+        let exp = -MU * r / r.length().powi(3);
+        assert_float_eq!(
+            evaluator.acceleration(r).to_array(),
+            exp.to_array(),
+            abs_all <= 1e-16
+        );
+    }
+
+    #[test]
+    fn test_gravity_caching() {
+        let field = field();
+        let r1 = DVec3::new(-1200.0, 6400.0, 3100.0);
+        // Same latitude and radius, different longitude
+        let r2 = DVec3::new(6400.0, 1200.0, 3100.0);
+        let r3 = DVec3::new(300.0, -500.0, -7100.0);
+        let fresh = |r: DVec3| field.evaluator().build().unwrap().acceleration(r);
+
+        let mut evaluator = field.evaluator().build().unwrap();
+        for r in [r1, r1, r2, r3, r1, r2] {
+            assert_eq!(evaluator.acceleration(r), fresh(r));
+        }
+    }
+
+    #[test]
+    fn test_gravity_evaluator_errors() {
+        let field = field();
+        assert_eq!(
+            field.evaluator().with_degree(5).build(),
+            Err(GravityError::DegreeTooHigh {
+                degree: 5,
+                max_degree: 4
+            })
+        );
+        assert_eq!(
+            field.evaluator().with_degree(2).with_order(3).build(),
+            Err(GravityError::OrderTooHigh {
+                order: 3,
+                degree: 2
+            })
+        );
+        assert_eq!(
+            GravityField::new(MU, R, 2).with_coefficients(3, 0, 0.0, 0.0),
+            Err(GravityError::DegreeTooHigh {
+                degree: 3,
+                max_degree: 2
+            })
+        );
+    }
+}
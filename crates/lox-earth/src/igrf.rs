@@ -0,0 +1,443 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module igrf provides the International Geomagnetic Reference Field (IGRF), a spherical
+//! harmonic model of the Earth's main magnetic field.
+//!
+//! The Gauss coefficients are read from the coefficient files published by IAGA, e.g.
+//! `igrf13coeffs.txt`.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use glam::DVec3;
+use thiserror::Error;
+
+use lox_time::julian_dates::JulianDate;
+
+/// The reference radius of the IGRF model in km.
+pub const IGRF_REFERENCE_RADIUS: f64 = 6371.2;
+
+/// The number of years for which the secular variation may be used to extrapolate the model
+/// beyond its last epoch.
+const SV_VALIDITY: f64 = 5.0;
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum IgrfError {
+    #[error("could not read IGRF coefficient file `{path}`: {message}")]
+    Io { path: String, message: String },
+    #[error("the IGRF coefficient file does not contain a `g/h n m` header")]
+    MissingHeader,
+    #[error("invalid IGRF coefficient file at line {line}: {message}")]
+    InvalidLine { line: usize, message: String },
+    #[error("year {year} is outside the validity of the model from {start} to {end}")]
+    OutOfRange { year: f64, start: f64, end: f64 },
+}
+
+/// The frame in which the magnetic field vector is expressed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MagneticFieldFrame {
+    /// Cartesian axes of the Earth-fixed frame.
+    #[default]
+    BodyFixed,
+    /// Local geocentric north, east, and down (towards the centre of the Earth).
+    NorthEastDown,
+}
+
+/// Schmidt semi-normalised Gauss coefficients in nT up to a maximum degree.
+#[derive(Clone, Debug, PartialEq)]
+struct GaussCoefficients {
+    g: Vec<f64>,
+    h: Vec<f64>,
+}
+
+impl GaussCoefficients {
+    fn zeros(degree: usize) -> Self {
+        let n = index(degree, degree) + 1;
+        Self {
+            g: vec![0.0; n],
+            h: vec![0.0; n],
+        }
+    }
+}
+
+fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+/// The International Geomagnetic Reference Field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Igrf {
+    degree: usize,
+    epochs: Vec<f64>,
+    coefficients: Vec<GaussCoefficients>,
+    secular_variation: GaussCoefficients,
+}
+
+impl Igrf {
+    /// Reads an IGRF model from a coefficient file in the standard IAGA format.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, IgrfError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|err| IgrfError::Io {
+            path: path.to_string_lossy().to_string(),
+            message: err.to_string(),
+        })?;
+        contents.parse()
+    }
+
+    /// The maximum degree of the spherical harmonic expansion.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The epochs of the main-field models as decimal years.
+    pub fn epochs(&self) -> &[f64] {
+        &self.epochs
+    }
+
+    /// Returns the range of decimal years for which the model is valid.
+    pub fn validity(&self) -> (f64, f64) {
+        let start = self.epochs[0];
+        let end = self.epochs[self.epochs.len() - 1] + SV_VALIDITY;
+        (start, end)
+    }
+
+    fn coefficients_at(&self, year: f64) -> Result<GaussCoefficients, IgrfError> {
+        let (start, end) = self.validity();
+        if !(start..=end).contains(&year) {
+            return Err(IgrfError::OutOfRange { year, start, end });
+        }
+        let last = self.epochs.len() - 1;
+        let mut coefficients = GaussCoefficients::zeros(self.degree);
+        if year >= self.epochs[last] {
+            let dt = year - self.epochs[last];
+            let main = &self.coefficients[last];
+            let sv = &self.secular_variation;
+            for i in 0..coefficients.g.len() {
+                coefficients.g[i] = main.g[i] + dt * sv.g[i];
+                coefficients.h[i] = main.h[i] + dt * sv.h[i];
+            }
+        } else {
+            let i = self.epochs.partition_point(|&epoch| epoch <= year) - 1;
+            let (t0, t1) = (self.epochs[i], self.epochs[i + 1]);
+            let w = (year - t0) / (t1 - t0);
+            let (c0, c1) = (&self.coefficients[i], &self.coefficients[i + 1]);
+            for j in 0..coefficients.g.len() {
+                coefficients.g[j] = c0.g[j] + w * (c1.g[j] - c0.g[j]);
+                coefficients.h[j] = c0.h[j] + w * (c1.h[j] - c0.h[j]);
+            }
+        }
+        Ok(coefficients)
+    }
+
+    /// Computes the magnetic field vector in nT at the given decimal year and Earth-fixed
+    /// position in km.
+    ///
+    /// Between epochs the coefficients are interpolated linearly, after the last epoch they are
+    /// extrapolated with the secular variation.
+    pub fn field_at_year(
+        &self,
+        year: f64,
+        position: DVec3,
+        frame: MagneticFieldFrame,
+    ) -> Result<DVec3, IgrfError> {
+        let coefficients = self.coefficients_at(year)?;
+
+        let r = position.length();
+        let colatitude = (position.z / r).clamp(-1.0, 1.0).acos();
+        let longitude = position.y.atan2(position.x);
+        let (sin_theta, cos_theta) = colatitude.sin_cos();
+        // Avoid the singularity of the east component at the poles.
+        let sin_theta_safe = sin_theta.max(1e-12);
+
+        let (p, dp) = legendre(self.degree, cos_theta, sin_theta);
+
+        let mut b_r = 0.0;
+        let mut b_theta = 0.0;
+        let mut b_phi = 0.0;
+        for n in 1..=self.degree {
+            let ratio = (IGRF_REFERENCE_RADIUS / r).powi(n as i32 + 2);
+            for m in 0..=n {
+                let i = index(n, m);
+                let (g, h) = (coefficients.g[i], coefficients.h[i]);
+                let (sin_m, cos_m) = (m as f64 * longitude).sin_cos();
+                let a = g * cos_m + h * sin_m;
+                b_r += (n + 1) as f64 * ratio * a * p[i];
+                b_theta -= ratio * a * dp[i];
+                b_phi += ratio * m as f64 * (g * sin_m - h * cos_m) * p[i] / sin_theta_safe;
+            }
+        }
+
+        let ned = DVec3::new(-b_theta, b_phi, -b_r);
+        Ok(match frame {
+            MagneticFieldFrame::NorthEastDown => ned,
+            MagneticFieldFrame::BodyFixed => {
+                let (sin_phi, cos_phi) = longitude.sin_cos();
+                let north = DVec3::new(-cos_theta * cos_phi, -cos_theta * sin_phi, sin_theta);
+                let east = DVec3::new(-sin_phi, cos_phi, 0.0);
+                let down = -position / r;
+                ned.x * north + ned.y * east + ned.z * down
+            }
+        })
+    }
+
+    /// Computes the magnetic field vector in nT at the given time and Earth-fixed position in km.
+    pub fn field(
+        &self,
+        time: impl JulianDate,
+        position: DVec3,
+        frame: MagneticFieldFrame,
+    ) -> Result<DVec3, IgrfError> {
+        let year = 2000.0 + time.days_since_j2000() / 365.25;
+        self.field_at_year(year, position, frame)
+    }
+}
+
+/// Computes the Schmidt semi-normalised associated Legendre functions and their derivatives with
+/// respect to the colatitude.
+fn legendre(degree: usize, cos_theta: f64, sin_theta: f64) -> (Vec<f64>, Vec<f64>) {
+    let len = index(degree, degree) + 1;
+    let mut p = vec![0.0; len];
+    let mut dp = vec![0.0; len];
+    p[0] = 1.0;
+    for n in 1..=degree {
+        let nf = n as f64;
+        // Sectoral terms
+        let i = index(n, n);
+        let j = index(n - 1, n - 1);
+        let k = if n == 1 {
+            1.0
+        } else {
+            (1.0 - 1.0 / (2.0 * nf)).sqrt()
+        };
+        p[i] = k * sin_theta * p[j];
+        dp[i] = k * (cos_theta * p[j] + sin_theta * dp[j]);
+        // Remaining terms
+        for m in 0..n {
+            let mf = m as f64;
+            let i = index(n, m);
+            let i1 = index(n - 1, m);
+            let norm = (nf * nf - mf * mf).sqrt();
+            let mut pnm = (2.0 * nf - 1.0) * cos_theta * p[i1];
+            let mut dpnm = (2.0 * nf - 1.0) * (cos_theta * dp[i1] - sin_theta * p[i1]);
+            if n >= 2 && m <= n - 2 {
+                let i2 = index(n - 2, m);
+                let k = ((nf - 1.0).powi(2) - mf * mf).sqrt();
+                pnm -= k * p[i2];
+                dpnm -= k * dp[i2];
+            }
+            p[i] = pnm / norm;
+            dp[i] = dpnm / norm;
+        }
+    }
+    (p, dp)
+}
+
+impl FromStr for Igrf {
+    type Err = IgrfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut epochs: Option<Vec<f64>> = None;
+        let mut rows: Vec<(bool, usize, usize, Vec<f64>)> = vec![];
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let invalid = |message: String| IgrfError::InvalidLine {
+                line: line_no,
+                message,
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("c/s") {
+                continue;
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if line.starts_with("g/h") {
+                if tokens.len() < 4 {
+                    return Err(invalid(format!(
+                        "expected at least 4 header columns but found {}",
+                        tokens.len()
+                    )));
+                }
+                // The last column contains the secular variation, e.g. `2020-25`
+                let parsed: Result<Vec<f64>, _> = tokens[3..tokens.len() - 1]
+                    .iter()
+                    .map(|t| t.parse::<f64>())
+                    .collect();
+                let parsed = parsed.map_err(|err| invalid(format!("invalid epoch: {}", err)))?;
+                if parsed.is_empty() {
+                    return Err(invalid("no epochs found".to_string()));
+                }
+                epochs = Some(parsed);
+                continue;
+            }
+            let Some(epochs) = &epochs else {
+                return Err(IgrfError::MissingHeader);
+            };
+            if tokens.len() != epochs.len() + 4 {
+                return Err(invalid(format!(
+                    "expected {} columns but found {}",
+                    epochs.len() + 4,
+                    tokens.len()
+                )));
+            }
+            let is_g = match tokens[0] {
+                "g" => true,
+                "h" => false,
+                other => return Err(invalid(format!("unknown coefficient type `{}`", other))),
+            };
+            let n: usize = tokens[1]
+                .parse()
+                .map_err(|err| invalid(format!("invalid degree: {}", err)))?;
+            let m: usize = tokens[2]
+                .parse()
+                .map_err(|err| invalid(format!("invalid order: {}", err)))?;
+            if n == 0 || m > n || (!is_g && m == 0) {
+                return Err(invalid(format!("invalid degree and order ({}, {})", n, m)));
+            }
+            let values: Result<Vec<f64>, _> =
+                tokens[3..].iter().map(|t| t.parse::<f64>()).collect();
+            let values = values.map_err(|err| invalid(format!("invalid coefficient: {}", err)))?;
+            rows.push((is_g, n, m, values));
+        }
+        let epochs = epochs.ok_or(IgrfError::MissingHeader)?;
+        let degree = rows.iter().map(|(_, n, _, _)| *n).max().unwrap_or(0);
+        let mut coefficients = vec![GaussCoefficients::zeros(degree); epochs.len()];
+        let mut secular_variation = GaussCoefficients::zeros(degree);
+        for (is_g, n, m, values) in rows {
+            let i = index(n, m);
+            for (c, value) in coefficients
+                .iter_mut()
+                .chain(std::iter::once(&mut secular_variation))
+                .zip(values)
+            {
+                if is_g {
+                    c.g[i] = value;
+                } else {
+                    c.h[i] = value;
+                }
+            }
+        }
+        Ok(Self {
+            degree,
+            epochs,
+            coefficients,
+            secular_variation,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_time::time;
+    use lox_time::time_scales::Tt;
+    use lox_time::Time;
+
+    use super::*;
+
+    const DIPOLE: &str = "g/h n m 2020.0 2020-25\ng 1 0 -30000.0 0.0\n";
+
+    fn excerpt() -> Igrf {
+        include_str!("../../../data/igrf13_excerpt.txt")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_igrf_parse() {
+        let igrf = excerpt();
+        assert_eq!(igrf.degree(), 3);
+        assert_eq!(igrf.epochs(), &[2015.0, 2020.0]);
+        assert_eq!(igrf.validity(), (2015.0, 2025.0));
+    }
+
+    #[test]
+    fn test_igrf_dipole() {
+        let igrf: Igrf = DIPOLE.parse().unwrap();
+        let a = IGRF_REFERENCE_RADIUS;
+        let ned = |position: DVec3| {
+            igrf.field_at_year(2020.0, position, MagneticFieldFrame::NorthEastDown)
+                .unwrap()
+        };
+
+        // At the equator the field points north
+        let b = ned(DVec3::new(a, 0.0, 0.0));
+        assert_float_eq!(b.x, 30000.0, rel <= 1e-12);
+        assert_float_eq!(b.y, 0.0, abs <= 1e-9);
+        assert_float_eq!(b.z, 0.0, abs <= 1e-9);
+
+        // At the north pole the field points down with twice the magnitude
+        let b = ned(DVec3::new(0.0, 0.0, a));
+        assert_float_eq!(b.z, 60000.0, rel <= 1e-12);
+
+        // The field decreases with the cube of the distance
+        let b = ned(DVec3::new(0.0, 2.0 * a, 0.0));
+        assert_float_eq!(b.x, 30000.0 / 8.0, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_igrf_frames() {
+        let igrf: Igrf = DIPOLE.parse().unwrap();
+        let position = DVec3::new(0.0, -IGRF_REFERENCE_RADIUS, 0.0);
+        let b = igrf
+            .field_at_year(2020.0, position, MagneticFieldFrame::BodyFixed)
+            .unwrap();
+        assert_float_eq!(b.to_array(), [0.0, 0.0, 30000.0], abs_all <= 1e-9);
+    }
+
+    #[test]
+    fn test_igrf_equator_magnitude() {
+        let igrf = excerpt();
+        let time = time!(Tt, 2022, 1, 1).unwrap();
+        let position = DVec3::new(6378.137, 0.0, 0.0);
+        let b = igrf
+            .field(time, position, MagneticFieldFrame::NorthEastDown)
+            .unwrap();
+        let b_body_fixed = igrf
+            .field(time, position, MagneticFieldFrame::BodyFixed)
+            .unwrap();
+        assert!((25000.0..40000.0).contains(&b.length()));
+        assert_float_eq!(b.length(), b_body_fixed.length(), rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_igrf_time_dependence() {
+        let igrf = excerpt();
+        let c = igrf.coefficients_at(2017.5).unwrap();
+        assert_float_eq!(c.g[1], (-29441.46 + -29404.8) / 2.0, rel <= 1e-12);
+        let c = igrf.coefficients_at(2022.0).unwrap();
+        assert_float_eq!(c.h[2], 4652.5 + 2.0 * -25.9, rel <= 1e-12);
+        assert_eq!(
+            igrf.coefficients_at(2026.0),
+            Err(IgrfError::OutOfRange {
+                year: 2026.0,
+                start: 2015.0,
+                end: 2025.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_igrf_invalid() {
+        assert_eq!(
+            "g 1 0 -30000.0 0.0".parse::<Igrf>(),
+            Err(IgrfError::MissingHeader)
+        );
+        assert!(matches!(
+            "g/h n m 2020.0 2020-25\nx 1 0 -30000.0 0.0".parse::<Igrf>(),
+            Err(IgrfError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            "# IGRF\ng/h n m".parse::<Igrf>(),
+            Err(IgrfError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            "g/h".parse::<Igrf>(),
+            Err(IgrfError::InvalidLine { line: 1, .. })
+        ));
+    }
+}
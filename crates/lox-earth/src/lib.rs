@@ -9,8 +9,11 @@
 pub mod cio;
 pub mod cip;
 pub mod coordinate_transformations;
+pub mod gravity;
+pub mod igrf;
 pub mod nutation;
 pub mod rotation_angle;
+pub mod sidereal_time;
 #[allow(dead_code)]
 pub mod tides;
 pub mod tio;
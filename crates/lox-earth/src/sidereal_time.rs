@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module sidereal_time exposes functions for calculating Greenwich and local sidereal time and the
+//! equation of time.
+
+use lox_bodies::Earth;
+use lox_math::math::{arcsec_to_rad, mod_two_pi, wrap_pi};
+use lox_math::types::units::{Days, JulianCenturies, Radians};
+
+use crate::rotation_angle::RotationAngle;
+
+/// Computes the Greenwich Mean Sidereal Time (GMST) in radians in the range [0, 2π), consistent
+/// with the IAU 2006 precession model and the IAU 2000 Earth Rotation Angle.
+pub fn greenwich_mean_sidereal_time_06(
+    days_since_j2000_ut1: Days,
+    centuries_since_j2000_tt: JulianCenturies,
+) -> Radians {
+    let t = centuries_since_j2000_tt;
+    let era = Earth::rotation_angle_00(days_since_j2000_ut1);
+    let arcsec = fast_polynomial::poly_array(
+        t,
+        &[
+            0.014506,
+            4612.156534,
+            1.3915817,
+            -0.00000044,
+            -0.000029956,
+            -0.0000000368,
+        ],
+    );
+    mod_two_pi(era + arcsec_to_rad(arcsec))
+}
+
+/// Computes the local sidereal time in radians in the range [0, 2π) from the Greenwich sidereal
+/// time `gmst` and the observer's `east_longitude`.
+///
+/// Longitudes are positive east of Greenwich, i.e. western longitudes must be passed as negative
+/// values.
+pub fn local_sidereal_time(gmst: Radians, east_longitude: Radians) -> Radians {
+    mod_two_pi(gmst + east_longitude)
+}
+
+/// Computes the equation of time, i.e. apparent minus mean solar time, as an angle in radians in
+/// the range [-π, π).
+///
+/// The result is positive when a sundial is ahead of the clock. The apparent position of the Sun
+/// is approximated with the low-precision formulae of the Astronomical Almanac, which are accurate
+/// to a few seconds of time between 1950 and 2050.
+pub fn equation_of_time(days_since_j2000_tt: Days) -> Radians {
+    let n = days_since_j2000_tt;
+    let mean_longitude = (280.460 + 0.9856474 * n).to_radians();
+    let mean_anomaly = (357.528 + 0.9856003 * n).to_radians();
+    let ecliptic_longitude = mean_longitude
+        + 1.915f64.to_radians() * mean_anomaly.sin()
+        + 0.020f64.to_radians() * (2.0 * mean_anomaly).sin();
+    let obliquity = (23.439 - 0.0000004 * n).to_radians();
+    let right_ascension = f64::atan2(
+        obliquity.cos() * ecliptic_longitude.sin(),
+        ecliptic_longitude.cos(),
+    );
+    wrap_pi(mean_longitude - right_ascension)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_greenwich_mean_sidereal_time_06() {
+        // Reference value from SOFA's `t_gmst06` at MJD 53736.0 (UT1 and TT).
+        let days = 2191.5;
+        let centuries = days / 36525.0;
+        let actual = greenwich_mean_sidereal_time_06(days, centuries);
+        assert_float_eq!(actual, 1.7541749718700912, abs <= 1e-12);
+    }
+
+    #[rstest]
+    #[case::east(1.0, 0.5, 1.5)]
+    #[case::west(1.0, -1.5, TAU - 0.5)]
+    #[case::wrap(6.0, 1.0, 7.0 - TAU)]
+    #[case::full_turn(0.0, TAU, 0.0)]
+    #[case::tiny_negative(0.0, -1e-17, 0.0)]
+    fn test_local_sidereal_time(
+        #[case] gmst: Radians,
+        #[case] east_longitude: Radians,
+        #[case] expected: Radians,
+    ) {
+        let actual = local_sidereal_time(gmst, east_longitude);
+        assert_float_eq!(actual, expected, abs <= 1e-15);
+        assert!((0.0..TAU).contains(&actual));
+    }
+
+    #[rstest]
+    // 2024-02-11T12:00 TT, the annual minimum of about -14.2 minutes.
+    #[case::february(8807.0, -14.2)]
+    // 2024-05-14T12:00 TT, a local maximum of about +3.7 minutes.
+    #[case::may(8900.0, 3.7)]
+    // 2024-11-03T12:00 TT, the annual maximum of about +16.4 minutes.
+    #[case::november(9073.0, 16.4)]
+    fn test_equation_of_time(#[case] days_since_j2000_tt: Days, #[case] expected_minutes: f64) {
+        let minutes = equation_of_time(days_since_j2000_tt) / TAU * 1440.0;
+        assert_float_eq!(minutes, expected_minutes, abs <= 0.2);
+    }
+}
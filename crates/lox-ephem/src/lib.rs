@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use lox_bodies::{DynOrigin, NaifId, Origin};
+use lox_math::constants::f64::physics::SPEED_OF_LIGHT;
 use lox_math::types::julian_dates::Epoch;
 
 #[cfg(feature = "python")]
@@ -6,19 +10,164 @@ pub mod spk;
 
 pub(crate) type Position = (f64, f64, f64);
 pub(crate) type Velocity = (f64, f64, f64);
-pub(crate) type Body = i32;
+
+/// NAIF id of an ephemeris object, i.e. a body or barycenter whose trajectory is tabulated in an
+/// ephemeris.
+///
+/// This is distinct from the NAIF ids of reference frames which are also stored in ephemeris
+/// files but must never be used to query states.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct EphemerisId(pub i32);
+
+impl From<i32> for EphemerisId {
+    fn from(id: i32) -> Self {
+        EphemerisId(id)
+    }
+}
+
+impl From<EphemerisId> for i32 {
+    fn from(id: EphemerisId) -> Self {
+        id.0
+    }
+}
+
+impl From<NaifId> for EphemerisId {
+    fn from(id: NaifId) -> Self {
+        EphemerisId(id.0)
+    }
+}
+
+impl From<DynOrigin> for EphemerisId {
+    fn from(origin: DynOrigin) -> Self {
+        origin.id().into()
+    }
+}
+
+/// The direction of the signal for which [Ephemeris::position_apparent] corrects the light time.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LightTimeCorrection {
+    /// The observer receives a signal at the epoch which the target emitted one light time
+    /// earlier, e.g. for optical observations.
+    #[default]
+    Reception,
+    /// The observer transmits a signal at the epoch which reaches the target one light time
+    /// later, e.g. for commanding or the uplink of a radar measurement.
+    Transmission,
+}
+
+/// The maximum number of light-time iterations. For solar system distances, each iteration gains
+/// about four digits, i.e. the light time is accurate to a few metres after two iterations and
+/// converges to the tolerance within five.
+const MAX_LIGHT_TIME_ITERATIONS: usize = 10;
+
+/// The convergence threshold of the light-time iteration in seconds.
+const LIGHT_TIME_TOLERANCE: f64 = 1e-12;
 
 pub trait Ephemeris {
     type Error: std::error::Error;
 
-    fn position(&self, epoch: Epoch, origin: Body, target: Body) -> Result<Position, Self::Error>;
-    fn velocity(&self, epoch: Epoch, origin: Body, target: Body) -> Result<Velocity, Self::Error>;
+    fn position(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        target: EphemerisId,
+    ) -> Result<Position, Self::Error>;
+    fn velocity(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        target: EphemerisId,
+    ) -> Result<Velocity, Self::Error>;
     fn state(
         &self,
         epoch: Epoch,
-        origin: Body,
-        target: Body,
+        origin: EphemerisId,
+        target: EphemerisId,
     ) -> Result<(Position, Velocity), Self::Error>;
+
+    /// Returns the states of all `targets` relative to `origin` at `epoch`.
+    ///
+    /// Unlike [`Ephemeris::state`], the targets do not need to be tabulated relative to `origin`
+    /// directly. The state of each target is the sum of the states along the path between
+    /// `origin` and the target as returned by [`path_from_ids`]. Each leg of these paths is only
+    /// queried once, e.g. the leg between the Solar System barycenter and the Earth-Moon
+    /// barycenter is shared by the Earth and the Moon.
+    fn states_at(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        targets: &[EphemerisId],
+    ) -> Result<Vec<(Position, Velocity)>, Self::Error> {
+        let mut legs: HashMap<(i32, i32), (Position, Velocity)> = HashMap::new();
+        targets
+            .iter()
+            .map(|target| {
+                let mut position = (0.0, 0.0, 0.0);
+                let mut velocity = (0.0, 0.0, 0.0);
+                for leg in path_from_ids(origin.0, target.0).windows(2) {
+                    let key = (leg[0], leg[1]);
+                    let (p, v) = match legs.get(&key) {
+                        Some(&state) => state,
+                        None => {
+                            let state = self.state(epoch, leg[0].into(), leg[1].into())?;
+                            legs.insert(key, state);
+                            state
+                        }
+                    };
+                    position = (position.0 + p.0, position.1 + p.1, position.2 + p.2);
+                    velocity = (velocity.0 + v.0, velocity.1 + v.1, velocity.2 + v.2);
+                }
+                Ok((position, velocity))
+            })
+            .collect()
+    }
+
+    /// Returns the apparent position of `target` as seen from `observer` at `epoch` corrected
+    /// for the one-way light time in the direction given by `correction`.
+    ///
+    /// The positions of the observer and the target are obtained relative to the Solar System
+    /// barycenter, and the light time is iterated until it changes by less than a picosecond.
+    /// Stellar aberration and the gravitational delay are not taken into account.
+    fn position_apparent(
+        &self,
+        epoch: Epoch,
+        observer: EphemerisId,
+        target: EphemerisId,
+        correction: LightTimeCorrection,
+    ) -> Result<Position, Self::Error> {
+        let barycentric = |epoch: Epoch, id: EphemerisId| -> Result<Position, Self::Error> {
+            let mut position = (0.0, 0.0, 0.0);
+            for leg in path_from_ids(0, id.0).windows(2) {
+                let p = self.position(epoch, leg[0].into(), leg[1].into())?;
+                position = (position.0 + p.0, position.1 + p.1, position.2 + p.2);
+            }
+            Ok(position)
+        };
+        let sign = match correction {
+            LightTimeCorrection::Reception => -1.0,
+            LightTimeCorrection::Transmission => 1.0,
+        };
+
+        let r_observer = barycentric(epoch, observer)?;
+        let mut light_time = 0.0;
+        let mut position = (0.0, 0.0, 0.0);
+        for _ in 0..MAX_LIGHT_TIME_ITERATIONS {
+            let r_target = barycentric(epoch + sign * light_time, target)?;
+            position = (
+                r_target.0 - r_observer.0,
+                r_target.1 - r_observer.1,
+                r_target.2 - r_observer.2,
+            );
+            let range = (position.0.powi(2) + position.1.powi(2) + position.2.powi(2)).sqrt();
+            let previous = light_time;
+            light_time = range / SPEED_OF_LIGHT;
+            if (light_time - previous).abs() < LIGHT_TIME_TOLERANCE {
+                break;
+            }
+        }
+        Ok(position)
+    }
 }
 
 fn ancestors(id: i32) -> Vec<i32> {
@@ -56,6 +205,9 @@ pub fn path_from_ids(origin: i32, target: i32) -> Vec<i32> {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::convert::Infallible;
+
     use super::*;
 
     #[test]
@@ -65,6 +217,158 @@ mod tests {
         assert_eq!(ancestors(399), vec![399, 3, 0]);
     }
 
+    #[test]
+    fn test_ephemeris_id() {
+        assert_eq!(EphemerisId::from(DynOrigin::Earth), EphemerisId(399));
+        assert_eq!(
+            EphemerisId::from(DynOrigin::SolarSystemBarycenter),
+            EphemerisId(0)
+        );
+        assert_eq!(EphemerisId::from(NaifId(301)), EphemerisId(301));
+        assert_eq!(EphemerisId::from(499), EphemerisId(499));
+        assert_eq!(i32::from(EphemerisId(599)), 599);
+    }
+
+    struct MockEphemeris {
+        queries: Cell<usize>,
+    }
+
+    impl MockEphemeris {
+        fn leg(origin: EphemerisId, target: EphemerisId) -> (Position, Velocity) {
+            let d = (target.0 - origin.0) as f64;
+            ((d, 2.0 * d, 3.0 * d), (d / 10.0, d / 20.0, d / 30.0))
+        }
+    }
+
+    impl Ephemeris for MockEphemeris {
+        type Error = Infallible;
+
+        fn position(
+            &self,
+            epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<Position, Infallible> {
+            Ok(self.state(epoch, origin, target)?.0)
+        }
+
+        fn velocity(
+            &self,
+            epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<Velocity, Infallible> {
+            Ok(self.state(epoch, origin, target)?.1)
+        }
+
+        fn state(
+            &self,
+            _epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<(Position, Velocity), Infallible> {
+            self.queries.set(self.queries.get() + 1);
+            Ok(Self::leg(origin, target))
+        }
+    }
+
+    #[test]
+    fn test_states_at() {
+        let ephemeris = MockEphemeris {
+            queries: Cell::new(0),
+        };
+        let origin = EphemerisId(399);
+        let targets: Vec<EphemerisId> = [10, 301, 499, 599, 3].map(EphemerisId).to_vec();
+
+        let states = ephemeris.states_at(0.0, origin, &targets).unwrap();
+
+        // Earth → EMB, EMB → SSB, EMB → Moon, SSB → Sun, SSB → MB, MB → Mars, SSB → JB, JB → Jupiter
+        assert_eq!(ephemeris.queries.get(), 8);
+
+        for (target, state) in targets.into_iter().zip(states) {
+            let mut position = (0.0, 0.0, 0.0);
+            let mut velocity = (0.0, 0.0, 0.0);
+            for leg in path_from_ids(origin.0, target.0).windows(2) {
+                let (p, v) = MockEphemeris::leg(leg[0].into(), leg[1].into());
+                position = (position.0 + p.0, position.1 + p.1, position.2 + p.2);
+                velocity = (velocity.0 + v.0, velocity.1 + v.1, velocity.2 + v.2);
+            }
+            assert_eq!(state, (position, velocity));
+        }
+    }
+
+    /// Jupiter moves along the x-axis at 30 km/s at a distance of 5 AU from the SSB at J2000.
+    struct MovingEphemeris;
+
+    const JUPITER_DISTANCE: f64 = 5.0 * 149597870.7;
+    const JUPITER_SPEED: f64 = 30.0;
+
+    impl Ephemeris for MovingEphemeris {
+        type Error = Infallible;
+
+        fn position(
+            &self,
+            epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<Position, Infallible> {
+            Ok(match (origin.0, target.0) {
+                (0, 5) => (JUPITER_DISTANCE + JUPITER_SPEED * epoch, 0.0, 0.0),
+                _ => (0.0, 0.0, 0.0),
+            })
+        }
+
+        fn velocity(
+            &self,
+            _epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<Velocity, Infallible> {
+            Ok(match (origin.0, target.0) {
+                (0, 5) => (JUPITER_SPEED, 0.0, 0.0),
+                _ => (0.0, 0.0, 0.0),
+            })
+        }
+
+        fn state(
+            &self,
+            epoch: Epoch,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<(Position, Velocity), Infallible> {
+            Ok((
+                self.position(epoch, origin, target)?,
+                self.velocity(epoch, origin, target)?,
+            ))
+        }
+    }
+
+    #[test]
+    fn test_position_apparent() {
+        let epoch = 1000.0;
+        let (observer, target) = (EphemerisId(0), EphemerisId(5));
+        let geometric = JUPITER_DISTANCE + JUPITER_SPEED * epoch;
+        let beta = JUPITER_SPEED / SPEED_OF_LIGHT;
+
+        // x = geometric ∓ v * x / c
+        for (correction, expected) in [
+            (LightTimeCorrection::Reception, geometric / (1.0 + beta)),
+            (LightTimeCorrection::Transmission, geometric / (1.0 - beta)),
+        ] {
+            let (x, y, z) = MovingEphemeris
+                .position_apparent(epoch, observer, target, correction)
+                .unwrap();
+            assert!((x - expected).abs() < 1e-6, "{x} != {expected}");
+            assert_eq!((y, z), (0.0, 0.0));
+        }
+
+        // The position of the observer is not delayed
+        let (x, _, _) = MovingEphemeris
+            .position_apparent(epoch, target, observer, LightTimeCorrection::Reception)
+            .unwrap();
+        assert_eq!(x, -geometric);
+    }
+
     #[test]
     fn test_path_from_ids() {
         assert_eq!(path_from_ids(399, 499), [399, 3, 0, 4, 499]);
@@ -6,42 +6,98 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, PoisonError};
 
 use lox_math::types::julian_dates::Epoch;
 
-use crate::{Body, Ephemeris, Position, Velocity};
+use crate::{Ephemeris, EphemerisId, Position, Velocity};
+
+use super::parser::{
+    BodyId, DafSpkError, Spk, SpkArray, SpkSegment, SpkType2Array, SpkType2Coefficients,
+};
+
+/// The number of Chebyshev records kept in an [`SpkRecordCache`].
+const RECORD_CACHE_CAPACITY: usize = 8;
+
+/// A least-recently-used cache of the Chebyshev records evaluated by an [`Spk`].
+///
+/// Repeated queries for nearby epochs, e.g. when propagating a trajectory or computing the state
+/// of several bodies at the same epoch, usually hit the same handful of records. The cache keeps
+/// copies of the most recently used records together with their validity intervals so that these
+/// queries skip the segment and record lookup. Cached results are bit-identical to uncached ones.
+///
+/// The cache never blocks: if it is held by another thread, the query falls back to the regular
+/// lookup.
+#[derive(Debug, Default)]
+pub(crate) struct SpkRecordCache(Mutex<VecDeque<CachedRecord>>);
+
+impl PartialEq for SpkRecordCache {
+    fn eq(&self, _other: &Self) -> bool {
+        // The cache does not affect query results and is ignored in comparisons.
+        true
+    }
+}
+
+#[derive(Debug)]
+struct CachedRecord {
+    origin: BodyId,
+    target: BodyId,
+    // Coverage of the segment the record belongs to
+    segment_initial_epoch: Epoch,
+    segment_final_epoch: Epoch,
+    intlen: f64,
+    record_number: usize,
+    coefficients: Vec<SpkType2Coefficients>,
+}
 
-use super::parser::{DafSpkError, Spk, SpkSegment, SpkType2Array, SpkType2Coefficients};
+impl CachedRecord {
+    fn fraction(&self, origin: BodyId, target: BodyId, epoch: Epoch) -> Option<f64> {
+        if self.origin != origin
+            || self.target != target
+            || epoch < self.segment_initial_epoch
+            || epoch >= self.segment_final_epoch
+        {
+            return None;
+        }
+        let seconds_from_record_start = epoch - self.segment_initial_epoch;
+        let record_number = (seconds_from_record_start / self.intlen).floor() as usize;
+        if record_number != self.record_number {
+            return None;
+        }
+        Some(seconds_from_record_start % self.intlen)
+    }
+}
 
 impl Spk {
     fn find_segment(
         &self,
-        origin: Body,
-        target: Body,
-    ) -> Result<(&SpkSegment, isize), DafSpkError> {
-        let mut sign = 1;
-
-        let mut target = target;
-        let mut origin = origin;
-        if target < origin {
-            (origin, target) = (target, origin);
-            sign = -1;
-        }
-
-        // An SPK file may contain any number of segments. A single file may contain overlapping segments:
-        // segments containing data for the same body over a common interval. When this happens, the
-        // latest segment in a file supersedes any competing segments earlier in the file.
-        let segment = self
+        epoch: Epoch,
+        origin: BodyId,
+        target: BodyId,
+    ) -> Result<(&[SpkSegment], usize), DafSpkError> {
+        let segments = self
             .segments
             .get(&origin)
             .ok_or(DafSpkError::UnableToFindMatchingSegment)?
             .get(&target)
-            .ok_or(DafSpkError::UnableToFindMatchingSegment)?
-            .last()
             .ok_or(DafSpkError::UnableToFindMatchingSegment)?;
 
-        Ok((segment, sign))
+        // An SPK file may contain any number of segments. A single file may contain overlapping segments:
+        // segments containing data for the same body over a common interval. When this happens, the
+        // latest segment in a file supersedes any competing segments earlier in the file.
+        //
+        // Segment coverage is treated as half-open, i.e. a segment owns its start epoch but not its
+        // final epoch. An epoch on the boundary between two adjacent segments is therefore always
+        // evaluated by the later segment, independent of the order of the segments in the file.
+        // Only the final epoch of the last segment is evaluated by the segment it closes.
+        let index = segments
+            .iter()
+            .rposition(|s| s.initial_epoch <= epoch && epoch < s.final_epoch)
+            .or_else(|| segments.iter().rposition(|s| epoch == s.final_epoch))
+            .ok_or(DafSpkError::UnableToFindMatchingSegment)?;
+
+        Ok((segments, index))
     }
 
     fn find_record<'a>(
@@ -49,7 +105,7 @@ impl Spk {
         array: &'a SpkType2Array,
         initial_epoch: Epoch,
         epoch: Epoch,
-    ) -> Result<(&'a Vec<SpkType2Coefficients>, f64), DafSpkError> {
+    ) -> Result<(&'a Vec<SpkType2Coefficients>, usize, f64), DafSpkError> {
         let seconds_from_record_start = epoch - initial_epoch;
 
         let intlen = array.intlen as f64;
@@ -70,142 +126,208 @@ impl Spk {
             .get(record_number)
             .ok_or(DafSpkError::UnableToFindMatchingRecord)?;
 
-        Ok((record, fraction))
+        Ok((record, record_number, fraction))
     }
 
     pub fn get_segments(&self) -> &HashMap<i32, HashMap<i32, Vec<SpkSegment>>> {
         &self.segments
     }
 
-    fn get_chebyshev_polynomial<'a>(
-        &'a self,
-        epoch: Epoch,
-        segment: &'a SpkSegment,
-    ) -> Result<(Vec<f64>, &'a Vec<SpkType2Coefficients>), DafSpkError> {
-        let (coefficients, record) = match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (record, fraction) = self.find_record(array, segment.initial_epoch, epoch)?;
-
-                let degree_of_polynomial = array.degree_of_polynomial() as usize;
-                let mut coefficients = Vec::<f64>::with_capacity(degree_of_polynomial);
-
-                coefficients.push(1f64);
-                coefficients.push(2f64 * fraction / array.intlen as f64 - 1f64);
-
-                for i in 2..degree_of_polynomial {
-                    coefficients
-                        .push(2f64 * coefficients[1] * coefficients[i - 1] - coefficients[i - 2]);
-                }
-
-                (coefficients, record)
-            }
-        };
-
-        Ok((coefficients, record))
+    /// Clears the cache of recently used Chebyshev records.
+    ///
+    /// This must be called after modifying [`Spk::segments`] of an `Spk` that has already been
+    /// queried.
+    pub fn clear_record_cache(&mut self) {
+        self.record_cache
+            .0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
     }
-}
 
-impl Ephemeris for Spk {
-    type Error = DafSpkError;
-
-    fn position(&self, epoch: Epoch, origin: Body, target: Body) -> Result<Position, DafSpkError> {
-        let (segment, sign) = self.find_segment(origin, target)?;
-
-        if epoch < segment.initial_epoch || epoch > segment.final_epoch {
-            return Err(DafSpkError::UnableToFindMatchingSegment);
+    /// Looks up the Chebyshev record for `epoch` and passes it to `f` together with the fraction
+    /// of the record interval, the length of the interval, and the sign of the result.
+    fn with_record<T>(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        target: EphemerisId,
+        f: impl FnOnce(&[SpkType2Coefficients], f64, f64, f64) -> T,
+    ) -> Result<T, DafSpkError> {
+        let mut sign = 1.0;
+
+        let mut target = target.0;
+        let mut origin = origin.0;
+        if target < origin {
+            (origin, target) = (target, origin);
+            sign = -1.0;
         }
 
-        let mut x = 0f64;
-        let mut y = 0f64;
-        let mut z = 0f64;
-
-        match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (polynomial, record) = self.get_chebyshev_polynomial(epoch, segment)?;
-                let sign = sign as f64;
-
-                let degree_of_polynomial = array.degree_of_polynomial() as usize;
-
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..degree_of_polynomial {
-                    x += sign * record[i].x * polynomial[i];
-                    y += sign * record[i].y * polynomial[i];
-                    z += sign * record[i].z * polynomial[i];
+        let mut cache = self.record_cache.0.try_lock().ok();
+
+        if let Some(cache) = cache.as_mut() {
+            let hit = cache.iter().enumerate().find_map(|(i, record)| {
+                record
+                    .fraction(origin, target, epoch)
+                    .map(|fraction| (i, fraction))
+            });
+            if let Some((i, fraction)) = hit {
+                if i != 0 {
+                    let record = cache.remove(i).unwrap();
+                    cache.push_front(record);
                 }
+                let record = &cache[0];
+                return Ok(f(&record.coefficients, fraction, record.intlen, sign));
             }
         }
 
-        Ok((x, y, z))
-    }
-
-    fn velocity(&self, epoch: Epoch, origin: Body, target: Body) -> Result<Velocity, DafSpkError> {
-        let (segment, sign) = self.find_segment(origin, target)?;
-
-        if epoch < segment.initial_epoch || epoch > segment.final_epoch {
-            return Err(DafSpkError::UnableToFindMatchingSegment);
+        let (segments, index) = self.find_segment(epoch, origin, target)?;
+        let segment = &segments[index];
+        let SpkArray::Type2(array) = &segment.data;
+        let (record, record_number, fraction) =
+            self.find_record(array, segment.initial_epoch, epoch)?;
+        let intlen = array.intlen as f64;
+        let result = f(record, fraction, intlen, sign);
+
+        // A record may only be served from the cache if no later segment supersedes any part of it.
+        let record_start = segment.initial_epoch + record_number as f64 * intlen;
+        let record_end = record_start + intlen;
+        let superseded = segments[index + 1..]
+            .iter()
+            .any(|s| s.initial_epoch <= record_end && s.final_epoch >= record_start);
+
+        if let Some(cache) = cache.as_mut().filter(|_| !superseded) {
+            // Reuse the allocation of the evicted record
+            let mut coefficients = if cache.len() == RECORD_CACHE_CAPACITY {
+                cache.pop_back().map(|r| r.coefficients).unwrap_or_default()
+            } else {
+                Vec::with_capacity(record.len())
+            };
+            coefficients.clear();
+            coefficients.extend_from_slice(record);
+            cache.push_front(CachedRecord {
+                origin,
+                target,
+                segment_initial_epoch: segment.initial_epoch,
+                segment_final_epoch: segment.final_epoch,
+                intlen,
+                record_number,
+                coefficients,
+            });
         }
 
-        let mut x = 0f64;
-        let mut y = 0f64;
-        let mut z = 0f64;
-
-        match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (polynomial, record) = self.get_chebyshev_polynomial(epoch, segment)?;
-                let sign = sign as f64;
-
-                let degree_of_polynomial = array.degree_of_polynomial() as usize;
+        Ok(result)
+    }
+}
 
-                let mut derivative = Vec::<f64>::with_capacity(degree_of_polynomial);
+// The Chebyshev polynomials and their derivatives are evaluated with their three-term recurrences
+// while summing up the series, which avoids allocating intermediate buffers for every query.
+
+fn position_from_record(
+    record: &[SpkType2Coefficients],
+    fraction: f64,
+    intlen: f64,
+    sign: f64,
+) -> Position {
+    let t = 2f64 * fraction / intlen - 1f64;
+
+    let mut x = 0f64;
+    let mut y = 0f64;
+    let mut z = 0f64;
+
+    // T(i-2) and T(i-1)
+    let (mut p0, mut p1) = (0f64, 0f64);
+
+    for (i, coefficients) in record.iter().enumerate() {
+        let p = match i {
+            0 => 1f64,
+            1 => t,
+            _ => 2f64 * t * p1 - p0,
+        };
+        x += sign * coefficients.x * p;
+        y += sign * coefficients.y * p;
+        z += sign * coefficients.z * p;
+        (p0, p1) = (p1, p);
+    }
 
-                derivative.push(0f64);
-                derivative.push(1f64);
+    (x, y, z)
+}
 
-                if degree_of_polynomial > 2 {
-                    derivative.push(4f64 * polynomial[1]);
-                    for i in 3..degree_of_polynomial {
-                        let x = 2f64 * polynomial[1] * derivative[i - 1] - derivative[i - 2]
-                            + polynomial[i - 1]
-                            + polynomial[i - 1];
+fn velocity_from_record(
+    record: &[SpkType2Coefficients],
+    fraction: f64,
+    intlen: f64,
+    sign: f64,
+) -> Velocity {
+    let t = 2f64 * fraction / intlen - 1f64;
+
+    let mut x = 0f64;
+    let mut y = 0f64;
+    let mut z = 0f64;
+
+    // T(i-2) and T(i-1)
+    let (mut p0, mut p1) = (0f64, 0f64);
+    // T'(i-2) and T'(i-1)
+    let (mut d0, mut d1) = (0f64, 0f64);
+
+    for (i, coefficients) in record.iter().enumerate() {
+        let (p, d) = match i {
+            0 => (1f64, 0f64),
+            1 => (t, 1f64),
+            2 => (2f64 * t * p1 - p0, 4f64 * t),
+            _ => (2f64 * t * p1 - p0, 2f64 * t * d1 - d0 + p1 + p1),
+        };
+        let d_scaled = 2.0 * d / intlen;
+        x += sign * coefficients.x * d_scaled;
+        y += sign * coefficients.y * d_scaled;
+        z += sign * coefficients.z * d_scaled;
+        (p0, p1) = (p1, p);
+        (d0, d1) = (d1, d);
+    }
 
-                        derivative.push(x);
-                    }
-                }
+    (x, y, z)
+}
 
-                let derivative: Vec<f64> = derivative
-                    .iter()
-                    .map(|d| 2.0 * d / array.intlen as f64)
-                    .collect();
+impl Ephemeris for Spk {
+    type Error = DafSpkError;
 
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..degree_of_polynomial {
-                    x += sign * record[i].x * derivative[i];
-                    y += sign * record[i].y * derivative[i];
-                    z += sign * record[i].z * derivative[i];
-                }
-            }
-        }
+    fn position(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        target: EphemerisId,
+    ) -> Result<Position, DafSpkError> {
+        self.with_record(epoch, origin, target, position_from_record)
+    }
 
-        Ok((x, y, z))
+    fn velocity(
+        &self,
+        epoch: Epoch,
+        origin: EphemerisId,
+        target: EphemerisId,
+    ) -> Result<Velocity, DafSpkError> {
+        self.with_record(epoch, origin, target, velocity_from_record)
     }
 
     fn state(
         &self,
         epoch: Epoch,
-        origin: Body,
-        target: Body,
+        origin: EphemerisId,
+        target: EphemerisId,
     ) -> Result<(Position, Velocity), DafSpkError> {
-        let position = self.position(epoch, origin, target)?;
-        let velocity = self.velocity(epoch, origin, target)?;
-
-        Ok((position, velocity))
+        self.with_record(epoch, origin, target, |record, fraction, intlen, sign| {
+            (
+                position_from_record(record, fraction, intlen, sign),
+                velocity_from_record(record, fraction, intlen, sign),
+            )
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::spk::parser::parse_daf_spk;
     use crate::spk::parser::test::{get_expected_segments, FILE_CONTENTS};
+    use crate::spk::parser::{parse_daf_spk, SpkArray};
 
     use super::*;
 
@@ -215,7 +337,7 @@ mod test {
 
         assert_eq!(
             Err(DafSpkError::UnableToFindMatchingSegment),
-            spk.position(2457388.5000000 as Epoch, 1, 2)
+            spk.position(2457388.5000000 as Epoch, 1.into(), 2.into())
         );
     }
 
@@ -225,7 +347,7 @@ mod test {
 
         assert_eq!(
             Ok((-32703259.291699532, 31370540.51993667, 20159681.594182793)),
-            spk.position(-14200747200.0 as Epoch, 0, 1)
+            spk.position(-14200747200.0 as Epoch, 0.into(), 1.into())
         );
     }
 
@@ -239,7 +361,7 @@ mod test {
                 -28.050723083678367,
                 -10.055174230490163,
             )),
-            spk.velocity(-14200747200.0 as Epoch, 0, 1)
+            spk.velocity(-14200747200.0 as Epoch, 0.into(), 1.into())
         );
     }
 
@@ -256,8 +378,142 @@ mod test {
                     -10.055174230490163,
                 ),
             )),
-            spk.state(-14200747200.0 as Epoch, 0, 1)
+            spk.state(-14200747200.0 as Epoch, 0.into(), 1.into())
+        );
+    }
+
+    fn linear_segment(initial_epoch: f64, final_epoch: f64, offset: f64) -> SpkSegment {
+        // A single Chebyshev record with two coefficients per component, i.e. `x(t) = t + offset`
+        let intlen = final_epoch - initial_epoch;
+        let c0 = (initial_epoch + final_epoch) / 2.0 + offset;
+        let c1 = intlen / 2.0;
+        SpkSegment {
+            name: "LINEAR".to_string(),
+            initial_epoch,
+            final_epoch,
+            target_id: 1,
+            center_id: 0,
+            reference_frame_id: 1,
+            data_type: 2,
+            initial_address: 0,
+            final_address: 0,
+            data: SpkArray::Type2(SpkType2Array {
+                records: vec![vec![
+                    SpkType2Coefficients {
+                        x: c0,
+                        y: c0,
+                        z: c0,
+                    },
+                    SpkType2Coefficients {
+                        x: c1,
+                        y: c1,
+                        z: c1,
+                    },
+                ]],
+                init: initial_epoch as u32,
+                intlen: intlen as u32,
+                rsize: 8,
+                n: 1,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_segment_boundary() {
+        let mut spk = parse_daf_spk(&FILE_CONTENTS).expect("Unable to parse DAF/SPK");
+        let origin = EphemerisId(0);
+        let target = EphemerisId(1);
+
+        // The later segment is deliberately stored first to show that the file order does not
+        // matter for adjacent segments.
+        let segments = vec![
+            linear_segment(100.0, 200.0, 0.5),
+            linear_segment(0.0, 100.0, 0.0),
+        ];
+        spk.segments.insert(0, HashMap::from([(1, segments)]));
+
+        let (x, _, _) = spk.position(100.0, origin, target).unwrap();
+        assert_eq!(x, 100.5);
+
+        let (x_after, _, _) = spk.position(100.0 + 1e-6, origin, target).unwrap();
+        assert!((x_after - x).abs() < 1e-5);
+        let (x_before, _, _) = spk.position(100.0 - 1e-6, origin, target).unwrap();
+        assert!((x_before - 100.0).abs() < 1e-5);
+
+        let (x, _, _) = spk.position(0.0, origin, target).unwrap();
+        assert_eq!(x, 0.0);
+        let (x, _, _) = spk.position(200.0, origin, target).unwrap();
+        assert_eq!(x, 200.5);
+        let (x, _, _) = spk.position(100.0, target, origin).unwrap();
+        assert_eq!(x, -100.5);
+
+        assert_eq!(
+            spk.position(200.0 + 1e-6, origin, target),
+            Err(DafSpkError::UnableToFindMatchingSegment)
+        );
+    }
+
+    #[test]
+    fn test_record_cache_dense_grid() {
+        let mut spk = parse_daf_spk(&FILE_CONTENTS).expect("Unable to parse DAF/SPK");
+        let segment = &spk.segments[&0][&1][0];
+        let SpkArray::Type2(array) = &segment.data;
+        // The test file only contains the first records of the segment
+        let initial_epoch = segment.initial_epoch;
+        let step = array.records.len() as f64 * array.intlen as f64 / 1000.0;
+
+        let epochs: Vec<Epoch> = (0..=1000)
+            .map(|i| initial_epoch + i as f64 * step)
+            .collect();
+        let cached: Vec<_> = epochs
+            .iter()
+            .map(|&epoch| spk.state(epoch, 0.into(), 1.into()).unwrap())
+            .collect();
+
+        for (&epoch, cached) in epochs.iter().zip(&cached).rev() {
+            spk.clear_record_cache();
+            assert_eq!(*cached, spk.state(epoch, 0.into(), 1.into()).unwrap());
+            assert_eq!(cached.0, spk.position(epoch, 0.into(), 1.into()).unwrap());
+            assert_eq!(cached.1, spk.velocity(epoch, 0.into(), 1.into()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_record_cache_jumps_between_segments() {
+        let mut spk = parse_daf_spk(&FILE_CONTENTS).expect("Unable to parse DAF/SPK");
+        let origin = EphemerisId(0);
+        let target = EphemerisId(1);
+
+        // The second segment supersedes part of the first one
+        let segments = vec![
+            linear_segment(0.0, 200.0, 0.0),
+            linear_segment(100.0, 150.0, 1.0),
+        ];
+        spk.segments.insert(0, HashMap::from([(1, segments)]));
+
+        for (epoch, expected) in [
+            (50.0, 50.0),
+            (120.0, 121.0),
+            (50.0, 50.0),
+            (175.0, 175.0),
+            (120.0, 121.0),
+            (150.0, 150.0),
+            (0.0, 0.0),
+            (200.0, 200.0),
+        ] {
+            let (x, _, _) = spk.position(epoch, origin, target).unwrap();
+            assert_eq!(x, expected);
+            let (x, _, _) = spk.position(epoch, target, origin).unwrap();
+            assert_eq!(x, -expected);
+        }
+
+        spk.segments.insert(
+            0,
+            HashMap::from([(1, vec![linear_segment(0.0, 200.0, 2.0)])]),
         );
+        spk.clear_record_cache();
+        let (x, _, _) = spk.position(50.0, origin, target).unwrap();
+        assert_eq!(x, 52.0);
     }
 
     #[test]
@@ -14,7 +14,9 @@ use nom::error::ErrorKind;
 use nom::number::complete as nn;
 use thiserror::Error;
 
-type BodyId = i32;
+use super::api::SpkRecordCache;
+
+pub(crate) type BodyId = i32;
 
 const RECORD_SIZE: u32 = 1024;
 
@@ -64,7 +66,7 @@ pub enum DafSpkError {
     UnableToFindMatchingRecord,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SpkType2Coefficients {
     pub x: f64,
     pub y: f64,
@@ -122,6 +124,7 @@ pub struct Spk {
     pub file_record: DafFileRecord,
     pub comment: String,
     pub segments: HashMap<BodyId, HashMap<BodyId, Vec<SpkSegment>>>,
+    pub(crate) record_cache: SpkRecordCache,
 }
 
 pub fn parse_daf_file_record_endianness(
@@ -421,6 +424,7 @@ pub fn parse_daf_spk(full_input: &[u8]) -> Result<Spk, DafSpkError> {
         file_record,
         comment,
         segments,
+        record_cache: Default::default(),
     })
 }
 
@@ -1775,6 +1779,7 @@ name is "19_spk") available from the NAIF website (http://naif.jpl.nasa.gov/tuto
             },
             comment: get_expected_comment_string(),
             segments: get_expected_segments(),
+            record_cache: Default::default(),
         }
     }
 
@@ -0,0 +1,394 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parser for state vector tables exported by the JPL Horizons system.
+//!
+//! Both the default text layout, where each record spans several `KEY = VALUE` lines, and the
+//! CSV layout (`CSV_FORMAT=YES`) are supported. Only the records between the `$$SOE` and `$$EOE`
+//! markers are read as states. The center body, reference frame, and output units are taken from
+//! the header preceding the `$$SOE` marker.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use thiserror::Error;
+
+const START_OF_EPHEMERIS: &str = "$$SOE";
+const END_OF_EPHEMERIS: &str = "$$EOE";
+const STATE_KEYS: [&str; 6] = ["X", "Y", "Z", "VX", "VY", "VZ"];
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum HorizonsError {
+    #[error("failed to read Horizons file: {0}")]
+    Io(String),
+    #[error("missing `{0}` marker")]
+    MissingMarker(&'static str),
+    #[error("missing `{0}` column in CSV header")]
+    MissingColumn(&'static str),
+    #[error("invalid record at line {line}: {reason}")]
+    InvalidRecord { line: usize, reason: String },
+}
+
+/// A single row of a Horizons vector table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HorizonsState {
+    /// Julian date in the time scale of the table, usually TDB.
+    pub julian_date: f64,
+    /// Calendar date as printed by Horizons, e.g. `A.D. 2024-Jan-01 00:00:00.0000 TDB`.
+    pub calendar_date: Option<String>,
+    /// Position in the output units of the table, usually km.
+    pub position: [f64; 3],
+    /// Velocity in the output units of the table, usually km/s.
+    pub velocity: [f64; 3],
+}
+
+/// The body relative to which the states of a Horizons vector table are given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HorizonsCenter {
+    pub name: String,
+    /// NAIF ID of the center body.
+    pub id: i32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HorizonsVectorTable {
+    center: Option<HorizonsCenter>,
+    reference_frame: Option<String>,
+    output_units: Option<String>,
+    states: Vec<HorizonsState>,
+}
+
+impl HorizonsVectorTable {
+    pub fn from_string(input: &str) -> Result<Self, HorizonsError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let start = lines
+            .iter()
+            .position(|line| line.trim() == START_OF_EPHEMERIS)
+            .ok_or(HorizonsError::MissingMarker(START_OF_EPHEMERIS))?;
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim() == END_OF_EPHEMERIS)
+            .map(|i| start + i)
+            .ok_or(HorizonsError::MissingMarker(END_OF_EPHEMERIS))?;
+
+        // Line numbers are one-based and refer to the full input.
+        let records: Vec<(usize, &str)> = lines[start + 1..end]
+            .iter()
+            .enumerate()
+            .map(|(i, line)| (start + i + 2, *line))
+            .filter(|(_, line)| !line.trim().is_empty())
+            .collect();
+
+        let is_csv = records.first().is_some_and(|(_, line)| line.contains(','));
+        let states = if is_csv {
+            let header = lines[..start]
+                .iter()
+                .rev()
+                .find(|line| line.contains(','))
+                .ok_or(HorizonsError::MissingColumn("JD"))?;
+            parse_csv_records(header, &records)?
+        } else {
+            parse_text_records(&records)?
+        };
+
+        let header = &lines[..start];
+        let center = header
+            .iter()
+            .find_map(|line| center_regex().captures(line))
+            .and_then(|caps| {
+                Some(HorizonsCenter {
+                    name: caps["name"].to_string(),
+                    id: caps["id"].parse().ok()?,
+                })
+            });
+        let header_value = |key: &str| {
+            header.iter().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                (name.trim() == key).then(|| value.trim().to_string())
+            })
+        };
+
+        Ok(Self {
+            center,
+            reference_frame: header_value("Reference frame"),
+            output_units: header_value("Output units"),
+            states,
+        })
+    }
+
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HorizonsError> {
+        let input = fs::read_to_string(path).map_err(|err| HorizonsError::Io(err.to_string()))?;
+        Self::from_string(&input)
+    }
+
+    /// Returns the center body declared in the header, e.g. `Sun (10)`.
+    pub fn center(&self) -> Option<&HorizonsCenter> {
+        self.center.as_ref()
+    }
+
+    /// Returns the reference frame declared in the header, e.g. `ICRF` or `Ecliptic of J2000.0`.
+    pub fn reference_frame(&self) -> Option<&str> {
+        self.reference_frame.as_deref()
+    }
+
+    /// Returns the output units declared in the header, e.g. `KM-S` or `AU-D`.
+    pub fn output_units(&self) -> Option<&str> {
+        self.output_units.as_deref()
+    }
+
+    pub fn states(&self) -> &[HorizonsState] {
+        &self.states
+    }
+
+    pub fn into_states(self) -> Vec<HorizonsState> {
+        self.states
+    }
+}
+
+fn center_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^Center body name\s*:\s*(?<name>.*?)\s*\((?<id>-?\d+)\)").unwrap()
+    })
+}
+
+fn epoch_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s*(?<jd>\d+\.\d*)\s*=\s*(?<calendar>.*?)\s*$").unwrap())
+}
+
+fn key_value_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?<key>[A-Z]+)\s*=\s*(?<value>[-+]?(?:\d+\.?\d*|\.\d+)(?:[Ee][-+]?\d+)?)")
+            .unwrap()
+    })
+}
+
+fn parse_value(line: usize, value: &str) -> Result<f64, HorizonsError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| HorizonsError::InvalidRecord {
+            line,
+            reason: format!("`{}` is not a number", value.trim()),
+        })
+}
+
+fn to_state(
+    line: usize,
+    julian_date: f64,
+    calendar_date: Option<String>,
+    values: &HashMap<&str, f64>,
+) -> Result<HorizonsState, HorizonsError> {
+    let mut state = [0.0; 6];
+    for (component, key) in state.iter_mut().zip(STATE_KEYS) {
+        *component = *values
+            .get(key)
+            .ok_or_else(|| HorizonsError::InvalidRecord {
+                line,
+                reason: format!("missing `{}`", key),
+            })?;
+    }
+    Ok(HorizonsState {
+        julian_date,
+        calendar_date,
+        position: [state[0], state[1], state[2]],
+        velocity: [state[3], state[4], state[5]],
+    })
+}
+
+fn parse_text_records(records: &[(usize, &str)]) -> Result<Vec<HorizonsState>, HorizonsError> {
+    let mut states = Vec::new();
+    // Line number, Julian date, and calendar date of the record being read
+    let mut current: Option<(usize, f64, Option<String>)> = None;
+    let mut values: HashMap<&str, f64> = HashMap::new();
+
+    for &(line, text) in records {
+        if let Some(caps) = epoch_regex().captures(text) {
+            if let Some((start, julian_date, calendar_date)) = current.take() {
+                states.push(to_state(start, julian_date, calendar_date, &values)?);
+                values.clear();
+            }
+            let julian_date = parse_value(line, &caps["jd"])?;
+            let calendar_date = Some(caps["calendar"].to_string()).filter(|c| !c.is_empty());
+            current = Some((line, julian_date, calendar_date));
+            continue;
+        }
+
+        if current.is_none() {
+            return Err(HorizonsError::InvalidRecord {
+                line,
+                reason: "expected a Julian date".to_string(),
+            });
+        }
+        let mut found = false;
+        for caps in key_value_regex().captures_iter(text) {
+            let key = caps.name("key").unwrap().as_str();
+            values.insert(key, parse_value(line, &caps["value"])?);
+            found = true;
+        }
+        if !found {
+            return Err(HorizonsError::InvalidRecord {
+                line,
+                reason: format!("unexpected content `{}`", text.trim()),
+            });
+        }
+    }
+
+    if let Some((start, julian_date, calendar_date)) = current {
+        states.push(to_state(start, julian_date, calendar_date, &values)?);
+    }
+    Ok(states)
+}
+
+fn parse_csv_records(
+    header: &str,
+    records: &[(usize, &str)],
+) -> Result<Vec<HorizonsState>, HorizonsError> {
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+    let jd_column = columns
+        .iter()
+        .position(|column| column.starts_with("JD"))
+        .ok_or(HorizonsError::MissingColumn("JD"))?;
+    let calendar_column = columns
+        .iter()
+        .position(|column| column.starts_with("Calendar Date"));
+    let mut state_columns = [0; 6];
+    for (index, key) in state_columns.iter_mut().zip(STATE_KEYS) {
+        *index = columns
+            .iter()
+            .position(|column| *column == key)
+            .ok_or(HorizonsError::MissingColumn(key))?;
+    }
+
+    records
+        .iter()
+        .map(|&(line, text)| {
+            let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+            let field = |index: usize| {
+                fields
+                    .get(index)
+                    .copied()
+                    .ok_or_else(|| HorizonsError::InvalidRecord {
+                        line,
+                        reason: format!("expected at least {} columns", index + 1),
+                    })
+            };
+            let julian_date = parse_value(line, field(jd_column)?)?;
+            let calendar_date = match calendar_column {
+                Some(index) => Some(field(index)?.to_string()),
+                None => None,
+            };
+            let mut values = HashMap::new();
+            for (&index, key) in state_columns.iter().zip(STATE_KEYS) {
+                values.insert(key, parse_value(line, field(index)?)?);
+            }
+            to_state(line, julian_date, calendar_date, &values)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const TEST_DATA_DIR: &str = "../../data";
+
+    #[test]
+    fn test_horizons_vector_table() {
+        let table = HorizonsVectorTable::from_path(
+            Path::new(TEST_DATA_DIR).join("horizons_mars_vectors.txt"),
+        )
+        .unwrap();
+        let states = table.states();
+
+        assert_eq!(
+            table.center(),
+            Some(&HorizonsCenter {
+                name: "Sun".to_string(),
+                id: 10,
+            })
+        );
+        assert_eq!(table.reference_frame(), Some("ICRF"));
+        assert_eq!(table.output_units(), Some("KM-S"));
+
+        assert_eq!(states.len(), 4);
+        assert_float_eq!(states[0].julian_date, 2460310.5, abs <= 0.0);
+        assert_eq!(
+            states[0].calendar_date.as_deref(),
+            Some("A.D. 2024-Jan-01 00:00:00.0000 TDB")
+        );
+        assert_float_eq!(states[0].position[0], -2.440309735052042E+07, rel <= 1e-15);
+        assert_float_eq!(states[0].position[1], -2.014697424383823E+08, rel <= 1e-15);
+        assert_float_eq!(states[0].position[2], -9.184862911622535E+07, rel <= 1e-15);
+        assert_float_eq!(states[0].velocity[0], 2.518226539516802E+01, rel <= 1e-15);
+        assert_float_eq!(states[0].velocity[1], 5.722007506094985E-01, rel <= 1e-15);
+        assert_float_eq!(states[0].velocity[2], -4.538059588432838E-01, rel <= 1e-15);
+
+        assert_float_eq!(states[2].julian_date, 2460312.5, abs <= 0.0);
+        assert_float_eq!(states[2].position[0], -2.005160189023539E+07, rel <= 1e-15);
+        assert_float_eq!(states[2].velocity[2], -4.538059588432838E-01, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_horizons_vector_table_csv() {
+        let input = r#"*******************************************************************************
+            JDTDB,            Calendar Date (TDB),                      X,                      Y,                      Z,                     VX,                     VY,                     VZ,
+**************************************************************************************************************************************************************************************************
+$$SOE
+2460310.500000000, A.D. 2024-Jan-01 00:00:00.0000, -2.440309735052042E+07, -2.014697424383823E+08, -9.184862911622535E+07,  2.518226539516802E+01,  5.722007506094985E-01, -4.538059588432838E-01,
+2460311.500000000, A.D. 2024-Jan-02 00:00:00.0000, -2.222734962037791E+07, -2.014203042935296E+08, -9.188783795106940E+07,  2.518226539516802E+01,  5.722007506094985E-01, -4.538059588432838E-01,
+$$EOE
+"#;
+        let table = HorizonsVectorTable::from_string(input).unwrap();
+        let text = HorizonsVectorTable::from_path(
+            Path::new(TEST_DATA_DIR).join("horizons_mars_vectors.txt"),
+        )
+        .unwrap();
+        let states = table.states();
+
+        assert_eq!(table.center(), None);
+        assert_eq!(table.reference_frame(), None);
+
+        assert_eq!(states.len(), 2);
+        assert_eq!(
+            states[1].calendar_date.as_deref(),
+            Some("A.D. 2024-Jan-02 00:00:00.0000")
+        );
+        assert_eq!(states[0].position, text.states()[0].position);
+        assert_eq!(states[1].velocity, text.states()[1].velocity);
+    }
+
+    #[rstest]
+    #[case::no_start("$$EOE\n", HorizonsError::MissingMarker("$$SOE"))]
+    #[case::no_end("$$SOE\n", HorizonsError::MissingMarker("$$EOE"))]
+    #[case::missing_component(
+        "$$SOE\n2460310.5 = A.D. 2024-Jan-01 00:00:00.0000 TDB\n X = 1.0 Y = 2.0 Z = 3.0\n VX= 4.0 VY= 5.0\n$$EOE\n",
+        HorizonsError::InvalidRecord { line: 2, reason: "missing `VZ`".to_string() }
+    )]
+    #[case::no_epoch(
+        "$$SOE\n X = 1.0 Y = 2.0 Z = 3.0\n$$EOE\n",
+        HorizonsError::InvalidRecord { line: 2, reason: "expected a Julian date".to_string() }
+    )]
+    #[case::missing_column(
+        "JDTDB, X, Y, Z, VX, VY,\n$$SOE\n2460310.5, 1.0, 2.0, 3.0, 4.0, 5.0,\n$$EOE\n",
+        HorizonsError::MissingColumn("VZ")
+    )]
+    fn test_horizons_vector_table_errors(#[case] input: &str, #[case] expected: HorizonsError) {
+        assert_eq!(HorizonsVectorTable::from_string(input), Err(expected));
+    }
+}
@@ -8,6 +8,8 @@
 
 //! Parse [EarthOrientationParams] from IERS CSV data.
 
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use lox_math::types::julian_dates::ModifiedJulianDayNumber;
@@ -25,6 +27,20 @@ pub enum EopError {
     },
     #[error("EarthOrientationParams cannot be empty, but empty input vectors were provided")]
     NoData,
+    #[error("data quality flags must have the same length as the MJD values, but got mjd.len()={len_mjd}, data_quality.len()={len_data_quality}")]
+    DataQualityMismatch {
+        len_mjd: usize,
+        len_data_quality: usize,
+    },
+}
+
+/// Indicates whether an EOP record contains final or predicted values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataQuality {
+    Final,
+    Predicted,
+    /// The quality of the data is not known, e.g. because the source provides no flags.
+    Unknown,
 }
 
 /// A representation of observed Earth orientation parameters, independent of input format.
@@ -34,6 +50,7 @@ pub struct EarthOrientationParams {
     x_pole: Vec<f64>,
     y_pole: Vec<f64>,
     delta_ut1_utc: Vec<f64>,
+    data_quality: Option<Vec<DataQuality>>,
 }
 
 impl EarthOrientationParams {
@@ -64,18 +81,46 @@ impl EarthOrientationParams {
             x_pole,
             y_pole,
             delta_ut1_utc,
+            data_quality: None,
+        })
+    }
+
+    /// Attaches per-record [DataQuality] flags.
+    pub fn with_data_quality(self, data_quality: Vec<DataQuality>) -> Result<Self, EopError> {
+        if data_quality.len() != self.mjd.len() {
+            return Err(EopError::DataQualityMismatch {
+                len_mjd: self.mjd.len(),
+                len_data_quality: data_quality.len(),
+            });
+        }
+        Ok(Self {
+            data_quality: Some(data_quality),
+            ..self
         })
     }
 
     pub fn parse_finals_csv<P: AsRef<Path>>(path: P) -> Result<Self, ParseFinalsCsvError> {
         let mut reader = csv::ReaderBuilder::new().delimiter(b';').from_path(&path)?;
+        let headers = reader.headers()?.clone();
+        // The `Type` columns precede the values they qualify
+        let type_column = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .and_then(|i| i.checked_sub(1))
+                .filter(|&i| &headers[i] == "Type")
+        };
+        let x_pole_type = type_column("x_pole");
+        let delta_ut1_utc_type = type_column("UT1-UTC");
         let mut mjd = Vec::new();
         let mut x_pole = Vec::new();
         let mut y_pole = Vec::new();
         let mut delta_ut1_utc = Vec::new();
+        let mut data_quality = Vec::new();
 
-        for (i, result) in reader.deserialize().enumerate() {
-            let record: Record = result?;
+        for (i, result) in reader.records().enumerate() {
+            let string_record = result?;
+            let record: Record = string_record.deserialize(Some(&headers))?;
             if record.x_pole.is_none() {
                 continue;
             }
@@ -99,12 +144,83 @@ impl EarthOrientationParams {
             x_pole.push(record_x_pole);
             y_pole.push(record_y_pole);
             delta_ut1_utc.push(record_delta_ut1_utc);
+
+            let is_predicted = [x_pole_type, delta_ut1_utc_type]
+                .into_iter()
+                .flatten()
+                .any(|column| string_record.get(column) == Some("prediction"));
+            data_quality.push(if is_predicted {
+                DataQuality::Predicted
+            } else {
+                DataQuality::Final
+            });
         }
 
-        Self::new(mjd, x_pole, y_pole, delta_ut1_utc).map_err(|e| ParseFinalsCsvError::InvalidEop {
-            path: path.as_ref().to_path_buf(),
-            source: e,
-        })
+        Self::new(mjd, x_pole, y_pole, delta_ut1_utc)
+            .and_then(|eop| eop.with_data_quality(data_quality))
+            .map_err(|e| ParseFinalsCsvError::InvalidEop {
+                path: path.as_ref().to_path_buf(),
+                source: e,
+            })
+    }
+
+    /// Merges several EOP series into a single continuous series ordered by MJD.
+    ///
+    /// Where several sources contain a record for the same MJD, a record flagged as
+    /// [DataQuality::Final] is preferred over one flagged as [DataQuality::Predicted], regardless
+    /// of the order of the sources. Otherwise, i.e. for records of the same or unknown quality,
+    /// `sources` are given in order of decreasing priority and the record of the first of these
+    /// sources is used.
+    pub fn merge<'a>(
+        sources: impl IntoIterator<Item = &'a EarthOrientationParams>,
+    ) -> Result<Self, EopError> {
+        type MergedRecord = (f64, f64, f64, Option<DataQuality>);
+        let mut records: BTreeMap<ModifiedJulianDayNumber, MergedRecord> = BTreeMap::new();
+        for source in sources {
+            for (i, &mjd) in source.mjd.iter().enumerate() {
+                let record = (
+                    source.x_pole[i],
+                    source.y_pole[i],
+                    source.delta_ut1_utc[i],
+                    source.data_quality.as_ref().map(|quality| quality[i]),
+                );
+                match records.entry(mjd) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(record);
+                    }
+                    Entry::Occupied(mut entry) => {
+                        if entry.get().3 == Some(DataQuality::Predicted)
+                            && record.3 == Some(DataQuality::Final)
+                        {
+                            entry.insert(record);
+                        }
+                    }
+                }
+            }
+        }
+        let mut mjd = Vec::with_capacity(records.len());
+        let mut x_pole = Vec::with_capacity(records.len());
+        let mut y_pole = Vec::with_capacity(records.len());
+        let mut delta_ut1_utc = Vec::with_capacity(records.len());
+        let mut data_quality = Vec::with_capacity(records.len());
+        for (record_mjd, (record_x_pole, record_y_pole, record_delta_ut1_utc, record_quality)) in
+            records
+        {
+            mjd.push(record_mjd);
+            x_pole.push(record_x_pole);
+            y_pole.push(record_y_pole);
+            delta_ut1_utc.push(record_delta_ut1_utc);
+            data_quality.push(record_quality);
+        }
+        let eop = Self::new(mjd, x_pole, y_pole, delta_ut1_utc)?;
+        // Flags are only retained if they are known for all records
+        match data_quality
+            .into_iter()
+            .collect::<Option<Vec<DataQuality>>>()
+        {
+            Some(data_quality) => eop.with_data_quality(data_quality),
+            None => Ok(eop),
+        }
     }
 
     pub fn mjd(&self) -> &[ModifiedJulianDayNumber] {
@@ -122,6 +238,38 @@ impl EarthOrientationParams {
     pub fn delta_ut1_utc(&self) -> &[f64] {
         &self.delta_ut1_utc
     }
+
+    /// Returns the [DataQuality] of the records which are used to interpolate the EOP at the given
+    /// MJD.
+    ///
+    /// If any of the bracketing records was predicted, the result is [DataQuality::Predicted].
+    /// Returns [DataQuality::Unknown] if no flags are available or the MJD lies outside the series.
+    pub fn data_quality(&self, mjd: f64) -> DataQuality {
+        let (Some(data_quality), Some(&first), Some(&last)) = (
+            self.data_quality.as_ref(),
+            self.mjd.first(),
+            self.mjd.last(),
+        ) else {
+            return DataQuality::Unknown;
+        };
+        if mjd < first as f64 || mjd > last as f64 {
+            return DataQuality::Unknown;
+        }
+        let upper = self.mjd.partition_point(|&record| (record as f64) < mjd);
+        let lower = if self.mjd[upper] as f64 == mjd {
+            upper
+        } else {
+            upper - 1
+        };
+        let bracket = [data_quality[lower], data_quality[upper]];
+        if bracket.contains(&DataQuality::Predicted) {
+            DataQuality::Predicted
+        } else if bracket.contains(&DataQuality::Unknown) {
+            DataQuality::Unknown
+        } else {
+            DataQuality::Final
+        }
+    }
 }
 
 #[derive(Clone, Debug, Error, PartialEq)]
@@ -293,6 +441,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_merge() {
+        let finals = EarthOrientationParams::new(
+            vec![60000, 60001, 60002],
+            vec![0.1, 0.2, 0.3],
+            vec![1.1, 1.2, 1.3],
+            vec![-0.1, -0.2, -0.3],
+        )
+        .unwrap();
+        let predictions = EarthOrientationParams::new(
+            vec![60002, 60003, 59999, 60001],
+            vec![9.3, 9.4, 9.0, 9.2],
+            vec![8.3, 8.4, 8.0, 8.2],
+            vec![7.3, 7.4, 7.0, 7.2],
+        )
+        .unwrap();
+
+        let merged = EarthOrientationParams::merge([&finals, &predictions]).unwrap();
+        assert_eq!(merged.mjd(), &[59999, 60000, 60001, 60002, 60003]);
+        assert_eq!(merged.x_pole(), &[9.0, 0.1, 0.2, 0.3, 9.4]);
+        assert_eq!(merged.y_pole(), &[8.0, 1.1, 1.2, 1.3, 8.4]);
+        assert_eq!(merged.delta_ut1_utc(), &[7.0, -0.1, -0.2, -0.3, 7.4]);
+
+        // Reversing the priority prefers the predictions at the overlap
+        let merged = EarthOrientationParams::merge([&predictions, &finals]).unwrap();
+        assert_eq!(merged.x_pole(), &[9.0, 0.1, 9.2, 9.3, 9.4]);
+    }
+
+    #[test]
+    fn test_merge_prefers_final_data() {
+        let finals = EarthOrientationParams::new(
+            vec![60000, 60001, 60002],
+            vec![0.1, 0.2, 0.3],
+            vec![1.1, 1.2, 1.3],
+            vec![-0.1, -0.2, -0.3],
+        )
+        .unwrap()
+        .with_data_quality(vec![
+            DataQuality::Final,
+            DataQuality::Final,
+            DataQuality::Predicted,
+        ])
+        .unwrap();
+        let predictions = EarthOrientationParams::new(
+            vec![60001, 60002, 60003],
+            vec![9.2, 9.3, 9.4],
+            vec![8.2, 8.3, 8.4],
+            vec![7.2, 7.3, 7.4],
+        )
+        .unwrap()
+        .with_data_quality(vec![DataQuality::Predicted; 3])
+        .unwrap();
+
+        // The final record at 60001 wins although the predictions are given first, while the
+        // tie between the predicted records at 60002 is resolved by the order of the sources
+        let merged = EarthOrientationParams::merge([&predictions, &finals]).unwrap();
+        assert_eq!(merged.mjd(), &[60000, 60001, 60002, 60003]);
+        assert_eq!(merged.x_pole(), &[0.1, 0.2, 9.3, 9.4]);
+        assert_eq!(merged.y_pole(), &[1.1, 1.2, 8.3, 8.4]);
+        assert_eq!(merged.delta_ut1_utc(), &[-0.1, -0.2, 7.3, 7.4]);
+        assert_eq!(merged.data_quality(60001.0), DataQuality::Final);
+        assert_eq!(merged.data_quality(60002.0), DataQuality::Predicted);
+
+        let merged = EarthOrientationParams::merge([&finals, &predictions]).unwrap();
+        assert_eq!(merged.x_pole(), &[0.1, 0.2, 0.3, 9.4]);
+    }
+
+    #[rstest]
+    #[case::first_record(41684.0, DataQuality::Final)]
+    #[case::historical(50000.5, DataQuality::Final)]
+    #[case::predicted(60377.0, DataQuality::Predicted)]
+    #[case::last_record(60749.0, DataQuality::Predicted)]
+    #[case::before(41683.0, DataQuality::Unknown)]
+    #[case::after(60749.5, DataQuality::Unknown)]
+    fn test_data_quality(#[case] mjd: f64, #[case] expected: DataQuality) {
+        let path = Path::new(TEST_DATA_DIR).join("finals2000A.all.csv");
+        let eop = EarthOrientationParams::parse_finals_csv(path).unwrap();
+        assert_eq!(eop.data_quality(mjd), expected);
+    }
+
+    #[test]
+    fn test_data_quality_boundary() {
+        let eop = EarthOrientationParams::new(
+            vec![60000, 60001, 60002],
+            vec![0.1, 0.2, 0.3],
+            vec![1.1, 1.2, 1.3],
+            vec![-0.1, -0.2, -0.3],
+        )
+        .unwrap();
+        assert_eq!(eop.data_quality(60000.0), DataQuality::Unknown);
+        let eop = eop
+            .with_data_quality(vec![
+                DataQuality::Final,
+                DataQuality::Final,
+                DataQuality::Predicted,
+            ])
+            .unwrap();
+        assert_eq!(eop.data_quality(60001.0), DataQuality::Final);
+        assert_eq!(eop.data_quality(60001.5), DataQuality::Predicted);
+        assert_eq!(
+            eop.with_data_quality(vec![DataQuality::Final]),
+            Err(EopError::DataQualityMismatch {
+                len_mjd: 3,
+                len_data_quality: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_no_data() {
+        assert_eq!(EarthOrientationParams::merge([]), Err(EopError::NoData));
+    }
+
     #[rstest]
     #[case::csv_no_such_file("missing.csv", ParseFinalsCsvError::Csv("No such file or directory (os error 2)".to_string()))]
     #[case::csv_parse_failure("finals_type_error.csv", ParseFinalsCsvError::Csv("CSV deserialize error: record 1 (line: 2, byte: 265): field 0: invalid digit found in string".to_string()))]
@@ -6,6 +6,8 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod horizons;
 pub mod iers;
 pub mod ndm;
 pub mod spice;
+pub mod tle;
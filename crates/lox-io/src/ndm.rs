@@ -40,9 +40,12 @@ pub mod json;
 pub mod kvn;
 pub mod xml;
 
+pub mod aem;
+pub mod cdm;
 pub mod common;
 pub mod ndm_ci;
 pub mod ocm;
 pub mod oem;
 pub mod omm;
 pub mod opm;
+pub mod tdm;
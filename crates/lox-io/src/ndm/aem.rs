@@ -0,0 +1,899 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializers for XML and KVN CCSDS Attitude Ephemeris Message
+//!
+//! The attitude ephemeris message is defined in
+//! [CCSDS 504.0-B-1](https://public.ccsds.org/Pubs/504x0b1c1.pdf). The
+//! `QUATERNION`, `QUATERNION/DERIVATIVE`, `EULER_ANGLE` and `EULER_ANGLE/RATE`
+//! attitude types are supported.
+//!
+//! To deserialize a KVN message:
+//! ```
+//! # let kvn = r#"CCSDS_AEM_VERS = 1.0
+//! # CREATION_DATE = 2002-11-04T17:22:31
+//! # ORIGINATOR = NASA/JPL
+//! # META_START
+//! # OBJECT_NAME = MARS GLOBAL SURVEYOR
+//! # OBJECT_ID = 1996-062A
+//! # REF_FRAME_A = EME2000
+//! # REF_FRAME_B = SC_BODY_1
+//! # ATTITUDE_DIR = A2B
+//! # TIME_SYSTEM = UTC
+//! # START_TIME = 1996-11-28T21:29:07.2555
+//! # STOP_TIME = 1996-11-30T01:28:02.5555
+//! # ATTITUDE_TYPE = QUATERNION
+//! # QUATERNION_TYPE = LAST
+//! # META_STOP
+//! # DATA_START
+//! # 1996-11-28T21:29:07.2555 0.56748 0.03146 0.45689 0.68427
+//! # DATA_STOP"#;
+//! #
+//! # use lox_io::ndm::aem::AemType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: AemType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+//! ```
+
+use serde;
+
+use super::{
+    common,
+    kvn::{
+        parser::{
+            get_next_nonempty_line, kvn_line_matches_key, parse_kvn_euler_angle_line,
+            parse_kvn_quaternion_line, parse_kvn_string_line, KvnEulerAngleValue, KvnLines,
+            KvnQuaternionValue,
+        },
+        KvnDeserializer, KvnDeserializerErr,
+    },
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AemType {
+    #[serde(rename = "@id")]
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
+    #[serde(rename = "header")]
+    pub header: common::AdmHeader,
+    #[serde(rename = "body")]
+    pub body: AemBody,
+}
+
+impl crate::ndm::xml::FromXmlStr<'_> for AemType {}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AemBody {
+    #[serde(rename = "segment")]
+    pub segment_list: Vec<AemSegment>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AemSegment {
+    #[serde(rename = "metadata")]
+    pub metadata: AemMetadata,
+    #[serde(rename = "data")]
+    pub data: AemData,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "META")]
+pub struct AemMetadata {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "OBJECT_NAME")]
+    pub object_name: String,
+    #[serde(rename = "OBJECT_ID")]
+    pub object_id: String,
+    #[serde(rename = "CENTER_NAME")]
+    pub center_name: Option<String>,
+    #[serde(rename = "REF_FRAME_A")]
+    pub ref_frame_a: String,
+    #[serde(rename = "REF_FRAME_B")]
+    pub ref_frame_b: String,
+    #[serde(rename = "ATTITUDE_DIR")]
+    pub attitude_dir: String,
+    #[serde(rename = "TIME_SYSTEM")]
+    pub time_system: String,
+    #[serde(rename = "START_TIME")]
+    pub start_time: common::EpochType,
+    #[serde(rename = "USEABLE_START_TIME")]
+    pub useable_start_time: Option<common::EpochType>,
+    #[serde(rename = "USEABLE_STOP_TIME")]
+    pub useable_stop_time: Option<common::EpochType>,
+    #[serde(rename = "STOP_TIME")]
+    pub stop_time: common::EpochType,
+    #[serde(rename = "ATTITUDE_TYPE")]
+    pub attitude_type: String,
+    #[serde(rename = "QUATERNION_TYPE")]
+    pub quaternion_type: Option<String>,
+    #[serde(rename = "EULER_ROT_SEQ")]
+    pub euler_rot_seq: Option<String>,
+    #[serde(rename = "RATE_FRAME")]
+    pub rate_frame: Option<String>,
+    #[serde(rename = "INTERPOLATION_METHOD")]
+    pub interpolation_method: Option<String>,
+    #[serde(rename = "INTERPOLATION_DEGREE")]
+    pub interpolation_degree: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AemData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "attitudeState")]
+    pub attitude_state_list: Vec<AemAttitudeStateType>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AemAttitudeStateType {
+    #[serde(rename = "$value")]
+    pub state: AemAttitudeState,
+}
+
+/// A time-tagged attitude record. The variant corresponds to the
+/// `ATTITUDE_TYPE` of the enclosing segment.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum AemAttitudeState {
+    #[serde(rename = "quaternionState")]
+    Quaternion(QuaternionStateType),
+    #[serde(rename = "quaternionDerivative")]
+    QuaternionDerivative(QuaternionDerivativeType),
+    #[serde(rename = "eulerAngle")]
+    EulerAngle(EulerAngleStateType),
+    #[serde(rename = "eulerAngleRate")]
+    EulerAngleRate(EulerAngleRateStateType),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct QuaternionType {
+    #[serde(rename = "Q1")]
+    pub q1: f64,
+    #[serde(rename = "Q2")]
+    pub q2: f64,
+    #[serde(rename = "Q3")]
+    pub q3: f64,
+    #[serde(rename = "QC")]
+    pub qc: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct QuaternionRateType {
+    #[serde(rename = "Q1_DOT")]
+    pub q1_dot: f64,
+    #[serde(rename = "Q2_DOT")]
+    pub q2_dot: f64,
+    #[serde(rename = "Q3_DOT")]
+    pub q3_dot: f64,
+    #[serde(rename = "QC_DOT")]
+    pub qc_dot: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct QuaternionStateType {
+    #[serde(rename = "EPOCH")]
+    pub epoch: common::EpochType,
+    #[serde(rename = "quaternion")]
+    pub quaternion: QuaternionType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct QuaternionDerivativeType {
+    #[serde(rename = "EPOCH")]
+    pub epoch: common::EpochType,
+    #[serde(rename = "quaternion")]
+    pub quaternion: QuaternionType,
+    #[serde(rename = "quaternionRate")]
+    pub quaternion_rate: QuaternionRateType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RotationAngleType {
+    #[serde(rename = "$text")]
+    pub base: f64,
+    #[serde(rename = "@angle")]
+    pub angle: Option<String>,
+    #[serde(rename = "@units")]
+    pub units: Option<common::AngleUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RotationRateType {
+    #[serde(rename = "$text")]
+    pub base: f64,
+    #[serde(rename = "@rate")]
+    pub rate: Option<String>,
+    #[serde(rename = "@units")]
+    pub units: Option<common::AngleRateUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RotationAnglesType {
+    #[serde(rename = "rotation1")]
+    pub rotation1: RotationAngleType,
+    #[serde(rename = "rotation2")]
+    pub rotation2: RotationAngleType,
+    #[serde(rename = "rotation3")]
+    pub rotation3: RotationAngleType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RotationRatesType {
+    #[serde(rename = "rotation1")]
+    pub rotation1: RotationRateType,
+    #[serde(rename = "rotation2")]
+    pub rotation2: RotationRateType,
+    #[serde(rename = "rotation3")]
+    pub rotation3: RotationRateType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EulerAngleStateType {
+    #[serde(rename = "EPOCH")]
+    pub epoch: common::EpochType,
+    #[serde(rename = "rotationAngles")]
+    pub rotation_angles: RotationAnglesType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EulerAngleRateStateType {
+    #[serde(rename = "EPOCH")]
+    pub epoch: common::EpochType,
+    #[serde(rename = "rotationAngles")]
+    pub rotation_angles: RotationAnglesType,
+    #[serde(rename = "rotationRates")]
+    pub rotation_rates: RotationRatesType,
+}
+
+impl From<KvnQuaternionValue> for QuaternionType {
+    fn from(value: KvnQuaternionValue) -> Self {
+        Self {
+            q1: value.q1,
+            q2: value.q2,
+            q3: value.q3,
+            qc: value.qc,
+        }
+    }
+}
+
+// Maps the `EULER_ROT_SEQ` axes (e.g. `312`) to the angle names that the XML
+// format stores as attributes of each rotation.
+fn euler_axis_names(euler_rot_seq: Option<&str>, suffix: &str) -> [Option<String>; 3] {
+    let mut names = [None, None, None];
+
+    if let Some(sequence) = euler_rot_seq {
+        let axes: Vec<_> = sequence
+            .trim()
+            .chars()
+            .map(|axis| match axis {
+                '1' | 'X' | 'x' => Some("X"),
+                '2' | 'Y' | 'y' => Some("Y"),
+                '3' | 'Z' | 'z' => Some("Z"),
+                _ => None,
+            })
+            .collect();
+
+        if axes.len() == 3 && axes.iter().all(|axis| axis.is_some()) {
+            for (name, axis) in names.iter_mut().zip(axes) {
+                *name = axis.map(|axis| format!("{}_{}", axis, suffix));
+            }
+        }
+    }
+
+    names
+}
+
+fn rotation_angles(value: &KvnEulerAngleValue, euler_rot_seq: Option<&str>) -> RotationAnglesType {
+    let [name1, name2, name3] = euler_axis_names(euler_rot_seq, "ANGLE");
+
+    let angle = |base, angle| RotationAngleType {
+        base,
+        angle,
+        units: None,
+    };
+
+    RotationAnglesType {
+        rotation1: angle(value.angle_1, name1),
+        rotation2: angle(value.angle_2, name2),
+        rotation3: angle(value.angle_3, name3),
+    }
+}
+
+fn rotation_rates(value: &KvnEulerAngleValue, euler_rot_seq: Option<&str>) -> RotationRatesType {
+    let [name1, name2, name3] = euler_axis_names(euler_rot_seq, "RATE");
+
+    // The rates are always present because they are only requested for
+    // EULER_ANGLE/RATE lines
+    let rate = |base: Option<f64>, rate| RotationRateType {
+        base: base.unwrap_or_default(),
+        rate,
+        units: None,
+    };
+
+    RotationRatesType {
+        rotation1: rate(value.rate_1, name1),
+        rotation2: rate(value.rate_2, name2),
+        rotation3: rate(value.rate_3, name3),
+    }
+}
+
+fn parse_kvn_attitude_state(
+    input: &str,
+    metadata: &AemMetadata,
+) -> Result<AemAttitudeState, KvnDeserializerErr<String>> {
+    let scalar_first = metadata
+        .quaternion_type
+        .as_deref()
+        .is_some_and(|quaternion_type| quaternion_type.trim().eq_ignore_ascii_case("FIRST"));
+    let euler_rot_seq = metadata.euler_rot_seq.as_deref();
+
+    let state = match metadata.attitude_type.trim().to_uppercase().as_str() {
+        "QUATERNION" => {
+            let value = parse_kvn_quaternion_line(input, scalar_first, false)?;
+
+            AemAttitudeState::Quaternion(QuaternionStateType {
+                epoch: common::EpochType(value.epoch.full_value.clone()),
+                quaternion: value.into(),
+            })
+        }
+        "QUATERNION/DERIVATIVE" => {
+            let value = parse_kvn_quaternion_line(input, scalar_first, true)?;
+
+            // The rates are always present because they were requested above
+            let quaternion_rate = QuaternionRateType {
+                q1_dot: value.q1_dot.unwrap_or_default(),
+                q2_dot: value.q2_dot.unwrap_or_default(),
+                q3_dot: value.q3_dot.unwrap_or_default(),
+                qc_dot: value.qc_dot.unwrap_or_default(),
+            };
+
+            AemAttitudeState::QuaternionDerivative(QuaternionDerivativeType {
+                epoch: common::EpochType(value.epoch.full_value.clone()),
+                quaternion: value.into(),
+                quaternion_rate,
+            })
+        }
+        "EULER_ANGLE" => {
+            let value = parse_kvn_euler_angle_line(input, false)?;
+
+            AemAttitudeState::EulerAngle(EulerAngleStateType {
+                rotation_angles: rotation_angles(&value, euler_rot_seq),
+                epoch: common::EpochType(value.epoch.full_value),
+            })
+        }
+        "EULER_ANGLE/RATE" => {
+            let value = parse_kvn_euler_angle_line(input, true)?;
+
+            AemAttitudeState::EulerAngleRate(EulerAngleRateStateType {
+                rotation_angles: rotation_angles(&value, euler_rot_seq),
+                rotation_rates: rotation_rates(&value, euler_rot_seq),
+                epoch: common::EpochType(value.epoch.full_value),
+            })
+        }
+        _ => Err(KvnDeserializerErr::UnsupportedAttitudeType {
+            input: metadata.attitude_type.clone(),
+        })?,
+    };
+
+    Ok(state)
+}
+
+fn expect_kvn_keyword(
+    lines: &mut KvnLines<'_>,
+    keyword: &str,
+) -> Result<(), KvnDeserializerErr<String>> {
+    match get_next_nonempty_line(lines) {
+        None => Err(KvnDeserializerErr::UnexpectedEndOfInput {
+            keyword: keyword.to_string(),
+            line: lines.line_number(),
+        }),
+        Some(next_line) => {
+            if kvn_line_matches_key(keyword, next_line)? {
+                lines.next().unwrap();
+                Ok(())
+            } else {
+                Err(KvnDeserializerErr::UnexpectedKeyword {
+                    found: next_line.to_string(),
+                    expected: keyword.to_string(),
+                    line: lines.line_number(),
+                })
+            }
+        }
+    }
+}
+
+// The layout of the data lines depends on the `ATTITUDE_TYPE` and
+// `QUATERNION_TYPE` of the metadata, which the derived deserializers have no
+// way of passing along. The segment is therefore deserialized by hand.
+impl KvnDeserializer for AemSegment {
+    fn deserialize(lines: &mut KvnLines<'_>) -> Result<Self, KvnDeserializerErr<String>> {
+        let metadata = AemMetadata::deserialize(lines)?;
+
+        expect_kvn_keyword(lines, "DATA_START")?;
+
+        let mut comment_list = Vec::new();
+        let mut attitude_state_list = Vec::new();
+
+        loop {
+            let next_line = get_next_nonempty_line(lines).ok_or_else(|| {
+                KvnDeserializerErr::UnexpectedEndOfInput {
+                    keyword: "DATA_STOP".to_string(),
+                    line: lines.line_number(),
+                }
+            })?;
+
+            if kvn_line_matches_key("DATA_STOP", next_line)? {
+                lines.next().unwrap();
+                break;
+            }
+
+            let line = lines.line_number();
+            if kvn_line_matches_key("COMMENT", next_line)? {
+                comment_list.push(
+                    parse_kvn_string_line(next_line)
+                        .map_err(|e| KvnDeserializerErr::from(e).with_line(line))?
+                        .value,
+                );
+            } else {
+                attitude_state_list.push(AemAttitudeStateType {
+                    state: parse_kvn_attitude_state(next_line, &metadata)
+                        .map_err(|e| e.with_line(line))?,
+                });
+            }
+
+            lines.next().unwrap();
+        }
+
+        Ok(AemSegment {
+            metadata,
+            data: AemData {
+                comment_list,
+                attitude_state_list,
+            },
+        })
+    }
+
+    fn should_check_key_match() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ndm::xml::FromXmlStr;
+
+    use super::*;
+
+    // Adapted from the examples in annex A of CCSDS 504.0-B-1
+    const AEM_KVN: &str = r#"CCSDS_AEM_VERS = 1.0
+CREATION_DATE = 2002-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+COMMENT This file was produced by M.R. Somebody, MSOO NAV/JPL, 2002 OCT 04.
+COMMENT It is to be used for attitude reconstruction only.
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = mars barycenter
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = A2B
+TIME_SYSTEM = UTC
+START_TIME = 1996-11-28T21:29:07.2555
+USEABLE_START_TIME = 1996-11-28T22:08:02.5555
+USEABLE_STOP_TIME = 1996-11-30T01:18:02.5555
+STOP_TIME = 1996-11-30T01:28:02.5555
+ATTITUDE_TYPE = QUATERNION
+QUATERNION_TYPE = LAST
+INTERPOLATION_METHOD = hermite
+INTERPOLATION_DEGREE = 7
+META_STOP
+
+DATA_START
+1996-11-28T21:29:07.2555 0.56748 0.03146 0.45689 0.68427
+1996-11-28T22:08:03.5555 0.42319 -0.45697 0.23784 0.74533
+DATA_STOP
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = A2B
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-18T12:05:00.5555
+STOP_TIME = 1996-12-18T12:10:00.5555
+ATTITUDE_TYPE = QUATERNION/DERIVATIVE
+QUATERNION_TYPE = FIRST
+META_STOP
+
+DATA_START
+COMMENT Spacecraft slewing
+1996-12-18T12:05:00.5555 0.68427 0.56748 0.03146 0.45689 0.0000 0.0005 -0.0002 0.0001
+DATA_STOP
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = A2B
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-18T12:10:00.5555
+STOP_TIME = 1996-12-18T12:20:00.5555
+ATTITUDE_TYPE = EULER_ANGLE/RATE
+EULER_ROT_SEQ = 312
+RATE_FRAME = SC_BODY_1
+META_STOP
+
+DATA_START
+1996-12-18T12:10:00.5555 -53.3688 120.3419 5.8256 0.1 -0.2 0.3
+DATA_STOP"#;
+
+    const AEM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<aem xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+        xsi:noNamespaceSchemaLocation="http://sanaregistry.org/r/ndmxml/ndmxml-1.0-master.xsd"
+        id="CCSDS_AEM_VERS" version="1.0">
+    <header>
+        <CREATION_DATE>2002-11-04T17:22:31</CREATION_DATE>
+        <ORIGINATOR>NASA/JPL</ORIGINATOR>
+    </header>
+    <body>
+        <segment>
+            <metadata>
+                <COMMENT>This file was produced by M.R. Somebody, MSOO NAV/JPL, 2002 OCT 04.</COMMENT>
+                <COMMENT>It is to be used for attitude reconstruction only.</COMMENT>
+                <OBJECT_NAME>MARS GLOBAL SURVEYOR</OBJECT_NAME>
+                <OBJECT_ID>1996-062A</OBJECT_ID>
+                <CENTER_NAME>mars barycenter</CENTER_NAME>
+                <REF_FRAME_A>EME2000</REF_FRAME_A>
+                <REF_FRAME_B>SC_BODY_1</REF_FRAME_B>
+                <ATTITUDE_DIR>A2B</ATTITUDE_DIR>
+                <TIME_SYSTEM>UTC</TIME_SYSTEM>
+                <START_TIME>1996-11-28T21:29:07.2555</START_TIME>
+                <USEABLE_START_TIME>1996-11-28T22:08:02.5555</USEABLE_START_TIME>
+                <USEABLE_STOP_TIME>1996-11-30T01:18:02.5555</USEABLE_STOP_TIME>
+                <STOP_TIME>1996-11-30T01:28:02.5555</STOP_TIME>
+                <ATTITUDE_TYPE>QUATERNION</ATTITUDE_TYPE>
+                <QUATERNION_TYPE>LAST</QUATERNION_TYPE>
+                <INTERPOLATION_METHOD>hermite</INTERPOLATION_METHOD>
+                <INTERPOLATION_DEGREE>7</INTERPOLATION_DEGREE>
+            </metadata>
+            <data>
+                <attitudeState>
+                    <quaternionState>
+                        <EPOCH>1996-11-28T21:29:07.2555</EPOCH>
+                        <quaternion>
+                            <Q1>0.56748</Q1>
+                            <Q2>0.03146</Q2>
+                            <Q3>0.45689</Q3>
+                            <QC>0.68427</QC>
+                        </quaternion>
+                    </quaternionState>
+                </attitudeState>
+                <attitudeState>
+                    <quaternionState>
+                        <EPOCH>1996-11-28T22:08:03.5555</EPOCH>
+                        <quaternion>
+                            <Q1>0.42319</Q1>
+                            <Q2>-0.45697</Q2>
+                            <Q3>0.23784</Q3>
+                            <QC>0.74533</QC>
+                        </quaternion>
+                    </quaternionState>
+                </attitudeState>
+            </data>
+        </segment>
+        <segment>
+            <metadata>
+                <OBJECT_NAME>MARS GLOBAL SURVEYOR</OBJECT_NAME>
+                <OBJECT_ID>1996-062A</OBJECT_ID>
+                <REF_FRAME_A>EME2000</REF_FRAME_A>
+                <REF_FRAME_B>SC_BODY_1</REF_FRAME_B>
+                <ATTITUDE_DIR>A2B</ATTITUDE_DIR>
+                <TIME_SYSTEM>UTC</TIME_SYSTEM>
+                <START_TIME>1996-12-18T12:05:00.5555</START_TIME>
+                <STOP_TIME>1996-12-18T12:10:00.5555</STOP_TIME>
+                <ATTITUDE_TYPE>QUATERNION/DERIVATIVE</ATTITUDE_TYPE>
+                <QUATERNION_TYPE>FIRST</QUATERNION_TYPE>
+            </metadata>
+            <data>
+                <COMMENT>Spacecraft slewing</COMMENT>
+                <attitudeState>
+                    <quaternionDerivative>
+                        <EPOCH>1996-12-18T12:05:00.5555</EPOCH>
+                        <quaternion>
+                            <QC>0.68427</QC>
+                            <Q1>0.56748</Q1>
+                            <Q2>0.03146</Q2>
+                            <Q3>0.45689</Q3>
+                        </quaternion>
+                        <quaternionRate>
+                            <QC_DOT>0.0000</QC_DOT>
+                            <Q1_DOT>0.0005</Q1_DOT>
+                            <Q2_DOT>-0.0002</Q2_DOT>
+                            <Q3_DOT>0.0001</Q3_DOT>
+                        </quaternionRate>
+                    </quaternionDerivative>
+                </attitudeState>
+            </data>
+        </segment>
+        <segment>
+            <metadata>
+                <OBJECT_NAME>MARS GLOBAL SURVEYOR</OBJECT_NAME>
+                <OBJECT_ID>1996-062A</OBJECT_ID>
+                <REF_FRAME_A>EME2000</REF_FRAME_A>
+                <REF_FRAME_B>SC_BODY_1</REF_FRAME_B>
+                <ATTITUDE_DIR>A2B</ATTITUDE_DIR>
+                <TIME_SYSTEM>UTC</TIME_SYSTEM>
+                <START_TIME>1996-12-18T12:10:00.5555</START_TIME>
+                <STOP_TIME>1996-12-18T12:20:00.5555</STOP_TIME>
+                <ATTITUDE_TYPE>EULER_ANGLE/RATE</ATTITUDE_TYPE>
+                <EULER_ROT_SEQ>312</EULER_ROT_SEQ>
+                <RATE_FRAME>SC_BODY_1</RATE_FRAME>
+            </metadata>
+            <data>
+                <attitudeState>
+                    <eulerAngleRate>
+                        <EPOCH>1996-12-18T12:10:00.5555</EPOCH>
+                        <rotationAngles>
+                            <rotation1 angle="Z_ANGLE">-53.3688</rotation1>
+                            <rotation2 angle="X_ANGLE">120.3419</rotation2>
+                            <rotation3 angle="Y_ANGLE">5.8256</rotation3>
+                        </rotationAngles>
+                        <rotationRates>
+                            <rotation1 rate="Z_RATE">0.1</rotation1>
+                            <rotation2 rate="X_RATE">-0.2</rotation2>
+                            <rotation3 rate="Y_RATE">0.3</rotation3>
+                        </rotationRates>
+                    </eulerAngleRate>
+                </attitudeState>
+            </data>
+        </segment>
+    </body>
+</aem>"#;
+
+    fn quaternion(q1: f64, q2: f64, q3: f64, qc: f64) -> QuaternionType {
+        QuaternionType { q1, q2, q3, qc }
+    }
+
+    fn angle(base: f64, angle: &str) -> RotationAngleType {
+        RotationAngleType {
+            base,
+            angle: Some(angle.to_string()),
+            units: None,
+        }
+    }
+
+    fn rate(base: f64, rate: &str) -> RotationRateType {
+        RotationRateType {
+            base,
+            rate: Some(rate.to_string()),
+            units: None,
+        }
+    }
+
+    fn expected_body() -> AemBody {
+        let metadata = AemMetadata {
+            object_name: "MARS GLOBAL SURVEYOR".to_string(),
+            object_id: "1996-062A".to_string(),
+            ref_frame_a: "EME2000".to_string(),
+            ref_frame_b: "SC_BODY_1".to_string(),
+            attitude_dir: "A2B".to_string(),
+            time_system: "UTC".to_string(),
+            ..Default::default()
+        };
+
+        AemBody {
+            segment_list: vec![
+                AemSegment {
+                    metadata: AemMetadata {
+                        comment_list: vec![
+                            "This file was produced by M.R. Somebody, MSOO NAV/JPL, 2002 OCT 04."
+                                .to_string(),
+                            "It is to be used for attitude reconstruction only.".to_string(),
+                        ],
+                        center_name: Some("mars barycenter".to_string()),
+                        start_time: common::EpochType("1996-11-28T21:29:07.2555".to_string()),
+                        useable_start_time: Some(common::EpochType(
+                            "1996-11-28T22:08:02.5555".to_string(),
+                        )),
+                        useable_stop_time: Some(common::EpochType(
+                            "1996-11-30T01:18:02.5555".to_string(),
+                        )),
+                        stop_time: common::EpochType("1996-11-30T01:28:02.5555".to_string()),
+                        attitude_type: "QUATERNION".to_string(),
+                        quaternion_type: Some("LAST".to_string()),
+                        interpolation_method: Some("hermite".to_string()),
+                        interpolation_degree: Some(7),
+                        ..metadata.clone()
+                    },
+                    data: AemData {
+                        comment_list: vec![],
+                        attitude_state_list: vec![
+                            AemAttitudeStateType {
+                                state: AemAttitudeState::Quaternion(QuaternionStateType {
+                                    epoch: common::EpochType(
+                                        "1996-11-28T21:29:07.2555".to_string(),
+                                    ),
+                                    quaternion: quaternion(0.56748, 0.03146, 0.45689, 0.68427),
+                                }),
+                            },
+                            AemAttitudeStateType {
+                                state: AemAttitudeState::Quaternion(QuaternionStateType {
+                                    epoch: common::EpochType(
+                                        "1996-11-28T22:08:03.5555".to_string(),
+                                    ),
+                                    quaternion: quaternion(0.42319, -0.45697, 0.23784, 0.74533),
+                                }),
+                            },
+                        ],
+                    },
+                },
+                AemSegment {
+                    metadata: AemMetadata {
+                        start_time: common::EpochType("1996-12-18T12:05:00.5555".to_string()),
+                        stop_time: common::EpochType("1996-12-18T12:10:00.5555".to_string()),
+                        attitude_type: "QUATERNION/DERIVATIVE".to_string(),
+                        quaternion_type: Some("FIRST".to_string()),
+                        ..metadata.clone()
+                    },
+                    data: AemData {
+                        comment_list: vec!["Spacecraft slewing".to_string()],
+                        attitude_state_list: vec![AemAttitudeStateType {
+                            state: AemAttitudeState::QuaternionDerivative(
+                                QuaternionDerivativeType {
+                                    epoch: common::EpochType(
+                                        "1996-12-18T12:05:00.5555".to_string(),
+                                    ),
+                                    quaternion: quaternion(0.56748, 0.03146, 0.45689, 0.68427),
+                                    quaternion_rate: QuaternionRateType {
+                                        q1_dot: 0.0005,
+                                        q2_dot: -0.0002,
+                                        q3_dot: 0.0001,
+                                        qc_dot: 0.0,
+                                    },
+                                },
+                            ),
+                        }],
+                    },
+                },
+                AemSegment {
+                    metadata: AemMetadata {
+                        start_time: common::EpochType("1996-12-18T12:10:00.5555".to_string()),
+                        stop_time: common::EpochType("1996-12-18T12:20:00.5555".to_string()),
+                        attitude_type: "EULER_ANGLE/RATE".to_string(),
+                        euler_rot_seq: Some("312".to_string()),
+                        rate_frame: Some("SC_BODY_1".to_string()),
+                        ..metadata
+                    },
+                    data: AemData {
+                        comment_list: vec![],
+                        attitude_state_list: vec![AemAttitudeStateType {
+                            state: AemAttitudeState::EulerAngleRate(EulerAngleRateStateType {
+                                epoch: common::EpochType("1996-12-18T12:10:00.5555".to_string()),
+                                rotation_angles: RotationAnglesType {
+                                    rotation1: angle(-53.3688, "Z_ANGLE"),
+                                    rotation2: angle(120.3419, "X_ANGLE"),
+                                    rotation3: angle(5.8256, "Y_ANGLE"),
+                                },
+                                rotation_rates: RotationRatesType {
+                                    rotation1: rate(0.1, "Z_RATE"),
+                                    rotation2: rate(-0.2, "X_RATE"),
+                                    rotation3: rate(0.3, "Y_RATE"),
+                                },
+                            }),
+                        }],
+                    },
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_parse_aem_message_kvn() {
+        let message: AemType = KvnDeserializer::from_kvn_str(AEM_KVN).unwrap();
+
+        assert_eq!(
+            message,
+            AemType {
+                id: None,
+                version: "1.0".to_string(),
+                header: common::AdmHeader {
+                    comment_list: vec![],
+                    creation_date: common::EpochType("2002-11-04T17:22:31".to_string()),
+                    originator: "NASA/JPL".to_string(),
+                    message_id: None,
+                },
+                body: expected_body(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_aem_message_xml() {
+        let message = AemType::from_xml_str(AEM_XML).unwrap();
+
+        assert_eq!(message.id, Some("CCSDS_AEM_VERS".to_string()));
+        assert_eq!(message.version, "1.0");
+        assert_eq!(message.body, expected_body());
+    }
+
+    #[test]
+    fn test_parse_aem_message_kvn_unsupported_attitude_type() {
+        let kvn = AEM_KVN.replace("ATTITUDE_TYPE = QUATERNION\n", "ATTITUDE_TYPE = SPIN\n");
+
+        let message: Result<AemType, _> = KvnDeserializer::from_kvn_str(&kvn);
+
+        assert_eq!(
+            message,
+            Err(KvnDeserializerErr::UnsupportedAttitudeType {
+                input: "SPIN".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_aem_message_kvn_wrong_line_format() {
+        // A plain quaternion line in a QUATERNION/DERIVATIVE segment
+        let kvn = AEM_KVN.replace(
+            "0.68427 0.56748 0.03146 0.45689 0.0000 0.0005 -0.0002 0.0001",
+            "0.68427 0.56748 0.03146 0.45689",
+        );
+
+        let message: Result<AemType, _> = KvnDeserializer::from_kvn_str(&kvn);
+
+        assert_eq!(
+            message,
+            Err(KvnDeserializerErr::InvalidAttitudeStateFormat {
+                input: "1996-12-18T12:05:00.5555 0.68427 0.56748 0.03146 0.45689".to_string(),
+                line: 45,
+            })
+        );
+    }
+}
@@ -0,0 +1,972 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializers for XML and KVN CCSDS Conjunction Data Message
+//!
+//! The conjunction data message is defined in
+//! [CCSDS 508.0-B-1](https://public.ccsds.org/Pubs/508x0b1e2s.pdf). A message
+//! consists of the relative metadata of the conjunction followed by exactly
+//! two segments, one for each object.
+//!
+//! To deserialize an XML message:
+//!
+//! ```
+//! # let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+//! # <cdm id="CCSDS_CDM_VERS" version="1.0">
+//! #     <header>
+//! #         <CREATION_DATE>2010-03-12T22:31:12.000</CREATION_DATE>
+//! #         <ORIGINATOR>JSPOC</ORIGINATOR>
+//! #         <MESSAGE_ID>201113719185</MESSAGE_ID>
+//! #     </header>
+//! #     <body>
+//! #         <relativeMetadataData>
+//! #             <TCA>2010-03-13T22:37:52.618</TCA>
+//! #             <MISS_DISTANCE units="m">715</MISS_DISTANCE>
+//! #         </relativeMetadataData>
+//! #     </body>
+//! # </cdm>"#;
+//! #
+//! # use lox_io::ndm::cdm::CdmType;
+//! use lox_io::ndm::xml::FromXmlStr;
+//!
+//! let message = CdmType::from_xml_str(xml).unwrap();
+//! ```
+
+use super::common::{self, DayIntervalUnits, NonNegativeDouble, PercentageUnits, WkgUnits};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmType {
+    #[serde(rename = "@id")]
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
+    #[serde(rename = "header")]
+    pub header: CdmHeader,
+    #[serde(rename = "body")]
+    pub body: CdmBody,
+}
+
+impl crate::ndm::xml::FromXmlStr<'_> for CdmType {}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmHeader {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "CREATION_DATE")]
+    pub creation_date: common::EpochType,
+    #[serde(rename = "ORIGINATOR")]
+    pub originator: String,
+    #[serde(rename = "MESSAGE_FOR")]
+    pub message_for: Option<String>,
+    #[serde(rename = "MESSAGE_ID")]
+    pub message_id: String,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmBody {
+    #[serde(rename = "relativeMetadataData")]
+    pub relative_metadata_data: RelativeMetadataData,
+    #[serde(rename = "segment")]
+    pub segment_list: Vec<CdmSegment>,
+}
+
+// The field order follows table 3-2 of CCSDS 508.0-B-1 because the KVN
+// parser expects the keywords in that order.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct RelativeMetadataData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "TCA")]
+    pub tca: common::EpochType,
+    #[serde(rename = "MISS_DISTANCE")]
+    pub miss_distance: common::DistanceType,
+    #[serde(rename = "RELATIVE_SPEED")]
+    pub relative_speed: Option<common::VelocityType>,
+    #[serde(rename = "relativeStateVector")]
+    pub relative_state_vector: RelativeStateVectorType,
+    #[serde(rename = "START_SCREEN_PERIOD")]
+    pub start_screen_period: Option<common::EpochType>,
+    #[serde(rename = "STOP_SCREEN_PERIOD")]
+    pub stop_screen_period: Option<common::EpochType>,
+    #[serde(rename = "SCREEN_VOLUME_FRAME")]
+    pub screen_volume_frame: Option<String>,
+    #[serde(rename = "SCREEN_VOLUME_SHAPE")]
+    pub screen_volume_shape: Option<String>,
+    #[serde(rename = "SCREEN_VOLUME_X")]
+    pub screen_volume_x: Option<common::DistanceType>,
+    #[serde(rename = "SCREEN_VOLUME_Y")]
+    pub screen_volume_y: Option<common::DistanceType>,
+    #[serde(rename = "SCREEN_VOLUME_Z")]
+    pub screen_volume_z: Option<common::DistanceType>,
+    #[serde(rename = "SCREEN_ENTRY_TIME")]
+    pub screen_entry_time: Option<common::EpochType>,
+    #[serde(rename = "SCREEN_EXIT_TIME")]
+    pub screen_exit_time: Option<common::EpochType>,
+    #[serde(rename = "COLLISION_PROBABILITY")]
+    pub collision_probability: Option<f64>,
+    #[serde(rename = "COLLISION_PROBABILITY_METHOD")]
+    pub collision_probability_method: Option<String>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct RelativeStateVectorType {
+    #[serde(rename = "RELATIVE_POSITION_R")]
+    pub relative_position_r: Option<common::DistanceType>,
+    #[serde(rename = "RELATIVE_POSITION_T")]
+    pub relative_position_t: Option<common::DistanceType>,
+    #[serde(rename = "RELATIVE_POSITION_N")]
+    pub relative_position_n: Option<common::DistanceType>,
+    #[serde(rename = "RELATIVE_VELOCITY_R")]
+    pub relative_velocity_r: Option<common::VelocityType>,
+    #[serde(rename = "RELATIVE_VELOCITY_T")]
+    pub relative_velocity_t: Option<common::VelocityType>,
+    #[serde(rename = "RELATIVE_VELOCITY_N")]
+    pub relative_velocity_n: Option<common::VelocityType>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmSegment {
+    #[serde(rename = "metadata")]
+    pub metadata: CdmMetadata,
+    #[serde(rename = "data")]
+    pub data: CdmData,
+}
+
+// The field order follows table 3-3 of CCSDS 508.0-B-1 because the KVN
+// parser expects the keywords in that order.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmMetadata {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "OBJECT")]
+    pub object: String,
+    #[serde(rename = "OBJECT_DESIGNATOR")]
+    pub object_designator: String,
+    #[serde(rename = "CATALOG_NAME")]
+    pub catalog_name: String,
+    #[serde(rename = "OBJECT_NAME")]
+    pub object_name: String,
+    #[serde(rename = "INTERNATIONAL_DESIGNATOR")]
+    pub international_designator: String,
+    #[serde(rename = "OBJECT_TYPE")]
+    pub object_type: Option<String>,
+    #[serde(rename = "OPERATOR_CONTACT_POSITION")]
+    pub operator_contact_position: Option<String>,
+    #[serde(rename = "OPERATOR_ORGANIZATION")]
+    pub operator_organization: Option<String>,
+    #[serde(rename = "OPERATOR_PHONE")]
+    pub operator_phone: Option<String>,
+    #[serde(rename = "OPERATOR_EMAIL")]
+    pub operator_email: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME")]
+    pub ephemeris_name: String,
+    #[serde(rename = "COVARIANCE_METHOD")]
+    pub covariance_method: String,
+    #[serde(rename = "MANEUVERABLE")]
+    pub maneuverable: String,
+    #[serde(rename = "ORBIT_CENTER")]
+    pub orbit_center: Option<String>,
+    #[serde(rename = "REF_FRAME")]
+    pub ref_frame: String,
+    #[serde(rename = "GRAVITY_MODEL")]
+    pub gravity_model: Option<String>,
+    #[serde(rename = "ATMOSPHERIC_MODEL")]
+    pub atmospheric_model: Option<String>,
+    #[serde(rename = "N_BODY_PERTURBATIONS")]
+    pub n_body_perturbations: Option<String>,
+    #[serde(rename = "SOLAR_RAD_PRESSURE")]
+    pub solar_rad_pressure: Option<String>,
+    #[serde(rename = "EARTH_TIDES")]
+    pub earth_tides: Option<String>,
+    #[serde(rename = "INTRACK_THRUST")]
+    pub intrack_thrust: Option<String>,
+}
+
+// The OD and additional parameter blocks are not optional here because the
+// KVN parser always yields the (possibly empty) nested struct.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "odParameters")]
+    pub od_parameters: CdmOdParametersType,
+    #[serde(rename = "additionalParameters")]
+    pub additional_parameters: CdmAdditionalParametersType,
+    #[serde(rename = "stateVector")]
+    pub state_vector: CdmStateVectorType,
+    #[serde(rename = "covarianceMatrix")]
+    pub covariance_matrix: CdmCovarianceMatrixType,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmOdParametersType {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "TIME_LASTOB_START")]
+    pub time_lastob_start: Option<common::EpochType>,
+    #[serde(rename = "TIME_LASTOB_END")]
+    pub time_lastob_end: Option<common::EpochType>,
+    #[serde(rename = "RECOMMENDED_OD_SPAN")]
+    pub recommended_od_span: Option<CdmDayIntervalType>,
+    #[serde(rename = "ACTUAL_OD_SPAN")]
+    pub actual_od_span: Option<CdmDayIntervalType>,
+    #[serde(rename = "OBS_AVAILABLE")]
+    pub obs_available: Option<u64>,
+    #[serde(rename = "OBS_USED")]
+    pub obs_used: Option<u64>,
+    #[serde(rename = "TRACKS_AVAILABLE")]
+    pub tracks_available: Option<u64>,
+    #[serde(rename = "TRACKS_USED")]
+    pub tracks_used: Option<u64>,
+    #[serde(rename = "RESIDUALS_ACCEPTED")]
+    pub residuals_accepted: Option<CdmPercentageType>,
+    #[serde(rename = "WEIGHTED_RMS")]
+    pub weighted_rms: Option<NonNegativeDouble>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmAdditionalParametersType {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "AREA_PC")]
+    pub area_pc: Option<common::AreaType>,
+    #[serde(rename = "MASS")]
+    pub mass: Option<common::MassType>,
+    #[serde(rename = "CD_AREA_OVER_MASS")]
+    pub cd_area_over_mass: Option<M2kgType>,
+    #[serde(rename = "CR_AREA_OVER_MASS")]
+    pub cr_area_over_mass: Option<M2kgType>,
+    #[serde(rename = "THRUST_ACCELERATION")]
+    pub thrust_acceleration: Option<common::AccType>,
+    #[serde(rename = "SEDR")]
+    pub sedr: Option<CdmWkgType>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmStateVectorType {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "X")]
+    pub x: common::PositionType,
+    #[serde(rename = "Y")]
+    pub y: common::PositionType,
+    #[serde(rename = "Z")]
+    pub z: common::PositionType,
+    #[serde(rename = "X_DOT")]
+    pub x_dot: common::VelocityType,
+    #[serde(rename = "Y_DOT")]
+    pub y_dot: common::VelocityType,
+    #[serde(rename = "Z_DOT")]
+    pub z_dot: common::VelocityType,
+}
+
+/// The lower triangle of the RTN covariance matrix of an object.
+///
+/// Only the 3x3 position block is mandatory. The velocity rows complete the
+/// 6x6 matrix and the drag, solar radiation pressure and thrust rows extend
+/// it to 9x9.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmCovarianceMatrixType {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "CR_R")]
+    pub cr_r: common::PositionCovarianceType,
+    #[serde(rename = "CT_R")]
+    pub ct_r: common::PositionCovarianceType,
+    #[serde(rename = "CT_T")]
+    pub ct_t: common::PositionCovarianceType,
+    #[serde(rename = "CN_R")]
+    pub cn_r: common::PositionCovarianceType,
+    #[serde(rename = "CN_T")]
+    pub cn_t: common::PositionCovarianceType,
+    #[serde(rename = "CN_N")]
+    pub cn_n: common::PositionCovarianceType,
+    #[serde(rename = "CRDOT_R")]
+    pub crdot_r: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CRDOT_T")]
+    pub crdot_t: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CRDOT_N")]
+    pub crdot_n: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CRDOT_RDOT")]
+    pub crdot_rdot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CTDOT_R")]
+    pub ctdot_r: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CTDOT_T")]
+    pub ctdot_t: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CTDOT_N")]
+    pub ctdot_n: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CTDOT_RDOT")]
+    pub ctdot_rdot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CTDOT_TDOT")]
+    pub ctdot_tdot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CNDOT_R")]
+    pub cndot_r: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CNDOT_T")]
+    pub cndot_t: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CNDOT_N")]
+    pub cndot_n: Option<common::PositionVelocityCovarianceType>,
+    #[serde(rename = "CNDOT_RDOT")]
+    pub cndot_rdot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CNDOT_TDOT")]
+    pub cndot_tdot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CNDOT_NDOT")]
+    pub cndot_ndot: Option<common::VelocityCovarianceType>,
+    #[serde(rename = "CDRG_R")]
+    pub cdrg_r: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_T")]
+    pub cdrg_t: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_N")]
+    pub cdrg_n: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_RDOT")]
+    pub cdrg_rdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_TDOT")]
+    pub cdrg_tdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_NDOT")]
+    pub cdrg_ndot: Option<CdmCovarianceType>,
+    #[serde(rename = "CDRG_DRG")]
+    pub cdrg_drg: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_R")]
+    pub csrp_r: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_T")]
+    pub csrp_t: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_N")]
+    pub csrp_n: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_RDOT")]
+    pub csrp_rdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_TDOT")]
+    pub csrp_tdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_NDOT")]
+    pub csrp_ndot: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_DRG")]
+    pub csrp_drg: Option<CdmCovarianceType>,
+    #[serde(rename = "CSRP_SRP")]
+    pub csrp_srp: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_R")]
+    pub cthr_r: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_T")]
+    pub cthr_t: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_N")]
+    pub cthr_n: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_RDOT")]
+    pub cthr_rdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_TDOT")]
+    pub cthr_tdot: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_NDOT")]
+    pub cthr_ndot: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_DRG")]
+    pub cthr_drg: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_SRP")]
+    pub cthr_srp: Option<CdmCovarianceType>,
+    #[serde(rename = "CTHR_THR")]
+    pub cthr_thr: Option<CdmCovarianceType>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(value_unit_struct)]
+pub struct CdmDayIntervalType {
+    #[serde(rename = "$text")]
+    pub base: NonNegativeDouble,
+    #[serde(rename = "@units")]
+    pub units: Option<DayIntervalUnits>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(value_unit_struct)]
+pub struct CdmPercentageType {
+    #[serde(rename = "$text")]
+    pub base: f64,
+    #[serde(rename = "@units")]
+    pub units: Option<PercentageUnits>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(value_unit_struct)]
+pub struct CdmWkgType {
+    #[serde(rename = "$text")]
+    pub base: NonNegativeDouble,
+    #[serde(rename = "@units")]
+    pub units: Option<WkgUnits>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct M2kgUnits(#[serde(rename = "$text")] pub String);
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(value_unit_struct)]
+pub struct M2kgType {
+    #[serde(rename = "$text")]
+    pub base: NonNegativeDouble,
+    #[serde(rename = "@units")]
+    pub units: Option<M2kgUnits>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct CdmCovarianceUnits(#[serde(rename = "$text")] pub String);
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(value_unit_struct)]
+pub struct CdmCovarianceType {
+    #[serde(rename = "$text")]
+    pub base: f64,
+    #[serde(rename = "@units")]
+    pub units: Option<CdmCovarianceUnits>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ndm::kvn::KvnDeserializer;
+    use crate::ndm::xml::FromXmlStr;
+
+    use super::*;
+
+    // Adapted from the example in annex C of CCSDS 508.0-B-1. The second
+    // object only carries the position block of the covariance matrix.
+    const CDM_KVN: &str = r#"CCSDS_CDM_VERS = 1.0
+CREATION_DATE = 2010-03-12T22:31:12.000
+ORIGINATOR = JSPOC
+MESSAGE_FOR = SATELLITE A
+MESSAGE_ID = 201113719185
+TCA = 2010-03-13T22:37:52.618
+MISS_DISTANCE = 715 [m]
+RELATIVE_SPEED = 14762 [m/s]
+RELATIVE_POSITION_R = 27.4 [m]
+RELATIVE_POSITION_T = -70.2 [m]
+RELATIVE_POSITION_N = 711.8 [m]
+RELATIVE_VELOCITY_R = -7.2 [m/s]
+RELATIVE_VELOCITY_T = -14692.0 [m/s]
+RELATIVE_VELOCITY_N = -1437.2 [m/s]
+START_SCREEN_PERIOD = 2010-03-12T18:29:32.212
+STOP_SCREEN_PERIOD = 2010-03-15T18:29:32.212
+SCREEN_VOLUME_FRAME = RTN
+SCREEN_VOLUME_SHAPE = ELLIPSOID
+SCREEN_VOLUME_X = 200 [m]
+SCREEN_VOLUME_Y = 1000 [m]
+SCREEN_VOLUME_Z = 1000 [m]
+SCREEN_ENTRY_TIME = 2010-03-13T22:37:52.222
+SCREEN_EXIT_TIME = 2010-03-13T22:37:52.824
+COLLISION_PROBABILITY = 4.835E-05
+COLLISION_PROBABILITY_METHOD = FOSTER-1992
+
+OBJECT = OBJECT1
+OBJECT_DESIGNATOR = 12345
+CATALOG_NAME = SATCAT
+OBJECT_NAME = SATELLITE A
+INTERNATIONAL_DESIGNATOR = 1997-030E
+OBJECT_TYPE = PAYLOAD
+OPERATOR_CONTACT_POSITION = OSA
+OPERATOR_ORGANIZATION = EUMETSAT
+OPERATOR_PHONE = +49615130312
+OPERATOR_EMAIL = JOHN.DOE@SOMEWHERE.NET
+EPHEMERIS_NAME = EPHEMERIS SATELLITE A
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = YES
+REF_FRAME = EME2000
+GRAVITY_MODEL = EGM-96: 36D 36O
+ATMOSPHERIC_MODEL = JACCHIA 70 DCA
+N_BODY_PERTURBATIONS = MOON, SUN
+SOLAR_RAD_PRESSURE = NO
+EARTH_TIDES = NO
+INTRACK_THRUST = NO
+COMMENT Covariance derived from a batch least squares fit
+TIME_LASTOB_START = 2010-03-12T02:14:12.746
+TIME_LASTOB_END = 2010-03-12T02:14:12.746
+RECOMMENDED_OD_SPAN = 7.88 [d]
+ACTUAL_OD_SPAN = 5.50 [d]
+OBS_AVAILABLE = 592
+OBS_USED = 579
+TRACKS_AVAILABLE = 123
+TRACKS_USED = 119
+RESIDUALS_ACCEPTED = 97.8 [%]
+WEIGHTED_RMS = 0.864
+AREA_PC = 5.2 [m**2]
+MASS = 251.6 [kg]
+CD_AREA_OVER_MASS = 0.045663 [m**2/kg]
+CR_AREA_OVER_MASS = 0.000000 [m**2/kg]
+THRUST_ACCELERATION = 0.0 [m/s**2]
+SEDR = 4.54570E-05 [W/kg]
+X = 2570.097065 [km]
+Y = 2244.654904 [km]
+Z = 6281.497978 [km]
+X_DOT = 4.418769571 [km/s]
+Y_DOT = 4.833547743 [km/s]
+Z_DOT = -3.526774282 [km/s]
+CR_R = 4.142E+01 [m**2]
+CT_R = -8.579E+00 [m**2]
+CT_T = 2.533E+03 [m**2]
+CN_R = -2.313E+01 [m**2]
+CN_T = 1.336E+01 [m**2]
+CN_N = 7.098E+01 [m**2]
+CRDOT_R = 2.520E-03 [m**2/s]
+CRDOT_T = -5.476E+00 [m**2/s]
+CRDOT_N = 8.626E-04 [m**2/s]
+CRDOT_RDOT = 5.744E-03 [m**2/s**2]
+CTDOT_R = -1.006E-02 [m**2/s]
+CTDOT_T = 4.041E-03 [m**2/s]
+CTDOT_N = -1.359E-03 [m**2/s]
+CTDOT_RDOT = -1.502E-05 [m**2/s**2]
+CTDOT_TDOT = 1.049E-05 [m**2/s**2]
+CNDOT_R = 1.053E-03 [m**2/s]
+CNDOT_T = -3.412E-03 [m**2/s]
+CNDOT_N = 1.213E-02 [m**2/s]
+CNDOT_RDOT = -3.004E-06 [m**2/s**2]
+CNDOT_TDOT = -1.091E-06 [m**2/s**2]
+CNDOT_NDOT = 5.529E-05 [m**2/s**2]
+
+OBJECT = OBJECT2
+OBJECT_DESIGNATOR = 30337
+CATALOG_NAME = SATCAT
+OBJECT_NAME = FENGYUN 1C DEB
+INTERNATIONAL_DESIGNATOR = 1999-025AA
+OBJECT_TYPE = DEBRIS
+EPHEMERIS_NAME = NONE
+COVARIANCE_METHOD = CALCULATED
+MANEUVERABLE = NO
+REF_FRAME = EME2000
+GRAVITY_MODEL = EGM-96: 36D 36O
+ATMOSPHERIC_MODEL = JACCHIA 70 DCA
+N_BODY_PERTURBATIONS = MOON, SUN
+SOLAR_RAD_PRESSURE = YES
+EARTH_TIDES = NO
+INTRACK_THRUST = NO
+X = 2569.540800 [km]
+Y = 2245.093614 [km]
+Z = 6281.599946 [km]
+X_DOT = -2.888612500 [km/s]
+Y_DOT = -6.007247516 [km/s]
+Z_DOT = 3.328770172 [km/s]
+CR_R = 1.337E+03 [m**2]
+CT_R = -4.806E+04 [m**2]
+CT_T = 2.492E+06 [m**2]
+CN_R = -3.298E+01 [m**2]
+CN_T = -7.5888E+02 [m**2]
+CN_N = 7.105E+01 [m**2]"#;
+
+    const CDM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cdm xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+        xsi:noNamespaceSchemaLocation="http://sanaregistry.org/r/ndmxml/ndmxml-1.0-master.xsd"
+        id="CCSDS_CDM_VERS" version="1.0">
+    <header>
+        <CREATION_DATE>2010-03-12T22:31:12.000</CREATION_DATE>
+        <ORIGINATOR>JSPOC</ORIGINATOR>
+        <MESSAGE_FOR>SATELLITE A</MESSAGE_FOR>
+        <MESSAGE_ID>201113719185</MESSAGE_ID>
+    </header>
+    <body>
+        <relativeMetadataData>
+            <TCA>2010-03-13T22:37:52.618</TCA>
+            <MISS_DISTANCE units="m">715</MISS_DISTANCE>
+            <RELATIVE_SPEED units="m/s">14762</RELATIVE_SPEED>
+            <relativeStateVector>
+                <RELATIVE_POSITION_R units="m">27.4</RELATIVE_POSITION_R>
+                <RELATIVE_POSITION_T units="m">-70.2</RELATIVE_POSITION_T>
+                <RELATIVE_POSITION_N units="m">711.8</RELATIVE_POSITION_N>
+                <RELATIVE_VELOCITY_R units="m/s">-7.2</RELATIVE_VELOCITY_R>
+                <RELATIVE_VELOCITY_T units="m/s">-14692.0</RELATIVE_VELOCITY_T>
+                <RELATIVE_VELOCITY_N units="m/s">-1437.2</RELATIVE_VELOCITY_N>
+            </relativeStateVector>
+            <START_SCREEN_PERIOD>2010-03-12T18:29:32.212</START_SCREEN_PERIOD>
+            <STOP_SCREEN_PERIOD>2010-03-15T18:29:32.212</STOP_SCREEN_PERIOD>
+            <SCREEN_VOLUME_FRAME>RTN</SCREEN_VOLUME_FRAME>
+            <SCREEN_VOLUME_SHAPE>ELLIPSOID</SCREEN_VOLUME_SHAPE>
+            <SCREEN_VOLUME_X units="m">200</SCREEN_VOLUME_X>
+            <SCREEN_VOLUME_Y units="m">1000</SCREEN_VOLUME_Y>
+            <SCREEN_VOLUME_Z units="m">1000</SCREEN_VOLUME_Z>
+            <SCREEN_ENTRY_TIME>2010-03-13T22:37:52.222</SCREEN_ENTRY_TIME>
+            <SCREEN_EXIT_TIME>2010-03-13T22:37:52.824</SCREEN_EXIT_TIME>
+            <COLLISION_PROBABILITY>4.835E-05</COLLISION_PROBABILITY>
+            <COLLISION_PROBABILITY_METHOD>FOSTER-1992</COLLISION_PROBABILITY_METHOD>
+        </relativeMetadataData>
+        <segment>
+            <metadata>
+                <OBJECT>OBJECT1</OBJECT>
+                <OBJECT_DESIGNATOR>12345</OBJECT_DESIGNATOR>
+                <CATALOG_NAME>SATCAT</CATALOG_NAME>
+                <OBJECT_NAME>SATELLITE A</OBJECT_NAME>
+                <INTERNATIONAL_DESIGNATOR>1997-030E</INTERNATIONAL_DESIGNATOR>
+                <OBJECT_TYPE>PAYLOAD</OBJECT_TYPE>
+                <OPERATOR_CONTACT_POSITION>OSA</OPERATOR_CONTACT_POSITION>
+                <OPERATOR_ORGANIZATION>EUMETSAT</OPERATOR_ORGANIZATION>
+                <OPERATOR_PHONE>+49615130312</OPERATOR_PHONE>
+                <OPERATOR_EMAIL>JOHN.DOE@SOMEWHERE.NET</OPERATOR_EMAIL>
+                <EPHEMERIS_NAME>EPHEMERIS SATELLITE A</EPHEMERIS_NAME>
+                <COVARIANCE_METHOD>CALCULATED</COVARIANCE_METHOD>
+                <MANEUVERABLE>YES</MANEUVERABLE>
+                <REF_FRAME>EME2000</REF_FRAME>
+                <GRAVITY_MODEL>EGM-96: 36D 36O</GRAVITY_MODEL>
+                <ATMOSPHERIC_MODEL>JACCHIA 70 DCA</ATMOSPHERIC_MODEL>
+                <N_BODY_PERTURBATIONS>MOON, SUN</N_BODY_PERTURBATIONS>
+                <SOLAR_RAD_PRESSURE>NO</SOLAR_RAD_PRESSURE>
+                <EARTH_TIDES>NO</EARTH_TIDES>
+                <INTRACK_THRUST>NO</INTRACK_THRUST>
+            </metadata>
+            <data>
+                <COMMENT>Covariance derived from a batch least squares fit</COMMENT>
+                <odParameters>
+                    <TIME_LASTOB_START>2010-03-12T02:14:12.746</TIME_LASTOB_START>
+                    <TIME_LASTOB_END>2010-03-12T02:14:12.746</TIME_LASTOB_END>
+                    <RECOMMENDED_OD_SPAN units="d">7.88</RECOMMENDED_OD_SPAN>
+                    <ACTUAL_OD_SPAN units="d">5.50</ACTUAL_OD_SPAN>
+                    <OBS_AVAILABLE>592</OBS_AVAILABLE>
+                    <OBS_USED>579</OBS_USED>
+                    <TRACKS_AVAILABLE>123</TRACKS_AVAILABLE>
+                    <TRACKS_USED>119</TRACKS_USED>
+                    <RESIDUALS_ACCEPTED units="%">97.8</RESIDUALS_ACCEPTED>
+                    <WEIGHTED_RMS>0.864</WEIGHTED_RMS>
+                </odParameters>
+                <additionalParameters>
+                    <AREA_PC units="m**2">5.2</AREA_PC>
+                    <MASS units="kg">251.6</MASS>
+                    <CD_AREA_OVER_MASS units="m**2/kg">0.045663</CD_AREA_OVER_MASS>
+                    <CR_AREA_OVER_MASS units="m**2/kg">0.000000</CR_AREA_OVER_MASS>
+                    <THRUST_ACCELERATION units="m/s**2">0.0</THRUST_ACCELERATION>
+                    <SEDR units="W/kg">4.54570E-05</SEDR>
+                </additionalParameters>
+                <stateVector>
+                    <X units="km">2570.097065</X>
+                    <Y units="km">2244.654904</Y>
+                    <Z units="km">6281.497978</Z>
+                    <X_DOT units="km/s">4.418769571</X_DOT>
+                    <Y_DOT units="km/s">4.833547743</Y_DOT>
+                    <Z_DOT units="km/s">-3.526774282</Z_DOT>
+                </stateVector>
+                <covarianceMatrix>
+                    <CR_R units="m**2">4.142E+01</CR_R>
+                    <CT_R units="m**2">-8.579E+00</CT_R>
+                    <CT_T units="m**2">2.533E+03</CT_T>
+                    <CN_R units="m**2">-2.313E+01</CN_R>
+                    <CN_T units="m**2">1.336E+01</CN_T>
+                    <CN_N units="m**2">7.098E+01</CN_N>
+                    <CRDOT_R units="m**2/s">2.520E-03</CRDOT_R>
+                    <CRDOT_T units="m**2/s">-5.476E+00</CRDOT_T>
+                    <CRDOT_N units="m**2/s">8.626E-04</CRDOT_N>
+                    <CRDOT_RDOT units="m**2/s**2">5.744E-03</CRDOT_RDOT>
+                    <CTDOT_R units="m**2/s">-1.006E-02</CTDOT_R>
+                    <CTDOT_T units="m**2/s">4.041E-03</CTDOT_T>
+                    <CTDOT_N units="m**2/s">-1.359E-03</CTDOT_N>
+                    <CTDOT_RDOT units="m**2/s**2">-1.502E-05</CTDOT_RDOT>
+                    <CTDOT_TDOT units="m**2/s**2">1.049E-05</CTDOT_TDOT>
+                    <CNDOT_R units="m**2/s">1.053E-03</CNDOT_R>
+                    <CNDOT_T units="m**2/s">-3.412E-03</CNDOT_T>
+                    <CNDOT_N units="m**2/s">1.213E-02</CNDOT_N>
+                    <CNDOT_RDOT units="m**2/s**2">-3.004E-06</CNDOT_RDOT>
+                    <CNDOT_TDOT units="m**2/s**2">-1.091E-06</CNDOT_TDOT>
+                    <CNDOT_NDOT units="m**2/s**2">5.529E-05</CNDOT_NDOT>
+                </covarianceMatrix>
+            </data>
+        </segment>
+        <segment>
+            <metadata>
+                <OBJECT>OBJECT2</OBJECT>
+                <OBJECT_DESIGNATOR>30337</OBJECT_DESIGNATOR>
+                <CATALOG_NAME>SATCAT</CATALOG_NAME>
+                <OBJECT_NAME>FENGYUN 1C DEB</OBJECT_NAME>
+                <INTERNATIONAL_DESIGNATOR>1999-025AA</INTERNATIONAL_DESIGNATOR>
+                <OBJECT_TYPE>DEBRIS</OBJECT_TYPE>
+                <EPHEMERIS_NAME>NONE</EPHEMERIS_NAME>
+                <COVARIANCE_METHOD>CALCULATED</COVARIANCE_METHOD>
+                <MANEUVERABLE>NO</MANEUVERABLE>
+                <REF_FRAME>EME2000</REF_FRAME>
+                <GRAVITY_MODEL>EGM-96: 36D 36O</GRAVITY_MODEL>
+                <ATMOSPHERIC_MODEL>JACCHIA 70 DCA</ATMOSPHERIC_MODEL>
+                <N_BODY_PERTURBATIONS>MOON, SUN</N_BODY_PERTURBATIONS>
+                <SOLAR_RAD_PRESSURE>YES</SOLAR_RAD_PRESSURE>
+                <EARTH_TIDES>NO</EARTH_TIDES>
+                <INTRACK_THRUST>NO</INTRACK_THRUST>
+            </metadata>
+            <data>
+                <stateVector>
+                    <X units="km">2569.540800</X>
+                    <Y units="km">2245.093614</Y>
+                    <Z units="km">6281.599946</Z>
+                    <X_DOT units="km/s">-2.888612500</X_DOT>
+                    <Y_DOT units="km/s">-6.007247516</Y_DOT>
+                    <Z_DOT units="km/s">3.328770172</Z_DOT>
+                </stateVector>
+                <covarianceMatrix>
+                    <CR_R units="m**2">1.337E+03</CR_R>
+                    <CT_R units="m**2">-4.806E+04</CT_R>
+                    <CT_T units="m**2">2.492E+06</CT_T>
+                    <CN_R units="m**2">-3.298E+01</CN_R>
+                    <CN_T units="m**2">-7.5888E+02</CN_T>
+                    <CN_N units="m**2">7.105E+01</CN_N>
+                </covarianceMatrix>
+            </data>
+        </segment>
+    </body>
+</cdm>"#;
+
+    fn assert_example(message: &CdmType) {
+        assert_eq!(message.version, "1.0");
+        assert_eq!(message.header.originator, "JSPOC");
+        assert_eq!(message.header.message_for.as_deref(), Some("SATELLITE A"));
+        assert_eq!(message.header.message_id, "201113719185");
+
+        let relative = &message.body.relative_metadata_data;
+        assert_eq!(relative.tca.0, "2010-03-13T22:37:52.618");
+        assert_eq!(relative.miss_distance.base, 715.0);
+        assert_eq!(
+            relative.miss_distance.units,
+            Some(common::PositionUnits("m".to_string()))
+        );
+        assert_eq!(
+            relative
+                .relative_state_vector
+                .relative_velocity_t
+                .as_ref()
+                .map(|v| v.base),
+            Some(-14692.0)
+        );
+        assert_eq!(relative.collision_probability, Some(4.835e-5));
+        assert_eq!(
+            relative.collision_probability_method.as_deref(),
+            Some("FOSTER-1992")
+        );
+
+        assert_eq!(message.body.segment_list.len(), 2);
+
+        let object1 = &message.body.segment_list[0];
+        assert_eq!(object1.metadata.object, "OBJECT1");
+        assert_eq!(object1.metadata.object_designator, "12345");
+        assert_eq!(object1.metadata.ref_frame, "EME2000");
+        assert_eq!(
+            object1.data.comment_list,
+            vec!["Covariance derived from a batch least squares fit".to_string()]
+        );
+        assert_eq!(object1.data.od_parameters.obs_used, Some(579));
+        assert_eq!(
+            object1.data.od_parameters.residuals_accepted,
+            Some(CdmPercentageType {
+                base: 97.8,
+                units: Some(PercentageUnits("%".to_string())),
+            })
+        );
+        assert_eq!(
+            object1.data.additional_parameters.sedr,
+            Some(CdmWkgType {
+                base: NonNegativeDouble(4.5457e-5),
+                units: Some(WkgUnits("W/kg".to_string())),
+            })
+        );
+        assert_eq!(object1.data.state_vector.x.base, 2570.097065);
+        assert_eq!(object1.data.covariance_matrix.cr_r.base, 41.42);
+        assert_eq!(
+            object1
+                .data
+                .covariance_matrix
+                .cndot_ndot
+                .as_ref()
+                .map(|c| c.base),
+            Some(5.529e-5)
+        );
+        assert_eq!(object1.data.covariance_matrix.cdrg_r, None);
+
+        let object2 = &message.body.segment_list[1];
+        assert_eq!(object2.metadata.object, "OBJECT2");
+        assert_eq!(object2.metadata.operator_email, None);
+        assert_eq!(object2.data.od_parameters, CdmOdParametersType::default());
+        assert_eq!(object2.data.state_vector.z_dot.base, 3.328770172);
+        assert_eq!(object2.data.covariance_matrix.cn_t.base, -758.88);
+        assert_eq!(object2.data.covariance_matrix.crdot_r, None);
+    }
+
+    #[test]
+    fn test_parse_cdm_message_kvn() {
+        let message: CdmType = KvnDeserializer::from_kvn_str(CDM_KVN).unwrap();
+
+        assert_example(&message);
+    }
+
+    #[test]
+    fn test_parse_cdm_message_xml() {
+        let message = CdmType::from_xml_str(CDM_XML).unwrap();
+
+        assert_eq!(message.id.as_deref(), Some("CCSDS_CDM_VERS"));
+        assert_example(&message);
+    }
+
+    #[test]
+    fn test_parse_cdm_message_kvn_and_xml_agree() {
+        let kvn: CdmType = KvnDeserializer::from_kvn_str(CDM_KVN).unwrap();
+        let xml = CdmType::from_xml_str(CDM_XML).unwrap();
+
+        assert_eq!(kvn.header, xml.header);
+        assert_eq!(kvn.body, xml.body);
+        assert_eq!(kvn.version, xml.version);
+    }
+}
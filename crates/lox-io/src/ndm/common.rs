@@ -349,6 +349,27 @@ pub struct OdmHeader {
     pub message_id: Option<String>,
 }
 
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AdmHeader {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "CREATION_DATE")]
+    pub creation_date: EpochType,
+    #[serde(rename = "ORIGINATOR")]
+    pub originator: String,
+    #[serde(rename = "MESSAGE_ID")]
+    pub message_id: Option<String>,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1075,3 +1096,141 @@ pub struct UserDefinedParameterType {
     #[serde(rename = "@parameter")]
     pub parameter: String,
 }
+
+#[derive(Clone, Debug, thiserror::Error, PartialEq)]
+#[error("unsupported units `{0}`")]
+pub struct UnsupportedUnitsError(pub String);
+
+// Omitted `units` attributes denote the CCSDS default units, i.e. the same scale as an explicit
+// `km`, `km/s`, or `km/s**2`
+fn kilometre_scale(units: Option<&str>, suffix: &str) -> Result<f64, UnsupportedUnitsError> {
+    let Some(units) = units else {
+        return Ok(1.0);
+    };
+    match units.trim().strip_suffix(suffix) {
+        Some("km") => Ok(1.0),
+        Some("m") => Ok(1e-3),
+        _ => Err(UnsupportedUnitsError(units.to_string())),
+    }
+}
+
+impl PositionType {
+    /// Returns the position component in km.
+    pub fn to_km(&self) -> Result<f64, UnsupportedUnitsError> {
+        let units = self.units.as_ref().map(|units| units.0.as_str());
+        Ok(self.base * kilometre_scale(units, "")?)
+    }
+}
+
+impl VelocityType {
+    /// Returns the velocity component in km/s.
+    pub fn to_km_per_s(&self) -> Result<f64, UnsupportedUnitsError> {
+        let units = self.units.as_ref().map(|units| units.0.as_str());
+        Ok(self.base * kilometre_scale(units, "/s")?)
+    }
+}
+
+impl AccType {
+    /// Returns the acceleration component in km/s².
+    pub fn to_km_per_s2(&self) -> Result<f64, UnsupportedUnitsError> {
+        let units = self.units.as_ref().map(|units| units.0.as_str());
+        Ok(self.base * kilometre_scale(units, "/s**2")?)
+    }
+}
+
+impl OemCovarianceMatrixType {
+    /// Returns the KVN lines of the covariance block without the `COVARIANCE_START` and
+    /// `COVARIANCE_STOP` keywords. The units of an element are written in square brackets after
+    /// its value if they are present.
+    pub fn to_kvn_lines(&self) -> Vec<String> {
+        fn element(base: f64, units: Option<&str>) -> String {
+            match units {
+                Some(units) => format!("{:?} [{}]", base, units),
+                None => format!("{:?}", base),
+            }
+        }
+        let position = |c: &PositionCovarianceType| {
+            element(c.base, c.units.as_ref().map(|units| units.0.as_str()))
+        };
+        let position_velocity = |c: &PositionVelocityCovarianceType| {
+            element(c.base, c.units.as_ref().map(|units| units.0.as_str()))
+        };
+        let velocity = |c: &VelocityCovarianceType| {
+            element(c.base, c.units.as_ref().map(|units| units.0.as_str()))
+        };
+
+        let mut lines: Vec<String> = self
+            .comment_list
+            .iter()
+            .map(|comment| format!("COMMENT {comment}"))
+            .collect();
+        lines.push(format!("EPOCH = {}", self.epoch.0));
+        if let Some(cov_ref_frame) = &self.cov_ref_frame {
+            lines.push(format!("COV_REF_FRAME = {cov_ref_frame}"));
+        }
+        let rows = [
+            vec![position(&self.cx_x)],
+            vec![position(&self.cy_x), position(&self.cy_y)],
+            vec![
+                position(&self.cz_x),
+                position(&self.cz_y),
+                position(&self.cz_z),
+            ],
+            vec![
+                position_velocity(&self.cx_dot_x),
+                position_velocity(&self.cx_dot_y),
+                position_velocity(&self.cx_dot_z),
+                velocity(&self.cx_dot_x_dot),
+            ],
+            vec![
+                position_velocity(&self.cy_dot_x),
+                position_velocity(&self.cy_dot_y),
+                position_velocity(&self.cy_dot_z),
+                velocity(&self.cy_dot_x_dot),
+                velocity(&self.cy_dot_y_dot),
+            ],
+            vec![
+                position_velocity(&self.cz_dot_x),
+                position_velocity(&self.cz_dot_y),
+                position_velocity(&self.cz_dot_z),
+                velocity(&self.cz_dot_x_dot),
+                velocity(&self.cz_dot_y_dot),
+                velocity(&self.cz_dot_z_dot),
+            ],
+        ];
+        lines.extend(rows.iter().map(|row| row.join(" ")));
+        lines
+    }
+}
+
+impl StateVectorType {
+    /// Returns the position in km, independent of which components declare their units.
+    pub fn position_km(&self) -> Result<[f64; 3], UnsupportedUnitsError> {
+        Ok([self.x.to_km()?, self.y.to_km()?, self.z.to_km()?])
+    }
+
+    /// Returns the velocity in km/s, independent of which components declare their units.
+    pub fn velocity_km_per_s(&self) -> Result<[f64; 3], UnsupportedUnitsError> {
+        Ok([
+            self.x_dot.to_km_per_s()?,
+            self.y_dot.to_km_per_s()?,
+            self.z_dot.to_km_per_s()?,
+        ])
+    }
+}
+
+impl StateVectorAccType {
+    /// Returns the position in km, independent of which components declare their units.
+    pub fn position_km(&self) -> Result<[f64; 3], UnsupportedUnitsError> {
+        Ok([self.x.to_km()?, self.y.to_km()?, self.z.to_km()?])
+    }
+
+    /// Returns the velocity in km/s, independent of which components declare their units.
+    pub fn velocity_km_per_s(&self) -> Result<[f64; 3], UnsupportedUnitsError> {
+        Ok([
+            self.x_dot.to_km_per_s()?,
+            self.y_dot.to_km_per_s()?,
+            self.z_dot.to_km_per_s()?,
+        ])
+    }
+}
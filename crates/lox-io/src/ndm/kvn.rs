@@ -1,6 +1,8 @@
-//! The public interface for the `KvnDeserializer` type
+//! The public interface for the `KvnDeserializer` type and the KVN serialization errors
 
 mod deserializer;
 pub(crate) mod parser;
+pub(crate) mod serializer;
 
 pub use deserializer::{KvnDeserializer, KvnDeserializerErr};
+pub use serializer::KvnSerializerErr;
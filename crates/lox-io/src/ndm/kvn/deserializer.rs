@@ -8,10 +8,10 @@
 
 use nom::error::ErrorKind;
 
+use super::parser::KvnLines;
+
 pub trait KvnDeserializer {
-    fn deserialize<'a>(
-        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
-    ) -> Result<Self, KvnDeserializerErr<String>>
+    fn deserialize(lines: &mut KvnLines<'_>) -> Result<Self, KvnDeserializerErr<String>>
     where
         Self: Sized;
 
@@ -19,24 +19,97 @@ pub trait KvnDeserializer {
     where
         Self: Sized,
     {
-        Self::deserialize(&mut kvn.lines().peekable())
+        Self::deserialize(&mut KvnLines::new(kvn))
     }
 
     fn should_check_key_match() -> bool;
 }
 
+// The `line` fields hold the one-based line number in the KVN input
 #[derive(PartialEq, Clone, thiserror::Error, Debug)]
 pub enum KvnDeserializerErr<I> {
-    InvalidDateTimeFormat { input: I },
-    InvalidNumberFormat { input: I },
-    InvalidStringFormat { input: I },
-    InvalidStateVectorFormat { input: I },
-    InvalidCovarianceMatrixFormat { input: I },
+    #[error("invalid date time format at line {line}: `{input}`")]
+    InvalidDateTimeFormat { input: I, line: usize },
+    #[error("invalid number format at line {line}: `{input}`")]
+    InvalidNumberFormat { input: I, line: usize },
+    #[error("invalid string format at line {line}: `{input}`")]
+    InvalidStringFormat { input: I, line: usize },
+    #[error("invalid state vector format at line {line}: `{input}`")]
+    InvalidStateVectorFormat { input: I, line: usize },
+    #[error("invalid covariance matrix format at line {line}: `{input}`")]
+    InvalidCovarianceMatrixFormat { input: I, line: usize },
+    #[error("invalid tracking data observation format at line {line}: `{input}`")]
+    InvalidTdmObservationFormat { input: I, line: usize },
+    #[error("invalid attitude state format at line {line}: `{input}`")]
+    InvalidAttitudeStateFormat { input: I, line: usize },
+    #[error("unsupported attitude type `{input}`")]
+    UnsupportedAttitudeType { input: I },
+    #[error("keyword {expected} not found")]
     KeywordNotFound { expected: I },
     // Has a second meaning: it stops the iterator for vector type deserializers
-    UnexpectedKeyword { found: I, expected: I },
+    #[error("expected {expected} at line {line}, found {found}")]
+    UnexpectedKeyword { found: I, expected: I, line: usize },
+    #[error("empty keyword in `{input}`")]
     EmptyKeyword { input: I },
+    #[error("empty value in `{input}`")]
     EmptyValue { input: I },
-    UnexpectedEndOfInput { keyword: I },
+    #[error("unexpected end of input at line {line}, expected {keyword}")]
+    UnexpectedEndOfInput { keyword: I, line: usize },
+    #[error("failed to parse `{0}`: {1:?}")]
     GeneralParserError(I, ErrorKind),
 }
+
+impl<I> KvnDeserializerErr<I> {
+    /// Returns the line number of errors which refer to a single line of the input.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::InvalidDateTimeFormat { line, .. }
+            | Self::InvalidNumberFormat { line, .. }
+            | Self::InvalidStringFormat { line, .. }
+            | Self::InvalidStateVectorFormat { line, .. }
+            | Self::InvalidCovarianceMatrixFormat { line, .. }
+            | Self::InvalidTdmObservationFormat { line, .. }
+            | Self::InvalidAttitudeStateFormat { line, .. }
+            | Self::UnexpectedKeyword { line, .. }
+            | Self::UnexpectedEndOfInput { line, .. } => Some(*line),
+            _ => None,
+        }
+    }
+
+    /// Sets the line number of errors which refer to a single line of the input.
+    ///
+    /// The conversions from the line parser errors cannot know the line number and set it to
+    /// zero.
+    pub fn with_line(self, line: usize) -> Self {
+        match self {
+            Self::InvalidDateTimeFormat { input, .. } => {
+                Self::InvalidDateTimeFormat { input, line }
+            }
+            Self::InvalidNumberFormat { input, .. } => Self::InvalidNumberFormat { input, line },
+            Self::InvalidStringFormat { input, .. } => Self::InvalidStringFormat { input, line },
+            Self::InvalidStateVectorFormat { input, .. } => {
+                Self::InvalidStateVectorFormat { input, line }
+            }
+            Self::InvalidCovarianceMatrixFormat { input, .. } => {
+                Self::InvalidCovarianceMatrixFormat { input, line }
+            }
+            Self::InvalidTdmObservationFormat { input, .. } => {
+                Self::InvalidTdmObservationFormat { input, line }
+            }
+            Self::InvalidAttitudeStateFormat { input, .. } => {
+                Self::InvalidAttitudeStateFormat { input, line }
+            }
+            Self::UnexpectedKeyword {
+                found, expected, ..
+            } => Self::UnexpectedKeyword {
+                found,
+                expected,
+                line,
+            },
+            Self::UnexpectedEndOfInput { keyword, .. } => {
+                Self::UnexpectedEndOfInput { keyword, line }
+            }
+            e => e,
+        }
+    }
+}
@@ -25,6 +25,16 @@ pub enum KvnStateVectorParserErr<I> {
     InvalidFormat { input: I },
 }
 
+#[derive(Debug, PartialEq)]
+pub enum KvnTdmObservationParserErr<I> {
+    InvalidFormat { input: I },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KvnAttitudeStateParserErr<I> {
+    InvalidFormat { input: I },
+}
+
 #[derive(Debug, PartialEq)]
 pub enum KvnCovarianceMatrixParserErr<I> {
     InvalidItemCount { input: I },
@@ -57,6 +67,33 @@ impl From<KvnStateVectorParserErr<&str>> for KvnDeserializerErr<String> {
             KvnStateVectorParserErr::InvalidFormat { input } => {
                 KvnDeserializerErr::InvalidStateVectorFormat {
                     input: input.to_string(),
+                    line: 0,
+                }
+            }
+        }
+    }
+}
+
+impl From<KvnTdmObservationParserErr<&str>> for KvnDeserializerErr<String> {
+    fn from(value: KvnTdmObservationParserErr<&str>) -> Self {
+        match value {
+            KvnTdmObservationParserErr::InvalidFormat { input } => {
+                KvnDeserializerErr::InvalidTdmObservationFormat {
+                    input: input.to_string(),
+                    line: 0,
+                }
+            }
+        }
+    }
+}
+
+impl From<KvnAttitudeStateParserErr<&str>> for KvnDeserializerErr<String> {
+    fn from(value: KvnAttitudeStateParserErr<&str>) -> Self {
+        match value {
+            KvnAttitudeStateParserErr::InvalidFormat { input } => {
+                KvnDeserializerErr::InvalidAttitudeStateFormat {
+                    input: input.to_string(),
+                    line: 0,
                 }
             }
         }
@@ -69,16 +106,19 @@ impl From<KvnCovarianceMatrixParserErr<&str>> for KvnDeserializerErr<String> {
             KvnCovarianceMatrixParserErr::InvalidItemCount { input } => {
                 KvnDeserializerErr::InvalidCovarianceMatrixFormat {
                     input: input.to_string(),
+                    line: 0,
                 }
             }
             KvnCovarianceMatrixParserErr::InvalidFormat { input } => {
                 KvnDeserializerErr::InvalidCovarianceMatrixFormat {
                     input: input.to_string(),
+                    line: 0,
                 }
             }
             KvnCovarianceMatrixParserErr::UnexpectedEndOfInput { keyword } => {
                 KvnDeserializerErr::UnexpectedEndOfInput {
                     keyword: keyword.to_string(),
+                    line: 0,
                 }
             }
         }
@@ -97,6 +137,7 @@ impl From<KvnStringParserErr<&str>> for KvnDeserializerErr<String> {
             KvnStringParserErr::InvalidFormat { input } => {
                 KvnDeserializerErr::InvalidStringFormat {
                     input: input.to_string(),
+                    line: 0,
                 }
             }
         }
@@ -115,6 +156,7 @@ impl From<KvnDateTimeParserErr<&str>> for KvnDeserializerErr<String> {
             KvnDateTimeParserErr::InvalidFormat { input } => {
                 KvnDeserializerErr::InvalidDateTimeFormat {
                     input: input.to_string(),
+                    line: 0,
                 }
             }
         }
@@ -131,8 +173,9 @@ impl From<KvnNumberParserErr<&str>> for KvnDeserializerErr<String> {
                 input: input.to_string(),
             },
             KvnNumberParserErr::InvalidFormat { input } => {
-                KvnDeserializerErr::InvalidDateTimeFormat {
+                KvnDeserializerErr::InvalidNumberFormat {
                     input: input.to_string(),
+                    line: 0,
                 }
             }
         }
@@ -179,34 +222,100 @@ pub struct KvnStateVectorValue {
     pub z_ddot: Option<f64>,
 }
 
+#[derive(PartialEq, Debug, Default)]
+pub struct KvnTdmObservationValue {
+    pub keyword: String,
+    pub epoch: KvnDateTimeValue,
+    pub value: f64,
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub struct KvnQuaternionValue {
+    pub epoch: KvnDateTimeValue,
+    pub q1: f64,
+    pub q2: f64,
+    pub q3: f64,
+    pub qc: f64,
+    pub q1_dot: Option<f64>,
+    pub q2_dot: Option<f64>,
+    pub q3_dot: Option<f64>,
+    pub qc_dot: Option<f64>,
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub struct KvnEulerAngleValue {
+    pub epoch: KvnDateTimeValue,
+    pub angle_1: f64,
+    pub angle_2: f64,
+    pub angle_3: f64,
+    pub rate_1: Option<f64>,
+    pub rate_2: Option<f64>,
+    pub rate_3: Option<f64>,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub struct KvnCovarianceMatrixValue {
-    pub cx_x: f64,
-    pub cy_x: f64,
-    pub cy_y: f64,
-    pub cz_x: f64,
-    pub cz_y: f64,
-    pub cz_z: f64,
-    pub cx_dot_x: f64,
-    pub cx_dot_y: f64,
-    pub cx_dot_z: f64,
-    pub cx_dot_x_dot: f64,
-    pub cy_dot_x: f64,
-    pub cy_dot_y: f64,
-    pub cy_dot_z: f64,
-    pub cy_dot_x_dot: f64,
-    pub cy_dot_y_dot: f64,
-    pub cz_dot_x: f64,
-    pub cz_dot_y: f64,
-    pub cz_dot_z: f64,
-    pub cz_dot_x_dot: f64,
-    pub cz_dot_y_dot: f64,
-    pub cz_dot_z_dot: f64,
+    pub cx_x: KvnValue<f64, String>,
+    pub cy_x: KvnValue<f64, String>,
+    pub cy_y: KvnValue<f64, String>,
+    pub cz_x: KvnValue<f64, String>,
+    pub cz_y: KvnValue<f64, String>,
+    pub cz_z: KvnValue<f64, String>,
+    pub cx_dot_x: KvnValue<f64, String>,
+    pub cx_dot_y: KvnValue<f64, String>,
+    pub cx_dot_z: KvnValue<f64, String>,
+    pub cx_dot_x_dot: KvnValue<f64, String>,
+    pub cy_dot_x: KvnValue<f64, String>,
+    pub cy_dot_y: KvnValue<f64, String>,
+    pub cy_dot_z: KvnValue<f64, String>,
+    pub cy_dot_x_dot: KvnValue<f64, String>,
+    pub cy_dot_y_dot: KvnValue<f64, String>,
+    pub cz_dot_x: KvnValue<f64, String>,
+    pub cz_dot_y: KvnValue<f64, String>,
+    pub cz_dot_z: KvnValue<f64, String>,
+    pub cz_dot_x_dot: KvnValue<f64, String>,
+    pub cz_dot_y_dot: KvnValue<f64, String>,
+    pub cz_dot_z_dot: KvnValue<f64, String>,
+}
+
+/// The lines of a KVN message together with the number of the next line.
+pub struct KvnLines<'a> {
+    lines: std::iter::Peekable<std::str::Lines<'a>>,
+    line_number: usize,
+}
+
+impl<'a> KvnLines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines().peekable(),
+            line_number: 1,
+        }
+    }
+
+    pub fn peek(&mut self) -> Option<&&'a str> {
+        self.lines.peek()
+    }
+
+    /// Returns the one-based number of the line which is returned by the next call to
+    /// [Iterator::next].
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl<'a> Iterator for KvnLines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next();
+        if line.is_some() {
+            self.line_number += 1;
+        }
+        line
+    }
 }
 
-pub fn get_next_nonempty_line<'a>(
-    lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
-) -> Option<&'a str> {
+pub fn get_next_nonempty_line<'a>(lines: &mut KvnLines<'a>) -> Option<&'a str> {
     loop {
         match lines.peek() {
             None => return None,
@@ -248,7 +357,7 @@ pub fn parse_kvn_state_vector(
 ) -> Result<KvnStateVectorValue, KvnStateVectorParserErr<&str>> {
     // This line is written in regex hell
     let re = Regex::new(
-        r"^(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s+)(?<x>(?:(?:[^ ]*)?))(?:\s+)(?<y>(?:(?:[^ ]*)?))(?:\s+)(?<z>(?:(?:[^ ]*)?))(?:\s+)(?<x_dot>(?:(?:[^ ]*)?))(?:\s+)(?<y_dot>(?:(?:[^ ]*)?))(?:\s+)(?<z_dot>(?:(?:[^ ]*)?))((?:\s+)(?<x_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<y_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<z_ddot>(?:(?:[^ ]*)?)))?(?:\s*)$",)
+        r"^(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?:(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))|(?<doy>(?:00[1-9]|0[1-9]\d|[12]\d{2}|3[0-5]\d|36[0-6])))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s+)(?<x>(?:(?:[^ ]*)?))(?:\s+)(?<y>(?:(?:[^ ]*)?))(?:\s+)(?<z>(?:(?:[^ ]*)?))(?:\s+)(?<x_dot>(?:(?:[^ ]*)?))(?:\s+)(?<y_dot>(?:(?:[^ ]*)?))(?:\s+)(?<z_dot>(?:(?:[^ ]*)?))((?:\s+)(?<x_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<y_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<z_ddot>(?:(?:[^ ]*)?)))?(?:\s*)$",)
     .unwrap();
 
     let captures = re
@@ -304,27 +413,177 @@ pub fn parse_kvn_state_vector(
     })
 }
 
+// TDM data lines have the form `KEYWORD = EPOCH VALUE` (CCSDS 503.0-B-2,
+// section 3.5.1.2), which none of the other line parsers understand.
+pub fn parse_kvn_tdm_observation(
+    input: &str,
+) -> Result<KvnTdmObservationValue, KvnTdmObservationParserErr<&str>> {
+    let re = Regex::new(
+        r"^(?:\s*)(?<keyword>[0-9A-Z_]+)(?:\s*)=(?:\s*)(?<full_date_value>(?<yr>(?:\d{4}))-(?:(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))|(?<doy>(?:00[1-9]|0[1-9]\d|[12]\d{2}|3[0-5]\d|36[0-6])))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s+)(?<value>(?:[^ ]+))(?:\s*)$",
+    )
+    .unwrap();
+
+    let captures = re
+        .captures(input)
+        .ok_or(KvnTdmObservationParserErr::InvalidFormat { input })?;
+
+    let keyword = captures
+        .name("keyword")
+        // This unwrap is okay because the keyword uses + so it will always capture
+        .unwrap()
+        .as_str()
+        .to_string();
+
+    let epoch = handle_datetime_capture(&captures);
+
+    let value = captures
+        .name("value")
+        // This unwrap is okay because the value uses + so it will always capture
+        .unwrap()
+        .as_str()
+        .parse::<f64>()
+        .map_err(|_| KvnTdmObservationParserErr::InvalidFormat { input })?;
+
+    Ok(KvnTdmObservationValue {
+        keyword,
+        epoch,
+        value,
+    })
+}
+
+// Splits an AEM data line into its epoch and exactly `expected_count` numbers.
+fn parse_kvn_attitude_line(
+    input: &str,
+    expected_count: usize,
+) -> Result<(KvnDateTimeValue, Vec<f64>), KvnAttitudeStateParserErr<&str>> {
+    let re = Regex::new(
+        r"^(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?:(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))|(?<doy>(?:00[1-9]|0[1-9]\d|[12]\d{2}|3[0-5]\d|36[0-6])))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?<values>(?:\s+[^ ]+)*)(?:\s*)$",
+    )
+    .unwrap();
+
+    let captures = re
+        .captures(input)
+        .ok_or(KvnAttitudeStateParserErr::InvalidFormat { input })?;
+
+    let epoch = handle_datetime_capture(&captures);
+
+    let values = captures
+        .name("values")
+        // This unwrap is okay because the values use * so they will always capture
+        .unwrap()
+        .as_str()
+        .split_whitespace()
+        .map(|value| value.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| KvnAttitudeStateParserErr::InvalidFormat { input })?;
+
+    if values.len() != expected_count {
+        return Err(KvnAttitudeStateParserErr::InvalidFormat { input });
+    }
+
+    Ok((epoch, values))
+}
+
+/// Parses an AEM `QUATERNION` or `QUATERNION/DERIVATIVE` data line. The
+/// scalar component comes first when `QUATERNION_TYPE = FIRST` and last
+/// otherwise. The derivative terms follow the same ordering.
+pub fn parse_kvn_quaternion_line(
+    input: &str,
+    scalar_first: bool,
+    with_derivative: bool,
+) -> Result<KvnQuaternionValue, KvnAttitudeStateParserErr<&str>> {
+    let expected_count = if with_derivative { 8 } else { 4 };
+
+    let (epoch, values) = parse_kvn_attitude_line(input, expected_count)?;
+
+    let reorder = |v: &[f64]| {
+        if scalar_first {
+            (v[1], v[2], v[3], v[0])
+        } else {
+            (v[0], v[1], v[2], v[3])
+        }
+    };
+
+    let (q1, q2, q3, qc) = reorder(&values[..4]);
+    let rates = with_derivative.then(|| reorder(&values[4..]));
+
+    Ok(KvnQuaternionValue {
+        epoch,
+        q1,
+        q2,
+        q3,
+        qc,
+        q1_dot: rates.map(|r| r.0),
+        q2_dot: rates.map(|r| r.1),
+        q3_dot: rates.map(|r| r.2),
+        qc_dot: rates.map(|r| r.3),
+    })
+}
+
+/// Parses an AEM `EULER_ANGLE` or `EULER_ANGLE/RATE` data line. The angles
+/// and rates are given in the order of the `EULER_ROT_SEQ` rotations.
+pub fn parse_kvn_euler_angle_line(
+    input: &str,
+    with_rate: bool,
+) -> Result<KvnEulerAngleValue, KvnAttitudeStateParserErr<&str>> {
+    let expected_count = if with_rate { 6 } else { 3 };
+
+    let (epoch, values) = parse_kvn_attitude_line(input, expected_count)?;
+
+    Ok(KvnEulerAngleValue {
+        epoch,
+        angle_1: values[0],
+        angle_2: values[1],
+        angle_3: values[2],
+        rate_1: values.get(3).copied(),
+        rate_2: values.get(4).copied(),
+        rate_3: values.get(5).copied(),
+    })
+}
+
+/// Parses a line of a covariance matrix. Each element may be followed by its units in square
+/// brackets, e.g. `3.3313494e-04 [km**2]`, with or without separating whitespace.
 fn parse_kvn_covariance_matrix_line<'a, T: Iterator<Item = &'a str> + ?Sized>(
     input: &mut T,
     expected_count: usize,
-) -> Result<Vec<f64>, KvnCovarianceMatrixParserErr<&'a str>> {
+) -> Result<Vec<KvnValue<f64, String>>, KvnCovarianceMatrixParserErr<&'a str>> {
     let next_line = input
         .next()
         .ok_or(KvnCovarianceMatrixParserErr::UnexpectedEndOfInput {
             keyword: "COVARIANCE_MATRIX ",
         })?;
 
-    let result: Result<Vec<f64>, _> = next_line
-        .split_whitespace()
-        .map(|matrix_element| {
-            matrix_element
-                .trim()
-                .parse::<f64>()
-                .map_err(|_| KvnCovarianceMatrixParserErr::InvalidFormat { input: next_line })
-        })
-        .collect();
+    let invalid_format = || KvnCovarianceMatrixParserErr::InvalidFormat { input: next_line };
+
+    let mut result: Vec<KvnValue<f64, String>> = Vec::with_capacity(expected_count);
 
-    let result = result?;
+    for token in next_line.split_whitespace() {
+        let (value, unit) = match token.find('[') {
+            Some(index) => {
+                let unit = token[index..]
+                    .strip_prefix('[')
+                    .and_then(|unit| unit.strip_suffix(']'))
+                    .filter(|unit| !unit.is_empty() && !unit.contains(['[', ']']))
+                    .ok_or_else(invalid_format)?;
+                (&token[..index], Some(unit.to_string()))
+            }
+            None => (token, None),
+        };
+
+        if value.is_empty() {
+            // A units token separated from its element by whitespace
+            match result.last_mut() {
+                Some(element) if element.unit.is_none() => element.unit = unit,
+                _ => return Err(invalid_format()),
+            }
+            continue;
+        }
+
+        result.push(KvnValue {
+            value: value.parse::<f64>().map_err(|_| invalid_format())?,
+            unit,
+        });
+    }
 
     if result.len() != expected_count {
         return Err(KvnCovarianceMatrixParserErr::InvalidItemCount { input: next_line });
@@ -339,53 +598,53 @@ pub fn parse_kvn_covariance_matrix<'a, T: Iterator<Item = &'a str> + ?Sized>(
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 1)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cx_x = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cx_x = iter.next().unwrap();
 
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 2)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cy_x = *iter.next().unwrap();
-    let cy_y = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cy_x = iter.next().unwrap();
+    let cy_y = iter.next().unwrap();
 
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 3)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cz_x = *iter.next().unwrap();
-    let cz_y = *iter.next().unwrap();
-    let cz_z = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cz_x = iter.next().unwrap();
+    let cz_y = iter.next().unwrap();
+    let cz_z = iter.next().unwrap();
 
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 4)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cx_dot_x = *iter.next().unwrap();
-    let cx_dot_y = *iter.next().unwrap();
-    let cx_dot_z = *iter.next().unwrap();
-    let cx_dot_x_dot = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cx_dot_x = iter.next().unwrap();
+    let cx_dot_y = iter.next().unwrap();
+    let cx_dot_z = iter.next().unwrap();
+    let cx_dot_x_dot = iter.next().unwrap();
 
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 5)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cy_dot_x = *iter.next().unwrap();
-    let cy_dot_y = *iter.next().unwrap();
-    let cy_dot_z = *iter.next().unwrap();
-    let cy_dot_x_dot = *iter.next().unwrap();
-    let cy_dot_y_dot = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cy_dot_x = iter.next().unwrap();
+    let cy_dot_y = iter.next().unwrap();
+    let cy_dot_z = iter.next().unwrap();
+    let cy_dot_x_dot = iter.next().unwrap();
+    let cy_dot_y_dot = iter.next().unwrap();
 
     let tokenized_line = parse_kvn_covariance_matrix_line(input, 6)?;
 
     // Unwrap is okay because we check the number of elements before
-    let mut iter = tokenized_line.iter();
-    let cz_dot_x = *iter.next().unwrap();
-    let cz_dot_y = *iter.next().unwrap();
-    let cz_dot_z = *iter.next().unwrap();
-    let cz_dot_x_dot = *iter.next().unwrap();
-    let cz_dot_y_dot = *iter.next().unwrap();
-    let cz_dot_z_dot = *iter.next().unwrap();
+    let mut iter = tokenized_line.into_iter();
+    let cz_dot_x = iter.next().unwrap();
+    let cz_dot_y = iter.next().unwrap();
+    let cz_dot_z = iter.next().unwrap();
+    let cz_dot_x_dot = iter.next().unwrap();
+    let cz_dot_y_dot = iter.next().unwrap();
+    let cz_dot_z_dot = iter.next().unwrap();
 
     Ok(KvnCovarianceMatrixValue {
         cx_x,
@@ -479,7 +738,7 @@ where
     };
 
     let regex_pattern = if with_unit {
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?))?(?:\s*)?$"
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?))?(?:\s*)?$"
     } else {
         r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?)(?:\s*)$"
     };
@@ -516,7 +775,7 @@ where
 
 fn is_empty_value(input: &str) -> bool {
     let re = Regex::new(
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?)?$",
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?)?$",
     )
     .unwrap();
 
@@ -533,7 +792,7 @@ pub fn parse_kvn_numeric_line(
 
     let regex_pattern = if with_unit {
         // Figure F-9: CCSDS 502.0-B-3
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?))?(?:\s*)?$"
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?))?(?:\s*)?$"
     } else {
         r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?)(?:\s*)?$"
     };
@@ -567,6 +826,24 @@ pub fn parse_kvn_numeric_line(
     Ok(KvnValue { value, unit })
 }
 
+// Converts the day of year of the `YYYY-DDDThh:mm:ss` epoch form, which
+// CCSDS NDMs allow besides calendar dates, into the month and day of the month.
+fn month_and_day(year: u16, day_of_year: u16) -> (u8, u8) {
+    let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let february = if is_leap_year { 29 } else { 28 };
+    let mut day = day_of_year;
+    for (month, days) in [31, february, 31, 30, 31, 30, 31, 31, 30, 31, 30]
+        .into_iter()
+        .enumerate()
+    {
+        if day <= days {
+            return (month as u8 + 1, day as u8);
+        }
+        day -= days;
+    }
+    (12, day as u8)
+}
+
 pub fn handle_datetime_capture(captures: &regex::Captures) -> KvnDateTimeValue {
     // yr is a mandatory decimal in the regex so we expect the capture to be
     // always there and unwrap is fine
@@ -580,13 +857,15 @@ pub fn handle_datetime_capture(captures: &regex::Captures) -> KvnDateTimeValue {
     // We don't do full validation of the date values. We only care if they
     // have the expected number of digits
 
-    // mo is a mandatory decimal in the regex so we expect the capture to be
-    // always there and unwrap is fine
-    let month = captures.name("mo").unwrap().as_str().parse::<u8>().unwrap();
-
-    // day is a mandatory decimal in the regex so we expect the capture to be
-    // always there and unwrap is fine
-    let day = captures.name("dy").unwrap().as_str().parse::<u8>().unwrap();
+    // Either mo and dy or doy are mandatory decimals in the regex so we
+    // expect the captures to be there and unwrap is fine
+    let (month, day) = match captures.name("doy") {
+        Some(doy) => month_and_day(year, doy.as_str().parse::<u16>().unwrap()),
+        None => (
+            captures.name("mo").unwrap().as_str().parse::<u8>().unwrap(),
+            captures.name("dy").unwrap().as_str().parse::<u8>().unwrap(),
+        ),
+    };
 
     // hr is a mandatory decimal in the regex so we expect the capture to be
     // always there and unwrap is fine
@@ -635,7 +914,7 @@ pub fn parse_kvn_datetime_line(
     };
 
     // Modified from Figure F-5: CCSDS 502.0-B-3
-    let re = Regex::new(r"^(?:\s*)?(?<keyword>[0-9A-Z_]*)(?:\s*)?=(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s*)?$").unwrap();
+    let re = Regex::new(r"^(?:\s*)?(?<keyword>[0-9A-Z_]*)(?:\s*)?=(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?:(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))|(?<doy>(?:00[1-9]|0[1-9]\d|[12]\d{2}|3[0-5]\d|36[0-6])))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s*)?$").unwrap();
 
     let captures = re
         .captures(input)
@@ -1030,7 +1309,38 @@ mod test {
             })
         );
 
-        // @TODO add support for ddd format
+        // Epochs may also be given as day of year, i.e. YYYY-DDDThh:mm:ss
+
+        assert_eq!(
+            parse_kvn_datetime_line("CREATION_DATE = 2005-160T20:15:00"),
+            Ok(KvnDateTimeValue {
+                year: 2005,
+                month: 6,
+                day: 9,
+                hour: 20,
+                minute: 15,
+                second: 0,
+                fractional_second: 0.0,
+                full_value: "2005-160T20:15:00".to_string(),
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_datetime_line("CREATION_DATE = 2024-060T00:00:00").map(|d| (d.month, d.day)),
+            Ok((2, 29))
+        );
+
+        assert_eq!(
+            parse_kvn_datetime_line("CREATION_DATE = 2024-366T00:00:00").map(|d| (d.month, d.day)),
+            Ok((12, 31))
+        );
+
+        assert_eq!(
+            parse_kvn_datetime_line("CREATION_DATE = 2024-000T00:00:00"),
+            Err(KvnDateTimeParserErr::InvalidFormat {
+                input: "CREATION_DATE = 2024-000T00:00:00"
+            })
+        );
 
         assert_eq!(
             parse_kvn_datetime_line("CREATION_DATE = 2021,06,03Q05!33!00-123"),
@@ -1105,7 +1415,7 @@ mod test {
         ASDFG = 12333.5123"#;
 
         assert_eq!(
-            crate::ndm::kvn::KvnDeserializer::deserialize(&mut kvn.lines().peekable()),
+            crate::ndm::kvn::KvnDeserializer::deserialize(&mut KvnLines::new(kvn)),
             Ok(AsdType {
                 semi_major_axis: DistanceType {
                     base: 41399.5123,
@@ -1211,8 +1521,214 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_tdm_observation_parser() {
+        assert_eq!(
+            parse_kvn_tdm_observation("RECEIVE_FREQ_1 = 2005-06-08T17:41:00 -409.2735"),
+            Ok(KvnTdmObservationValue {
+                keyword: "RECEIVE_FREQ_1".to_string(),
+                epoch: KvnDateTimeValue {
+                    year: 2005,
+                    month: 6,
+                    day: 8,
+                    hour: 17,
+                    minute: 41,
+                    second: 0,
+                    fractional_second: 0.0,
+                    full_value: "2005-06-08T17:41:00".to_string(),
+                },
+                value: -409.2735,
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_tdm_observation("   RANGE=2005-06-08T18:27:00.5     3.92429985151986e7   "),
+            Ok(KvnTdmObservationValue {
+                keyword: "RANGE".to_string(),
+                epoch: KvnDateTimeValue {
+                    year: 2005,
+                    month: 6,
+                    day: 8,
+                    hour: 18,
+                    minute: 27,
+                    second: 0,
+                    fractional_second: 0.5,
+                    full_value: "2005-06-08T18:27:00.5".to_string(),
+                },
+                value: 39242998.5151986,
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_tdm_observation("RANGE = 2005-159T18:27:00 39242998.5"),
+            Ok(KvnTdmObservationValue {
+                keyword: "RANGE".to_string(),
+                epoch: KvnDateTimeValue {
+                    year: 2005,
+                    month: 6,
+                    day: 8,
+                    hour: 18,
+                    minute: 27,
+                    second: 0,
+                    fractional_second: 0.0,
+                    full_value: "2005-159T18:27:00".to_string(),
+                },
+                value: 39242998.5,
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_tdm_observation("RANGE = 2005-367T18:27:00 39242998.5"),
+            Err(KvnTdmObservationParserErr::InvalidFormat {
+                input: "RANGE = 2005-367T18:27:00 39242998.5"
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_tdm_observation("DATA_STOP"),
+            Err(KvnTdmObservationParserErr::InvalidFormat { input: "DATA_STOP" })
+        );
+
+        assert_eq!(
+            parse_kvn_tdm_observation("RANGE = 2005-06-08T18:27:00 abc"),
+            Err(KvnTdmObservationParserErr::InvalidFormat {
+                input: "RANGE = 2005-06-08T18:27:00 abc"
+            })
+        );
+    }
+
+    #[test]
+    fn test_quaternion_parser() {
+        let epoch = KvnDateTimeValue {
+            year: 1996,
+            month: 11,
+            day: 28,
+            hour: 21,
+            minute: 29,
+            second: 7,
+            fractional_second: 0.25,
+            full_value: "1996-11-28T21:29:07.25".to_string(),
+        };
+
+        assert_eq!(
+            parse_kvn_quaternion_line(
+                "1996-11-28T21:29:07.25 0.56748 0.03146 0.45689 0.68427",
+                false,
+                false
+            ),
+            Ok(KvnQuaternionValue {
+                epoch: KvnDateTimeValue {
+                    full_value: "1996-11-28T21:29:07.25".to_string(),
+                    ..epoch
+                },
+                q1: 0.56748,
+                q2: 0.03146,
+                q3: 0.45689,
+                qc: 0.68427,
+                q1_dot: None,
+                q2_dot: None,
+                q3_dot: None,
+                qc_dot: None,
+            })
+        );
+
+        // With QUATERNION_TYPE = FIRST the scalar part leads both the
+        // quaternion and its derivative
+        assert_eq!(
+            parse_kvn_quaternion_line(
+                "  1996-11-28T21:29:07.25   0.68427 0.56748 0.03146 0.45689 0.4 0.1 0.2 0.3  ",
+                true,
+                true
+            ),
+            Ok(KvnQuaternionValue {
+                epoch: KvnDateTimeValue {
+                    full_value: "1996-11-28T21:29:07.25".to_string(),
+                    ..epoch
+                },
+                q1: 0.56748,
+                q2: 0.03146,
+                q3: 0.45689,
+                qc: 0.68427,
+                q1_dot: Some(0.1),
+                q2_dot: Some(0.2),
+                q3_dot: Some(0.3),
+                qc_dot: Some(0.4),
+            })
+        );
+
+        // A derivative line is not a valid plain quaternion line and vice versa
+        let input = "1996-11-28T21:29:07.25 0.68427 0.56748 0.03146 0.45689 0.4 0.1 0.2 0.3";
+        assert_eq!(
+            parse_kvn_quaternion_line(input, true, false),
+            Err(KvnAttitudeStateParserErr::InvalidFormat { input })
+        );
+        let input = "1996-11-28T21:29:07.25 0.56748 0.03146 0.45689 0.68427";
+        assert_eq!(
+            parse_kvn_quaternion_line(input, false, true),
+            Err(KvnAttitudeStateParserErr::InvalidFormat { input })
+        );
+        assert_eq!(
+            parse_kvn_quaternion_line("DATA_STOP", false, false),
+            Err(KvnAttitudeStateParserErr::InvalidFormat { input: "DATA_STOP" })
+        );
+    }
+
+    #[test]
+    fn test_euler_angle_parser() {
+        let epoch = KvnDateTimeValue {
+            year: 1996,
+            month: 11,
+            day: 28,
+            hour: 21,
+            minute: 29,
+            second: 7,
+            fractional_second: 0.0,
+            full_value: "1996-11-28T21:29:07".to_string(),
+        };
+
+        assert_eq!(
+            parse_kvn_euler_angle_line("1996-11-28T21:29:07 -53.3688 120.3419 5.8256", false),
+            Ok(KvnEulerAngleValue {
+                epoch: KvnDateTimeValue {
+                    full_value: "1996-11-28T21:29:07".to_string(),
+                    ..epoch
+                },
+                angle_1: -53.3688,
+                angle_2: 120.3419,
+                angle_3: 5.8256,
+                rate_1: None,
+                rate_2: None,
+                rate_3: None,
+            })
+        );
+
+        assert_eq!(
+            parse_kvn_euler_angle_line(
+                "1996-11-28T21:29:07 -53.3688 120.3419 5.8256 0.1 -0.2 0.3",
+                true
+            ),
+            Ok(KvnEulerAngleValue {
+                epoch,
+                angle_1: -53.3688,
+                angle_2: 120.3419,
+                angle_3: 5.8256,
+                rate_1: Some(0.1),
+                rate_2: Some(-0.2),
+                rate_3: Some(0.3),
+            })
+        );
+
+        let input = "1996-11-28T21:29:07 -53.3688 120.3419 abc";
+        assert_eq!(
+            parse_kvn_euler_angle_line(input, false),
+            Err(KvnAttitudeStateParserErr::InvalidFormat { input })
+        );
+    }
+
     #[test]
     fn test_covariance_matrix_parser() {
+        let kvn_value = |value| KvnValue { value, unit: None };
+
         // 5.2.5.4 Values in the covariance matrix shall be expressed in the
         // applicable reference frame (COV_REF_FRAME keyword if used, or
         // REF_FRAME keyword if not), and shall be presented sequentially from
@@ -1230,27 +1746,27 @@ mod test {
         assert_eq!(
             parse_kvn_covariance_matrix(&mut kvn.lines()),
             Ok(KvnCovarianceMatrixValue {
-                cx_x: 3.3313494e-04,
-                cy_x: 4.6189273e-04,
-                cy_y: 6.7824216e-04,
-                cz_x: -3.0700078e-04,
-                cz_y: -4.2212341e-04,
-                cz_z: 3.2319319e-04,
-                cx_dot_x: -3.3493650e-07,
-                cx_dot_y: -4.6860842e-07,
-                cx_dot_z: 2.4849495e-07,
-                cx_dot_x_dot: 4.2960228e-10,
-                cy_dot_x: -2.2118325e-07,
-                cy_dot_y: -2.8641868e-07,
-                cy_dot_z: 1.7980986e-07,
-                cy_dot_x_dot: 2.6088992e-10,
-                cy_dot_y_dot: 1.7675147e-10,
-                cz_dot_x: -3.0413460e-07,
-                cz_dot_y: -4.9894969e-07,
-                cz_dot_z: 3.5403109e-07,
-                cz_dot_x_dot: 1.8692631e-10,
-                cz_dot_y_dot: 1.0088625e-10,
-                cz_dot_z_dot: 6.2244443e-10,
+                cx_x: kvn_value(3.3313494e-04),
+                cy_x: kvn_value(4.6189273e-04),
+                cy_y: kvn_value(6.7824216e-04),
+                cz_x: kvn_value(-3.0700078e-04),
+                cz_y: kvn_value(-4.2212341e-04),
+                cz_z: kvn_value(3.2319319e-04),
+                cx_dot_x: kvn_value(-3.3493650e-07),
+                cx_dot_y: kvn_value(-4.6860842e-07),
+                cx_dot_z: kvn_value(2.4849495e-07),
+                cx_dot_x_dot: kvn_value(4.2960228e-10),
+                cy_dot_x: kvn_value(-2.2118325e-07),
+                cy_dot_y: kvn_value(-2.8641868e-07),
+                cy_dot_z: kvn_value(1.7980986e-07),
+                cy_dot_x_dot: kvn_value(2.6088992e-10),
+                cy_dot_y_dot: kvn_value(1.7675147e-10),
+                cz_dot_x: kvn_value(-3.0413460e-07),
+                cz_dot_y: kvn_value(-4.9894969e-07),
+                cz_dot_z: kvn_value(3.5403109e-07),
+                cz_dot_x_dot: kvn_value(1.8692631e-10),
+                cz_dot_y_dot: kvn_value(1.0088625e-10),
+                cz_dot_z_dot: kvn_value(6.2244443e-10),
             })
         );
 
@@ -1267,28 +1783,80 @@ mod test {
         assert_eq!(
             parse_kvn_covariance_matrix(&mut kvn.lines()),
             Ok(KvnCovarianceMatrixValue {
-                cx_x: 3.3313494e-04,
-                cy_x: 4.6189273e-04,
-                cy_y: 6.7824216e-04,
-                cz_x: -3.0700078e-04,
-                cz_y: -4.2212341e-04,
-                cz_z: 3.2319319e-04,
-                cx_dot_x: -3.3493650e-07,
-                cx_dot_y: -4.6860842e-07,
-                cx_dot_z: 2.4849495e-07,
-                cx_dot_x_dot: 4.2960228e-10,
-                cy_dot_x: -2.2118325e-07,
-                cy_dot_y: -2.8641868e-07,
-                cy_dot_z: 1.7980986e-07,
-                cy_dot_x_dot: 2.6088992e-10,
-                cy_dot_y_dot: 1.7675147e-10,
-                cz_dot_x: -3.0413460e-07,
-                cz_dot_y: -4.9894969e-07,
-                cz_dot_z: 3.5403109e-07,
-                cz_dot_x_dot: 1.8692631e-10,
-                cz_dot_y_dot: 1.0088625e-10,
-                cz_dot_z_dot: 6.2244443e-10,
+                cx_x: kvn_value(3.3313494e-04),
+                cy_x: kvn_value(4.6189273e-04),
+                cy_y: kvn_value(6.7824216e-04),
+                cz_x: kvn_value(-3.0700078e-04),
+                cz_y: kvn_value(-4.2212341e-04),
+                cz_z: kvn_value(3.2319319e-04),
+                cx_dot_x: kvn_value(-3.3493650e-07),
+                cx_dot_y: kvn_value(-4.6860842e-07),
+                cx_dot_z: kvn_value(2.4849495e-07),
+                cx_dot_x_dot: kvn_value(4.2960228e-10),
+                cy_dot_x: kvn_value(-2.2118325e-07),
+                cy_dot_y: kvn_value(-2.8641868e-07),
+                cy_dot_z: kvn_value(1.7980986e-07),
+                cy_dot_x_dot: kvn_value(2.6088992e-10),
+                cy_dot_y_dot: kvn_value(1.7675147e-10),
+                cz_dot_x: kvn_value(-3.0413460e-07),
+                cz_dot_y: kvn_value(-4.9894969e-07),
+                cz_dot_z: kvn_value(3.5403109e-07),
+                cz_dot_x_dot: kvn_value(1.8692631e-10),
+                cz_dot_y_dot: kvn_value(1.0088625e-10),
+                cz_dot_z_dot: kvn_value(6.2244443e-10),
             })
         );
     }
+
+    #[test]
+    fn test_covariance_matrix_parser_units() {
+        let kvn = "3.3313494e-04 [km**2]
+4.6189273e-04[km**2] 6.7824216e-04
+-3.0700078e-04 -4.2212341e-04 3.2319319e-04
+-3.3493650e-07 -4.6860842e-07 2.4849495e-07 4.2960228e-10 [km**2/s**2]
+-2.2118325e-07 -2.8641868e-07 1.7980986e-07 2.6088992e-10 1.7675147e-10
+-3.0413460e-07 -4.9894969e-07 3.5403109e-07 1.8692631e-10 1.0088625e-10 6.2244443e-10";
+
+        let matrix = parse_kvn_covariance_matrix(&mut kvn.lines()).unwrap();
+        assert_eq!(
+            matrix.cx_x,
+            KvnValue {
+                value: 3.3313494e-04,
+                unit: Some("km**2".to_string())
+            }
+        );
+        assert_eq!(
+            matrix.cy_x,
+            KvnValue {
+                value: 4.6189273e-04,
+                unit: Some("km**2".to_string())
+            }
+        );
+        assert_eq!(
+            matrix.cy_y,
+            KvnValue {
+                value: 6.7824216e-04,
+                unit: None
+            }
+        );
+        assert_eq!(
+            matrix.cx_dot_x_dot,
+            KvnValue {
+                value: 4.2960228e-10,
+                unit: Some("km**2/s**2".to_string())
+            }
+        );
+
+        for input in [
+            "[km**2] 3.3313494e-04",
+            "3.3313494e-04 [km**2] [km**2]",
+            "3.3313494e-04 [km**2",
+            "3.3313494e-04 []",
+        ] {
+            assert_eq!(
+                parse_kvn_covariance_matrix(&mut input.lines()),
+                Err(KvnCovarianceMatrixParserErr::InvalidFormat { input })
+            );
+        }
+    }
 }
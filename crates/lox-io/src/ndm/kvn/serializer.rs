@@ -0,0 +1,295 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serialization of flat NDM structures, e.g. headers and metadata blocks, into KVN lines.
+//!
+//! The keywords are taken from the `serde` field names, so the same renames are used for KVN and
+//! XML. Fields are written in declaration order, `None` values are omitted, and `COMMENT` lists
+//! are written as one `COMMENT` line per entry.
+
+use serde::ser::{self, Impossible, Serialize};
+
+#[derive(Clone, Debug, thiserror::Error, PartialEq)]
+pub enum KvnSerializerErr {
+    #[error("cannot serialize {0} as KVN")]
+    UnsupportedType(&'static str),
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl ser::Error for KvnSerializerErr {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        KvnSerializerErr::Custom(msg.to_string())
+    }
+}
+
+/// Serializes a struct into KVN lines of the form `KEYWORD = value`.
+pub(crate) fn to_kvn_lines<T: Serialize>(value: &T) -> Result<Vec<String>, KvnSerializerErr> {
+    match value.serialize(ValueSerializer)? {
+        KvnValue::Lines(lines) => Ok(lines),
+        _ => Err(KvnSerializerErr::UnsupportedType("a non-struct value")),
+    }
+}
+
+enum KvnValue {
+    None,
+    Scalar(String),
+    List(Vec<String>),
+    Lines(Vec<String>),
+}
+
+struct ValueSerializer;
+
+macro_rules! serialize_display {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<KvnValue, KvnSerializerErr> {
+                Ok(KvnValue::Scalar(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = KvnValue;
+    type Error = KvnSerializerErr;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = Impossible<KvnValue, KvnSerializerErr>;
+    type SerializeTupleStruct = Impossible<KvnValue, KvnSerializerErr>;
+    type SerializeTupleVariant = Impossible<KvnValue, KvnSerializerErr>;
+    type SerializeMap = Impossible<KvnValue, KvnSerializerErr>;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = Impossible<KvnValue, KvnSerializerErr>;
+
+    serialize_display!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_char: char,
+        serialize_str: &str
+    );
+
+    // The `Debug` representation always contains a decimal point or an exponent and round-trips
+    fn serialize_f32(self, v: f32) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::Scalar(format!("{:?}", v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::Scalar(format!("{:?}", v)))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<KvnValue, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(
+        self,
+        value: &T,
+    ) -> Result<KvnValue, KvnSerializerErr> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::Scalar(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<KvnValue, KvnSerializerErr> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<KvnValue, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a newtype variant"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ListSerializer, KvnSerializerErr> {
+        Ok(ListSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<StructSerializer, KvnSerializerErr> {
+        Ok(StructSerializer(Vec::with_capacity(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, KvnSerializerErr> {
+        Err(KvnSerializerErr::UnsupportedType("a struct variant"))
+    }
+}
+
+struct ListSerializer(Vec<String>);
+
+impl ser::SerializeSeq for ListSerializer {
+    type Ok = KvnValue;
+    type Error = KvnSerializerErr;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), KvnSerializerErr> {
+        match value.serialize(ValueSerializer)? {
+            KvnValue::Scalar(value) => {
+                self.0.push(value);
+                Ok(())
+            }
+            _ => Err(KvnSerializerErr::UnsupportedType(
+                "a list of non-scalar values",
+            )),
+        }
+    }
+
+    fn end(self) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::List(self.0))
+    }
+}
+
+struct StructSerializer(Vec<String>);
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = KvnValue;
+    type Error = KvnSerializerErr;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), KvnSerializerErr> {
+        match value.serialize(ValueSerializer)? {
+            KvnValue::None => {}
+            KvnValue::Scalar(value) if key == "COMMENT" => self.0.push(format!("COMMENT {value}")),
+            KvnValue::Scalar(value) => self.0.push(format!("{key} = {value}")),
+            KvnValue::List(comments) if key == "COMMENT" => self
+                .0
+                .extend(comments.iter().map(|comment| format!("COMMENT {comment}"))),
+            KvnValue::List(_) => return Err(KvnSerializerErr::UnsupportedType("a list")),
+            KvnValue::Lines(_) => return Err(KvnSerializerErr::UnsupportedType("a nested struct")),
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<KvnValue, KvnSerializerErr> {
+        Ok(KvnValue::Lines(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Newtype(String);
+
+    #[derive(serde::Serialize)]
+    struct Block {
+        #[serde(rename = "COMMENT")]
+        comment_list: Vec<String>,
+        #[serde(rename = "EPOCH")]
+        epoch: Newtype,
+        #[serde(rename = "CENTER_NAME")]
+        center_name: Option<String>,
+        #[serde(rename = "MASS")]
+        mass: Option<f64>,
+        #[serde(rename = "COUNT")]
+        count: u64,
+    }
+
+    #[test]
+    fn test_to_kvn_lines() {
+        let block = Block {
+            comment_list: vec!["first".to_string(), "second".to_string()],
+            epoch: Newtype("2024-01-01T00:00:00".to_string()),
+            center_name: None,
+            mass: Some(1000.0),
+            count: 3,
+        };
+        assert_eq!(
+            to_kvn_lines(&block).unwrap(),
+            vec![
+                "COMMENT first",
+                "COMMENT second",
+                "EPOCH = 2024-01-01T00:00:00",
+                "MASS = 1000.0",
+                "COUNT = 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_kvn_lines_unsupported() {
+        assert_eq!(
+            to_kvn_lines(&1.0),
+            Err(KvnSerializerErr::UnsupportedType("a non-struct value"))
+        );
+    }
+}
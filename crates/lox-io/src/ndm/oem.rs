@@ -105,6 +105,8 @@ use serde;
 
 use super::{common, kvn::parser::KvnStateVectorValue};
 
+pub mod reader;
+
 #[derive(
     Clone,
     Debug,
@@ -266,6 +268,7 @@ impl From<KvnStateVectorValue> for crate::ndm::common::StateVectorAccType {
 
 #[cfg(test)]
 mod test {
+    use crate::ndm::kvn::{KvnDeserializer, KvnDeserializerErr};
     use crate::ndm::xml::FromXmlStr;
 
     use super::*;
@@ -458,6 +461,51 @@ mod test {
                 version: "2.0".to_string(),
             }
         );
+
+        // Components without a `units` attribute use the same default units as tagged ones
+        for state in &message.body.segment_list[0].data.state_vector_list {
+            assert_eq!(state.position_km(), Ok([1.0, 1.0, 1.0]));
+            assert_eq!(state.velocity_km_per_s(), Ok([1.0, 1.0, 1.0]));
+        }
+    }
+
+    #[test]
+    fn test_state_vector_units() {
+        let position = |base, units: Option<&str>| common::PositionType {
+            base,
+            units: units.map(|units| common::PositionUnits(units.to_string())),
+        };
+        let velocity = |base, units: Option<&str>| common::VelocityType {
+            base,
+            units: units.map(|units| common::VelocityUnits(units.to_string())),
+        };
+        let state = common::StateVectorAccType {
+            epoch: common::EpochType("2004-100T00:00:00".to_string()),
+            x: position(1.0, Some("km")),
+            y: position(2000.0, Some("m")),
+            z: position(3.0, None),
+            x_dot: velocity(4.0, None),
+            y_dot: velocity(5000.0, Some("m/s")),
+            z_dot: velocity(6.0, Some("km/s")),
+            x_ddot: Some(common::AccType {
+                base: 7.0,
+                units: Some(common::AccUnits("m/s**2".to_string())),
+            }),
+            y_ddot: None,
+            z_ddot: None,
+        };
+        assert_eq!(state.position_km(), Ok([1.0, 2.0, 3.0]));
+        assert_eq!(state.velocity_km_per_s(), Ok([4.0, 5.0, 6.0]));
+        assert_eq!(state.x_ddot.as_ref().unwrap().to_km_per_s2(), Ok(7e-3));
+
+        let state = common::StateVectorAccType {
+            y: position(1.0, Some("au")),
+            ..state
+        };
+        assert_eq!(
+            state.position_km(),
+            Err(common::UnsupportedUnitsError("au".to_string()))
+        );
     }
 
     #[test]
@@ -1770,4 +1818,173 @@ COVARIANCE_STOP"#;
             })
         );
     }
+
+    const OEM_KVN_TWO_SEGMENTS: &str = r#"CCSDS_OEM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME = J2000
+TIME_SYSTEM = TAI
+START_TIME = 1996-12-18T12:00:00.331
+STOP_TIME = 1996-12-28T21:28:00.331
+META_STOP
+1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME = J2000
+TIME_SYSTEM = TAI
+START_TIME = 1996-12-28T21:29:07.267
+STOP_TIME = 1996-12-30T01:28:02.267
+INTERPOLATION = HERMITE
+INTERPOLATION_DEGREE = 7
+META_STOP
+1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945
+"#;
+
+    #[test]
+    fn test_parse_oem_message_kvn_last_segment_without_covariance() {
+        let oem = OemType::from_kvn_str(OEM_KVN_TWO_SEGMENTS).unwrap();
+        assert_eq!(oem.body.segment_list.len(), 2);
+        assert!(oem.body.segment_list[1]
+            .data
+            .covariance_matrix_list
+            .is_empty());
+    }
+
+    fn oem_kvn_with_covariance(covariance: &str) -> String {
+        format!(
+            "CCSDS_OEM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME = EME2000
+TIME_SYSTEM = TAI
+START_TIME = 1996-12-28T21:29:07.267
+STOP_TIME = 1996-12-28T21:29:07.267
+META_STOP
+1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945
+COVARIANCE_START
+{covariance}
+COVARIANCE_STOP
+"
+        )
+    }
+
+    #[test]
+    fn test_parse_oem_message_kvn_covariance_units() {
+        let unitless = OemType::from_kvn_str(&oem_kvn_with_covariance(
+            "EPOCH = 1996-12-28T21:29:07.267
+COV_REF_FRAME = RTN
+3.3313494e-04
+4.6189273e-04 6.7824216e-04
+-3.0700078e-04 -4.2212341e-04 3.2319319e-04
+-3.3493650e-07 -4.6860842e-07 2.4849495e-07 4.2960228e-10
+-2.2118325e-07 -2.8641868e-07 1.7980986e-07 2.6088992e-10 1.7675147e-10
+-3.0413460e-07 -4.9894969e-07 3.5403109e-07 1.8692631e-10 1.0088625e-10 6.2244443e-10",
+        ))
+        .unwrap();
+        let with_units = OemType::from_kvn_str(&oem_kvn_with_covariance(
+            "EPOCH = 1996-12-28T21:29:07.267
+COV_REF_FRAME = RTN
+3.3313494e-04 [km**2]
+4.6189273e-04 [km**2] 6.7824216e-04[km**2]
+-3.0700078e-04 -4.2212341e-04 3.2319319e-04 [km**2]
+-3.3493650e-07 [km**2/s] -4.6860842e-07 2.4849495e-07 4.2960228e-10 [km**2/s**2]
+-2.2118325e-07 -2.8641868e-07 1.7980986e-07 2.6088992e-10 1.7675147e-10
+-3.0413460e-07 -4.9894969e-07 3.5403109e-07 1.8692631e-10 1.0088625e-10 6.2244443e-10",
+        ))
+        .unwrap();
+
+        let unitless = &unitless.body.segment_list[0].data.covariance_matrix_list[0];
+        let covariance = &with_units.body.segment_list[0].data.covariance_matrix_list[0];
+        assert_eq!(unitless.cx_x.units, None);
+        assert_eq!(
+            covariance.cx_x.units,
+            Some(common::PositionCovarianceUnits("km**2".to_string()))
+        );
+        assert_eq!(
+            covariance.cy_y.units,
+            Some(common::PositionCovarianceUnits("km**2".to_string()))
+        );
+        assert_eq!(covariance.cz_x.units, None);
+        assert_eq!(
+            covariance.cx_dot_x.units,
+            Some(common::PositionVelocityCovarianceUnits(
+                "km**2/s".to_string()
+            ))
+        );
+        assert_eq!(
+            covariance.cx_dot_x_dot.units,
+            Some(common::VelocityCovarianceUnits("km**2/s**2".to_string()))
+        );
+        assert_eq!(covariance.cx_dot_x_dot.base, unitless.cx_dot_x_dot.base);
+
+        for covariance in [unitless, covariance] {
+            let kvn = oem_kvn_with_covariance(&covariance.to_kvn_lines().join("\n"));
+            let round_trip = OemType::from_kvn_str(&kvn).unwrap();
+            assert_eq!(
+                &round_trip.body.segment_list[0].data.covariance_matrix_list[0],
+                covariance
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_oem_message_kvn_error_line_numbers() {
+        let kvn = OEM_KVN_TWO_SEGMENTS.replace(
+            "CREATION_DATE = 1996-11-04T17:22:31\nORIGINATOR = NASA/JPL",
+            "ORIGINATOR = NASA/JPL\nCREATION_DATE = 1996-11-04T17:22:31",
+        );
+        let err = OemType::from_kvn_str(&kvn).unwrap_err();
+        assert_eq!(
+            err,
+            KvnDeserializerErr::UnexpectedKeyword {
+                found: "ORIGINATOR = NASA/JPL".to_string(),
+                expected: "CREATION_DATE".to_string(),
+                line: 2,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected CREATION_DATE at line 2, found ORIGINATOR = NASA/JPL"
+        );
+
+        let kvn = OEM_KVN_TWO_SEGMENTS
+            .replace("INTERPOLATION_DEGREE = 7", "INTERPOLATION_DEGREE = seven");
+        let err = OemType::from_kvn_str(&kvn).unwrap_err();
+        assert_eq!(
+            err,
+            KvnDeserializerErr::InvalidNumberFormat {
+                input: "INTERPOLATION_DEGREE = seven".to_string(),
+                line: 25,
+            }
+        );
+
+        let kvn = OEM_KVN_TWO_SEGMENTS.replace(
+            "CREATION_DATE = 1996-11-04T17:22:31",
+            "CREATION_DATE = 1996-11-04",
+        );
+        let err = OemType::from_kvn_str(&kvn).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid date time format at line 2: `CREATION_DATE = 1996-11-04`"
+        );
+
+        let kvn = OEM_KVN_TWO_SEGMENTS.split("META_START").next().unwrap();
+        let err = OemType::from_kvn_str(kvn).unwrap_err();
+        assert!(matches!(
+            err,
+            KvnDeserializerErr::UnexpectedEndOfInput { line: 5, .. }
+        ));
+    }
 }
@@ -0,0 +1,482 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Streaming reader for KVN Orbit Ephemeris Messages
+//!
+//! [OemKvnReader] reads the version and header of a message eagerly and then yields the
+//! remainder one [OemRecord] at a time, so that ephemerides with a large number of state vectors
+//! can be processed in constant memory. The metadata of a segment is always yielded before its
+//! state vectors.
+//!
+//! ```
+//! # let kvn = "CCSDS_OEM_VERS = 3.0
+//! # CREATION_DATE = 1996-11-04T17:22:31
+//! # ORIGINATOR = NASA/JPL
+//! # META_START
+//! # OBJECT_NAME = MARS GLOBAL SURVEYOR
+//! # OBJECT_ID = 1996-062A
+//! # CENTER_NAME = MARS BARYCENTER
+//! # REF_FRAME = J2000
+//! # TIME_SYSTEM = TAI
+//! # START_TIME = 1996-12-18T12:00:00.331
+//! # STOP_TIME = 1996-12-18T12:01:00.331
+//! # META_STOP
+//! # 1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+//! # 1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124 -1.99608";
+//! use lox_io::ndm::oem::reader::{OemKvnReader, OemRecord};
+//!
+//! let reader = OemKvnReader::new(kvn.as_bytes()).unwrap();
+//! assert_eq!(reader.header().originator, "NASA/JPL");
+//! for record in reader {
+//!     match record.unwrap() {
+//!         OemRecord::Metadata(metadata) => println!("{}", metadata.object_name),
+//!         OemRecord::StateVector(state) => println!("{}", state.epoch.0),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+//!
+//! Errors in a single state vector line or a single metadata or covariance block are yielded
+//! without ending the iteration, i.e. the reader resumes with the next line or block. Only I/O
+//! errors end the iteration.
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::ndm::common::{OdmHeader, OemCovarianceMatrixType, StateVectorAccType};
+use crate::ndm::kvn::parser::{
+    kvn_line_matches_key, parse_kvn_state_vector, parse_kvn_string_line, KvnLines,
+};
+use crate::ndm::kvn::{KvnDeserializer, KvnDeserializerErr};
+
+use super::{OemData, OemMetadata, OemSegment};
+
+#[derive(Debug, Error)]
+pub enum OemReaderError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Kvn(#[from] KvnDeserializerErr<String>),
+}
+
+/// A single item of the body of an OEM.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OemRecord {
+    /// The metadata block which starts a new segment.
+    Metadata(OemMetadata),
+    /// A comment in the data section of the current segment.
+    Comment(String),
+    StateVector(StateVectorAccType),
+    CovarianceMatrix(Box<OemCovarianceMatrixType>),
+}
+
+/// Reads a KVN OEM from a buffered reader one record at a time.
+pub struct OemKvnReader<R> {
+    lines: std::io::Lines<R>,
+    peeked: Option<String>,
+    // The one-based number of the line which is returned by the next call to `next_line`
+    line_number: usize,
+    version: String,
+    header: OdmHeader,
+    pending: VecDeque<OemCovarianceMatrixType>,
+    in_segment: bool,
+    done: bool,
+}
+
+impl<R: BufRead> OemKvnReader<R> {
+    /// Creates a reader and parses the version and header of the message.
+    pub fn new(reader: R) -> Result<Self, OemReaderError> {
+        let mut reader = Self {
+            lines: reader.lines(),
+            peeked: None,
+            line_number: 1,
+            version: String::new(),
+            header: OdmHeader::default(),
+            pending: VecDeque::new(),
+            in_segment: false,
+            done: false,
+        };
+
+        let line_number = reader.skip_empty_lines()?;
+        let version_line = reader.next_line()?.unwrap_or_default();
+        if !kvn_line_matches_key("CCSDS_OEM_VERS", &version_line).unwrap_or(false) {
+            return Err(KvnDeserializerErr::UnexpectedKeyword {
+                found: version_line,
+                expected: "CCSDS_OEM_VERS".to_string(),
+                line: line_number,
+            })?;
+        }
+        reader.version = parse_kvn_string_line(&version_line)
+            .map_err(|err| KvnDeserializerErr::from(err).with_line(line_number))?
+            .value;
+
+        let first_line = reader.line_number;
+        let mut block = String::new();
+        while let Some(line) = reader.peek_line()? {
+            if kvn_line_matches_key("META_START", line).unwrap_or(false) {
+                break;
+            }
+            block.push_str(&reader.next_line()?.unwrap_or_default());
+            block.push('\n');
+        }
+        reader.header = deserialize_block(&block, first_line)?;
+
+        Ok(reader)
+    }
+
+    /// Returns the value of the `CCSDS_OEM_VERS` keyword.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn header(&self) -> &OdmHeader {
+        &self.header
+    }
+
+    /// Returns an iterator which collects the records of each segment into an [OemSegment].
+    ///
+    /// Only the state vectors of a single segment are held in memory at a time.
+    pub fn segments(self) -> OemKvnSegments<R> {
+        OemKvnSegments {
+            reader: self,
+            segment: None,
+        }
+    }
+
+    fn peek_line(&mut self) -> Result<Option<&str>, std::io::Error> {
+        if self.peeked.is_none() {
+            self.peeked = self.lines.next().transpose()?;
+        }
+        Ok(self.peeked.as_deref())
+    }
+
+    fn next_line(&mut self) -> Result<Option<String>, std::io::Error> {
+        self.peek_line()?;
+        let line = self.peeked.take();
+        if line.is_some() {
+            self.line_number += 1;
+        }
+        Ok(line)
+    }
+
+    /// Skips empty lines and returns the number of the next non-empty line.
+    fn skip_empty_lines(&mut self) -> Result<usize, std::io::Error> {
+        while let Some(line) = self.peek_line()? {
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.next_line()?;
+        }
+        Ok(self.line_number)
+    }
+
+    /// Reads the lines up to and including the line with the `stop` keyword.
+    fn read_block(&mut self, stop: &str) -> Result<String, OemReaderError> {
+        let mut block = String::new();
+        loop {
+            let line = self
+                .next_line()?
+                .ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                    keyword: stop.to_string(),
+                    line: self.line_number,
+                })?;
+            block.push_str(&line);
+            block.push('\n');
+            if kvn_line_matches_key(stop, &line).unwrap_or(false) {
+                return Ok(block);
+            }
+        }
+    }
+
+    fn next_record(&mut self) -> Result<Option<OemRecord>, OemReaderError> {
+        if let Some(covariance_matrix) = self.pending.pop_front() {
+            return Ok(Some(OemRecord::CovarianceMatrix(Box::new(
+                covariance_matrix,
+            ))));
+        }
+
+        let line_number = self.skip_empty_lines()?;
+        let Some(line) = self.peek_line()? else {
+            return Ok(None);
+        };
+
+        if kvn_line_matches_key("META_START", line).unwrap_or(false) {
+            // The data of a segment with invalid metadata is rejected
+            self.in_segment = false;
+            let block = self.read_block("META_STOP")?;
+            let metadata = deserialize_block(&block, line_number)?;
+            self.in_segment = true;
+            return Ok(Some(OemRecord::Metadata(metadata)));
+        }
+
+        // The lines are consumed before parsing so that the reader can resume after an error
+        let line = self.next_line()?.unwrap_or_default();
+
+        if !self.in_segment {
+            return Err(KvnDeserializerErr::UnexpectedKeyword {
+                found: line,
+                expected: "META_START".to_string(),
+                line: line_number,
+            })?;
+        }
+
+        if kvn_line_matches_key("COVARIANCE_START", &line).unwrap_or(false) {
+            let block = line + "\n" + &self.read_block("COVARIANCE_STOP")?;
+            let data: OemData = deserialize_block(&block, line_number)?;
+            self.pending.extend(data.covariance_matrix_list);
+            return self.next_record();
+        }
+
+        if kvn_line_matches_key("COMMENT", &line).unwrap_or(false) {
+            let comment = parse_kvn_string_line(&line)
+                .map_err(|err| KvnDeserializerErr::from(err).with_line(line_number))?;
+            return Ok(Some(OemRecord::Comment(comment.value)));
+        }
+
+        let state_vector = parse_kvn_state_vector(&line)
+            .map_err(|err| KvnDeserializerErr::from(err).with_line(line_number))?;
+        Ok(Some(OemRecord::StateVector(state_vector.into())))
+    }
+}
+
+impl<R: BufRead> Iterator for OemKvnReader<R> {
+    type Item = Result<OemRecord, OemReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_record() {
+            Ok(record) => {
+                self.done = record.is_none();
+                record.map(Ok)
+            }
+            Err(err) => {
+                self.done = matches!(err, OemReaderError::Io(_));
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Iterator over the segments of an [OemKvnReader], see [OemKvnReader::segments].
+///
+/// An erroneous record is yielded as an error and the remaining records of its segment are still
+/// collected.
+pub struct OemKvnSegments<R> {
+    reader: OemKvnReader<R>,
+    // The segment whose records are currently being read
+    segment: Option<OemSegment>,
+}
+
+impl<R: BufRead> Iterator for OemKvnSegments<R> {
+    type Item = Result<OemSegment, OemReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.reader.next() {
+                None => return self.segment.take().map(Ok),
+                Some(Err(err)) => return Some(Err(err)),
+                Some(Ok(record)) => record,
+            };
+            if let OemRecord::Metadata(metadata) = record {
+                let segment = OemSegment {
+                    metadata,
+                    data: OemData::default(),
+                };
+                match self.segment.replace(segment) {
+                    Some(segment) => return Some(Ok(segment)),
+                    None => continue,
+                }
+            }
+            // The reader only yields data records after a metadata block
+            let Some(segment) = self.segment.as_mut() else {
+                continue;
+            };
+            let data = &mut segment.data;
+            match record {
+                OemRecord::Comment(comment) => data.comment_list.push(comment),
+                OemRecord::StateVector(state_vector) => data.state_vector_list.push(state_vector),
+                OemRecord::CovarianceMatrix(covariance_matrix) => {
+                    data.covariance_matrix_list.push(*covariance_matrix)
+                }
+                OemRecord::Metadata(_) => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Deserializes a block of lines which starts at line `first_line` of the message.
+fn deserialize_block<T: KvnDeserializer>(
+    block: &str,
+    first_line: usize,
+) -> Result<T, KvnDeserializerErr<String>> {
+    T::deserialize(&mut KvnLines::new(block)).map_err(|err| match err.line() {
+        Some(line) => err.with_line(line + first_line - 1),
+        None => err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ndm::oem::OemType;
+
+    const KVN: &str = "CCSDS_OEM_VERS = 3.0
+COMMENT Streaming test
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-18T12:00:00.331
+STOP_TIME = 1996-12-18T12:02:00.331
+INTERPOLATION = HERMITE
+INTERPOLATION_DEGREE = 7
+META_STOP
+
+COMMENT This file was produced by M.R. Somebody, MSOO NAV/JPL, 1996NOV 04.
+1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124 -1.99608
+1996-12-18T12:02:00.331 2776.033 -336.859 -2008.682 5.63678 -2.33951 -1.94687
+
+COVARIANCE_START
+EPOCH = 1996-12-18T12:00:00.331
+COV_REF_FRAME = EME2000
+3.3313494e-04
+4.6189273e-04 6.7824216e-04
+-3.0700078e-04 -4.2212341e-04 3.2319319e-04
+-3.3493650e-07 -4.6860842e-07 2.4849495e-07 4.2960228e-10
+-2.2118325e-07 -2.8641868e-07 1.7980986e-07 2.6088992e-10 1.7675147e-10
+-3.0413460e-07 -4.9894969e-07 3.5403109e-07 1.8692631e-10 1.0088625e-10 6.2244443e-10
+EPOCH = 1996-12-18T12:02:00.331
+COV_REF_FRAME = RTN
+1.0
+2.0 3.0
+4.0 5.0 6.0
+7.0 8.0 9.0 10.0
+11.0 12.0 13.0 14.0 15.0
+16.0 17.0 18.0 19.0 20.0 21.0
+COVARIANCE_STOP
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-28T21:29:07.267
+STOP_TIME = 1996-12-28T21:59:02.267
+META_STOP
+1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945
+1996-12-28T21:59:02.267 -2445.234 -878.141 1873.073 1.86043 -3.421256 -0.996366
+";
+
+    #[test]
+    fn test_oem_kvn_reader_segments() {
+        let exp = OemType::from_kvn_str(KVN).unwrap();
+        let reader = OemKvnReader::new(KVN.as_bytes()).unwrap();
+        assert_eq!(reader.version(), "3.0");
+        assert_eq!(reader.header(), &exp.header);
+        let segments: Vec<OemSegment> = reader.segments().map(Result::unwrap).collect();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].data.covariance_matrix_list.len(), 2);
+        assert_eq!(segments, exp.body.segment_list);
+    }
+
+    #[test]
+    fn test_oem_kvn_reader_records() {
+        let reader = OemKvnReader::new(KVN.as_bytes()).unwrap();
+        let records: Vec<OemRecord> = reader.map(Result::unwrap).collect();
+        let kinds: Vec<&str> = records
+            .iter()
+            .map(|record| match record {
+                OemRecord::Metadata(_) => "metadata",
+                OemRecord::Comment(_) => "comment",
+                OemRecord::StateVector(_) => "state vector",
+                OemRecord::CovarianceMatrix(_) => "covariance matrix",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "metadata",
+                "comment",
+                "state vector",
+                "state vector",
+                "state vector",
+                "covariance matrix",
+                "covariance matrix",
+                "metadata",
+                "state vector",
+                "state vector",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_oem_kvn_reader_recovers_from_errors() {
+        let kvn = KVN
+            .replace(
+                "1996-12-18T12:01:00.331 2783.419",
+                "1996-12-18T12:01:00.331 2783.419 abc",
+            )
+            .replace("START_TIME = 1996-12-28T21:29:07.267", "START_TIME = now");
+        let mut segments = OemKvnReader::new(kvn.as_bytes()).unwrap().segments();
+
+        let err = segments.next().unwrap().unwrap_err();
+        assert!(matches!(
+            err,
+            OemReaderError::Kvn(KvnDeserializerErr::InvalidStateVectorFormat { line: 20, .. })
+        ));
+
+        let err = segments.next().unwrap().unwrap_err();
+        assert!(matches!(
+            err,
+            OemReaderError::Kvn(KvnDeserializerErr::InvalidDateTimeFormat { line: 48, .. })
+        ));
+
+        // The data of the segment with invalid metadata is rejected
+        for line in [51, 52] {
+            let err = segments.next().unwrap().unwrap_err();
+            assert!(matches!(
+                err,
+                OemReaderError::Kvn(KvnDeserializerErr::UnexpectedKeyword { line: l, .. }) if l == line
+            ));
+        }
+
+        let segment = segments.next().unwrap().unwrap();
+        assert_eq!(segment.data.state_vector_list.len(), 2);
+        assert_eq!(segment.data.covariance_matrix_list.len(), 2);
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn test_oem_kvn_reader_invalid_header() {
+        let kvn = KVN.replace("CCSDS_OEM_VERS", "CCSDS_OPM_VERS");
+        let err = OemKvnReader::new(kvn.as_bytes()).err().unwrap();
+        assert!(matches!(
+            err,
+            OemReaderError::Kvn(KvnDeserializerErr::UnexpectedKeyword { line: 1, .. })
+        ));
+
+        let kvn = KVN.replace("ORIGINATOR = NASA/JPL", "");
+        let err = OemKvnReader::new(kvn.as_bytes()).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unexpected end of input at line 6, expected ORIGINATOR"
+        );
+    }
+}
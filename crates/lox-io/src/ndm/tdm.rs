@@ -0,0 +1,733 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializers for XML and KVN CCSDS Tracking Data Message
+//!
+//! The tracking data message is defined in
+//! [CCSDS 503.0-B-2](https://public.ccsds.org/Pubs/503x0b2c1.pdf). Each
+//! observation carries a single data keyword (e.g. `RANGE`,
+//! `RECEIVE_FREQ_1`) together with its epoch and value.
+//!
+//! To deserialize a KVN message:
+//! ```
+//! # let kvn = r#"CCSDS_TDM_VERS = 2.0
+//! # CREATION_DATE = 2005-06-09T20:15:00
+//! # ORIGINATOR = NASA/JPL
+//! # META_START
+//! # TIME_SYSTEM = UTC
+//! # PARTICIPANT_1 = DSS-25
+//! # PARTICIPANT_2 = yyyy-nnnA
+//! # MODE = SEQUENTIAL
+//! # PATH = 2,1
+//! # META_STOP
+//! # DATA_START
+//! # RECEIVE_FREQ_1 = 2005-06-08T17:41:00 -409.2735
+//! # DATA_STOP"#;
+//! #
+//! # use lox_io::ndm::tdm::TdmType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: TdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+//! ```
+//!
+//! A message can be written back to KVN with [TdmType::to_kvn_string].
+
+use std::fmt;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+
+use super::common;
+use super::kvn::parser::KvnTdmObservationValue;
+use super::kvn::serializer::to_kvn_lines;
+use super::kvn::KvnSerializerErr;
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct TdmType {
+    #[serde(rename = "@id")]
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
+    #[serde(rename = "header")]
+    pub header: TdmHeader,
+    #[serde(rename = "body")]
+    pub body: TdmBody,
+}
+
+impl crate::ndm::xml::FromXmlStr<'_> for TdmType {}
+
+impl TdmType {
+    /// Serializes the message as KVN which can be parsed with
+    /// [KvnDeserializer](crate::ndm::kvn::KvnDeserializer).
+    pub fn to_kvn_string(&self) -> Result<String, KvnSerializerErr> {
+        let mut lines = vec![format!("CCSDS_TDM_VERS = {}", self.version)];
+        lines.extend(to_kvn_lines(&self.header)?);
+        for segment in &self.body.segment_list {
+            lines.push(String::new());
+            lines.push("META_START".to_string());
+            lines.extend(to_kvn_lines(&segment.metadata)?);
+            lines.push("META_STOP".to_string());
+            lines.push(String::new());
+            lines.push("DATA_START".to_string());
+            lines.extend(
+                segment
+                    .data
+                    .comment_list
+                    .iter()
+                    .map(|comment| format!("COMMENT {comment}")),
+            );
+            lines.extend(segment.data.observation_list.iter().map(|observation| {
+                format!(
+                    "{} = {} {:?}",
+                    observation.keyword, observation.epoch.0, observation.value
+                )
+            }));
+            lines.push("DATA_STOP".to_string());
+        }
+        lines.push(String::new());
+        Ok(lines.join("\n"))
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct TdmHeader {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "CREATION_DATE")]
+    pub creation_date: common::EpochType,
+    #[serde(rename = "ORIGINATOR")]
+    pub originator: String,
+    #[serde(rename = "MESSAGE_ID")]
+    pub message_id: Option<String>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct TdmBody {
+    #[serde(rename = "segment")]
+    pub segment_list: Vec<TdmSegment>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct TdmSegment {
+    #[serde(rename = "metadata")]
+    pub metadata: TdmMetadata,
+    #[serde(rename = "data")]
+    pub data: TdmData,
+}
+
+// The field order follows table 3-3 of CCSDS 503.0-B-2 because the KVN
+// parser expects the keywords in that order.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "META")]
+pub struct TdmMetadata {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "TRACK_ID")]
+    pub track_id: Option<String>,
+    #[serde(rename = "DATA_TYPES")]
+    pub data_types: Option<String>,
+    #[serde(rename = "TIME_SYSTEM")]
+    pub time_system: String,
+    #[serde(rename = "START_TIME")]
+    pub start_time: Option<common::EpochType>,
+    #[serde(rename = "STOP_TIME")]
+    pub stop_time: Option<common::EpochType>,
+    #[serde(rename = "PARTICIPANT_1")]
+    pub participant_1: String,
+    #[serde(rename = "PARTICIPANT_2")]
+    pub participant_2: Option<String>,
+    #[serde(rename = "PARTICIPANT_3")]
+    pub participant_3: Option<String>,
+    #[serde(rename = "PARTICIPANT_4")]
+    pub participant_4: Option<String>,
+    #[serde(rename = "PARTICIPANT_5")]
+    pub participant_5: Option<String>,
+    #[serde(rename = "MODE")]
+    pub mode: Option<String>,
+    #[serde(rename = "PATH")]
+    pub path: Option<String>,
+    #[serde(rename = "PATH_1")]
+    pub path_1: Option<String>,
+    #[serde(rename = "PATH_2")]
+    pub path_2: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME_1")]
+    pub ephemeris_name_1: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME_2")]
+    pub ephemeris_name_2: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME_3")]
+    pub ephemeris_name_3: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME_4")]
+    pub ephemeris_name_4: Option<String>,
+    #[serde(rename = "EPHEMERIS_NAME_5")]
+    pub ephemeris_name_5: Option<String>,
+    #[serde(rename = "TRANSMIT_BAND")]
+    pub transmit_band: Option<String>,
+    #[serde(rename = "RECEIVE_BAND")]
+    pub receive_band: Option<String>,
+    #[serde(rename = "TURNAROUND_NUMERATOR")]
+    pub turnaround_numerator: Option<u64>,
+    #[serde(rename = "TURNAROUND_DENOMINATOR")]
+    pub turnaround_denominator: Option<u64>,
+    #[serde(rename = "TIMETAG_REF")]
+    pub timetag_ref: Option<String>,
+    #[serde(rename = "INTEGRATION_INTERVAL")]
+    pub integration_interval: Option<f64>,
+    #[serde(rename = "INTEGRATION_REF")]
+    pub integration_ref: Option<String>,
+    #[serde(rename = "FREQ_OFFSET")]
+    pub freq_offset: Option<f64>,
+    #[serde(rename = "RANGE_MODE")]
+    pub range_mode: Option<String>,
+    #[serde(rename = "RANGE_MODULUS")]
+    pub range_modulus: Option<f64>,
+    #[serde(rename = "RANGE_UNITS")]
+    pub range_units: Option<String>,
+    #[serde(rename = "ANGLE_TYPE")]
+    pub angle_type: Option<String>,
+    #[serde(rename = "REFERENCE_FRAME")]
+    pub reference_frame: Option<String>,
+    #[serde(rename = "INTERPOLATION")]
+    pub interpolation: Option<String>,
+    #[serde(rename = "INTERPOLATION_DEGREE")]
+    pub interpolation_degree: Option<u64>,
+    #[serde(rename = "DOPPLER_COUNT_BIAS")]
+    pub doppler_count_bias: Option<f64>,
+    #[serde(rename = "DOPPLER_COUNT_SCALE")]
+    pub doppler_count_scale: Option<u64>,
+    #[serde(rename = "DOPPLER_COUNT_ROLLOVER")]
+    pub doppler_count_rollover: Option<String>,
+    #[serde(rename = "TRANSMIT_DELAY_1")]
+    pub transmit_delay_1: Option<f64>,
+    #[serde(rename = "TRANSMIT_DELAY_2")]
+    pub transmit_delay_2: Option<f64>,
+    #[serde(rename = "TRANSMIT_DELAY_3")]
+    pub transmit_delay_3: Option<f64>,
+    #[serde(rename = "TRANSMIT_DELAY_4")]
+    pub transmit_delay_4: Option<f64>,
+    #[serde(rename = "TRANSMIT_DELAY_5")]
+    pub transmit_delay_5: Option<f64>,
+    #[serde(rename = "RECEIVE_DELAY_1")]
+    pub receive_delay_1: Option<f64>,
+    #[serde(rename = "RECEIVE_DELAY_2")]
+    pub receive_delay_2: Option<f64>,
+    #[serde(rename = "RECEIVE_DELAY_3")]
+    pub receive_delay_3: Option<f64>,
+    #[serde(rename = "RECEIVE_DELAY_4")]
+    pub receive_delay_4: Option<f64>,
+    #[serde(rename = "RECEIVE_DELAY_5")]
+    pub receive_delay_5: Option<f64>,
+    #[serde(rename = "DATA_QUALITY")]
+    pub data_quality: Option<String>,
+    #[serde(rename = "CORRECTION_ANGLE_1")]
+    pub correction_angle_1: Option<f64>,
+    #[serde(rename = "CORRECTION_ANGLE_2")]
+    pub correction_angle_2: Option<f64>,
+    #[serde(rename = "CORRECTION_DOPPLER")]
+    pub correction_doppler: Option<f64>,
+    #[serde(rename = "CORRECTION_MAG")]
+    pub correction_mag: Option<f64>,
+    #[serde(rename = "CORRECTION_RANGE")]
+    pub correction_range: Option<f64>,
+    #[serde(rename = "CORRECTION_RCS")]
+    pub correction_rcs: Option<f64>,
+    #[serde(rename = "CORRECTION_RECEIVE")]
+    pub correction_receive: Option<f64>,
+    #[serde(rename = "CORRECTION_TRANSMIT")]
+    pub correction_transmit: Option<f64>,
+    #[serde(rename = "CORRECTION_ABERRATION_YEARLY")]
+    pub correction_aberration_yearly: Option<f64>,
+    #[serde(rename = "CORRECTION_ABERRATION_DIURNAL")]
+    pub correction_aberration_diurnal: Option<f64>,
+    #[serde(rename = "CORRECTIONS_APPLIED")]
+    pub corrections_applied: Option<String>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "DATA")]
+pub struct TdmData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "observation")]
+    pub observation_list: Vec<TdmObservation>,
+}
+
+/// A single tracking observation, e.g. `RANGE = 2005-159T18:27:00 39242998.5`
+/// in KVN or an `<observation>` element with an `EPOCH` and a `RANGE` child
+/// in XML.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TdmObservation {
+    pub epoch: common::EpochType,
+    /// The observation type, i.e. the data keyword such as `RECEIVE_FREQ_1`
+    pub keyword: String,
+    pub value: f64,
+}
+
+impl From<KvnTdmObservationValue> for TdmObservation {
+    fn from(value: KvnTdmObservationValue) -> Self {
+        Self {
+            epoch: common::EpochType(value.epoch.full_value),
+            keyword: value.keyword,
+            value: value.value,
+        }
+    }
+}
+
+// The XML schema uses a different element name for every observation type,
+// so the keyword has to be recovered from the element name by hand.
+impl<'de> serde::Deserialize<'de> for TdmObservation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(TdmObservationVisitor)
+    }
+}
+
+struct TdmObservationVisitor;
+
+impl<'de> Visitor<'de> for TdmObservationVisitor {
+    type Value = TdmObservation;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a TDM observation with an EPOCH and a single data keyword")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut epoch: Option<String> = None;
+        let mut data: Option<(String, f64)> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "EPOCH" {
+                if epoch.is_some() {
+                    return Err(de::Error::duplicate_field("EPOCH"));
+                }
+                epoch = Some(map.next_value()?);
+            } else {
+                if data.is_some() {
+                    return Err(de::Error::custom(format!(
+                        "unexpected second data keyword `{}` in TDM observation",
+                        key
+                    )));
+                }
+                data = Some((key, map.next_value()?));
+            }
+        }
+
+        let epoch = epoch.ok_or_else(|| de::Error::missing_field("EPOCH"))?;
+        let (keyword, value) =
+            data.ok_or_else(|| de::Error::custom("missing data keyword in TDM observation"))?;
+
+        Ok(TdmObservation {
+            epoch: common::EpochType(epoch),
+            keyword,
+            value,
+        })
+    }
+}
+
+impl serde::Serialize for TdmObservation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("EPOCH", &self.epoch.0)?;
+        map.serialize_entry(&self.keyword, &self.value)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ndm::kvn::KvnDeserializer;
+    use crate::ndm::xml::FromXmlStr;
+
+    use super::*;
+
+    // Adapted from the examples in annex D of CCSDS 503.0-B-2
+    const TDM_KVN: &str = r#"CCSDS_TDM_VERS = 2.0
+COMMENT TDM example created by yyyyy-nnnA Nav Team (NASA/JPL)
+COMMENT StarTrek 1-way data, Ka band down
+CREATION_DATE = 2005-160T20:15:00
+ORIGINATOR = NASA/JPL
+
+META_START
+COMMENT Data quality degraded by antenna pointing problem...
+COMMENT Slightly noisy data
+TIME_SYSTEM = UTC
+PARTICIPANT_1 = DSS-25
+PARTICIPANT_2 = yyyy-nnnA
+MODE = SEQUENTIAL
+PATH = 2,1
+INTEGRATION_INTERVAL = 1.0
+INTEGRATION_REF = MIDDLE
+FREQ_OFFSET = 32021035200.0
+TRANSMIT_DELAY_1 = 0.000077
+RECEIVE_DELAY_1 = 0.000077
+DATA_QUALITY = DEGRADED
+META_STOP
+
+DATA_START
+COMMENT TRANSMIT_FREQ_2 is spacecraft reference downlink
+TRANSMIT_FREQ_2 = 2005-159T17:41:00 32023442781.733
+RECEIVE_FREQ_1 = 2005-159T17:41:00 -409.2735
+RECEIVE_FREQ_1 = 2005-159T17:41:01 -371.1568
+RECEIVE_FREQ_1 = 2005-159T17:41:02 -333.0551
+DATA_STOP
+
+META_START
+TIME_SYSTEM = UTC
+PARTICIPANT_1 = DSS-24
+PARTICIPANT_2 = yyyy-nnnA
+MODE = SEQUENTIAL
+PATH = 1,2,1
+TRANSMIT_BAND = X
+RECEIVE_BAND = X
+TURNAROUND_NUMERATOR = 240
+TURNAROUND_DENOMINATOR = 221
+TIMETAG_REF = RECEIVE
+RANGE_MODE = COHERENT
+RANGE_MODULUS = 2.0e+26
+RANGE_UNITS = RU
+META_STOP
+
+DATA_START
+TRANSMIT_FREQ_1 = 2005-159T18:27:00 7180064367.3536
+RANGE = 2005-159T18:27:00 39242998.5151986
+DATA_STOP"#;
+
+    const TDM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tdm xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+        xsi:noNamespaceSchemaLocation="http://sanaregistry.org/r/ndmxml/ndmxml-2.0.0-master-2.0.xsd"
+        id="CCSDS_TDM_VERS" version="2.0">
+    <header>
+        <COMMENT>TDM example created by yyyyy-nnnA Nav Team (NASA/JPL)</COMMENT>
+        <COMMENT>StarTrek 1-way data, Ka band down</COMMENT>
+        <CREATION_DATE>2005-160T20:15:00</CREATION_DATE>
+        <ORIGINATOR>NASA/JPL</ORIGINATOR>
+    </header>
+    <body>
+        <segment>
+            <metadata>
+                <COMMENT>Data quality degraded by antenna pointing problem...</COMMENT>
+                <COMMENT>Slightly noisy data</COMMENT>
+                <TIME_SYSTEM>UTC</TIME_SYSTEM>
+                <PARTICIPANT_1>DSS-25</PARTICIPANT_1>
+                <PARTICIPANT_2>yyyy-nnnA</PARTICIPANT_2>
+                <MODE>SEQUENTIAL</MODE>
+                <PATH>2,1</PATH>
+                <INTEGRATION_INTERVAL>1.0</INTEGRATION_INTERVAL>
+                <INTEGRATION_REF>MIDDLE</INTEGRATION_REF>
+                <FREQ_OFFSET>32021035200.0</FREQ_OFFSET>
+                <TRANSMIT_DELAY_1>0.000077</TRANSMIT_DELAY_1>
+                <RECEIVE_DELAY_1>0.000077</RECEIVE_DELAY_1>
+                <DATA_QUALITY>DEGRADED</DATA_QUALITY>
+            </metadata>
+            <data>
+                <COMMENT>TRANSMIT_FREQ_2 is spacecraft reference downlink</COMMENT>
+                <observation>
+                    <EPOCH>2005-159T17:41:00</EPOCH>
+                    <TRANSMIT_FREQ_2>32023442781.733</TRANSMIT_FREQ_2>
+                </observation>
+                <observation>
+                    <EPOCH>2005-159T17:41:00</EPOCH>
+                    <RECEIVE_FREQ_1>-409.2735</RECEIVE_FREQ_1>
+                </observation>
+                <observation>
+                    <EPOCH>2005-159T17:41:01</EPOCH>
+                    <RECEIVE_FREQ_1>-371.1568</RECEIVE_FREQ_1>
+                </observation>
+                <observation>
+                    <EPOCH>2005-159T17:41:02</EPOCH>
+                    <RECEIVE_FREQ_1>-333.0551</RECEIVE_FREQ_1>
+                </observation>
+            </data>
+        </segment>
+        <segment>
+            <metadata>
+                <TIME_SYSTEM>UTC</TIME_SYSTEM>
+                <PARTICIPANT_1>DSS-24</PARTICIPANT_1>
+                <PARTICIPANT_2>yyyy-nnnA</PARTICIPANT_2>
+                <MODE>SEQUENTIAL</MODE>
+                <PATH>1,2,1</PATH>
+                <TRANSMIT_BAND>X</TRANSMIT_BAND>
+                <RECEIVE_BAND>X</RECEIVE_BAND>
+                <TURNAROUND_NUMERATOR>240</TURNAROUND_NUMERATOR>
+                <TURNAROUND_DENOMINATOR>221</TURNAROUND_DENOMINATOR>
+                <TIMETAG_REF>RECEIVE</TIMETAG_REF>
+                <RANGE_MODE>COHERENT</RANGE_MODE>
+                <RANGE_MODULUS>2.0e+26</RANGE_MODULUS>
+                <RANGE_UNITS>RU</RANGE_UNITS>
+            </metadata>
+            <data>
+                <observation>
+                    <EPOCH>2005-159T18:27:00</EPOCH>
+                    <TRANSMIT_FREQ_1>7180064367.3536</TRANSMIT_FREQ_1>
+                </observation>
+                <observation>
+                    <EPOCH>2005-159T18:27:00</EPOCH>
+                    <RANGE>39242998.5151986</RANGE>
+                </observation>
+            </data>
+        </segment>
+    </body>
+</tdm>"#;
+
+    fn observation(epoch: &str, keyword: &str, value: f64) -> TdmObservation {
+        TdmObservation {
+            epoch: common::EpochType(epoch.to_string()),
+            keyword: keyword.to_string(),
+            value,
+        }
+    }
+
+    fn expected_message() -> TdmType {
+        TdmType {
+            id: None,
+            version: "2.0".to_string(),
+            header: TdmHeader {
+                comment_list: vec![
+                    "TDM example created by yyyyy-nnnA Nav Team (NASA/JPL)".to_string(),
+                    "StarTrek 1-way data, Ka band down".to_string(),
+                ],
+                creation_date: common::EpochType("2005-160T20:15:00".to_string()),
+                originator: "NASA/JPL".to_string(),
+                message_id: None,
+            },
+            body: TdmBody {
+                segment_list: vec![
+                    TdmSegment {
+                        metadata: TdmMetadata {
+                            comment_list: vec![
+                                "Data quality degraded by antenna pointing problem...".to_string(),
+                                "Slightly noisy data".to_string(),
+                            ],
+                            time_system: "UTC".to_string(),
+                            participant_1: "DSS-25".to_string(),
+                            participant_2: Some("yyyy-nnnA".to_string()),
+                            mode: Some("SEQUENTIAL".to_string()),
+                            path: Some("2,1".to_string()),
+                            integration_interval: Some(1.0),
+                            integration_ref: Some("MIDDLE".to_string()),
+                            freq_offset: Some(32021035200.0),
+                            transmit_delay_1: Some(0.000077),
+                            receive_delay_1: Some(0.000077),
+                            data_quality: Some("DEGRADED".to_string()),
+                            ..Default::default()
+                        },
+                        data: TdmData {
+                            comment_list: vec![
+                                "TRANSMIT_FREQ_2 is spacecraft reference downlink".to_string()
+                            ],
+                            observation_list: vec![
+                                observation(
+                                    "2005-159T17:41:00",
+                                    "TRANSMIT_FREQ_2",
+                                    32023442781.733,
+                                ),
+                                observation("2005-159T17:41:00", "RECEIVE_FREQ_1", -409.2735),
+                                observation("2005-159T17:41:01", "RECEIVE_FREQ_1", -371.1568),
+                                observation("2005-159T17:41:02", "RECEIVE_FREQ_1", -333.0551),
+                            ],
+                        },
+                    },
+                    TdmSegment {
+                        metadata: TdmMetadata {
+                            time_system: "UTC".to_string(),
+                            participant_1: "DSS-24".to_string(),
+                            participant_2: Some("yyyy-nnnA".to_string()),
+                            mode: Some("SEQUENTIAL".to_string()),
+                            path: Some("1,2,1".to_string()),
+                            transmit_band: Some("X".to_string()),
+                            receive_band: Some("X".to_string()),
+                            turnaround_numerator: Some(240),
+                            turnaround_denominator: Some(221),
+                            timetag_ref: Some("RECEIVE".to_string()),
+                            range_mode: Some("COHERENT".to_string()),
+                            range_modulus: Some(2.0e26),
+                            range_units: Some("RU".to_string()),
+                            ..Default::default()
+                        },
+                        data: TdmData {
+                            comment_list: vec![],
+                            observation_list: vec![
+                                observation(
+                                    "2005-159T18:27:00",
+                                    "TRANSMIT_FREQ_1",
+                                    7180064367.3536,
+                                ),
+                                observation("2005-159T18:27:00", "RANGE", 39242998.5151986),
+                            ],
+                        },
+                    },
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse_tdm_message_kvn() {
+        let message: TdmType = KvnDeserializer::from_kvn_str(TDM_KVN).unwrap();
+
+        assert_eq!(message, expected_message());
+    }
+
+    #[test]
+    fn test_parse_tdm_message_xml() {
+        let message = TdmType::from_xml_str(TDM_XML).unwrap();
+
+        assert_eq!(
+            message,
+            TdmType {
+                id: Some("CCSDS_TDM_VERS".to_string()),
+                ..expected_message()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_tdm_message_kvn_and_xml_agree() {
+        let kvn: TdmType = KvnDeserializer::from_kvn_str(TDM_KVN).unwrap();
+        let xml = TdmType::from_xml_str(TDM_XML).unwrap();
+
+        assert_eq!(kvn.header, xml.header);
+        assert_eq!(kvn.body, xml.body);
+        assert_eq!(kvn.version, xml.version);
+    }
+
+    #[test]
+    fn test_write_tdm_message_kvn() {
+        let message = expected_message();
+        let kvn = message.to_kvn_string().unwrap();
+        let actual: TdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+
+        assert_eq!(actual, message);
+    }
+
+    #[test]
+    fn test_write_tdm_message_kvn_angles_range_doppler() {
+        let epochs = ["2024-03-01T10:00:00.000", "2024-03-01T10:01:00.000"];
+        let mut observations = vec![];
+        for (i, epoch) in epochs.iter().enumerate() {
+            let i = i as f64;
+            observations.push(observation(epoch, "ANGLE_1", 123.456789 + i));
+            observations.push(observation(epoch, "ANGLE_2", 12.3456789 + i));
+            observations.push(observation(epoch, "RANGE", 2345.6789012345 + i));
+            observations.push(observation(epoch, "DOPPLER_INSTANTANEOUS", -6.54321 + i));
+        }
+        let message = TdmType {
+            version: "2.0".to_string(),
+            header: TdmHeader {
+                creation_date: common::EpochType("2024-03-02T00:00:00".to_string()),
+                originator: "LOX".to_string(),
+                ..Default::default()
+            },
+            body: TdmBody {
+                segment_list: vec![TdmSegment {
+                    metadata: TdmMetadata {
+                        time_system: "UTC".to_string(),
+                        participant_1: "CEBREROS".to_string(),
+                        participant_2: Some("SPACECRAFT".to_string()),
+                        mode: Some("SEQUENTIAL".to_string()),
+                        path: Some("1,2,1".to_string()),
+                        range_units: Some("km".to_string()),
+                        angle_type: Some("AZEL".to_string()),
+                        ..Default::default()
+                    },
+                    data: TdmData {
+                        comment_list: vec!["Simulated observations".to_string()],
+                        observation_list: observations,
+                    },
+                }],
+            },
+            ..Default::default()
+        };
+        let kvn = message.to_kvn_string().unwrap();
+        assert!(kvn.starts_with("CCSDS_TDM_VERS = 2.0\n"));
+        assert!(kvn.contains("ANGLE_1 = 2024-03-01T10:00:00.000 123.456789\n"));
+
+        let actual: TdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+        assert_eq!(actual, message);
+    }
+
+    #[test]
+    fn test_parse_tdm_observation_xml_errors() {
+        let xml = r#"<tdm id="CCSDS_TDM_VERS" version="2.0">
+    <body>
+        <segment>
+            <data>
+                <observation>
+                    <EPOCH>2005-159T18:27:00</EPOCH>
+                    <RANGE>1.0</RANGE>
+                    <DOPPLER_INTEGRATED>2.0</DOPPLER_INTEGRATED>
+                </observation>
+            </data>
+        </segment>
+    </body>
+</tdm>"#;
+
+        assert!(TdmType::from_xml_str(xml).is_err());
+    }
+}
@@ -23,6 +23,14 @@ use thiserror::Error;
 #[error(transparent)]
 pub struct KernelError(#[from] Error<String>);
 
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum KernelPoolError {
+    #[error("variable `{0}` is not defined in any loaded kernel")]
+    UndefinedVariable(String),
+    #[error("variable `{0}` is not of type {1}")]
+    TypeMismatch(String, &'static str),
+}
+
 #[derive(Clone, Debug, PartialEq)]
 enum Value {
     Double(f64),
@@ -75,6 +83,33 @@ impl Kernel {
         }
     }
 
+    pub fn get_string(&self, key: &str) -> Option<&str> {
+        let value = self.items.get(key)?;
+        if let Value::String(v) = value {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the units declared for the variable `key` through a companion `<key>_UNITS`
+    /// variable, e.g. `BODY399_RADII_UNITS = 'KILOMETERS'`.
+    ///
+    /// Most kernels do not declare units and rely on the SPICE conventions instead, in which
+    /// case `None` is returned.
+    pub fn get_units(&self, key: &str) -> Option<&str> {
+        self.get_string(&format!("{key}_UNITS"))
+    }
+
+    pub fn get_string_array(&self, key: &str) -> Option<&Vec<String>> {
+        let value = self.items.get(key)?;
+        if let Value::StringArray(v) = value {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     pub fn get_timestamp_array(&self, key: &str) -> Option<&Vec<String>> {
         let value = self.items.get(key)?;
         if let Value::TimestampArray(v) = value {
@@ -84,11 +119,162 @@ impl Kernel {
         }
     }
 
+    /// Returns the phase angles of the nutation-precession terms of the rotational elements of the
+    /// body with the NAIF `id`.
+    ///
+    /// The angles are shared by all bodies of a planetary system and defined by the
+    /// `BODY<n>_NUT_PREC_ANGLES` variable of its barycenter, e.g. `BODY5_NUT_PREC_ANGLES` for
+    /// Jupiter and its satellites. Each angle is returned as the coefficients of a polynomial in
+    /// Julian centuries with ascending degree and in degrees. The degree of the polynomials is
+    /// given by `BODY<n>_MAX_PHASE_DEGREE` and defaults to one.
+    pub fn get_nut_prec_angles(&self, id: i32) -> Option<Vec<Vec<f64>>> {
+        let system_id = nut_prec_system_id(id);
+        let angles = self.get_double_array(&format!("BODY{system_id}_NUT_PREC_ANGLES"))?;
+        let degree = self
+            .get_double_array(&format!("BODY{system_id}_MAX_PHASE_DEGREE"))
+            .and_then(|degree| degree.first().copied())
+            .or_else(|| self.get_double(&format!("BODY{system_id}_MAX_PHASE_DEGREE")))
+            .map_or(1, |degree| degree as usize);
+        Some(
+            angles
+                .chunks(degree + 1)
+                .map(|coefficients| coefficients.to_vec())
+                .collect(),
+        )
+    }
+
     pub fn keys(&self) -> Vec<&String> {
         self.items.keys().collect()
     }
 }
 
+/// A collection of text kernels which are queried as a whole, like the kernel pool of the SPICE
+/// toolkit.
+///
+/// If a variable is defined in several kernels, the definition of the kernel that was loaded last
+/// takes precedence.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KernelPool {
+    kernels: Vec<Kernel>,
+}
+
+impl KernelPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(&mut self, kernel: Kernel) {
+        self.kernels.push(kernel);
+    }
+
+    pub fn load_string(&mut self, input: &str) -> Result<(), KernelError> {
+        self.load(Kernel::from_string(input)?);
+        Ok(())
+    }
+
+    pub fn kernels(&self) -> &[Kernel] {
+        &self.kernels
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some()
+    }
+
+    pub fn get_double(&self, key: &str) -> Result<f64, KernelPoolError> {
+        match self.get(key)? {
+            Value::Double(v) => Ok(*v),
+            _ => Err(KernelPoolError::TypeMismatch(key.to_string(), "double")),
+        }
+    }
+
+    pub fn get_double_array(&self, key: &str) -> Result<&Vec<f64>, KernelPoolError> {
+        match self.get(key)? {
+            Value::DoubleArray(v) => Ok(v),
+            _ => Err(KernelPoolError::TypeMismatch(
+                key.to_string(),
+                "double array",
+            )),
+        }
+    }
+
+    pub fn get_string(&self, key: &str) -> Result<&str, KernelPoolError> {
+        match self.get(key)? {
+            Value::String(v) => Ok(v),
+            _ => Err(KernelPoolError::TypeMismatch(key.to_string(), "string")),
+        }
+    }
+
+    pub fn get_string_array(&self, key: &str) -> Result<&Vec<String>, KernelPoolError> {
+        match self.get(key)? {
+            Value::StringArray(v) => Ok(v),
+            _ => Err(KernelPoolError::TypeMismatch(
+                key.to_string(),
+                "string array",
+            )),
+        }
+    }
+
+    pub fn get_timestamp_array(&self, key: &str) -> Result<&Vec<String>, KernelPoolError> {
+        match self.get(key)? {
+            Value::TimestampArray(v) => Ok(v),
+            _ => Err(KernelPoolError::TypeMismatch(
+                key.to_string(),
+                "timestamp array",
+            )),
+        }
+    }
+
+    /// Returns the names of the variables which are defined with different values in more than
+    /// one of the loaded kernels, sorted alphabetically.
+    ///
+    /// Redefinitions are valid and resolved in favour of the last loaded kernel. This method
+    /// allows callers to detect them, e.g. to warn about unintentionally shadowed constants.
+    pub fn conflicts(&self) -> Vec<&str> {
+        let mut first: HashMap<&str, &Value> = HashMap::new();
+        let mut conflicts: Vec<&str> = Vec::new();
+        for kernel in &self.kernels {
+            for (key, value) in &kernel.items {
+                match first.get(key.as_str()) {
+                    Some(&other) if other != value => {
+                        if !conflicts.contains(&key.as_str()) {
+                            conflicts.push(key);
+                        }
+                    }
+                    Some(_) => {}
+                    None => {
+                        first.insert(key, value);
+                    }
+                }
+            }
+        }
+        conflicts.sort_unstable();
+        conflicts
+    }
+
+    fn find(&self, key: &str) -> Option<&Value> {
+        self.kernels
+            .iter()
+            .rev()
+            .find_map(|kernel| kernel.items.get(key))
+    }
+
+    fn get(&self, key: &str) -> Result<&Value, KernelPoolError> {
+        self.find(key)
+            .ok_or_else(|| KernelPoolError::UndefinedVariable(key.to_string()))
+    }
+}
+
+/// Returns the NAIF ID under which the nutation-precession angles of the body with the NAIF `id` are
+/// defined. Like the SPICE toolkit, this is the ID of the system barycenter for planets and
+/// satellites and the ID of the body itself otherwise.
+pub fn nut_prec_system_id(id: i32) -> i32 {
+    if (100..1000).contains(&id) {
+        id / 100
+    } else {
+        id
+    }
+}
+
 fn kernel(s: &str) -> IResult<&str, (&str, Entries, &str)> {
     let header = preceded(tag("KPL/"), alpha1);
     let mut parser = tuple((
@@ -395,6 +581,124 @@ mod tests {
         assert_eq!(key_value(input), Ok(("", (exp_key, exp_value))));
     }
 
+    #[test]
+    fn test_units() {
+        let input = "KPL/PCK
+\\begindata
+BODY399_RADII       = ( 6378136.6 6378136.6 6356751.9 )
+BODY399_RADII_UNITS = 'METERS'
+BODY499_RADII       = ( 3396.19 3396.19 3376.20 )
+\\begintext
+";
+        let kernel = Kernel::from_string(input).expect("kernel should be parsable");
+        assert_eq!(kernel.get_units("BODY399_RADII"), Some("METERS"));
+        assert_eq!(kernel.get_units("BODY499_RADII"), None);
+        assert_eq!(kernel.get_string("BODY399_RADII_UNITS"), Some("METERS"));
+        assert!(kernel.get_string("BODY399_RADII").is_none());
+    }
+
+    #[test]
+    fn test_kernel_pool() {
+        let pck = "KPL/PCK
+\\begindata
+BODY399_RADII = ( 6378.1366 6378.1366 6356.7519 )
+BODY499_RADII = ( 3396.19 3396.19 3376.20 )
+NAIF_BODY_NAME = ( 'EARTH' 'MARS' )
+\\begintext
+";
+        let gm = "KPL/PCK
+\\begindata
+BODY399_GM = ( 3.98600435436096E+05 )
+BODY399_RADII = ( 6378.137 6378.137 6356.752 )
+BODY499_RADII = ( 3396.19 3396.19 3376.20 )
+FRAME_ID = 'ITRF93'
+\\begintext
+";
+        let mut pool = KernelPool::new();
+        pool.load_string(pck).expect("kernel should be parsable");
+        pool.load_string(gm).expect("kernel should be parsable");
+        assert_eq!(pool.kernels().len(), 2);
+        assert!(pool.load_string("foo").is_err());
+        assert_eq!(pool.kernels().len(), 2);
+
+        // The last loaded kernel wins
+        assert_eq!(
+            pool.get_double_array("BODY399_RADII"),
+            Ok(&vec![6378.137, 6378.137, 6356.752])
+        );
+        assert_eq!(
+            pool.get_double_array("BODY399_GM"),
+            Ok(&vec![3.98600435436096e5])
+        );
+        assert_eq!(
+            pool.get_string_array("NAIF_BODY_NAME"),
+            Ok(&vec!["EARTH".to_string(), "MARS".to_string()])
+        );
+        assert_eq!(pool.get_string("FRAME_ID"), Ok("ITRF93"));
+        assert!(pool.contains("BODY499_RADII"));
+        assert!(!pool.contains("BODY599_RADII"));
+
+        assert_eq!(
+            pool.get_double_array("BODY599_RADII"),
+            Err(KernelPoolError::UndefinedVariable(
+                "BODY599_RADII".to_string()
+            ))
+        );
+        assert_eq!(
+            pool.get_double("FRAME_ID"),
+            Err(KernelPoolError::TypeMismatch(
+                "FRAME_ID".to_string(),
+                "double"
+            ))
+        );
+
+        // Identical redefinitions are not conflicts
+        assert_eq!(pool.conflicts(), vec!["BODY399_RADII"]);
+    }
+
+    #[test]
+    fn test_nut_prec_angles() {
+        let input = "KPL/PCK
+\\begindata
+BODY5_NUT_PREC_ANGLES = (    73.32      91472.9
+                             24.62      45137.2
+                            283.90       4850.7
+                            355.80       1191.3 )
+BODY4_NUT_PREC_ANGLES = ( 190.72646643 15917.10818695 0.
+                          21.46892470 31834.27934054 0. )
+BODY4_MAX_PHASE_DEGREE = 2
+\\begintext
+";
+        let kernel = Kernel::from_string(input).expect("kernel should be parsable");
+
+        // Satellites share the angles of their planetary system
+        let exp = vec![
+            vec![73.32, 91472.9],
+            vec![24.62, 45137.2],
+            vec![283.90, 4850.7],
+            vec![355.80, 1191.3],
+        ];
+        assert_eq!(kernel.get_nut_prec_angles(599), Some(exp.clone()));
+        assert_eq!(kernel.get_nut_prec_angles(501), Some(exp));
+
+        let exp = vec![
+            vec![190.72646643, 15917.10818695, 0.0],
+            vec![21.46892470, 31834.27934054, 0.0],
+        ];
+        assert_eq!(kernel.get_nut_prec_angles(401), Some(exp));
+        assert_eq!(kernel.get_nut_prec_angles(699), None);
+        assert_eq!(kernel.get_nut_prec_angles(10), None);
+    }
+
+    #[test]
+    fn test_nut_prec_system_id() {
+        assert_eq!(nut_prec_system_id(501), 5);
+        assert_eq!(nut_prec_system_id(599), 5);
+        assert_eq!(nut_prec_system_id(301), 3);
+        assert_eq!(nut_prec_system_id(10), 10);
+        assert_eq!(nut_prec_system_id(2000004), 2000004);
+    }
+
     #[test]
     fn test_data_block() {
         assert_eq!(start_tag("\\begindata\n"), Ok(("", "\\begindata")));
@@ -0,0 +1,521 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Native representation of NORAD two-line element sets and conversions from and to CCSDS
+//! Orbit Mean-Elements Messages.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use thiserror::Error;
+
+use crate::ndm::common::{AngleType, EpochType, InclinationType, NonNegativeDouble, OdmHeader};
+use crate::ndm::omm::{
+    BStarType, DRevType, ElementSetNoType, MeanElementsType, OmmBody, OmmData, OmmMetadata,
+    OmmSegment, OmmType, RevType, TleParametersType,
+};
+
+/// Mean element theories which are compatible with two-line element sets.
+const TLE_THEORIES: [&str; 5] = ["SGP", "SGP4", "SDP4", "SGP8", "SDP8"];
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum TleError {
+    #[error("mean element theory `{0}` is not compatible with two-line element sets")]
+    UnsupportedMeanElementTheory(String),
+    #[error("required field `{0}` is missing")]
+    MissingField(&'static str),
+    #[error("invalid epoch `{0}`")]
+    InvalidEpoch(String),
+    #[error("invalid element set number `{0}`")]
+    InvalidElementSetNo(String),
+    #[error("NORAD catalog number must be non-negative but was {0}")]
+    InvalidNoradCatId(i32),
+}
+
+/// A two-line element set with all values in their customary TLE units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tle {
+    /// Name of the object, i.e. the contents of line 0 if present.
+    pub object_name: Option<String>,
+    pub norad_cat_id: u32,
+    pub classification: char,
+    /// International designator in TLE form, e.g. `98067A`.
+    pub international_designator: String,
+    /// Full four-digit year of the epoch.
+    pub epoch_year: i32,
+    /// Day of the year of the epoch including the fraction of the day, starting at 1.0.
+    pub epoch_day: f64,
+    /// First derivative of the mean motion divided by two in rev/day².
+    pub mean_motion_dot: f64,
+    /// Second derivative of the mean motion divided by six in rev/day³.
+    pub mean_motion_ddot: f64,
+    /// Drag term in 1/earth radii.
+    pub bstar: f64,
+    pub ephemeris_type: u8,
+    pub element_set_no: u32,
+    /// Inclination in degrees.
+    pub inclination: f64,
+    /// Right ascension of the ascending node in degrees.
+    pub ra_of_asc_node: f64,
+    pub eccentricity: f64,
+    /// Argument of pericenter in degrees.
+    pub arg_of_pericenter: f64,
+    /// Mean anomaly in degrees.
+    pub mean_anomaly: f64,
+    /// Mean motion in rev/day.
+    pub mean_motion: f64,
+    pub rev_at_epoch: u32,
+}
+
+impl TryFrom<&OmmType> for Tle {
+    type Error = TleError;
+
+    fn try_from(omm: &OmmType) -> Result<Self, Self::Error> {
+        let metadata = &omm.body.segment.metadata;
+        let data = &omm.body.segment.data;
+
+        let theory = metadata.mean_element_theory.trim();
+        if !TLE_THEORIES.iter().any(|t| t.eq_ignore_ascii_case(theory)) {
+            return Err(TleError::UnsupportedMeanElementTheory(theory.to_string()));
+        }
+
+        let mean_elements = &data.mean_elements;
+        let tle_parameters = data
+            .tle_parameters
+            .as_ref()
+            .ok_or(TleError::MissingField("tleParameters"))?;
+
+        let mean_motion = mean_elements
+            .mean_motion
+            .as_ref()
+            .ok_or(TleError::MissingField("MEAN_MOTION"))?
+            .base;
+        let norad_cat_id = tle_parameters
+            .norad_cat_id
+            .ok_or(TleError::MissingField("NORAD_CAT_ID"))?;
+        let norad_cat_id =
+            u32::try_from(norad_cat_id).map_err(|_| TleError::InvalidNoradCatId(norad_cat_id))?;
+        let bstar = tle_parameters
+            .bstar
+            .as_ref()
+            .ok_or(TleError::MissingField("BSTAR"))?
+            .base;
+        let element_set_no = match &tle_parameters.element_set_no {
+            Some(ElementSetNoType(no)) => no
+                .trim()
+                .parse()
+                .map_err(|_| TleError::InvalidElementSetNo(no.clone()))?,
+            None => 0,
+        };
+        let (epoch_year, epoch_day) = parse_epoch(&mean_elements.epoch.0)?;
+
+        Ok(Tle {
+            object_name: Some(metadata.object_name.clone()).filter(|name| !name.is_empty()),
+            norad_cat_id,
+            classification: tle_parameters
+                .classification_type
+                .as_ref()
+                .and_then(|c| c.chars().next())
+                .unwrap_or('U'),
+            international_designator: to_tle_designator(&metadata.object_id),
+            epoch_year,
+            epoch_day,
+            mean_motion_dot: tle_parameters.mean_motion_dot.base,
+            mean_motion_ddot: tle_parameters
+                .mean_motion_ddot
+                .as_ref()
+                .map_or(0.0, |ddot| ddot.base),
+            bstar,
+            ephemeris_type: tle_parameters.ephemeris_type.unwrap_or_default() as u8,
+            element_set_no,
+            inclination: mean_elements.inclination.base,
+            ra_of_asc_node: mean_elements.ra_of_asc_node.base,
+            eccentricity: mean_elements.eccentricity.0,
+            arg_of_pericenter: mean_elements.arg_of_pericenter.base,
+            mean_anomaly: mean_elements.mean_anomaly.base,
+            mean_motion,
+            rev_at_epoch: tle_parameters.rev_at_epoch.unwrap_or_default() as u32,
+        })
+    }
+}
+
+impl From<&Tle> for OmmType {
+    fn from(tle: &Tle) -> Self {
+        OmmType {
+            id: Some("CCSDS_OMM_VERS".to_string()),
+            version: "3.0".to_string(),
+            header: OdmHeader::default(),
+            body: OmmBody {
+                segment: OmmSegment {
+                    metadata: OmmMetadata {
+                        object_name: tle.object_name.clone().unwrap_or_default(),
+                        object_id: to_omm_object_id(&tle.international_designator),
+                        center_name: "EARTH".to_string(),
+                        ref_frame: "TEME".to_string(),
+                        time_system: "UTC".to_string(),
+                        mean_element_theory: "SGP4".to_string(),
+                        ..Default::default()
+                    },
+                    data: OmmData {
+                        mean_elements: MeanElementsType {
+                            epoch: EpochType(format_epoch(tle.epoch_year, tle.epoch_day)),
+                            mean_motion: Some(RevType {
+                                base: tle.mean_motion,
+                                units: None,
+                            }),
+                            eccentricity: NonNegativeDouble(tle.eccentricity),
+                            inclination: InclinationType {
+                                base: tle.inclination,
+                                units: None,
+                            },
+                            ra_of_asc_node: AngleType {
+                                base: tle.ra_of_asc_node,
+                                units: None,
+                            },
+                            arg_of_pericenter: AngleType {
+                                base: tle.arg_of_pericenter,
+                                units: None,
+                            },
+                            mean_anomaly: AngleType {
+                                base: tle.mean_anomaly,
+                                units: None,
+                            },
+                            ..Default::default()
+                        },
+                        tle_parameters: Some(TleParametersType {
+                            ephemeris_type: Some(tle.ephemeris_type as i32),
+                            classification_type: Some(tle.classification.to_string()),
+                            norad_cat_id: Some(tle.norad_cat_id as i32),
+                            element_set_no: Some(ElementSetNoType(tle.element_set_no.to_string())),
+                            rev_at_epoch: Some(tle.rev_at_epoch as u64),
+                            bstar: Some(BStarType {
+                                base: tle.bstar,
+                                units: None,
+                            }),
+                            mean_motion_dot: DRevType {
+                                base: tle.mean_motion_dot,
+                                units: None,
+                            },
+                            mean_motion_ddot: Some(DRevType {
+                                base: tle.mean_motion_ddot,
+                                units: None,
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                },
+            },
+        }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_before_month(year: i32, month: u32) -> u32 {
+    const CUMULATIVE_DAYS: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let leap = if month > 2 && is_leap_year(year) {
+        1
+    } else {
+        0
+    };
+    CUMULATIVE_DAYS[(month - 1) as usize] + leap
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let next = if month == 12 {
+        days_in_year(year)
+    } else {
+        days_before_month(year, month + 1)
+    };
+    next - days_before_month(year, month)
+}
+
+fn epoch_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^(?<year>\d{4})-(?:(?<month>\d{2})-(?<day>\d{2})|(?<doy>\d{3}))T(?<hour>\d{2}):(?<minute>\d{2}):(?<second>\d{2}(?:\.\d*)?)Z?$",
+        )
+        .unwrap()
+    })
+}
+
+/// Parses a CCSDS epoch in either calendar (`YYYY-MM-DDThh:mm:ss`) or ordinal
+/// (`YYYY-DDDThh:mm:ss`) form into the year and the fractional day of the year.
+fn parse_epoch(epoch: &str) -> Result<(i32, f64), TleError> {
+    let err = || TleError::InvalidEpoch(epoch.to_string());
+    let captures = epoch_regex().captures(epoch.trim()).ok_or_else(err)?;
+    let field = |name: &str| captures.name(name).map(|m| m.as_str().parse::<u32>());
+
+    let year: i32 = captures["year"].parse().map_err(|_| err())?;
+    let day_of_year = match (field("month"), field("day"), field("doy")) {
+        (Some(Ok(month)), Some(Ok(day)), None)
+            if (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day) =>
+        {
+            days_before_month(year, month) + day
+        }
+        (None, None, Some(Ok(doy))) if (1..=days_in_year(year)).contains(&doy) => doy,
+        _ => return Err(err()),
+    };
+    let hour: u32 = captures["hour"].parse().map_err(|_| err())?;
+    let minute: u32 = captures["minute"].parse().map_err(|_| err())?;
+    let second: f64 = captures["second"].parse().map_err(|_| err())?;
+    if hour > 23 || minute > 59 || second >= 61.0 {
+        return Err(err());
+    }
+
+    let seconds_of_day = (hour * 3600 + minute * 60) as f64 + second;
+    Ok((year, day_of_year as f64 + seconds_of_day / 86400.0))
+}
+
+/// Formats the TLE epoch as a CCSDS calendar epoch with microsecond resolution.
+fn format_epoch(year: i32, day: f64) -> String {
+    let day_of_year = day.floor() as u32;
+    let microseconds = ((day - day.floor()) * 86400e6).round() as u64;
+    let (day_of_year, microseconds) = if microseconds == 86_400_000_000 {
+        (day_of_year + 1, 0)
+    } else {
+        (day_of_year, microseconds)
+    };
+    let month = (1..=12)
+        .rev()
+        .find(|&m| days_before_month(year, m) < day_of_year)
+        .unwrap_or(1);
+    let day_of_month = day_of_year - days_before_month(year, month);
+    let seconds = microseconds / 1_000_000;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}",
+        year,
+        month,
+        day_of_month,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+        microseconds % 1_000_000
+    )
+}
+
+/// Converts an OMM object id (`1998-067A`) into a TLE international designator (`98067A`).
+fn to_tle_designator(object_id: &str) -> String {
+    match object_id.split_once('-') {
+        Some((year, piece)) if year.len() == 4 => format!("{}{}", &year[2..], piece),
+        _ => object_id.to_string(),
+    }
+}
+
+/// Converts a TLE international designator (`98067A`) into an OMM object id (`1998-067A`).
+fn to_omm_object_id(designator: &str) -> String {
+    match designator.get(0..2).map(str::parse::<u32>) {
+        Some(Ok(year)) => {
+            let century = if year < 57 { 2000 } else { 1900 };
+            format!("{}-{}", century + year, &designator[2..])
+        }
+        _ => designator.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::ndm::xml::FromXmlStr;
+
+    use super::*;
+
+    const OMM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<omm id="CCSDS_OMM_VERS" version="2.0">
+<header>
+    <CREATION_DATE>2021-03-24T23:00:00.000</CREATION_DATE>
+    <ORIGINATOR>CelesTrak</ORIGINATOR>
+</header>
+<body>
+<segment>
+    <metadata>
+        <OBJECT_NAME>STARLETTE</OBJECT_NAME>
+        <OBJECT_ID>1975-010A</OBJECT_ID>
+        <CENTER_NAME>EARTH</CENTER_NAME>
+        <REF_FRAME>TEME</REF_FRAME>
+        <TIME_SYSTEM>UTC</TIME_SYSTEM>
+        <MEAN_ELEMENT_THEORY>SGP4</MEAN_ELEMENT_THEORY>
+    </metadata>
+    <data>
+        <meanElements>
+            <EPOCH>2008-09-20T12:25:40.104192</EPOCH>
+            <MEAN_MOTION units="rev/day">15.72125391</MEAN_MOTION>
+            <ECCENTRICITY>0.0006703</ECCENTRICITY>
+            <INCLINATION units="deg">51.6416</INCLINATION>
+            <RA_OF_ASC_NODE units="deg">247.4627</RA_OF_ASC_NODE>
+            <ARG_OF_PERICENTER units="deg">130.5360</ARG_OF_PERICENTER>
+            <MEAN_ANOMALY units="deg">325.0288</MEAN_ANOMALY>
+            <GM units="km**3/s**2">398600.8</GM>
+        </meanElements>
+        <tleParameters>
+            <EPHEMERIS_TYPE>0</EPHEMERIS_TYPE>
+            <CLASSIFICATION_TYPE>U</CLASSIFICATION_TYPE>
+            <NORAD_CAT_ID>7646</NORAD_CAT_ID>
+            <ELEMENT_SET_NO>999</ELEMENT_SET_NO>
+            <REV_AT_EPOCH>32997</REV_AT_EPOCH>
+            <BSTAR>-.47102E-5</BSTAR>
+            <MEAN_MOTION_DOT>-.147E-5</MEAN_MOTION_DOT>
+            <MEAN_MOTION_DDOT>0</MEAN_MOTION_DDOT>
+        </tleParameters>
+    </data>
+</segment>
+</body>
+</omm>"#;
+
+    #[test]
+    fn test_omm_tle_round_trip() {
+        let omm = OmmType::from_xml_str(OMM).unwrap();
+        let tle = Tle::try_from(&omm).unwrap();
+
+        assert_eq!(tle.object_name.as_deref(), Some("STARLETTE"));
+        assert_eq!(tle.norad_cat_id, 7646);
+        assert_eq!(tle.international_designator, "75010A");
+        assert_eq!(tle.epoch_year, 2008);
+        assert_float_eq!(tle.epoch_day, 264.51782528, abs <= 1e-8);
+        assert_eq!(tle.mean_motion, 15.72125391);
+        assert_eq!(tle.bstar, -0.47102e-5);
+        assert_eq!(tle.element_set_no, 999);
+        assert_eq!(tle.rev_at_epoch, 32997);
+
+        let act = OmmType::from(&tle);
+        let act_metadata = &act.body.segment.metadata;
+        let exp_metadata = &omm.body.segment.metadata;
+        assert_eq!(act_metadata.object_name, exp_metadata.object_name);
+        assert_eq!(act_metadata.object_id, exp_metadata.object_id);
+        assert_eq!(act_metadata.center_name, exp_metadata.center_name);
+        assert_eq!(act_metadata.ref_frame, exp_metadata.ref_frame);
+        assert_eq!(act_metadata.time_system, exp_metadata.time_system);
+        assert_eq!(
+            act_metadata.mean_element_theory,
+            exp_metadata.mean_element_theory
+        );
+
+        let act_elements = &act.body.segment.data.mean_elements;
+        let exp_elements = &omm.body.segment.data.mean_elements;
+        assert_eq!(act_elements.epoch, exp_elements.epoch);
+        assert_eq!(
+            act_elements.mean_motion.as_ref().map(|n| n.base),
+            exp_elements.mean_motion.as_ref().map(|n| n.base)
+        );
+        assert_eq!(act_elements.eccentricity, exp_elements.eccentricity);
+        assert_eq!(act_elements.inclination.base, exp_elements.inclination.base);
+        assert_eq!(
+            act_elements.ra_of_asc_node.base,
+            exp_elements.ra_of_asc_node.base
+        );
+        assert_eq!(
+            act_elements.arg_of_pericenter.base,
+            exp_elements.arg_of_pericenter.base
+        );
+        assert_eq!(
+            act_elements.mean_anomaly.base,
+            exp_elements.mean_anomaly.base
+        );
+
+        let act_tle = act.body.segment.data.tle_parameters.as_ref().unwrap();
+        let exp_tle = omm.body.segment.data.tle_parameters.as_ref().unwrap();
+        assert_eq!(act_tle.ephemeris_type, exp_tle.ephemeris_type);
+        assert_eq!(act_tle.classification_type, exp_tle.classification_type);
+        assert_eq!(act_tle.norad_cat_id, exp_tle.norad_cat_id);
+        assert_eq!(act_tle.element_set_no, exp_tle.element_set_no);
+        assert_eq!(act_tle.rev_at_epoch, exp_tle.rev_at_epoch);
+        assert_eq!(
+            act_tle.bstar.as_ref().map(|b| b.base),
+            exp_tle.bstar.as_ref().map(|b| b.base)
+        );
+        assert_eq!(act_tle.mean_motion_dot.base, exp_tle.mean_motion_dot.base);
+        assert_eq!(
+            act_tle.mean_motion_ddot.as_ref().map(|d| d.base),
+            exp_tle.mean_motion_ddot.as_ref().map(|d| d.base)
+        );
+    }
+
+    #[test]
+    fn test_omm_tle_unsupported_theory() {
+        let mut omm = OmmType::from_xml_str(OMM).unwrap();
+        omm.body.segment.metadata.mean_element_theory = "DSST".to_string();
+        assert_eq!(
+            Tle::try_from(&omm),
+            Err(TleError::UnsupportedMeanElementTheory("DSST".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_omm_tle_missing_mean_motion() {
+        let mut omm = OmmType::from_xml_str(OMM).unwrap();
+        omm.body.segment.data.mean_elements.mean_motion = None;
+        assert_eq!(
+            Tle::try_from(&omm),
+            Err(TleError::MissingField("MEAN_MOTION"))
+        );
+    }
+
+    #[test]
+    fn test_parse_epoch() {
+        let (year, day) = parse_epoch("2004-281T17:26:06").unwrap();
+        assert_eq!(year, 2004);
+        assert_float_eq!(
+            day,
+            281.0 + (17.0 * 3600.0 + 26.0 * 60.0 + 6.0) / 86400.0,
+            abs <= 1e-12
+        );
+        let (year, day) = parse_epoch("2024-03-01T00:00:00").unwrap();
+        assert_eq!(year, 2024);
+        assert_eq!(day, 61.0);
+        assert_eq!(parse_epoch("2024-366T00:00:00").unwrap(), (2024, 366.0));
+        assert_eq!(parse_epoch("2024-02-29T00:00:00").unwrap(), (2024, 60.0));
+        assert!(parse_epoch("2024-13-01T00:00:00").is_err());
+        assert!(parse_epoch("2024-03-01 00:00:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_epoch_invalid_dates() {
+        for epoch in [
+            "2024-01-00T00:00:00",
+            "2024-02-31T00:00:00",
+            "2023-02-29T00:00:00",
+            "2024-04-31T00:00:00",
+            "2024-000T00:00:00",
+            "2023-366T00:00:00",
+            "2024-367T00:00:00",
+        ] {
+            assert_eq!(
+                parse_epoch(epoch),
+                Err(TleError::InvalidEpoch(epoch.to_string())),
+                "{epoch}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_omm_tle_negative_norad_cat_id() {
+        let mut omm = OmmType::from_xml_str(OMM).unwrap();
+        let tle_parameters = omm.body.segment.data.tle_parameters.as_mut().unwrap();
+        tle_parameters.norad_cat_id = Some(-1);
+        assert_eq!(Tle::try_from(&omm), Err(TleError::InvalidNoradCatId(-1)));
+    }
+
+    #[test]
+    fn test_format_epoch() {
+        assert_eq!(format_epoch(2024, 61.0), "2024-03-01T00:00:00.000000");
+        assert_eq!(format_epoch(2023, 365.5), "2023-12-31T12:00:00.000000");
+    }
+}
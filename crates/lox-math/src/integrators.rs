@@ -0,0 +1,573 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runge-Kutta integrators for systems of first-order ordinary differential equations
+//! `dy/dt = f(t, y)`.
+
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum IntegrationError {
+    #[error("step size must be positive and finite but was {0}")]
+    InvalidStepSize(f64),
+    #[error("step size fell below the minimum of {min_step} at t = {t}")]
+    MinimumStepSize { t: f64, min_step: f64 },
+    #[error("maximum number of {0} steps exceeded")]
+    MaxStepsExceeded(usize),
+    #[error("derivative is not finite at t = {0}")]
+    NonFiniteDerivative(f64),
+}
+
+/// An accepted integration step with the state and its derivative.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub t: f64,
+    pub y: Vec<f64>,
+    pub dy: Vec<f64>,
+}
+
+/// The accepted steps of an integration from the initial to the final time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution {
+    steps: Vec<Step>,
+}
+
+impl Solution {
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn initial(&self) -> &Step {
+        self.steps.first().unwrap()
+    }
+
+    pub fn last(&self) -> &Step {
+        self.steps.last().unwrap()
+    }
+
+    /// Returns the state at `t` by cubic Hermite interpolation between the enclosing steps, or
+    /// `None` if `t` is outside of the integration span.
+    pub fn interpolate(&self, t: f64) -> Option<Vec<f64>> {
+        let t0 = self.initial().t;
+        let t1 = self.last().t;
+        if !(t0.min(t1)..=t0.max(t1)).contains(&t) {
+            return None;
+        }
+        let direction = (t1 - t0).signum();
+        let i = self
+            .steps
+            .partition_point(|step| direction * (step.t - t) < 0.0)
+            .clamp(1, self.steps.len().max(2) - 1);
+        let (s0, s1) = match self.steps.get(i) {
+            Some(s1) => (&self.steps[i - 1], s1),
+            None => return Some(self.last().y.clone()),
+        };
+        let h = s1.t - s0.t;
+        let x = (t - s0.t) / h;
+        let h00 = (1.0 + 2.0 * x) * (1.0 - x).powi(2);
+        let h10 = x * (1.0 - x).powi(2);
+        let h01 = x.powi(2) * (3.0 - 2.0 * x);
+        let h11 = x.powi(2) * (x - 1.0);
+        Some(
+            (0..s0.y.len())
+                .map(|j| h00 * s0.y[j] + h10 * h * s0.dy[j] + h01 * s1.y[j] + h11 * h * s1.dy[j])
+                .collect(),
+        )
+    }
+}
+
+fn evaluate<F>(f: &F, t: f64, y: &[f64]) -> Result<Vec<f64>, IntegrationError>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    let dy = f(t, y);
+    if dy.iter().all(|d| d.is_finite()) {
+        Ok(dy)
+    } else {
+        Err(IntegrationError::NonFiniteDerivative(t))
+    }
+}
+
+// Returns `y + h * sum(a_i * k_i)`
+fn stage(y: &[f64], h: f64, terms: &[(f64, &[f64])]) -> Vec<f64> {
+    let mut out = y.to_vec();
+    for &(a, k) in terms {
+        if a == 0.0 {
+            continue;
+        }
+        for (o, k) in out.iter_mut().zip(k) {
+            *o += h * a * k;
+        }
+    }
+    out
+}
+
+/// Integrates `f` from `t0` to `t1` with the classical fourth-order Runge-Kutta method and a fixed
+/// step size `step`. The last step is shortened to end exactly at `t1`. Integration backwards in
+/// time is supported by passing `t1 < t0`.
+pub fn rk4<F>(f: F, t0: f64, y0: &[f64], t1: f64, step: f64) -> Result<Solution, IntegrationError>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    if !(step.is_finite() && step > 0.0) {
+        return Err(IntegrationError::InvalidStepSize(step));
+    }
+    let direction = if t1 < t0 { -1.0 } else { 1.0 };
+    let mut steps = vec![Step {
+        t: t0,
+        y: y0.to_vec(),
+        dy: evaluate(&f, t0, y0)?,
+    }];
+    let n = ((t1 - t0).abs() / step).ceil() as usize;
+    for i in 1..=n {
+        let Step { t, y, dy: k1 } = steps.last().unwrap();
+        let t_next = if i == n {
+            t1
+        } else {
+            t0 + direction * step * i as f64
+        };
+        let h = t_next - t;
+        let k2 = evaluate(&f, t + h / 2.0, &stage(y, h, &[(0.5, k1)]))?;
+        let k3 = evaluate(&f, t + h / 2.0, &stage(y, h, &[(0.5, &k2)]))?;
+        let k4 = evaluate(&f, t_next, &stage(y, h, &[(1.0, &k3)]))?;
+        let y_next = stage(
+            y,
+            h,
+            &[
+                (1.0 / 6.0, k1),
+                (1.0 / 3.0, &k2),
+                (1.0 / 3.0, &k3),
+                (1.0 / 6.0, &k4),
+            ],
+        );
+        let dy_next = evaluate(&f, t_next, &y_next)?;
+        steps.push(Step {
+            t: t_next,
+            y: y_next,
+            dy: dy_next,
+        });
+    }
+    Ok(Solution { steps })
+}
+
+// Dormand-Prince 5(4) coefficients
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [f64; 1] = [1.0 / 5.0];
+const A3: [f64; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [f64; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [f64; 4] = [
+    19372.0 / 6561.0,
+    -25360.0 / 2187.0,
+    64448.0 / 6561.0,
+    -212.0 / 729.0,
+];
+const A6: [f64; 5] = [
+    9017.0 / 3168.0,
+    -355.0 / 33.0,
+    46732.0 / 5247.0,
+    49.0 / 176.0,
+    -5103.0 / 18656.0,
+];
+const B: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+// Difference between the fifth- and fourth-order weights
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// Adaptive Dormand-Prince 5(4) integrator.
+///
+/// The local error is controlled component-wise with `atol + rtol * |y|`. The step size is
+/// adapted by a PI controller, which avoids the oscillating step sizes of a pure error-based
+/// controller on mildly stiff segments. Integration fails with
+/// [IntegrationError::MinimumStepSize] if the error cannot be controlled with steps larger than
+/// the minimum step size.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rk45 {
+    rtol: f64,
+    atol: f64,
+    initial_step: Option<f64>,
+    min_step: f64,
+    max_step: f64,
+    max_steps: usize,
+}
+
+impl Default for Rk45 {
+    fn default() -> Self {
+        Self {
+            rtol: 1e-6,
+            atol: 1e-9,
+            initial_step: None,
+            min_step: 1e-12,
+            max_step: f64::INFINITY,
+            max_steps: 100_000,
+        }
+    }
+}
+
+const SAFETY: f64 = 0.9;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+const ALPHA: f64 = 0.7 / 5.0;
+const BETA: f64 = 0.4 / 5.0;
+
+impl Rk45 {
+    pub fn with_tolerances(self, rtol: f64, atol: f64) -> Self {
+        Self { rtol, atol, ..self }
+    }
+
+    /// Sets the size of the first step. By default, it is estimated from the derivative at the
+    /// initial state.
+    pub fn with_initial_step(self, initial_step: f64) -> Self {
+        Self {
+            initial_step: Some(initial_step),
+            ..self
+        }
+    }
+
+    pub fn with_min_step(self, min_step: f64) -> Self {
+        Self { min_step, ..self }
+    }
+
+    pub fn with_max_step(self, max_step: f64) -> Self {
+        Self { max_step, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    fn error_norm(&self, y: &[f64], y_next: &[f64], error: &[f64]) -> f64 {
+        let sum: f64 = y
+            .iter()
+            .zip(y_next)
+            .zip(error)
+            .map(|((y, y_next), e)| {
+                let scale = self.atol + self.rtol * y.abs().max(y_next.abs());
+                (e / scale).powi(2)
+            })
+            .sum();
+        (sum / y.len().max(1) as f64).sqrt()
+    }
+
+    // Hairer, Nørsett & Wanner, Solving Ordinary Differential Equations I, Section II.4
+    fn initial_step<F>(&self, f: &F, t0: f64, y0: &[f64], dy0: &[f64]) -> f64
+    where
+        F: Fn(f64, &[f64]) -> Vec<f64>,
+    {
+        let scale: Vec<f64> = y0.iter().map(|y| self.atol + self.rtol * y.abs()).collect();
+        let norm = |v: &[f64]| {
+            let sum: f64 = v.iter().zip(&scale).map(|(v, s)| (v / s).powi(2)).sum();
+            (sum / v.len().max(1) as f64).sqrt()
+        };
+        let d0 = norm(y0);
+        let d1 = norm(dy0);
+        let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+            1e-6
+        } else {
+            0.01 * d0 / d1
+        };
+        let y1 = stage(y0, h0, &[(1.0, dy0)]);
+        let d2 = match evaluate(f, t0 + h0, &y1) {
+            Ok(dy1) => {
+                let diff: Vec<f64> = dy1.iter().zip(dy0).map(|(a, b)| a - b).collect();
+                norm(&diff) / h0
+            }
+            Err(_) => return h0,
+        };
+        let h1 = if d1.max(d2) <= 1e-15 {
+            (h0 * 1e-3).max(1e-6)
+        } else {
+            (0.01 / d1.max(d2)).powf(1.0 / 5.0)
+        };
+        (100.0 * h0).min(h1)
+    }
+
+    /// Integrates `f` from `t0` to `t1` and returns all accepted steps.
+    pub fn integrate<F>(
+        &self,
+        f: F,
+        t0: f64,
+        y0: &[f64],
+        t1: f64,
+    ) -> Result<Solution, IntegrationError>
+    where
+        F: Fn(f64, &[f64]) -> Vec<f64>,
+    {
+        let direction = if t1 < t0 { -1.0 } else { 1.0 };
+        let dy0 = evaluate(&f, t0, y0)?;
+        let mut h = match self.initial_step {
+            Some(h) if !(h.is_finite() && h > 0.0) => {
+                return Err(IntegrationError::InvalidStepSize(h))
+            }
+            Some(h) => h,
+            None => self.initial_step(&f, t0, y0, &dy0),
+        }
+        .clamp(self.min_step, self.max_step);
+
+        let mut steps = vec![Step {
+            t: t0,
+            y: y0.to_vec(),
+            dy: dy0,
+        }];
+        let mut previous_error: f64 = 1e-4;
+        let mut rejected = false;
+        let mut count = 0;
+
+        while direction * (t1 - steps.last().unwrap().t) > 0.0 {
+            if count >= self.max_steps {
+                return Err(IntegrationError::MaxStepsExceeded(self.max_steps));
+            }
+            count += 1;
+
+            let Step { t, y, dy: k1 } = steps.last().unwrap();
+            let t = *t;
+            let remaining = (t1 - t).abs();
+            let last = h >= remaining;
+            let h_signed = direction * h.min(remaining);
+
+            let k2 = evaluate(&f, t + C[1] * h_signed, &stage(y, h_signed, &[(A2[0], k1)]))?;
+            let k3 = evaluate(
+                &f,
+                t + C[2] * h_signed,
+                &stage(y, h_signed, &[(A3[0], k1), (A3[1], &k2)]),
+            )?;
+            let k4 = evaluate(
+                &f,
+                t + C[3] * h_signed,
+                &stage(y, h_signed, &[(A4[0], k1), (A4[1], &k2), (A4[2], &k3)]),
+            )?;
+            let k5 = evaluate(
+                &f,
+                t + C[4] * h_signed,
+                &stage(
+                    y,
+                    h_signed,
+                    &[(A5[0], k1), (A5[1], &k2), (A5[2], &k3), (A5[3], &k4)],
+                ),
+            )?;
+            let k6 = evaluate(
+                &f,
+                t + C[5] * h_signed,
+                &stage(
+                    y,
+                    h_signed,
+                    &[
+                        (A6[0], k1),
+                        (A6[1], &k2),
+                        (A6[2], &k3),
+                        (A6[3], &k4),
+                        (A6[4], &k5),
+                    ],
+                ),
+            )?;
+            let y_next = stage(
+                y,
+                h_signed,
+                &[
+                    (B[0], k1),
+                    (B[2], &k3),
+                    (B[3], &k4),
+                    (B[4], &k5),
+                    (B[5], &k6),
+                ],
+            );
+            let t_next = if last { t1 } else { t + h_signed };
+            // First same as last: the derivative at the new state is the first stage of the next
+            // step
+            let k7 = evaluate(&f, t_next, &y_next)?;
+
+            let error: Vec<f64> = (0..y.len())
+                .map(|i| {
+                    h_signed
+                        * (E[0] * k1[i]
+                            + E[2] * k3[i]
+                            + E[3] * k4[i]
+                            + E[4] * k5[i]
+                            + E[5] * k6[i]
+                            + E[6] * k7[i])
+                })
+                .collect();
+            let error = self.error_norm(y, &y_next, &error);
+
+            if error <= 1.0 {
+                let factor = if error == 0.0 {
+                    MAX_FACTOR
+                } else {
+                    SAFETY * error.powf(-ALPHA) * previous_error.powf(BETA)
+                };
+                let factor = if rejected {
+                    factor.clamp(MIN_FACTOR, 1.0)
+                } else {
+                    factor.clamp(MIN_FACTOR, MAX_FACTOR)
+                };
+                previous_error = error.max(1e-4);
+                rejected = false;
+                steps.push(Step {
+                    t: t_next,
+                    y: y_next,
+                    dy: k7,
+                });
+                h = (h * factor).min(self.max_step);
+            } else {
+                let factor = (SAFETY * error.powf(-1.0 / 5.0)).max(MIN_FACTOR);
+                rejected = true;
+                h *= factor;
+            }
+
+            if h < self.min_step {
+                if direction * (t1 - steps.last().unwrap().t) <= 0.0 {
+                    break;
+                }
+                return Err(IntegrationError::MinimumStepSize {
+                    t: steps.last().unwrap().t,
+                    min_step: self.min_step,
+                });
+            }
+        }
+
+        Ok(Solution { steps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use std::f64::consts::TAU;
+
+    use super::*;
+
+    fn oscillator(_t: f64, y: &[f64]) -> Vec<f64> {
+        vec![y[1], -y[0]]
+    }
+
+    #[test]
+    fn test_rk4_harmonic_oscillator() {
+        let solution = rk4(oscillator, 0.0, &[1.0, 0.0], TAU, 0.01).unwrap();
+        let last = solution.last();
+        assert_eq!(last.t, TAU);
+        assert_eq!(solution.steps().len(), 630);
+        assert_float_eq!(last.y[0], 1.0, abs <= 1e-9);
+        assert_float_eq!(last.y[1], 0.0, abs <= 1e-9);
+    }
+
+    #[test]
+    fn test_rk4_order() {
+        // Halving the step size reduces the global error by 2^4
+        let error = |step: f64| {
+            let solution = rk4(|_, y| vec![y[0]], 0.0, &[1.0], 1.0, step).unwrap();
+            (solution.last().y[0] - 1f64.exp()).abs()
+        };
+        let ratio = error(0.1) / error(0.05);
+        assert_float_eq!(ratio, 16.0, abs <= 1.0);
+    }
+
+    #[test]
+    fn test_rk4_backwards() {
+        let solution = rk4(|_, y| vec![y[0]], 1.0, &[1f64.exp()], 0.0, 0.01).unwrap();
+        assert_eq!(solution.last().t, 0.0);
+        assert_float_eq!(solution.last().y[0], 1.0, abs <= 1e-9);
+    }
+
+    #[test]
+    fn test_rk4_invalid_step() {
+        assert_eq!(
+            rk4(oscillator, 0.0, &[1.0, 0.0], 1.0, 0.0),
+            Err(IntegrationError::InvalidStepSize(0.0))
+        );
+    }
+
+    #[test]
+    fn test_rk45_harmonic_oscillator() {
+        let rk45 = Rk45::default().with_tolerances(1e-10, 1e-12);
+        let solution = rk45
+            .integrate(oscillator, 0.0, &[1.0, 0.0], 10.0 * TAU)
+            .unwrap();
+        let last = solution.last();
+        assert_eq!(last.t, 10.0 * TAU);
+        assert_float_eq!(last.y[0], 1.0, abs <= 1e-8);
+        assert_float_eq!(last.y[1], 0.0, abs <= 1e-8);
+
+        // Dense output between the accepted steps
+        for t in [0.1, 1.0, 12.3, 50.0] {
+            let y = solution.interpolate(t).unwrap();
+            assert_float_eq!(y[0], t.cos(), abs <= 1e-6);
+            assert_float_eq!(y[1], -t.sin(), abs <= 1e-6);
+        }
+        assert_eq!(solution.interpolate(-1.0), None);
+        assert_eq!(solution.interpolate(100.0), None);
+    }
+
+    #[test]
+    fn test_rk45_tolerance() {
+        let error = |tolerance: f64| {
+            let rk45 = Rk45::default().with_tolerances(tolerance, tolerance);
+            let solution = rk45.integrate(oscillator, 0.0, &[1.0, 0.0], TAU).unwrap();
+            ((solution.last().y[0] - 1.0).abs(), solution.steps().len())
+        };
+        let (coarse_error, coarse_steps) = error(1e-5);
+        let (fine_error, fine_steps) = error(1e-10);
+        assert!(fine_error < coarse_error);
+        assert!(fine_error < 1e-8);
+        assert!(fine_steps > coarse_steps);
+    }
+
+    #[test]
+    fn test_rk45_backwards() {
+        let rk45 = Rk45::default().with_tolerances(1e-10, 1e-12);
+        let solution = rk45
+            .integrate(|_, y| vec![y[0]], 1.0, &[1f64.exp()], 0.0)
+            .unwrap();
+        assert_eq!(solution.last().t, 0.0);
+        assert_float_eq!(solution.last().y[0], 1.0, abs <= 1e-9);
+        let y = solution.interpolate(0.5).unwrap();
+        assert_float_eq!(y[0], 0.5f64.exp(), abs <= 1e-6);
+    }
+
+    #[test]
+    fn test_rk45_stiff() {
+        // A fast transient decaying onto a slowly varying solution
+        let f = |t: f64, y: &[f64]| vec![-50.0 * (y[0] - t.cos())];
+        let solution = Rk45::default().integrate(f, 0.0, &[0.0], 10.0).unwrap();
+        let exp = 2500.0 / 2501.0 * 10f64.cos() + 50.0 / 2501.0 * 10f64.sin();
+        assert_float_eq!(solution.last().y[0], exp, abs <= 1e-5);
+    }
+
+    #[test]
+    fn test_rk45_minimum_step() {
+        // The solution of y' = y^2 with y(0) = 1 has a singularity at t = 1
+        let rk45 = Rk45::default().with_min_step(1e-6);
+        let result = rk45.integrate(|_, y| vec![y[0].powi(2)], 0.0, &[1.0], 2.0);
+        let Err(IntegrationError::MinimumStepSize { t, min_step }) = result else {
+            panic!("expected a minimum step size error but got {:?}", result)
+        };
+        assert_eq!(min_step, 1e-6);
+        assert!(t < 1.0 && t > 0.99);
+    }
+
+    #[test]
+    fn test_rk45_max_steps() {
+        let rk45 = Rk45::default().with_max_steps(10);
+        assert_eq!(
+            rk45.integrate(oscillator, 0.0, &[1.0, 0.0], 100.0),
+            Err(IntegrationError::MaxStepsExceeded(10))
+        );
+    }
+}
@@ -0,0 +1,360 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use glam::DVec3;
+use thiserror::Error;
+
+use crate::series::SeriesError;
+use crate::vector_traits::Diff;
+
+const MIN_POINTS_HERMITE: usize = 2;
+const MIN_POINTS_LAGRANGE: usize = 2;
+
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("`{x}` is outside of the interpolation range [{start}, {end}]")]
+pub struct OutOfRangeError {
+    pub x: f64,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Hermite interpolation of position and velocity samples.
+///
+/// By default, a cubic Hermite polynomial is constructed from the two samples bracketing the query
+/// time. Higher degrees, e.g. those declared by CCSDS OEM files, use the `(degree + 1) / 2`
+/// samples closest to the query time. Since the interpolant reproduces the sampled positions and
+/// velocities exactly, it is continuously differentiable at the sample points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HermiteInterpolator {
+    times: Vec<f64>,
+    positions: Vec<DVec3>,
+    velocities: Vec<DVec3>,
+    points: usize,
+}
+
+impl HermiteInterpolator {
+    pub fn new(
+        times: Vec<f64>,
+        positions: Vec<DVec3>,
+        velocities: Vec<DVec3>,
+    ) -> Result<Self, SeriesError> {
+        if !times.diff().iter().all(|&d| d > 0.0) {
+            return Err(SeriesError::NonMonotonic);
+        }
+
+        let n = times.len();
+
+        if positions.len() != n {
+            return Err(SeriesError::DimensionMismatch(n, positions.len()));
+        }
+
+        if velocities.len() != n {
+            return Err(SeriesError::DimensionMismatch(n, velocities.len()));
+        }
+
+        if n < MIN_POINTS_HERMITE {
+            return Err(SeriesError::InsufficientPoints(n, MIN_POINTS_HERMITE));
+        }
+
+        Ok(Self {
+            times,
+            positions,
+            velocities,
+            points: MIN_POINTS_HERMITE,
+        })
+    }
+
+    /// Sets the degree of the interpolating polynomial.
+    ///
+    /// Hermite polynomials built from positions and velocities are of odd degree, so even degrees
+    /// are rounded up. The degree is limited by the number of available samples.
+    pub fn with_degree(mut self, degree: usize) -> Self {
+        self.points = (degree + 1)
+            .div_ceil(2)
+            .clamp(MIN_POINTS_HERMITE, self.times.len());
+        self
+    }
+
+    /// The effective degree of the interpolating polynomial.
+    pub fn degree(&self) -> usize {
+        2 * self.points - 1
+    }
+
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the interpolated position and velocity at `t`.
+    pub fn interpolate(&self, t: f64) -> Result<(DVec3, DVec3), OutOfRangeError> {
+        let first = window(&self.times, t, self.points)?;
+        let range = first..first + self.points;
+
+        let origin = self.times[first];
+        let nodes: Vec<f64> = self.times[range.clone()]
+            .iter()
+            .flat_map(|&x| [x - origin; 2])
+            .collect();
+        let mut coefficients: Vec<DVec3> = self.positions[range.clone()]
+            .iter()
+            .flat_map(|&p| [p; 2])
+            .collect();
+
+        // Divided differences with each node repeated twice, where the first-order differences
+        // at repeated nodes are the velocities
+        let m = nodes.len();
+        for order in 1..m {
+            for j in (order..m).rev() {
+                coefficients[j] = if order == 1 && j % 2 == 1 {
+                    self.velocities[first + j / 2]
+                } else {
+                    (coefficients[j] - coefficients[j - 1]) / (nodes[j] - nodes[j - order])
+                };
+            }
+        }
+
+        // Evaluate the Newton form and its derivative by Horner's scheme
+        let x = t - origin;
+        let mut position = coefficients[m - 1];
+        let mut velocity = DVec3::ZERO;
+        for k in (0..m - 1).rev() {
+            velocity = velocity * (x - nodes[k]) + position;
+            position = position * (x - nodes[k]) + coefficients[k];
+        }
+        Ok((position, velocity))
+    }
+}
+
+/// Lagrange interpolation of position and velocity samples.
+///
+/// Positions and velocities are interpolated independently with a Lagrange polynomial of the
+/// given degree through the `degree + 1` samples closest to the query time. The default degree is
+/// one, i.e. linear interpolation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LagrangeInterpolator {
+    times: Vec<f64>,
+    positions: Vec<DVec3>,
+    velocities: Vec<DVec3>,
+    points: usize,
+}
+
+impl LagrangeInterpolator {
+    pub fn new(
+        times: Vec<f64>,
+        positions: Vec<DVec3>,
+        velocities: Vec<DVec3>,
+    ) -> Result<Self, SeriesError> {
+        if !times.diff().iter().all(|&d| d > 0.0) {
+            return Err(SeriesError::NonMonotonic);
+        }
+
+        let n = times.len();
+
+        if positions.len() != n {
+            return Err(SeriesError::DimensionMismatch(n, positions.len()));
+        }
+
+        if velocities.len() != n {
+            return Err(SeriesError::DimensionMismatch(n, velocities.len()));
+        }
+
+        if n < MIN_POINTS_LAGRANGE {
+            return Err(SeriesError::InsufficientPoints(n, MIN_POINTS_LAGRANGE));
+        }
+
+        Ok(Self {
+            times,
+            positions,
+            velocities,
+            points: MIN_POINTS_LAGRANGE,
+        })
+    }
+
+    /// Sets the degree of the interpolating polynomial which is limited by the number of
+    /// available samples.
+    pub fn with_degree(mut self, degree: usize) -> Self {
+        self.points = (degree + 1).clamp(MIN_POINTS_LAGRANGE, self.times.len());
+        self
+    }
+
+    /// The effective degree of the interpolating polynomial.
+    pub fn degree(&self) -> usize {
+        self.points - 1
+    }
+
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the interpolated position and velocity at `t`.
+    pub fn interpolate(&self, t: f64) -> Result<(DVec3, DVec3), OutOfRangeError> {
+        let first = window(&self.times, t, self.points)?;
+        let range = first..first + self.points;
+        let nodes = &self.times[range.clone()];
+        let mut position = DVec3::ZERO;
+        let mut velocity = DVec3::ZERO;
+        for (i, &xi) in nodes.iter().enumerate() {
+            let weight: f64 = nodes
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &xj)| (t - xj) / (xi - xj))
+                .product();
+            position += weight * self.positions[first + i];
+            velocity += weight * self.velocities[first + i];
+        }
+        Ok((position, velocity))
+    }
+}
+
+/// Returns the index of the first of the `points` samples which are closest to `t`, centred on
+/// the interval bracketing `t`.
+fn window(times: &[f64], t: f64, points: usize) -> Result<usize, OutOfRangeError> {
+    let n = times.len();
+    let (start, end) = (times[0], times[n - 1]);
+    if !(start..=end).contains(&t) {
+        return Err(OutOfRangeError { x: t, start, end });
+    }
+    // Index of the sample at or before `t`
+    let idx = times.partition_point(|&x| x <= t).saturating_sub(1);
+    Ok((idx + 1).saturating_sub(points / 2).min(n - points))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::*;
+
+    /// Circular motion with unit radius and angular velocity.
+    fn samples(times: &[f64]) -> HermiteInterpolator {
+        let positions = times
+            .iter()
+            .map(|t| DVec3::new(t.cos(), t.sin(), 0.0))
+            .collect();
+        let velocities = times
+            .iter()
+            .map(|t| DVec3::new(-t.sin(), t.cos(), 0.0))
+            .collect();
+        HermiteInterpolator::new(times.to_vec(), positions, velocities).unwrap()
+    }
+
+    #[test]
+    fn test_hermite_cubic() {
+        let times: Vec<f64> = (0..=20).map(|i| 0.1 * i as f64).collect();
+        let hermite = samples(&times);
+        assert_eq!(hermite.degree(), 3);
+        for t in [0.0, 0.05, 0.512, 1.337, 2.0] {
+            let (position, velocity) = hermite.interpolate(t).unwrap();
+            assert_float_eq!(position.x, t.cos(), abs <= 1e-5);
+            assert_float_eq!(position.y, t.sin(), abs <= 1e-5);
+            assert_float_eq!(velocity.x, -t.sin(), abs <= 1e-3);
+            assert_float_eq!(velocity.y, t.cos(), abs <= 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_hermite_higher_degree() {
+        let times: Vec<f64> = (0..=20).map(|i| 0.1 * i as f64).collect();
+        let hermite = samples(&times).with_degree(7);
+        assert_eq!(hermite.degree(), 7);
+        for t in [0.0, 0.05, 0.512, 1.337, 1.95, 2.0] {
+            let (position, velocity) = hermite.interpolate(t).unwrap();
+            assert_float_eq!(position.x, t.cos(), abs <= 1e-11);
+            assert_float_eq!(position.y, t.sin(), abs <= 1e-11);
+            assert_float_eq!(velocity.x, -t.sin(), abs <= 1e-9);
+            assert_float_eq!(velocity.y, t.cos(), abs <= 1e-9);
+        }
+        assert_eq!(samples(&times[..3]).with_degree(7).degree(), 5);
+        assert_eq!(samples(&times).with_degree(4).degree(), 5);
+    }
+
+    #[test]
+    fn test_hermite_c1_continuity() {
+        let times = [0.0, 0.3, 0.7, 1.2, 1.5];
+        for degree in [3, 5] {
+            let hermite = samples(&times).with_degree(degree);
+            for &t in &times[1..times.len() - 1] {
+                let eps = 1e-9;
+                let (p0, v0) = hermite.interpolate(t - eps).unwrap();
+                let (p1, v1) = hermite.interpolate(t + eps).unwrap();
+                let (p, v) = hermite.interpolate(t).unwrap();
+                assert_float_eq!(p.x, t.cos(), abs <= 1e-15);
+                assert_float_eq!(v.y, t.cos(), abs <= 1e-15);
+                assert!((p1 - p0).length() < 1e-8);
+                assert!((v1 - v0).length() < 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermite_out_of_range() {
+        let hermite = samples(&[0.0, 1.0, 2.0]);
+        assert_eq!(
+            hermite.interpolate(2.5),
+            Err(OutOfRangeError {
+                x: 2.5,
+                start: 0.0,
+                end: 2.0
+            })
+        );
+        assert!(hermite.interpolate(-1e-12).is_err());
+    }
+
+    #[test]
+    fn test_hermite_errors() {
+        let p = vec![DVec3::ZERO; 2];
+        assert_eq!(
+            HermiteInterpolator::new(vec![0.0], vec![DVec3::ZERO], vec![DVec3::ZERO]),
+            Err(SeriesError::InsufficientPoints(1, 2))
+        );
+        assert_eq!(
+            HermiteInterpolator::new(vec![0.0, 1.0], p.clone(), vec![DVec3::ZERO]),
+            Err(SeriesError::DimensionMismatch(2, 1))
+        );
+        assert_eq!(
+            HermiteInterpolator::new(vec![1.0, 0.0], p.clone(), p),
+            Err(SeriesError::NonMonotonic)
+        );
+    }
+
+    fn lagrange_samples(times: &[f64]) -> LagrangeInterpolator {
+        let positions = times
+            .iter()
+            .map(|t| DVec3::new(t.cos(), t.sin(), 0.0))
+            .collect();
+        let velocities = times
+            .iter()
+            .map(|t| DVec3::new(-t.sin(), t.cos(), 0.0))
+            .collect();
+        LagrangeInterpolator::new(times.to_vec(), positions, velocities).unwrap()
+    }
+
+    #[test]
+    fn test_lagrange() {
+        let times: Vec<f64> = (0..=20).map(|i| 0.1 * i as f64).collect();
+        let linear = lagrange_samples(&times);
+        assert_eq!(linear.degree(), 1);
+        let (position, _) = linear.interpolate(0.05).unwrap();
+        assert_float_eq!(position.x, (1.0 + 0.1f64.cos()) / 2.0, abs <= 1e-15);
+
+        let lagrange = lagrange_samples(&times).with_degree(8);
+        assert_eq!(lagrange.degree(), 8);
+        for t in [0.0, 0.05, 0.512, 1.337, 1.95, 2.0] {
+            let (position, velocity) = lagrange.interpolate(t).unwrap();
+            assert_float_eq!(position.x, t.cos(), abs <= 1e-10);
+            assert_float_eq!(position.y, t.sin(), abs <= 1e-10);
+            assert_float_eq!(velocity.x, -t.sin(), abs <= 1e-10);
+            assert_float_eq!(velocity.y, t.cos(), abs <= 1e-10);
+        }
+        for &t in &times {
+            let (position, _) = lagrange.interpolate(t).unwrap();
+            assert_float_eq!(position.x, t.cos(), abs <= 1e-15);
+        }
+        assert!(lagrange.interpolate(2.1).is_err());
+    }
+}
@@ -8,11 +8,14 @@
 
 pub mod constants;
 pub mod glam;
+pub mod integrators;
+pub mod interpolation;
 pub mod is_close;
 pub mod linear_algebra;
 pub mod math;
 #[cfg(feature = "python")]
 pub mod python;
+pub mod quaternions;
 pub mod roots;
 pub mod series;
 pub mod slices;
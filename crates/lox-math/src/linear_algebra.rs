@@ -5,4 +5,6 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
+pub mod dmat6;
+pub mod lu;
 pub mod tridiagonal;
@@ -0,0 +1,211 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Index, IndexMut, Mul};
+
+use glam::DMat3;
+
+use crate::linear_algebra::lu::{LinAlgError, Lu};
+
+type Idx = (usize, usize);
+
+/// A 6x6 matrix, e.g. a position-velocity covariance or a state transformation, which is stored
+/// in row-major order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DMat6([[f64; 6]; 6]);
+
+impl DMat6 {
+    pub const ZERO: Self = Self([[0.0; 6]; 6]);
+
+    pub const IDENTITY: Self = {
+        let mut m = [[0.0; 6]; 6];
+        let mut i = 0;
+        while i < 6 {
+            m[i][i] = 1.0;
+            i += 1;
+        }
+        Self(m)
+    };
+
+    pub fn from_rows(rows: [[f64; 6]; 6]) -> Self {
+        Self(rows)
+    }
+
+    /// Creates a matrix from the 3x3 blocks `[[a, b], [c, d]]`.
+    pub fn from_blocks(a: DMat3, b: DMat3, c: DMat3, d: DMat3) -> Self {
+        let mut m = Self::ZERO;
+        for (block, (i0, j0)) in [(a, (0, 0)), (b, (0, 3)), (c, (3, 0)), (d, (3, 3))] {
+            for i in 0..3 {
+                for j in 0..3 {
+                    m.0[i0 + i][j0 + j] = block.col(j)[i];
+                }
+            }
+        }
+        m
+    }
+
+    pub fn to_rows(&self) -> [[f64; 6]; 6] {
+        self.0
+    }
+
+    /// Returns the 3x3 block starting at row `3 * i` and column `3 * j`.
+    pub fn block(&self, i: usize, j: usize) -> DMat3 {
+        let (i0, j0) = (3 * i, 3 * j);
+        DMat3::from_cols_array_2d(&[
+            [self.0[i0][j0], self.0[i0 + 1][j0], self.0[i0 + 2][j0]],
+            [
+                self.0[i0][j0 + 1],
+                self.0[i0 + 1][j0 + 1],
+                self.0[i0 + 2][j0 + 1],
+            ],
+            [
+                self.0[i0][j0 + 2],
+                self.0[i0 + 1][j0 + 2],
+                self.0[i0 + 2][j0 + 2],
+            ],
+        ])
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut m = Self::ZERO;
+        for i in 0..6 {
+            for j in 0..6 {
+                m.0[j][i] = self.0[i][j];
+            }
+        }
+        m
+    }
+
+    /// Returns the inverse of the matrix, see [Lu].
+    ///
+    /// # Errors
+    ///
+    /// Returns [LinAlgError::Singular] if the matrix is rank-deficient.
+    pub fn inverse(&self) -> Result<Self, LinAlgError> {
+        Ok(Self(Lu::new(&self.0)?.inverse()))
+    }
+
+    /// Solves `Mx = b` for `x`, see [Lu].
+    ///
+    /// # Errors
+    ///
+    /// Returns [LinAlgError::Singular] if the matrix is rank-deficient.
+    pub fn solve(&self, b: &[f64; 6]) -> Result<[f64; 6], LinAlgError> {
+        Ok(Lu::new(&self.0)?.solve(b))
+    }
+
+    /// Returns the largest absolute difference between the elements of both matrices.
+    pub fn max_abs_diff(&self, other: &Self) -> f64 {
+        self.0
+            .iter()
+            .flatten()
+            .zip(other.0.iter().flatten())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Default for DMat6 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for DMat6 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut m = Self::ZERO;
+        for i in 0..6 {
+            for j in 0..6 {
+                m.0[i][j] = (0..6).map(|k| self.0[i][k] * rhs.0[k][j]).sum();
+            }
+        }
+        m
+    }
+}
+
+impl Index<Idx> for DMat6 {
+    type Output = f64;
+
+    fn index(&self, (i, j): Idx) -> &Self::Output {
+        &self.0[i][j]
+    }
+}
+
+impl IndexMut<Idx> for DMat6 {
+    fn index_mut(&mut self, (i, j): Idx) -> &mut Self::Output {
+        &mut self.0[i][j]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> DMat6 {
+        let mut rows = [[0.0; 6]; 6];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (6 * i + j) as f64;
+            }
+        }
+        DMat6::from_rows(rows)
+    }
+
+    #[test]
+    fn test_dmat6_blocks() {
+        let a = DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let m = DMat6::from_blocks(a, 2.0 * a, 3.0 * a, 4.0 * a);
+        // Column-major input
+        assert_eq!(m[(0, 1)], 4.0);
+        assert_eq!(m[(1, 0)], 2.0);
+        assert_eq!(m[(4, 3)], 8.0);
+        assert_eq!(m.block(0, 0), a);
+        assert_eq!(m.block(0, 1), 2.0 * a);
+        assert_eq!(m.block(1, 0), 3.0 * a);
+        assert_eq!(m.block(1, 1), 4.0 * a);
+    }
+
+    #[test]
+    fn test_dmat6_mul() {
+        let m = matrix();
+        assert_eq!(m * DMat6::IDENTITY, m);
+        assert_eq!(DMat6::IDENTITY * m, m);
+        assert_eq!(m * DMat6::ZERO, DMat6::ZERO);
+        let mm = m * m;
+        let exp: f64 = (0..6)
+            .map(|k| (6 * 2 + k) as f64 * (6 * k + 3) as f64)
+            .sum();
+        assert_eq!(mm[(2, 3)], exp);
+    }
+
+    #[test]
+    fn test_dmat6_inverse() {
+        let a = DMat3::from_cols_array(&[4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0]);
+        let m = DMat6::from_blocks(a, 0.1 * a, 0.1 * a, a);
+        let inverse = m.inverse().unwrap();
+        assert!((m * inverse).max_abs_diff(&DMat6::IDENTITY) < 1e-14);
+        let x = [1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+        let b: [f64; 6] = std::array::from_fn(|i| (0..6).map(|j| m[(i, j)] * x[j]).sum());
+        let actual = m.solve(&b).unwrap();
+        for (act, exp) in actual.iter().zip(x) {
+            assert!((act - exp).abs() < 1e-13);
+        }
+        assert_eq!(matrix().inverse(), Err(LinAlgError::Singular));
+    }
+
+    #[test]
+    fn test_dmat6_transpose() {
+        let m = matrix();
+        assert_eq!(m.transpose()[(1, 4)], m[(4, 1)]);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.max_abs_diff(&m), 0.0);
+        assert_eq!(m.max_abs_diff(&m.transpose()), 25.0);
+    }
+}
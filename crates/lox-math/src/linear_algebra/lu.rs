@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module `lu` provides the LU decomposition of small, dense square matrices stored in row-major
+//! order, e.g. for solving linear systems and inverting covariance matrices.
+
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+pub enum LinAlgError {
+    #[error("matrix is singular to working precision")]
+    Singular,
+}
+
+/// The LU decomposition `PA = LU` of an `N`x`N` matrix `A` with partial pivoting.
+///
+/// In each column the row with the largest absolute value is chosen as the pivot, which keeps the
+/// multipliers of `L` bounded by one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<const N: usize> {
+    // Unit lower triangular `L` below and `U` on and above the diagonal
+    lu: [[f64; N]; N],
+    permutation: [usize; N],
+    sign: f64,
+}
+
+impl<const N: usize> Lu<N> {
+    /// Decomposes the row-major matrix `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [LinAlgError::Singular] if `a` is rank-deficient, i.e. if a pivot is zero relative
+    /// to the largest element of `a`.
+    pub fn new(a: &[[f64; N]; N]) -> Result<Self, LinAlgError> {
+        let mut lu = *a;
+        let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+        let mut sign = 1.0;
+        let scale = a.iter().flatten().fold(0.0, |max: f64, v| max.max(v.abs()));
+        let tolerance = scale * N as f64 * f64::EPSILON;
+        for k in 0..N {
+            let pivot_row = (k..N)
+                .max_by(|&i, &j| lu[i][k].abs().total_cmp(&lu[j][k].abs()))
+                .unwrap();
+            let pivot = lu[pivot_row][k];
+            // Also rejects matrices containing NaNs
+            if pivot.is_nan() || pivot.abs() <= tolerance {
+                return Err(LinAlgError::Singular);
+            }
+            if pivot_row != k {
+                lu.swap(pivot_row, k);
+                permutation.swap(pivot_row, k);
+                sign = -sign;
+            }
+            let (upper, lower) = lu.split_at_mut(k + 1);
+            let pivot_values = &upper[k];
+            for row in lower {
+                let factor = row[k] / pivot;
+                row[k] = factor;
+                for (value, &p) in row.iter_mut().zip(pivot_values).skip(k + 1) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        Ok(Self {
+            lu,
+            permutation,
+            sign,
+        })
+    }
+
+    /// Solves `Ax = b` for `x`.
+    pub fn solve(&self, b: &[f64; N]) -> [f64; N] {
+        let mut x: [f64; N] = std::array::from_fn(|i| b[self.permutation[i]]);
+        // Forward substitution with the unit lower triangular `L`
+        for i in 0..N {
+            for j in 0..i {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+        }
+        // Back substitution with `U`
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+
+    /// Returns the inverse of `A` in row-major order.
+    pub fn inverse(&self) -> [[f64; N]; N] {
+        let mut inverse = [[0.0; N]; N];
+        for j in 0..N {
+            let mut e = [0.0; N];
+            e[j] = 1.0;
+            let column = self.solve(&e);
+            for (row, value) in inverse.iter_mut().zip(column) {
+                row[j] = value;
+            }
+        }
+        inverse
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.sign * (0..N).map(|i| self.lu[i][i]).product::<f64>()
+    }
+}
+
+/// Solves the linear system `Ax = b` for the row-major matrix `a` via [Lu].
+///
+/// # Errors
+///
+/// Returns [LinAlgError::Singular] if `a` is rank-deficient.
+pub fn solve<const N: usize>(a: &[[f64; N]; N], b: &[f64; N]) -> Result<[f64; N], LinAlgError> {
+    Ok(Lu::new(a)?.solve(b))
+}
+
+/// Inverts the row-major matrix `a` via [Lu].
+///
+/// # Errors
+///
+/// Returns [LinAlgError::Singular] if `a` is rank-deficient.
+pub fn inverse<const N: usize>(a: &[[f64; N]; N]) -> Result<[[f64; N]; N], LinAlgError> {
+    Ok(Lu::new(a)?.inverse())
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use glam::DMat3;
+
+    use super::*;
+
+    fn multiply<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) -> [[f64; N]; N] {
+        std::array::from_fn(|i| std::array::from_fn(|j| (0..N).map(|k| a[i][k] * b[k][j]).sum()))
+    }
+
+    fn assert_identity<const N: usize>(m: &[[f64; N]; N], tolerance: f64) {
+        for (i, row) in m.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_float_eq!(value, expected, abs <= tolerance, "element ({i}, {j})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_3x3() {
+        // Requires pivoting because of the zero in the upper-left corner
+        let a = [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]];
+        let inverse = inverse(&a).unwrap();
+        let expected = DMat3::from_cols_array_2d(&a).transpose().inverse();
+        for (i, row) in inverse.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                assert_float_eq!(value, expected.col(j)[i], abs <= 1e-15);
+            }
+        }
+        assert_identity(&multiply(&a, &inverse), 1e-15);
+        assert_identity(&multiply(&inverse, &a), 1e-15);
+        assert_float_eq!(Lu::new(&a).unwrap().determinant(), -5.0, abs <= 1e-14);
+    }
+
+    #[test]
+    fn test_inverse_6x6() {
+        // A symmetric positive-definite position-velocity covariance in km and km/s
+        let mut a = [[0.0; 6]; 6];
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let scale = if i < 3 && j < 3 {
+                    1.0
+                } else if i >= 3 && j >= 3 {
+                    1e-6
+                } else {
+                    1e-3
+                };
+                *value = scale * 0.5f64.powi((i as i32 - j as i32).abs());
+            }
+            row[i] *= 2.0;
+        }
+        let inverse = inverse(&a).unwrap();
+        assert_identity(&multiply(&a, &inverse), 1e-9);
+        assert_identity(&multiply(&inverse, &a), 1e-9);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = [[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+        let b = [8.0, -11.0, -3.0];
+        let x = solve(&a, &b).unwrap();
+        for (act, exp) in x.iter().zip([2.0, 3.0, -1.0]) {
+            assert_float_eq!(*act, exp, abs <= 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_singular() {
+        let rank_deficient = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+        assert_eq!(inverse(&rank_deficient), Err(LinAlgError::Singular));
+        assert_eq!(
+            solve(&rank_deficient, &[1.0, 2.0, 3.0]),
+            Err(LinAlgError::Singular)
+        );
+        assert_eq!(inverse(&[[0.0; 3]; 3]), Err(LinAlgError::Singular));
+        assert_eq!(
+            inverse(&[[f64::NAN, 0.0], [0.0, 1.0]]),
+            Err(LinAlgError::Singular)
+        );
+        // Nearly singular due to rounding only
+        let a = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(inverse(&a), Err(LinAlgError::Singular));
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::f64::consts::{PI, TAU};
 
-use crate::types::units::{Arcseconds, Radians};
+use crate::types::units::{Arcseconds, Degrees, Radians};
 
 /// Normalizes an angle `a` to the range [center-π, center+π).
 pub fn normalize_two_pi(a: Radians, center: Radians) -> Radians {
@@ -24,16 +24,82 @@ pub fn arcsec_to_rad(arcsec: Arcseconds) -> Radians {
     arcsec * RADIANS_IN_ARCSECOND
 }
 
+/// Converts degrees to radians in the range [0, 2π).
+///
+/// The angle is reduced modulo 360° before the conversion, which is exact, so that multiples of
+/// 360° map to exactly zero.
+#[inline]
+pub fn deg_to_rad_two_pi(deg: Degrees) -> Radians {
+    let deg = deg.rem_euclid(360.0);
+    // Tiny negative inputs round up to 360°
+    if deg == 360.0 {
+        return 0.0;
+    }
+    deg.to_radians()
+}
+
 /// Modulus after division by 2π, returning in the range [0,2π).
+///
+/// Multiples of 2π, such as 2π itself, map to exactly zero. Tiny negative angles, whose sum with
+/// 2π would round to 2π, also map to zero.
 pub fn mod_two_pi(a: f64) -> f64 {
-    let w = a % (2.0 * PI);
-    if w < 0.0 {
-        w + 2.0 * PI
+    let w = a % TAU;
+    if w >= 0.0 {
+        return w;
+    }
+    let w = w + TAU;
+    if w == TAU {
+        0.0
+    } else {
+        w
+    }
+}
+
+/// Wraps an angle `a` to the range [-π, π).
+///
+/// Unlike [normalize_two_pi], the reduction is exact, i.e. multiples of 2π map to exactly zero
+/// and π maps to exactly -π.
+pub fn wrap_pi(a: Radians) -> Radians {
+    // The remainder is exact and, by Sterbenz' lemma, so is the subsequent shift by 2π.
+    let w = a % TAU;
+    if w >= PI {
+        w - TAU
+    } else if w < -PI {
+        w + TAU
     } else {
         w
     }
 }
 
+/// Wraps all angles of a series to the range [center-π, center+π).
+pub fn wrap_angles(angles: &[Radians], center: Radians) -> Vec<Radians> {
+    angles
+        .iter()
+        .map(|&a| normalize_two_pi(a, center))
+        .collect()
+}
+
+/// Unwraps a series of angles by removing the multiples of 2π from all jumps between
+/// consecutive angles that are greater than π, e.g. to obtain a continuous series from angles
+/// that have been wrapped to [0, 2π).
+///
+/// The first angle is returned unchanged. The underlying signal must change by less than π
+/// between consecutive samples for the result to be unambiguous.
+pub fn unwrap_angles(angles: &[Radians]) -> Vec<Radians> {
+    let mut unwrapped = Vec::with_capacity(angles.len());
+    let mut offset = 0.0;
+    for (i, &a) in angles.iter().enumerate() {
+        if i > 0 {
+            let jump = a - angles[i - 1];
+            if jump.abs() > PI {
+                offset -= TAU * (jump / TAU).round();
+            }
+        }
+        unwrapped.push(a + offset);
+    }
+    unwrapped
+}
+
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
@@ -87,4 +153,101 @@ mod tests {
             rel <= TOLERANCE,
         );
     }
+
+    #[test]
+    fn test_wrap_pi() {
+        assert_float_eq!(wrap_pi(PI), -PI, rel <= TOLERANCE);
+        assert_float_eq!(wrap_pi(3.0 * PI / 2.0), -PI / 2.0, rel <= TOLERANCE);
+        assert_float_eq!(wrap_pi(-TAU - 0.5), -0.5, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_wrap_pi_boundaries() {
+        assert_eq!(wrap_pi(TAU), 0.0);
+        assert_eq!(wrap_pi(-TAU), 0.0);
+        assert_eq!(wrap_pi(2.0 * TAU), 0.0);
+        assert_eq!(wrap_pi(-PI), -PI);
+        assert_eq!(wrap_pi(3.0 * PI), -PI);
+        assert_eq!(wrap_pi(-3.0 * PI), -PI);
+        assert_eq!(wrap_pi(-1e-300), -1e-300);
+        for a in [-100.0, -7.0, -PI, -1.0, 0.0, 1.0, PI, 7.0, 100.0] {
+            let w = wrap_pi(a);
+            assert!((-PI..PI).contains(&w), "{a} was wrapped to {w}");
+            assert_float_eq!(w.sin(), a.sin(), abs <= 1e-13);
+            assert_float_eq!(w.cos(), a.cos(), abs <= 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_mod_two_pi() {
+        assert_eq!(mod_two_pi(0.0), 0.0);
+        assert_eq!(mod_two_pi(TAU), 0.0);
+        assert_eq!(mod_two_pi(-TAU), 0.0);
+        assert_eq!(mod_two_pi(3.0 * PI), PI);
+        assert_eq!(mod_two_pi(-PI), PI);
+        assert_eq!(mod_two_pi(-1e-300), 0.0);
+        assert_float_eq!(mod_two_pi(-0.5), TAU - 0.5, rel <= TOLERANCE);
+        for a in [-100.0, -7.0, -1.0, 1.0, 7.0, 100.0] {
+            let w = mod_two_pi(a);
+            assert!((0.0..TAU).contains(&w), "{a} was wrapped to {w}");
+            assert_float_eq!(wrap_pi(w), wrap_pi(a), abs <= 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_deg_to_rad_two_pi() {
+        assert_eq!(deg_to_rad_two_pi(0.0), 0.0);
+        assert_eq!(deg_to_rad_two_pi(360.0), 0.0);
+        assert_eq!(deg_to_rad_two_pi(-720.0), 0.0);
+        assert_eq!(deg_to_rad_two_pi(-1e-300), 0.0);
+        assert_eq!(deg_to_rad_two_pi(180.0), PI);
+        assert_eq!(deg_to_rad_two_pi(-90.0), 270f64.to_radians());
+        assert_eq!(deg_to_rad_two_pi(405.0), 45f64.to_radians());
+        // Degrees survive the round trip through radians
+        for deg in [0.0, 0.1, 45.0, 90.0, 179.999, 247.4627, 359.9] {
+            assert_float_eq!(deg_to_rad_two_pi(deg).to_degrees(), deg, abs <= 1e-12);
+            assert_float_eq!(
+                deg_to_rad_two_pi(deg - 360.0).to_degrees(),
+                deg,
+                abs <= 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrap_angles() {
+        let wrapped = wrap_angles(&[-PI / 2.0, 0.5, TAU + 0.5], PI);
+        assert_float_eq!(wrapped[0], 3.0 * PI / 2.0, rel <= TOLERANCE);
+        assert_float_eq!(wrapped[1], 0.5, rel <= TOLERANCE);
+        assert_float_eq!(wrapped[2], 0.5, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_unwrap_angles_sawtooth() {
+        // A continuous, non-monotonic signal which produces a sawtooth when wrapped to [0, 2π)
+        let signal: Vec<f64> = (0..500)
+            .map(|i| {
+                let t = i as f64 * 0.1;
+                0.8 * t + 2.0 * t.sin()
+            })
+            .collect();
+        let wrapped: Vec<f64> = signal.iter().map(|&a| mod_two_pi(a)).collect();
+        assert!(wrapped.windows(2).any(|w| (w[1] - w[0]).abs() > PI));
+
+        let unwrapped = unwrap_angles(&wrapped);
+        assert_eq!(unwrapped.len(), signal.len());
+        for (act, exp) in unwrapped.iter().zip(signal.iter()) {
+            assert_float_eq!(*act, *exp, abs <= 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_angles_descending() {
+        let unwrapped = unwrap_angles(&[0.2, 0.1, TAU - 0.1, TAU - 0.3, 0.0]);
+        let expected = [0.2, 0.1, -0.1, -0.3, 0.0];
+        for (act, exp) in unwrapped.iter().zip(expected.iter()) {
+            assert_float_eq!(*act, *exp, abs <= 1e-15);
+        }
+        assert!(unwrap_angles(&[]).is_empty());
+    }
 }
@@ -0,0 +1,632 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Quaternions for attitude representation and frame rotations.
+//!
+//! [Quaternion] follows the Hamilton convention (`ij = k`) in a right-handed coordinate system and
+//! stores its components scalar-first, i.e. `q = w + xi + yj + zk` is represented as
+//! `[w, x, y, z]`. Use [Quaternion::from_scalar_last] and [Quaternion::to_scalar_last] for data
+//! in the scalar-last convention, e.g. CCSDS AEM files with `QUATERNION_TYPE = LAST` or
+//! [glam::DQuat].
+//!
+//! A unit quaternion and a rotation matrix are equivalent if they transform vectors in the same
+//! way, i.e. `q.rotate(v) == m * v`. For the coordinate transformations between reference frames,
+//! which are represented by rotation matrices throughout LOX, the quaternion thus transforms the
+//! coordinates of a vector from the source to the target frame.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Mul, Neg};
+use std::str::FromStr;
+
+use glam::{DMat3, DQuat, DVec3, EulerRot};
+use thiserror::Error;
+
+use crate::types::units::Radians;
+
+/// Below this value of the cosine (three-axis sequences) or sine (two-axis sequences) of the middle
+/// Euler angle, the first and last rotation axes are considered to be aligned.
+const GIMBAL_LOCK_TOLERANCE: f64 = 1e-10;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("invalid Euler rotation sequence `{0}`")]
+pub struct EulerSequenceError(String);
+
+/// The sequence of axes of a set of Euler angles.
+///
+/// The angles `(a, b, c)` are the angles of three successive coordinate transformations about the
+/// axes of the respectively rotated frame. For example, [EulerSequence::Zxz] (3-1-3) corresponds to
+/// the rotation matrix `R3(c) * R1(b) * R3(a)`, where `R1` and `R3` transform coordinates into a
+/// frame that is rotated about the x- or z-axis respectively. This matches the `EULER_ROT_SEQ`
+/// keyword of CCSDS AEM files and can be parsed from its values, e.g. `312` or `ZXY`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EulerSequence {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    Xyx,
+    Xzx,
+    Yxy,
+    Yzy,
+    Zxz,
+    Zyz,
+}
+
+impl EulerSequence {
+    /// Returns `true` for the sequences whose first and last axes are identical, e.g. 3-1-3.
+    pub fn is_two_axis(&self) -> bool {
+        matches!(
+            self,
+            EulerSequence::Xyx
+                | EulerSequence::Xzx
+                | EulerSequence::Yxy
+                | EulerSequence::Yzy
+                | EulerSequence::Zxz
+                | EulerSequence::Zyz
+        )
+    }
+
+    /// Returns the indices of the three rotation axes, where `0`, `1`, and `2` denote x, y, and z.
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerSequence::Xyz => [0, 1, 2],
+            EulerSequence::Xzy => [0, 2, 1],
+            EulerSequence::Yxz => [1, 0, 2],
+            EulerSequence::Yzx => [1, 2, 0],
+            EulerSequence::Zxy => [2, 0, 1],
+            EulerSequence::Zyx => [2, 1, 0],
+            EulerSequence::Xyx => [0, 1, 0],
+            EulerSequence::Xzx => [0, 2, 0],
+            EulerSequence::Yxy => [1, 0, 1],
+            EulerSequence::Yzy => [1, 2, 1],
+            EulerSequence::Zxz => [2, 0, 2],
+            EulerSequence::Zyz => [2, 1, 2],
+        }
+    }
+
+    /// Returns the rotation matrix of the coordinate transformation given by the Euler angles
+    /// `(a, b, c)`.
+    pub fn rotation_matrix(&self, a: Radians, b: Radians, c: Radians) -> DMat3 {
+        Quaternion::from_euler(*self, a, b, c).to_rotation_matrix()
+    }
+
+    /// Returns the Euler angles `(a, b, c)` of the coordinate transformation given by the rotation
+    /// matrix `m`.
+    ///
+    /// The middle angle lies within `[-π/2, π/2]` for three-axis sequences and within `[0, π]` for
+    /// two-axis sequences. The other angles lie within `[-π, π]`.
+    ///
+    /// If the middle angle is close to `±π/2` (three-axis sequences) or to `0` or `π` (two-axis
+    /// sequences), the first and last rotation axes are aligned and only the sum or difference of
+    /// the first and last angle is defined (gimbal lock). In this case, the last angle is set to
+    /// zero and the first angle describes the complete rotation about the aligned axes.
+    pub fn euler_angles(&self, m: &DMat3) -> (Radians, Radians, Radians) {
+        let [i, j, k] = self.axes();
+        // The third axis which is not part of two-axis sequences
+        let k = if self.is_two_axis() { 3 - i - j } else { k };
+        // +1 for cyclic permutations of (x, y, z) and -1 otherwise
+        let s = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+        // The elements of the active rotation `R_i(a) * R_j(b) * R_k(c)` which is the transpose of
+        // the coordinate transformation
+        let r = |row: usize, col: usize| m.col(row)[col];
+
+        let (b, sin_or_cos_b) = if self.is_two_axis() {
+            let sin_b = r(i, j).hypot(r(i, k));
+            (sin_b.atan2(r(i, i)), sin_b)
+        } else {
+            let cos_b = r(i, i).hypot(r(i, j));
+            ((s * r(i, k)).atan2(cos_b), cos_b)
+        };
+
+        if sin_or_cos_b < GIMBAL_LOCK_TOLERANCE {
+            // The j-axis is invariant under the last two rotations if the last angle is zero
+            let a = (s * r(k, j)).atan2(r(j, j));
+            return (a, b, 0.0);
+        }
+
+        if self.is_two_axis() {
+            let a = r(j, i).atan2(-s * r(k, i));
+            let c = r(i, j).atan2(s * r(i, k));
+            (a, b, c)
+        } else {
+            let a = (-s * r(j, k)).atan2(r(k, k));
+            let c = (-s * r(i, j)).atan2(r(i, i));
+            (a, b, c)
+        }
+    }
+
+    /// The intrinsic active rotation of glam with the same order of axes. It is the inverse of the
+    /// coordinate transformation for the same angles.
+    fn euler_rot(&self) -> EulerRot {
+        match self {
+            EulerSequence::Xyz => EulerRot::XYZ,
+            EulerSequence::Xzy => EulerRot::XZY,
+            EulerSequence::Yxz => EulerRot::YXZ,
+            EulerSequence::Yzx => EulerRot::YZX,
+            EulerSequence::Zxy => EulerRot::ZXY,
+            EulerSequence::Zyx => EulerRot::ZYX,
+            EulerSequence::Xyx => EulerRot::XYX,
+            EulerSequence::Xzx => EulerRot::XZX,
+            EulerSequence::Yxy => EulerRot::YXY,
+            EulerSequence::Yzy => EulerRot::YZY,
+            EulerSequence::Zxz => EulerRot::ZXZ,
+            EulerSequence::Zyz => EulerRot::ZYZ,
+        }
+    }
+}
+
+impl Display for EulerSequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let axes: String = self
+            .axes()
+            .iter()
+            .map(|&axis| ['X', 'Y', 'Z'][axis])
+            .collect();
+        write!(f, "{}", axes)
+    }
+}
+
+impl FromStr for EulerSequence {
+    type Err = EulerSequenceError;
+
+    /// Parses the values of the CCSDS `EULER_ROT_SEQ` keyword, which are given as axis numbers
+    /// (e.g. `313`) or axis names (e.g. `ZXZ`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let axes: Vec<char> = s
+            .trim()
+            .chars()
+            .map(|axis| match axis {
+                '1' | 'x' | 'X' => 'x',
+                '2' | 'y' | 'Y' => 'y',
+                '3' | 'z' | 'Z' => 'z',
+                _ => '?',
+            })
+            .collect();
+        match axes.as_slice() {
+            ['x', 'y', 'z'] => Ok(EulerSequence::Xyz),
+            ['x', 'z', 'y'] => Ok(EulerSequence::Xzy),
+            ['y', 'x', 'z'] => Ok(EulerSequence::Yxz),
+            ['y', 'z', 'x'] => Ok(EulerSequence::Yzx),
+            ['z', 'x', 'y'] => Ok(EulerSequence::Zxy),
+            ['z', 'y', 'x'] => Ok(EulerSequence::Zyx),
+            ['x', 'y', 'x'] => Ok(EulerSequence::Xyx),
+            ['x', 'z', 'x'] => Ok(EulerSequence::Xzx),
+            ['y', 'x', 'y'] => Ok(EulerSequence::Yxy),
+            ['y', 'z', 'y'] => Ok(EulerSequence::Yzy),
+            ['z', 'x', 'z'] => Ok(EulerSequence::Zxz),
+            ['z', 'y', 'z'] => Ok(EulerSequence::Zyz),
+            _ => Err(EulerSequenceError(s.to_string())),
+        }
+    }
+}
+
+/// A quaternion with scalar-first component order in the Hamilton convention.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+    /// Creates a quaternion from the scalar part `w` and the vector part `(x, y, z)`.
+    pub const fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub const fn from_scalar_first([w, x, y, z]: [f64; 4]) -> Self {
+        Self::new(w, x, y, z)
+    }
+
+    pub const fn from_scalar_last([x, y, z, w]: [f64; 4]) -> Self {
+        Self::new(w, x, y, z)
+    }
+
+    /// Returns the unit quaternion of the rotation by `angle` about `axis`.
+    pub fn from_axis_angle(axis: DVec3, angle: Radians) -> Self {
+        DQuat::from_axis_angle(axis.normalize(), angle).into()
+    }
+
+    /// Returns the unit quaternion which is equivalent to the rotation matrix `m`.
+    ///
+    /// The result is only meaningful if `m` is orthonormal with a determinant of one.
+    pub fn from_rotation_matrix(m: &DMat3) -> Self {
+        DQuat::from_mat3(m).into()
+    }
+
+    /// Returns the unit quaternion of the coordinate transformation given by the Euler angles
+    /// `(a, b, c)` in the given `sequence`.
+    pub fn from_euler(sequence: EulerSequence, a: Radians, b: Radians, c: Radians) -> Self {
+        Self::from(DQuat::from_euler(sequence.euler_rot(), a, b, c)).conjugate()
+    }
+
+    pub fn to_scalar_first(&self) -> [f64; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
+    pub fn to_scalar_last(&self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    pub fn scalar(&self) -> f64 {
+        self.w
+    }
+
+    pub fn vector(&self) -> DVec3 {
+        DVec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the quaternion scaled to unit norm. The components are not finite if the norm is
+    /// zero.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Self::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// Returns the conjugate quaternion, which is the inverse rotation for unit quaternions.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Returns the equivalent rotation matrix of a unit quaternion.
+    pub fn to_rotation_matrix(&self) -> DMat3 {
+        DMat3::from_quat(DQuat::from(*self))
+    }
+
+    /// Returns the Euler angles `(a, b, c)` of the coordinate transformation in the given
+    /// `sequence`.
+    ///
+    /// See [EulerSequence::euler_angles] for the ranges of the angles and the handling of gimbal
+    /// lock.
+    pub fn to_euler(&self, sequence: EulerSequence) -> (Radians, Radians, Radians) {
+        sequence.euler_angles(&self.to_rotation_matrix())
+    }
+
+    /// Transforms the vector `v` with a unit quaternion.
+    pub fn rotate(&self, v: DVec3) -> DVec3 {
+        // v' = v + 2w(u × v) + 2u × (u × v)
+        let u = self.vector();
+        let t = 2.0 * u.cross(v);
+        v + self.w * t + u.cross(t)
+    }
+
+    /// Spherically interpolates between two unit quaternions with `t` in `[0, 1]` along the shorter
+    /// arc.
+    ///
+    /// Since `q` and `-q` describe the same rotation, `end` is negated if necessary, so that the
+    /// interpolation never takes the long way around.
+    pub fn slerp(&self, end: &Self, t: f64) -> Self {
+        let mut dot = self.dot(end);
+        let end = if dot < 0.0 {
+            dot = -dot;
+            -*end
+        } else {
+            *end
+        };
+        // Fall back to linear interpolation for almost identical rotations, where the angle is too
+        // small to be divided by its sine
+        if dot > 1.0 - 1e-12 {
+            return self.lerp(&end, t).normalize();
+        }
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let s0 = ((1.0 - t) * theta).sin() / sin_theta;
+        let s1 = (t * theta).sin() / sin_theta;
+        Self::new(
+            s0 * self.w + s1 * end.w,
+            s0 * self.x + s1 * end.x,
+            s0 * self.y + s1 * end.y,
+            s0 * self.z + s1 * end.z,
+        )
+    }
+
+    fn lerp(&self, end: &Self, t: f64) -> Self {
+        Self::new(
+            self.w + t * (end.w - self.w),
+            self.x + t * (end.x - self.x),
+            self.y + t * (end.y - self.y),
+            self.z + t * (end.z - self.z),
+        )
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    /// Returns the Hamilton product. For unit quaternions, `(q1 * q2).rotate(v)` is equal to
+    /// `q1.rotate(q2.rotate(v))`, like the product of the equivalent rotation matrices.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (w1, v1) = (self.w, self.vector());
+        let (w2, v2) = (rhs.w, rhs.vector());
+        let w = w1 * w2 - v1.dot(v2);
+        let v = w1 * v2 + w2 * v1 + v1.cross(v2);
+        Self::new(w, v.x, v.y, v.z)
+    }
+}
+
+impl Mul<DVec3> for Quaternion {
+    type Output = DVec3;
+
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl From<DQuat> for Quaternion {
+    fn from(q: DQuat) -> Self {
+        Self::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<Quaternion> for DQuat {
+    fn from(q: Quaternion) -> Self {
+        DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use float_eq::assert_float_eq;
+
+    use super::*;
+
+    const SEQUENCES: [EulerSequence; 12] = [
+        EulerSequence::Xyz,
+        EulerSequence::Xzy,
+        EulerSequence::Yxz,
+        EulerSequence::Yzx,
+        EulerSequence::Zxy,
+        EulerSequence::Zyx,
+        EulerSequence::Xyx,
+        EulerSequence::Xzx,
+        EulerSequence::Yxy,
+        EulerSequence::Yzy,
+        EulerSequence::Zxz,
+        EulerSequence::Zyz,
+    ];
+
+    fn assert_quaternion_eq(act: Quaternion, exp: Quaternion) {
+        for (a, e) in act.to_scalar_first().iter().zip(exp.to_scalar_first()) {
+            assert_float_eq!(*a, e, abs <= 1e-14);
+        }
+    }
+
+    /// The coordinate transformation into a frame rotated by `angle` about `axis`.
+    fn transformation(axis: char, angle: f64) -> DMat3 {
+        match axis {
+            'x' => DMat3::from_rotation_x(-angle),
+            'y' => DMat3::from_rotation_y(-angle),
+            _ => DMat3::from_rotation_z(-angle),
+        }
+    }
+
+    #[test]
+    fn test_quaternion_component_order() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(q.scalar(), 1.0);
+        assert_eq!(q.vector(), DVec3::new(2.0, 3.0, 4.0));
+        assert_eq!(q.to_scalar_first(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(q.to_scalar_last(), [2.0, 3.0, 4.0, 1.0]);
+        assert_eq!(Quaternion::from_scalar_last([2.0, 3.0, 4.0, 1.0]), q);
+        assert_eq!(Quaternion::from_scalar_first([1.0, 2.0, 3.0, 4.0]), q);
+        assert_eq!(Quaternion::from(DQuat::from(q)), q);
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, -3.0, -4.0));
+        assert_float_eq!(q.norm(), 30.0_f64.sqrt(), rel <= 1e-15);
+        assert_float_eq!(q.normalize().norm(), 1.0, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_quaternion_hamilton_product() {
+        let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(i * j, k);
+        assert_eq!(j * k, i);
+        assert_eq!(k * i, j);
+        assert_eq!(i * i, -Quaternion::IDENTITY);
+        assert_eq!(i * j * k, -Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn test_quaternion_rotation_handedness() {
+        // A positive rotation about z maps x onto y in a right-handed system
+        let q = Quaternion::from_axis_angle(DVec3::Z, FRAC_PI_2);
+        assert!((q * DVec3::X).abs_diff_eq(DVec3::Y, 1e-15));
+        assert!((q.conjugate() * DVec3::Y).abs_diff_eq(DVec3::X, 1e-15));
+
+        let q1 = Quaternion::from_axis_angle(DVec3::new(1.0, 2.0, 3.0), 0.7);
+        let q2 = Quaternion::from_axis_angle(DVec3::new(-3.0, 0.5, 1.0), 2.1);
+        let v = DVec3::new(0.3, -1.2, 2.5);
+        assert!(((q1 * q2) * v).abs_diff_eq(q1 * (q2 * v), 1e-14));
+        let m = q1.to_rotation_matrix() * q2.to_rotation_matrix();
+        assert!(((q1 * q2) * v).abs_diff_eq(m * v, 1e-14));
+    }
+
+    #[test]
+    fn test_quaternion_rotation_matrix_round_trip() {
+        let axes = [
+            DVec3::X,
+            DVec3::new(1.0, 2.0, 3.0),
+            DVec3::new(-0.3, 0.1, -2.0),
+            DVec3::new(0.0, -1.0, 1e-9),
+        ];
+        // Include angles close to π, where the scalar part vanishes
+        let angles = [0.0, 1e-8, 0.5, FRAC_PI_2, 2.5, PI - 1e-9, PI];
+        for axis in axes {
+            for angle in angles {
+                let q = Quaternion::from_axis_angle(axis, angle);
+                let m = q.to_rotation_matrix();
+                assert!((m * m.transpose()).abs_diff_eq(DMat3::IDENTITY, 1e-15));
+                let act = Quaternion::from_rotation_matrix(&m);
+                // q and -q are the same rotation
+                let act = if act.dot(&q) < 0.0 { -act } else { act };
+                assert_quaternion_eq(act, q);
+                assert!(act.to_rotation_matrix().abs_diff_eq(m, 1e-15));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quaternion_from_euler() {
+        let (a, b, c) = (0.3, -0.8, 1.9);
+        for sequence in SEQUENCES {
+            let axes: Vec<char> = format!("{:?}", sequence).to_lowercase().chars().collect();
+            let exp = transformation(axes[2], c)
+                * transformation(axes[1], b)
+                * transformation(axes[0], a);
+            let q = Quaternion::from_euler(sequence, a, b, c);
+            assert!(
+                q.to_rotation_matrix().abs_diff_eq(exp, 1e-15),
+                "{sequence:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quaternion_euler_round_trip() {
+        for sequence in SEQUENCES {
+            let b = if sequence.is_two_axis() { 1.2 } else { -0.6 };
+            let q = Quaternion::from_euler(sequence, 0.4, b, -2.3);
+            let (a1, b1, c1) = q.to_euler(sequence);
+            assert_float_eq!(a1, 0.4, abs <= 1e-12);
+            assert_float_eq!(b1, b, abs <= 1e-12);
+            assert_float_eq!(c1, -2.3, abs <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_euler_angles_random_round_trip() {
+        // Deterministic pseudo-random angles from a linear congruential generator
+        let mut state: u64 = 0x2545f4914f6cdd1d;
+        let mut random = |lower: f64, upper: f64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            lower + (upper - lower) * (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for sequence in SEQUENCES {
+            for _ in 0..20 {
+                let a = random(-PI, PI);
+                let c = random(-PI, PI);
+                // Stay away from the singularities of the middle angle
+                let b = if sequence.is_two_axis() {
+                    random(0.01, PI - 0.01)
+                } else {
+                    random(-FRAC_PI_2 + 0.01, FRAC_PI_2 - 0.01)
+                };
+                let m = sequence.rotation_matrix(a, b, c);
+                let (a1, b1, c1) = sequence.euler_angles(&m);
+                assert_float_eq!(a1, a, abs <= 1e-12, "{sequence}");
+                assert_float_eq!(b1, b, abs <= 1e-12, "{sequence}");
+                assert_float_eq!(c1, c, abs <= 1e-12, "{sequence}");
+
+                let q = Quaternion::from_rotation_matrix(&m);
+                let (a2, b2, c2) = q.to_euler(sequence);
+                assert_float_eq!(a2, a, abs <= 1e-12, "{sequence}");
+                assert_float_eq!(b2, b, abs <= 1e-12, "{sequence}");
+                assert_float_eq!(c2, c, abs <= 1e-12, "{sequence}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_euler_angles_gimbal_lock() {
+        for sequence in SEQUENCES {
+            let singular = if sequence.is_two_axis() {
+                vec![0.0, PI]
+            } else {
+                vec![FRAC_PI_2, -FRAC_PI_2]
+            };
+            for b in singular {
+                let m = sequence.rotation_matrix(0.4, b, -1.1);
+                let (a1, b1, c1) = sequence.euler_angles(&m);
+                assert!(a1.is_finite() && b1.is_finite(), "{sequence}");
+                assert_float_eq!(b1, b, abs <= 1e-7, "{sequence}");
+                assert_eq!(c1, 0.0);
+                let act = sequence.rotation_matrix(a1, b1, c1);
+                assert!(act.abs_diff_eq(m, 1e-14), "{sequence}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_euler_sequence_from_str() {
+        for sequence in SEQUENCES {
+            let name = sequence.to_string();
+            assert_eq!(name.parse::<EulerSequence>(), Ok(sequence));
+            assert_eq!(name.to_lowercase().parse::<EulerSequence>(), Ok(sequence));
+            let digits: String = sequence
+                .axes()
+                .iter()
+                .map(|axis| (axis + 1).to_string())
+                .collect();
+            assert_eq!(digits.parse::<EulerSequence>(), Ok(sequence));
+        }
+        assert_eq!(" 312 ".parse::<EulerSequence>(), Ok(EulerSequence::Zxy));
+        assert_eq!("ZXZ".parse::<EulerSequence>(), Ok(EulerSequence::Zxz));
+        for invalid in ["", "ZZX", "XY", "XYZX", "3-1-3", "ABC"] {
+            assert_eq!(
+                invalid.parse::<EulerSequence>(),
+                Err(EulerSequenceError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let q0 = Quaternion::IDENTITY;
+        let q1 = Quaternion::from_axis_angle(DVec3::Z, FRAC_PI_2);
+        assert_quaternion_eq(q0.slerp(&q1, 0.0), q0);
+        assert_quaternion_eq(q0.slerp(&q1, 1.0), q1);
+        assert_quaternion_eq(
+            q0.slerp(&q1, 0.5),
+            Quaternion::from_axis_angle(DVec3::Z, FRAC_PI_4),
+        );
+
+        // The negated quaternion is the same rotation and must not cause a detour through the
+        // opposite hemisphere
+        let mid = q0.slerp(&-q1, 0.5);
+        let mid = if mid.scalar() < 0.0 { -mid } else { mid };
+        assert_quaternion_eq(mid, Quaternion::from_axis_angle(DVec3::Z, FRAC_PI_4));
+
+        // Nearly identical rotations
+        let q2 = Quaternion::from_axis_angle(DVec3::Z, 1e-10);
+        let act = q0.slerp(&q2, 0.5);
+        assert_float_eq!(act.norm(), 1.0, abs <= 1e-15);
+        assert_quaternion_eq(act, Quaternion::from_axis_angle(DVec3::Z, 5e-11));
+    }
+}
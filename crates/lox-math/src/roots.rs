@@ -130,6 +130,18 @@ impl Default for Brent {
     }
 }
 
+impl Brent {
+    /// Returns a copy of the root finder with the absolute tolerance `abs_tol` and the relative
+    /// tolerance `rel_tol`.
+    pub fn with_tolerances(self, abs_tol: f64, rel_tol: f64) -> Self {
+        Self {
+            abs_tol,
+            rel_tol,
+            ..self
+        }
+    }
+}
+
 impl<F> FindBracketedRoot<F> for Brent
 where
     F: Fn(f64) -> f64,
@@ -348,6 +360,15 @@ mod tests {
         assert_float_eq!(act, 1.3652300134140969, rel <= 1e-8);
     }
 
+    #[test]
+    fn test_brent_tolerances() {
+        let brent = Brent::default().with_tolerances(1e-14, 2.0 * f64::EPSILON);
+        let act = brent
+            .find_in_bracket(|x| x.powi(3) + 4.0 * x.powi(2) - 10.0, (1.0, 1.5))
+            .expect("should converge");
+        assert_float_eq!(act, 1.3652300134140969, abs <= 1e-14);
+    }
+
     #[test]
     fn test_secant_cubic() {
         let secant = Secant::default();
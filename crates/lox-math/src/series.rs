@@ -6,6 +6,8 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cmp::Ordering;
+
 use fast_polynomial::poly_array;
 use thiserror::Error;
 
@@ -23,6 +25,10 @@ pub enum SeriesError {
     InsufficientPoints(usize, usize),
     #[error("x-axis must be strictly monotonic")]
     NonMonotonic,
+    #[error("the lower bound of the domain must be less than the upper bound")]
+    InvalidDomain,
+    #[error("a Chebyshev series requires at least one coefficient")]
+    NoCoefficients,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -193,6 +199,173 @@ impl<T: AsRef<[f64]>, U: AsRef<[f64]>> Series<T, U> {
     }
 }
 
+/// A Chebyshev series `f(x) = c₀T₀(t) + c₁T₁(t) + … + cₙTₙ(t)` on the domain `[a, b]`, where
+/// `t = (2x - a - b) / (b - a)` maps the domain onto `[-1, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chebyshev {
+    coefficients: Vec<f64>,
+    derivative: Vec<f64>,
+    domain: (f64, f64),
+}
+
+impl Chebyshev {
+    pub fn new(coefficients: Vec<f64>, domain: (f64, f64)) -> Result<Self, SeriesError> {
+        let (a, b) = domain;
+        if a.partial_cmp(&b) != Some(Ordering::Less) {
+            return Err(SeriesError::InvalidDomain);
+        }
+        if coefficients.is_empty() {
+            return Err(SeriesError::NoCoefficients);
+        }
+        let derivative = derivative_coefficients(&coefficients);
+        Ok(Self {
+            coefficients,
+            derivative,
+            domain,
+        })
+    }
+
+    /// Fits a Chebyshev series of the given `degree` to the samples `xs` and `ys` in the
+    /// least-squares sense. The domain of the series spans the samples.
+    pub fn fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<Self, SeriesError> {
+        let n = xs.len();
+        if ys.len() != n {
+            return Err(SeriesError::DimensionMismatch(n, ys.len()));
+        }
+        if n < degree + 1 {
+            return Err(SeriesError::InsufficientPoints(n, degree + 1));
+        }
+        if !xs.diff().iter().all(|&d| d > 0.0) {
+            return Err(SeriesError::NonMonotonic);
+        }
+        let domain = (xs[0], xs[n - 1]);
+        let (a, b) = domain;
+        let columns: Vec<Vec<f64>> = (0..=degree)
+            .map(|k| {
+                xs.iter()
+                    .map(|&x| chebyshev_t(k, normalize(x, a, b)))
+                    .collect()
+            })
+            .collect();
+        let coefficients = least_squares(columns, ys.to_vec());
+        Self::new(coefficients, domain)
+    }
+
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn domain(&self) -> (f64, f64) {
+        self.domain
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        let (a, b) = self.domain;
+        clenshaw(&self.coefficients, normalize(x, a, b))
+    }
+
+    /// Evaluates the first derivative of the series with respect to `x`.
+    pub fn eval_derivative(&self, x: f64) -> f64 {
+        let (a, b) = self.domain;
+        2.0 / (b - a) * clenshaw(&self.derivative, normalize(x, a, b))
+    }
+}
+
+fn normalize(x: f64, a: f64, b: f64) -> f64 {
+    (2.0 * x - a - b) / (b - a)
+}
+
+/// Evaluates the Chebyshev polynomial of the first kind `Tₖ(t)`.
+fn chebyshev_t(k: usize, t: f64) -> f64 {
+    let (mut t0, mut t1) = (1.0, t);
+    match k {
+        0 => t0,
+        _ => {
+            for _ in 1..k {
+                (t0, t1) = (t1, 2.0 * t * t1 - t0);
+            }
+            t1
+        }
+    }
+}
+
+/// Evaluates a Chebyshev series at `t` by Clenshaw's recurrence.
+///
+/// Close to the endpoints of `[-1, 1]`, the recurrence loses accuracy because the terms `2t bₖ₊₁`
+/// and `bₖ₊₂` nearly cancel. Reinsch's modification, which recurs on the differences of
+/// successive terms instead, is used there.
+fn clenshaw(coefficients: &[f64], t: f64) -> f64 {
+    let (c0, rest) = coefficients.split_first().unwrap();
+    if t.abs() <= 0.5 {
+        let (mut b1, mut b2) = (0.0, 0.0);
+        for &c in rest.iter().rev() {
+            (b1, b2) = (c + 2.0 * t * b1 - b2, b1);
+        }
+        return c0 + t * b1 - b2;
+    }
+    let sign = t.signum();
+    let u = 2.0 * (t - sign);
+    let (mut b, mut d) = (0.0, 0.0);
+    for &c in rest.iter().rev() {
+        d = c + u * b + sign * d;
+        b = d + sign * b;
+    }
+    c0 + 0.5 * u * b + sign * d
+}
+
+/// Returns the coefficients of the derivative of a Chebyshev series with respect to `t`.
+fn derivative_coefficients(coefficients: &[f64]) -> Vec<f64> {
+    let n = coefficients.len();
+    if n == 1 {
+        return vec![0.0];
+    }
+    let mut derivative = vec![0.0; n + 1];
+    for k in (1..n).rev() {
+        derivative[k - 1] = derivative[k + 1] + 2.0 * k as f64 * coefficients[k];
+    }
+    derivative[0] /= 2.0;
+    derivative.truncate(n - 1);
+    derivative
+}
+
+/// Solves the overdetermined system `A x = y` in the least-squares sense by Householder QR
+/// decomposition. `A` is given by its `columns` which must be linearly independent.
+fn least_squares(mut columns: Vec<Vec<f64>>, mut y: Vec<f64>) -> Vec<f64> {
+    let m = columns.len();
+    let n = y.len();
+    for k in 0..m {
+        let norm = columns[k][k..].iter().map(|v| v * v).sum::<f64>().sqrt();
+        let alpha = if columns[k][k] > 0.0 { -norm } else { norm };
+        let mut v = columns[k][k..].to_vec();
+        v[0] -= alpha;
+        let v_norm2: f64 = v.iter().map(|v| v * v).sum();
+        if v_norm2 == 0.0 {
+            continue;
+        }
+        let reflect = |col: &mut [f64]| {
+            let dot: f64 = v.iter().zip(&col[k..]).map(|(v, c)| v * c).sum();
+            let f = 2.0 * dot / v_norm2;
+            for (c, v) in col[k..n].iter_mut().zip(&v) {
+                *c -= f * v;
+            }
+        };
+        for column in columns.iter_mut().skip(k) {
+            reflect(column);
+        }
+        reflect(&mut y);
+    }
+    let mut x = vec![0.0; m];
+    for k in (0..m).rev() {
+        let sum: f64 = (k + 1..m).map(|j| columns[j][k] * x[j]).sum();
+        x[k] = (y[k] - sum) / columns[k][k];
+    }
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
@@ -304,4 +477,95 @@ mod tests {
     ) {
         assert_eq!(actual, expected);
     }
+
+    #[rstest]
+    #[case(2.0)]
+    #[case(2.5)]
+    #[case(4.0)]
+    #[case(5.9)]
+    #[case(6.0)]
+    fn test_chebyshev_eval(#[case] x: f64) {
+        let chebyshev = Chebyshev::new(vec![1.0, 2.0, 3.0, 4.0], (2.0, 6.0)).unwrap();
+        let t = (x - 4.0) / 2.0;
+        let expected =
+            1.0 + 2.0 * t + 3.0 * (2.0 * t.powi(2) - 1.0) + 4.0 * (4.0 * t.powi(3) - 3.0 * t);
+        assert_float_eq!(chebyshev.eval(x), expected, abs <= 1e-13);
+        let expected_derivative = (2.0 + 12.0 * t + 4.0 * (12.0 * t.powi(2) - 3.0)) / 2.0;
+        assert_float_eq!(
+            chebyshev.eval_derivative(x),
+            expected_derivative,
+            abs <= 1e-13
+        );
+    }
+
+    #[test]
+    fn test_chebyshev_endpoints() {
+        // Tₖ(1) = 1 and Tₖ(-1) = (-1)ᵏ
+        let coefficients: Vec<f64> = (0..40).map(|k| 1.0 / (k as f64 + 1.0).powi(2)).collect();
+        let chebyshev = Chebyshev::new(coefficients.clone(), (-1.0, 1.0)).unwrap();
+        let upper: f64 = coefficients.iter().sum();
+        let lower: f64 = coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, c)| if k % 2 == 0 { *c } else { -c })
+            .sum();
+        assert_float_eq!(chebyshev.eval(1.0), upper, ulps <= 4);
+        assert_float_eq!(chebyshev.eval(-1.0), lower, ulps <= 4);
+        // T'ₖ(1) = k²
+        let derivative: f64 = coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, c)| (k * k) as f64 * c)
+            .sum();
+        assert_float_eq!(chebyshev.eval_derivative(1.0), derivative, rel <= 1e-13);
+    }
+
+    #[test]
+    fn test_chebyshev_derivative_finite_difference() {
+        let xs: Vec<f64> = (0..=100).map(|i| 0.03 * i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.sin() * (0.5 * x).exp()).collect();
+        let chebyshev = Chebyshev::fit(&xs, &ys, 15).unwrap();
+        let h = 1e-6;
+        for x in [1e-3, 0.4, 1.234, 2.5, 3.0 - 1e-3] {
+            let expected = (chebyshev.eval(x + h) - chebyshev.eval(x - h)) / (2.0 * h);
+            assert_float_eq!(chebyshev.eval_derivative(x), expected, abs <= 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_fit() {
+        let xs: Vec<f64> = (0..50).map(|i| 3.0 * i as f64 / 49.0).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+        let chebyshev = Chebyshev::fit(&xs, &ys, 14).unwrap();
+        assert_eq!(chebyshev.degree(), 14);
+        assert_eq!(chebyshev.domain(), (0.0, 3.0));
+        for x in [0.0, 0.123, 1.5, 2.71, 3.0] {
+            assert_float_eq!(chebyshev.eval(x), x.sin(), abs <= 1e-12);
+            assert_float_eq!(chebyshev.eval_derivative(x), x.cos(), abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_fit_exact() {
+        let expected = Chebyshev::new(vec![0.5, -1.0, 0.25, 2.0], (-2.0, 2.0)).unwrap();
+        let xs: Vec<f64> = (0..=8).map(|i| -2.0 + 0.5 * i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| expected.eval(x)).collect();
+        let actual = Chebyshev::fit(&xs, &ys, 3).unwrap();
+        for (actual, expected) in actual.coefficients().iter().zip(expected.coefficients()) {
+            assert_float_eq!(actual, expected, abs <= 1e-14);
+        }
+    }
+
+    #[rstest]
+    #[case(Chebyshev::new(vec![1.0], (1.0, 1.0)), Err(SeriesError::InvalidDomain))]
+    #[case(Chebyshev::new(vec![], (0.0, 1.0)), Err(SeriesError::NoCoefficients))]
+    #[case(Chebyshev::fit(&[0.0, 1.0], &[1.0], 1), Err(SeriesError::DimensionMismatch(2, 1)))]
+    #[case(Chebyshev::fit(&[0.0, 1.0], &[1.0, 2.0], 2), Err(SeriesError::InsufficientPoints(2, 3)))]
+    #[case(Chebyshev::fit(&[1.0, 0.0], &[1.0, 2.0], 1), Err(SeriesError::NonMonotonic))]
+    fn test_chebyshev_errors(
+        #[case] actual: Result<Chebyshev, SeriesError>,
+        #[case] expected: Result<Chebyshev, SeriesError>,
+    ) {
+        assert_eq!(actual, expected);
+    }
 }
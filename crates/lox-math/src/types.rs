@@ -6,5 +6,7 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod decibels;
+pub mod frequencies;
 pub mod julian_dates;
 pub mod units;
@@ -0,0 +1,323 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `decibels` exposes logarithmic quantities for link budget calculations.
+
+    [Decibel] represents a dimensionless ratio such as a gain or a loss, while [DecibelWatt] and
+    [DecibelMilliwatt] represent absolute power levels. Only physically meaningful operations are
+    implemented:
+
+    - Ratios can be added and subtracted, i.e. gains and losses cascade.
+    - A ratio can be added to or subtracted from a power level, e.g. an amplifier gain.
+    - The difference of two power levels is a ratio.
+
+    Adding two power levels or multiplying two decibel values does not compile.
+
+    [DecibelUnits] provides the `.db()`, `.dbw()` and `.dbm()` constructors for `f64`.
+*/
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A dimensionless ratio in decibels, e.g. a gain or a loss.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Decibel(f64);
+
+impl Decibel {
+    pub const fn new(db: f64) -> Self {
+        Self(db)
+    }
+
+    /// Converts a ratio of power quantities into decibels, i.e. `10 log10(ratio)`.
+    pub fn from_linear(ratio: f64) -> Self {
+        Self(10.0 * ratio.log10())
+    }
+
+    /// Converts a ratio of root-power (field) quantities such as voltages into decibels, i.e.
+    /// `20 log10(ratio)`.
+    pub fn from_amplitude_ratio(ratio: f64) -> Self {
+        Self(20.0 * ratio.log10())
+    }
+
+    /// Returns the value in decibels.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the corresponding ratio of power quantities.
+    pub fn to_linear(&self) -> f64 {
+        10.0f64.powf(self.0 / 10.0)
+    }
+
+    /// Returns the corresponding ratio of root-power (field) quantities.
+    pub fn to_amplitude_ratio(&self) -> f64 {
+        10.0f64.powf(self.0 / 20.0)
+    }
+}
+
+impl Display for Decibel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)?;
+        write!(f, " dB")
+    }
+}
+
+impl Add for Decibel {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Decibel {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Decibel {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Decibel {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Decibel {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// A power level in decibels relative to one watt (dBW).
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DecibelWatt(f64);
+
+impl DecibelWatt {
+    pub const fn new(dbw: f64) -> Self {
+        Self(dbw)
+    }
+
+    pub fn from_watts(watts: f64) -> Self {
+        Self(10.0 * watts.log10())
+    }
+
+    /// Returns the value in dBW.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    pub fn to_watts(&self) -> f64 {
+        10.0f64.powf(self.0 / 10.0)
+    }
+}
+
+impl Display for DecibelWatt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)?;
+        write!(f, " dBW")
+    }
+}
+
+/// A power level in decibels relative to one milliwatt (dBm).
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DecibelMilliwatt(f64);
+
+impl DecibelMilliwatt {
+    pub const fn new(dbm: f64) -> Self {
+        Self(dbm)
+    }
+
+    pub fn from_milliwatts(milliwatts: f64) -> Self {
+        Self(10.0 * milliwatts.log10())
+    }
+
+    /// Returns the value in dBm.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    pub fn to_milliwatts(&self) -> f64 {
+        10.0f64.powf(self.0 / 10.0)
+    }
+}
+
+impl Display for DecibelMilliwatt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)?;
+        write!(f, " dBm")
+    }
+}
+
+/// One watt is 1000 milliwatts, i.e. 0 dBW = 30 dBm.
+const DBM_PER_DBW: f64 = 30.0;
+
+impl From<DecibelWatt> for DecibelMilliwatt {
+    fn from(power: DecibelWatt) -> Self {
+        Self(power.0 + DBM_PER_DBW)
+    }
+}
+
+impl From<DecibelMilliwatt> for DecibelWatt {
+    fn from(power: DecibelMilliwatt) -> Self {
+        Self(power.0 - DBM_PER_DBW)
+    }
+}
+
+macro_rules! power_level_ops {
+    ($power:ident) => {
+        impl Add<Decibel> for $power {
+            type Output = Self;
+
+            fn add(self, rhs: Decibel) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Add<$power> for Decibel {
+            type Output = $power;
+
+            fn add(self, rhs: $power) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl AddAssign<Decibel> for $power {
+            fn add_assign(&mut self, rhs: Decibel) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub<Decibel> for $power {
+            type Output = Self;
+
+            fn sub(self, rhs: Decibel) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign<Decibel> for $power {
+            fn sub_assign(&mut self, rhs: Decibel) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        /// The ratio of two power levels.
+        impl Sub for $power {
+            type Output = Decibel;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Decibel(self.0 - rhs.0)
+            }
+        }
+    };
+}
+
+power_level_ops!(DecibelWatt);
+power_level_ops!(DecibelMilliwatt);
+
+/// Convenience constructors for decibel quantities, e.g. `3.0.db()` or `-120.0.dbw()`.
+pub trait DecibelUnits {
+    fn db(self) -> Decibel;
+    fn dbw(self) -> DecibelWatt;
+    fn dbm(self) -> DecibelMilliwatt;
+}
+
+impl DecibelUnits for f64 {
+    fn db(self) -> Decibel {
+        Decibel(self)
+    }
+
+    fn dbw(self) -> DecibelWatt {
+        DecibelWatt(self)
+    }
+
+    fn dbm(self) -> DecibelMilliwatt {
+        DecibelMilliwatt(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1.0, 0.0, 0.0)]
+    #[case(2.0, 3.010299956639812, 6.020599913279624)]
+    #[case(10.0, 10.0, 20.0)]
+    #[case(0.01, -20.0, -40.0)]
+    fn test_decibel_conversions(#[case] ratio: f64, #[case] power: f64, #[case] amplitude: f64) {
+        let db = Decibel::from_linear(ratio);
+        assert_float_eq!(db.value(), power, abs <= 1e-12);
+        assert_float_eq!(db.to_linear(), ratio, rel <= 1e-12);
+        let db = Decibel::from_amplitude_ratio(ratio);
+        assert_float_eq!(db.value(), amplitude, abs <= 1e-12);
+        assert_float_eq!(db.to_amplitude_ratio(), ratio, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_decibel_arithmetic() {
+        let mut gain = 30.0.db() + 3.0.db();
+        assert_eq!(gain, 33.0.db());
+        gain -= 10.0.db();
+        assert_eq!(gain, 23.0.db());
+        assert_eq!(-gain, (-23.0).db());
+        // Cascading gains multiplies the linear ratios
+        assert_float_eq!(
+            (10.0.db() + 3.0.db()).to_linear(),
+            10.0.db().to_linear() * 3.0.db().to_linear(),
+            rel <= 1e-12
+        );
+    }
+
+    #[test]
+    fn test_power_levels() {
+        let transmitter = DecibelWatt::from_watts(20.0);
+        assert_float_eq!(transmitter.value(), 13.010299956639813, abs <= 1e-12);
+        assert_float_eq!(transmitter.to_watts(), 20.0, rel <= 1e-12);
+
+        let eirp = transmitter + 20.0.db() - 1.0.db();
+        assert_float_eq!(eirp.value(), 32.01029995663981, abs <= 1e-12);
+        let received = eirp - 180.0.db();
+        assert_eq!(eirp - received, 180.0.db());
+
+        let mut level = 0.0.dbw();
+        level += 3.0.db();
+        level -= 1.0.db();
+        assert_eq!(level, 2.0.dbw());
+        assert_eq!(3.0.db() + 10.0.dbm(), 13.0.dbm());
+    }
+
+    #[test]
+    fn test_dbw_dbm() {
+        assert_eq!(DecibelMilliwatt::from(0.0.dbw()), 30.0.dbm());
+        assert_eq!(DecibelWatt::from((-100.0).dbm()), (-130.0).dbw());
+        let power = DecibelMilliwatt::from_milliwatts(500.0);
+        assert_float_eq!(DecibelWatt::from(power).to_watts(), 0.5, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(3.0.db().to_string(), "3 dB");
+        assert_eq!((-120.5).dbw().to_string(), "-120.5 dBW");
+        assert_eq!(format!("{:.1}", 30.0.dbm()), "30.0 dBm");
+    }
+}
@@ -0,0 +1,210 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module `frequencies` exposes [Frequency] and its classification into IEEE radar bands.
+
+use std::fmt::{Display, Formatter};
+
+use crate::constants::f64::physics::SPEED_OF_LIGHT;
+use crate::types::units::Hertz;
+
+/// The frequency bands defined by IEEE Std 521-2019.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FrequencyBand {
+    /// 3 MHz to 30 MHz
+    Hf,
+    /// 30 MHz to 300 MHz
+    Vhf,
+    /// 300 MHz to 1 GHz
+    Uhf,
+    /// 1 GHz to 2 GHz
+    L,
+    /// 2 GHz to 4 GHz
+    S,
+    /// 4 GHz to 8 GHz
+    C,
+    /// 8 GHz to 12 GHz
+    X,
+    /// 12 GHz to 18 GHz
+    Ku,
+    /// 18 GHz to 27 GHz
+    K,
+    /// 27 GHz to 40 GHz
+    Ka,
+    /// 40 GHz to 75 GHz
+    V,
+    /// 75 GHz to 110 GHz
+    W,
+    /// 110 GHz to 300 GHz
+    G,
+}
+
+impl FrequencyBand {
+    const EDGES: [(Hertz, FrequencyBand); 13] = [
+        (3e6, FrequencyBand::Hf),
+        (30e6, FrequencyBand::Vhf),
+        (300e6, FrequencyBand::Uhf),
+        (1e9, FrequencyBand::L),
+        (2e9, FrequencyBand::S),
+        (4e9, FrequencyBand::C),
+        (8e9, FrequencyBand::X),
+        (12e9, FrequencyBand::Ku),
+        (18e9, FrequencyBand::K),
+        (27e9, FrequencyBand::Ka),
+        (40e9, FrequencyBand::V),
+        (75e9, FrequencyBand::W),
+        (110e9, FrequencyBand::G),
+    ];
+
+    const UPPER_EDGE: Hertz = 300e9;
+
+    /// Returns the lower (inclusive) and upper (exclusive) edge of the band in Hz.
+    pub fn edges(&self) -> (Hertz, Hertz) {
+        let idx = *self as usize;
+        let upper = Self::EDGES
+            .get(idx + 1)
+            .map_or(Self::UPPER_EDGE, |&(edge, _)| edge);
+        (Self::EDGES[idx].0, upper)
+    }
+}
+
+impl Display for FrequencyBand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            FrequencyBand::Hf => "HF",
+            FrequencyBand::Vhf => "VHF",
+            FrequencyBand::Uhf => "UHF",
+            FrequencyBand::L => "L",
+            FrequencyBand::S => "S",
+            FrequencyBand::C => "C",
+            FrequencyBand::X => "X",
+            FrequencyBand::Ku => "Ku",
+            FrequencyBand::K => "K",
+            FrequencyBand::Ka => "Ka",
+            FrequencyBand::V => "V",
+            FrequencyBand::W => "W",
+            FrequencyBand::G => "G",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A frequency in Hz.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Frequency(Hertz);
+
+impl Frequency {
+    pub const fn new(hz: Hertz) -> Self {
+        Self(hz)
+    }
+
+    pub fn hz(hz: f64) -> Self {
+        Self(hz)
+    }
+
+    pub fn khz(khz: f64) -> Self {
+        Self(khz * 1e3)
+    }
+
+    pub fn mhz(mhz: f64) -> Self {
+        Self(mhz * 1e6)
+    }
+
+    pub fn ghz(ghz: f64) -> Self {
+        Self(ghz * 1e9)
+    }
+
+    /// Returns the frequency in Hz.
+    pub fn value(&self) -> Hertz {
+        self.0
+    }
+
+    /// Returns the wavelength in vacuum in km.
+    pub fn wavelength(&self) -> f64 {
+        SPEED_OF_LIGHT / self.0
+    }
+
+    /// Returns the IEEE band containing the frequency or `None` if the frequency is below 3 MHz
+    /// or at or above 300 GHz.
+    ///
+    /// The lower edge of each band is inclusive and the upper edge is exclusive, e.g. 2 GHz is
+    /// classified as S band.
+    pub fn band(&self) -> Option<FrequencyBand> {
+        if self.0.is_nan() || self.0 >= FrequencyBand::UPPER_EDGE {
+            return None;
+        }
+        FrequencyBand::EDGES
+            .iter()
+            .rev()
+            .find(|(edge, _)| self.0 >= *edge)
+            .map(|&(_, band)| band)
+    }
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)?;
+        write!(f, " Hz")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::below_hf(Frequency::mhz(2.999), None)]
+    #[case::hf(Frequency::mhz(3.0), Some(FrequencyBand::Hf))]
+    #[case::vhf(Frequency::mhz(145.8), Some(FrequencyBand::Vhf))]
+    #[case::uhf(Frequency::mhz(437.5), Some(FrequencyBand::Uhf))]
+    #[case::l(Frequency::mhz(1575.42), Some(FrequencyBand::L))]
+    #[case::s_lower_edge(Frequency::ghz(2.0), Some(FrequencyBand::S))]
+    #[case::s(Frequency::ghz(2.2), Some(FrequencyBand::S))]
+    #[case::c(Frequency::ghz(6.0), Some(FrequencyBand::C))]
+    #[case::x(Frequency::ghz(8.4), Some(FrequencyBand::X))]
+    #[case::ku(Frequency::ghz(14.0), Some(FrequencyBand::Ku))]
+    #[case::k(Frequency::ghz(26.999), Some(FrequencyBand::K))]
+    #[case::ka(Frequency::ghz(32.0), Some(FrequencyBand::Ka))]
+    #[case::v(Frequency::ghz(40.0), Some(FrequencyBand::V))]
+    #[case::w(Frequency::ghz(94.0), Some(FrequencyBand::W))]
+    #[case::g(Frequency::ghz(299.0), Some(FrequencyBand::G))]
+    #[case::above_g(Frequency::ghz(300.0), None)]
+    #[case::nan(Frequency::hz(f64::NAN), None)]
+    fn test_frequency_band(#[case] frequency: Frequency, #[case] expected: Option<FrequencyBand>) {
+        assert_eq!(frequency.band(), expected);
+        if let Some(band) = expected {
+            let (lower, upper) = band.edges();
+            assert!(lower <= frequency.value() && frequency.value() < upper);
+        }
+    }
+
+    #[test]
+    fn test_frequency_band_edges() {
+        assert_eq!(FrequencyBand::Hf.edges(), (3e6, 30e6));
+        assert_eq!(FrequencyBand::Ka.edges(), (27e9, 40e9));
+        assert_eq!(FrequencyBand::G.edges(), (110e9, 300e9));
+        assert_eq!(FrequencyBand::Ku.to_string(), "Ku");
+        assert_eq!(FrequencyBand::Uhf.to_string(), "UHF");
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_eq!(Frequency::khz(2.2e6), Frequency::ghz(2.2));
+        assert_eq!(Frequency::mhz(2200.0).value(), 2.2e9);
+        assert_eq!(Frequency::new(1e9).to_string(), "1000000000 Hz");
+        // The S-band downlink wavelength of about 13.6 cm
+        assert_float_eq!(
+            Frequency::ghz(2.2).wavelength(),
+            1.3626929909090909e-4,
+            rel <= 1e-15
+        );
+    }
+}
@@ -14,8 +14,12 @@ pub type Microarcseconds = f64;
 
 pub type Radians = f64;
 
+pub type Degrees = f64;
+
 pub type Seconds = f64;
 
 pub type JulianCenturies = f64;
 
 pub type Days = f64;
+
+pub type Hertz = f64;
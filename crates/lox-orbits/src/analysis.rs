@@ -1,4 +1,4 @@
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_PI_2, PI, SQRT_2, TAU};
 
 /*
  * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
@@ -7,21 +7,33 @@ use std::f64::consts::PI;
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use lox_bodies::{Origin, RotationalElements, Spheroid, TrySpheroid};
+use std::cell::RefCell;
+
+use glam::DVec3;
+use itertools::Itertools;
+use libm::{erf, erfc};
+use lox_bodies::{Origin, RotationalElements, Spheroid, Sun, TrySpheroid};
+use lox_ephem::Ephemeris;
+use lox_io::ndm::common::EpochType;
+use lox_io::ndm::tdm::TdmObservation;
+use lox_math::constants::f64::physics::SPEED_OF_LIGHT;
+use lox_math::linear_algebra::dmat6::DMat6;
 use lox_math::roots::Brent;
 use lox_math::series::{Series, SeriesError};
-use lox_math::types::units::Radians;
+use lox_math::types::units::{Hertz, Radians};
+use lox_time::calendar_dates::CalendarDate;
 use lox_time::deltas::TimeDelta;
-use lox_time::time_scales::Tdb;
+use lox_time::time_of_day::CivilTime;
+use lox_time::time_scales::{Tdb, TimeScale};
 use lox_time::transformations::TryToScale;
-use lox_time::TimeLike;
+use lox_time::{Time, TimeLike};
 use thiserror::Error;
 
-use crate::events::{find_windows, Window};
+use crate::events::{find_windows, golden_section_max, with_grazing_maxima, Window};
 use crate::frames::{
-    BodyFixed, DynFrame, FrameTransformationProvider, Icrf, TryRotateTo, TryToFrame,
+    BodyFixed, DynFrame, FrameTransformationProvider, Icrf, ReferenceFrame, TryRotateTo, TryToFrame,
 };
-use crate::ground::{DynGroundLocation, GroundLocation};
+use crate::ground::{DynGroundLocation, GroundLocation, Observables};
 use crate::states::State;
 use crate::trajectories::{DynTrajectory, Trajectory};
 
@@ -63,6 +75,33 @@ impl ElevationMask {
     }
 }
 
+/// Implementers of `MinElevation` define the minimum elevation in radians above which a spacecraft
+/// is considered visible from a ground location as a function of azimuth.
+///
+/// Besides [ElevationMask], `MinElevation` is implemented for constant elevations and for closures,
+/// e.g. to model terrain obstructions.
+pub trait MinElevation {
+    fn min_elevation(&self, azimuth: Radians) -> Radians;
+}
+
+impl MinElevation for ElevationMask {
+    fn min_elevation(&self, azimuth: Radians) -> Radians {
+        ElevationMask::min_elevation(self, azimuth)
+    }
+}
+
+impl MinElevation for f64 {
+    fn min_elevation(&self, _azimuth: Radians) -> Radians {
+        *self
+    }
+}
+
+impl<F: Fn(Radians) -> Radians> MinElevation for F {
+    fn min_elevation(&self, azimuth: Radians) -> Radians {
+        self(azimuth)
+    }
+}
+
 pub fn elevation_dyn<T: TimeLike + TryToScale<Tdb, P> + Clone, P: FrameTransformationProvider>(
     time: T,
     gs: &DynGroundLocation,
@@ -117,10 +156,11 @@ pub fn elevation<
     T: TimeLike + TryToScale<Tdb, P> + Clone,
     O: Origin + TrySpheroid + RotationalElements + Clone,
     P: FrameTransformationProvider,
+    M: MinElevation + ?Sized,
 >(
     time: T,
     gs: &GroundLocation<O>,
-    mask: &ElevationMask,
+    mask: &M,
     sc: &Trajectory<T, O, Icrf>,
     provider: &P,
 ) -> Radians {
@@ -135,10 +175,11 @@ pub fn visibility<
     T: TimeLike + TryToScale<Tdb, P> + Clone,
     O: Origin + Spheroid + RotationalElements + Clone,
     P: FrameTransformationProvider,
+    M: MinElevation + ?Sized,
 >(
     times: &[T],
     gs: &GroundLocation<O>,
-    mask: &ElevationMask,
+    mask: &M,
     sc: &Trajectory<T, O, Icrf>,
     provider: &P,
 ) -> Vec<Window<T>> {
@@ -169,8 +210,755 @@ pub fn visibility<
     )
 }
 
+/// Returns the frequency observed for a signal transmitted at `frequency` between a ground location
+/// and a spacecraft moving apart at `range_rate` in km/s.
+///
+/// The range rate is positive if the distance increases. Hence, an approaching spacecraft is
+/// observed at a higher and a receding spacecraft at a lower frequency. The relativistic
+/// longitudinal Doppler formula is used.
+pub fn doppler_shift(frequency: Hertz, range_rate: f64) -> Hertz {
+    let beta = range_rate / SPEED_OF_LIGHT;
+    frequency * ((1.0 - beta) / (1.0 + beta)).sqrt()
+}
+
+/// Applies the stellar aberration due to the motion of the observer at `observer_velocity` in km/s
+/// to `direction`.
+///
+/// `direction` is the direction from the observer to the target in the frame in which the
+/// velocity is given, e.g. the light-time corrected position from
+/// [Ephemeris::position_apparent] together with the barycentric velocity of the observer, which
+/// results in the "LT+S" apparent position of SPICE. The aberrated direction is computed with the
+/// relativistic formula and returned with the length of `direction`.
+pub fn apply_aberration(direction: DVec3, observer_velocity: DVec3) -> DVec3 {
+    let u = direction.normalize();
+    let beta = observer_velocity / SPEED_OF_LIGHT;
+    let gamma_inv = (1.0 - beta.length_squared()).sqrt();
+    let u_beta = u.dot(beta);
+    let aberrated = (gamma_inv * u + beta + u_beta / (1.0 + gamma_inv) * beta) / (1.0 + u_beta);
+    aberrated.normalize() * direction.length()
+}
+
+/// Returns the observed frequencies at the ground location `gs` for a signal transmitted at
+/// `frequency` by the spacecraft `sc` at the given `times`, see [doppler_shift].
+pub fn doppler<
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    O: Origin + TrySpheroid + RotationalElements + Clone,
+    P: FrameTransformationProvider,
+>(
+    frequency: Hertz,
+    times: &[T],
+    gs: &GroundLocation<O>,
+    sc: &Trajectory<T, O, Icrf>,
+    provider: &P,
+) -> Vec<Hertz> {
+    let body_fixed = BodyFixed(gs.origin());
+    times
+        .iter()
+        .map(|time| {
+            let sc = sc.interpolate_at(time.clone());
+            let sc = sc.try_to_frame(body_fixed.clone(), provider).unwrap();
+            let obs = gs.observables(sc);
+            doppler_shift(frequency, obs.range_rate())
+        })
+        .collect()
+}
+
+/// A pass of a spacecraft over a ground location.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pass<T: TimeLike> {
+    window: Window<T>,
+    tca: T,
+    max_elevation: Radians,
+}
+
+impl<T: TimeLike> Pass<T> {
+    pub fn new(window: Window<T>, tca: T, max_elevation: Radians) -> Self {
+        Self {
+            window,
+            tca,
+            max_elevation,
+        }
+    }
+
+    pub fn window(&self) -> &Window<T> {
+        &self.window
+    }
+
+    /// The acquisition of signal, i.e. the time when the spacecraft rises above the mask.
+    pub fn aos(&self) -> &T {
+        self.window.start()
+    }
+
+    /// The time of closest approach, i.e. the time of maximum elevation.
+    pub fn tca(&self) -> &T {
+        &self.tca
+    }
+
+    /// The loss of signal, i.e. the time when the spacecraft sets below the mask.
+    pub fn los(&self) -> &T {
+        self.window.end()
+    }
+
+    /// The maximum elevation during the pass in radians.
+    pub fn max_elevation(&self) -> Radians {
+        self.max_elevation
+    }
+}
+
+impl<B> GroundLocation<B>
+where
+    B: Origin + Spheroid + RotationalElements + Clone,
+{
+    /// Predicts the passes of `trajectory` above the elevation `mask` of the ground location.
+    ///
+    /// The acquisition and loss of signal are refined by root-finding on the elevation above the
+    /// mask and the time of closest approach by maximising the elevation during each pass.
+    pub fn visibility_windows<T, M, P>(
+        &self,
+        trajectory: &Trajectory<T, B, Icrf>,
+        mask: M,
+        provider: &P,
+    ) -> Vec<Pass<T>>
+    where
+        T: TimeLike + TryToScale<Tdb, P> + Clone,
+        M: MinElevation,
+        P: FrameTransformationProvider,
+    {
+        let times = trajectory.times();
+        let windows = visibility(&times, self, &mask, trajectory, provider);
+        let start = trajectory.start_time();
+        let seconds = |t: &T| (t.clone() - start.clone()).to_decimal_seconds();
+        let steps: Vec<f64> = times.iter().map(seconds).collect();
+        let raw_elevation = |t: f64| {
+            elevation(
+                start.clone() + TimeDelta::from_decimal_seconds(t).unwrap(),
+                self,
+                &0.0,
+                trajectory,
+                provider,
+            )
+        };
+        windows
+            .into_iter()
+            .map(|window| {
+                let (t0, t1) = (seconds(window.start()), seconds(window.end()));
+                // Bracket the maximum by the best sample within the pass and its neighbours
+                let mut candidates = vec![t0];
+                candidates.extend(steps.iter().copied().filter(|&t| t > t0 && t < t1));
+                candidates.push(t1);
+                let (best, _) = candidates
+                    .iter()
+                    .map(|&t| raw_elevation(t))
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .unwrap();
+                let lower = candidates[best.saturating_sub(1)];
+                let upper = candidates[(best + 1).min(candidates.len() - 1)];
+                let (tca, max_elevation) = golden_section_max(raw_elevation, (lower, upper));
+                let tca = start.clone() + TimeDelta::from_decimal_seconds(tca).unwrap();
+                Pass::new(window, tca, max_elevation)
+            })
+            .collect()
+    }
+}
+
+/// Illumination condition of a spacecraft with respect to an occulting body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Eclipse {
+    /// The solar disk is fully visible.
+    None,
+    /// The solar disk is partially hidden by the occulting body. This includes annular eclipses
+    /// where the occulting body appears smaller than the Sun.
+    Penumbra,
+    /// The solar disk is fully hidden by the occulting body.
+    Umbra,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EclipseInterval<T: TimeLike> {
+    window: Window<T>,
+    eclipse: Eclipse,
+}
+
+impl<T: TimeLike> EclipseInterval<T> {
+    pub fn new(window: Window<T>, eclipse: Eclipse) -> Self {
+        Self { window, eclipse }
+    }
+
+    pub fn window(&self) -> &Window<T> {
+        &self.window
+    }
+
+    pub fn start(&self) -> &T {
+        self.window.start()
+    }
+
+    pub fn end(&self) -> &T {
+        self.window.end()
+    }
+
+    pub fn eclipse(&self) -> Eclipse {
+        self.eclipse
+    }
+}
+
+/// Apparent radii of the Sun and the occulting body and their angular separation as seen from
+/// the spacecraft.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ShadowGeometry {
+    sun_radius: f64,
+    body_radius: f64,
+    separation: f64,
+}
+
+impl ShadowGeometry {
+    const NAN: Self = Self {
+        sun_radius: f64::NAN,
+        body_radius: f64::NAN,
+        separation: f64::NAN,
+    };
+
+    pub(crate) fn new(sc_sun: DVec3, sc_body: DVec3, sun_radius: f64, body_radius: f64) -> Self {
+        let sun_radius = (sun_radius / sc_sun.length()).min(1.0).asin();
+        let body_radius = (body_radius / sc_body.length()).min(1.0).asin();
+        let separation = (-sc_sun).angle_between(-sc_body);
+        Self {
+            sun_radius,
+            body_radius,
+            separation,
+        }
+    }
+
+    fn from_state<T, O, B, E>(
+        state: &State<T, O, Icrf>,
+        occulting_body: &B,
+        ephemeris: &E,
+    ) -> Result<Self, E::Error>
+    where
+        T: TimeLike + Clone,
+        O: Origin + Clone,
+        B: Origin + Spheroid + Clone,
+        E: Ephemeris,
+    {
+        let sc_sun = state.to_origin(Sun, ephemeris)?.position();
+        let sc_body = if state.origin().id() == occulting_body.id() {
+            state.position()
+        } else {
+            state
+                .to_origin(occulting_body.clone(), ephemeris)?
+                .position()
+        };
+        Ok(Self::new(
+            sc_sun,
+            sc_body,
+            Sun.equatorial_radius(),
+            occulting_body.equatorial_radius(),
+        ))
+    }
+
+    fn eclipse(&self) -> Eclipse {
+        if self.umbra() > 0.0 {
+            Eclipse::Umbra
+        } else if self.penumbra() > 0.0 {
+            Eclipse::Penumbra
+        } else {
+            Eclipse::None
+        }
+    }
+
+    /// Positive while the spacecraft is within the penumbra or umbra.
+    fn penumbra(&self) -> f64 {
+        self.sun_radius + self.body_radius - self.separation
+    }
+
+    /// Positive while the spacecraft is within the umbra.
+    fn umbra(&self) -> f64 {
+        self.body_radius - self.sun_radius - self.separation
+    }
+
+    /// Returns the visible fraction of the solar disk, which varies continuously between one in
+    /// sunlight and zero in the umbra.
+    pub(crate) fn illumination(&self) -> f64 {
+        let (a, b, c) = (self.sun_radius, self.body_radius, self.separation);
+        if c >= a + b {
+            1.0
+        } else if c <= b - a {
+            0.0
+        } else if c <= a - b {
+            // Annular eclipse
+            1.0 - (b / a).powi(2)
+        } else {
+            // Area of the overlap of both disks (Montenbruck & Gill, Satellite Orbits, 3.4.2)
+            let x = (c.powi(2) + a.powi(2) - b.powi(2)) / (2.0 * c);
+            let y = (a.powi(2) - x.powi(2)).max(0.0).sqrt();
+            let area = a.powi(2) * (x / a).clamp(-1.0, 1.0).acos()
+                + b.powi(2) * ((c - x) / b).clamp(-1.0, 1.0).acos()
+                - c * y;
+            1.0 - area / (PI * a.powi(2))
+        }
+    }
+}
+
+/// Returns the beta angle of the orbit of `state`, i.e. the angle between the orbit plane and
+/// `sun_direction`.
+///
+/// The beta angle is positive if the Sun lies on the side of the orbit normal `r×v`, i.e. north of
+/// the orbit plane for prograde orbits, and within `[-π/2, π/2]`. `sun_direction` does not need to
+/// be normalized but must be given in the same inertial frame as `state`.
+pub fn beta_angle<T, O, R>(state: &State<T, O, R>, sun_direction: DVec3) -> Radians
+where
+    T: TimeLike,
+    O: Origin,
+    R: ReferenceFrame,
+{
+    let normal = state.position().cross(state.velocity()).normalize();
+    normal
+        .dot(sun_direction.normalize())
+        .clamp(-1.0, 1.0)
+        .asin()
+}
+
+/// Returns the beta angle of the orbit of `state` with the direction from its origin to the Sun
+/// obtained from `ephemeris`, see [beta_angle].
+///
+/// # Errors
+///
+/// Returns the error of the `ephemeris` if the position of the Sun is not available.
+pub fn solar_beta_angle<T, O, E>(
+    state: &State<T, O, Icrf>,
+    ephemeris: &E,
+) -> Result<Radians, E::Error>
+where
+    T: TimeLike + Clone,
+    O: Origin + Clone,
+    E: Ephemeris,
+{
+    let origin = State::new(state.time(), DVec3::ZERO, DVec3::ZERO, state.origin(), Icrf);
+    let sun_direction = -origin.to_origin(Sun, ephemeris)?.position();
+    Ok(beta_angle(state, sun_direction))
+}
+
+/// Finds the intervals during which `trajectory` is sunlit or within the penumbra or umbra of
+/// `occulting_body`.
+///
+/// A conical shadow model based on the equatorial radius of the occulting body and the finite
+/// apparent size of the solar disk is used. The returned intervals partition the time span of the
+/// trajectory and their boundaries are refined by root-finding. Grazing passes through the shadow
+/// which fall between the trajectory's samples are also detected.
+///
+/// # Errors
+///
+/// Returns the error of the `ephemeris` if the position of the Sun or the occulting body is not
+/// available during the time span of the trajectory.
+pub fn eclipse<T, O, B, E>(
+    trajectory: &Trajectory<T, O, Icrf>,
+    ephemeris: &E,
+    occulting_body: B,
+) -> Result<Vec<EclipseInterval<T>>, E::Error>
+where
+    T: TimeLike + Clone,
+    O: Origin + Clone,
+    B: Origin + Spheroid + Clone,
+    E: Ephemeris,
+{
+    let start = trajectory.start_time();
+    let end = trajectory.end_time();
+    let steps: Vec<f64> = trajectory
+        .times()
+        .into_iter()
+        .map(|t| (t - start.clone()).to_decimal_seconds())
+        .collect();
+    let error: RefCell<Option<E::Error>> = RefCell::new(None);
+    let geometry = |t: f64| {
+        let state = trajectory.interpolate(TimeDelta::from_decimal_seconds(t).unwrap());
+        match ShadowGeometry::from_state(&state, &occulting_body, ephemeris) {
+            Ok(geometry) => geometry,
+            Err(err) => {
+                error.borrow_mut().get_or_insert(err);
+                ShadowGeometry::NAN
+            }
+        }
+    };
+    let penumbra = |t: f64| geometry(t).penumbra();
+    let umbra = |t: f64| geometry(t).umbra();
+
+    let root_finder = Brent::default();
+    let penumbra_steps = with_grazing_maxima(penumbra, &steps);
+    let penumbra_windows = find_windows(
+        penumbra,
+        start.clone(),
+        end.clone(),
+        &penumbra_steps,
+        root_finder,
+    );
+    let umbra_steps = with_grazing_maxima(umbra, &steps);
+    let umbra_windows = find_windows(umbra, start.clone(), end.clone(), &umbra_steps, root_finder);
+    if let Some(err) = error.into_inner() {
+        return Err(err);
+    }
+
+    let seconds = |t: &T| (t.clone() - start.clone()).to_decimal_seconds();
+    let to_spans = |windows: &[Window<T>]| -> Vec<(f64, f64)> {
+        windows
+            .iter()
+            .map(|w| (seconds(w.start()), seconds(w.end())))
+            .collect()
+    };
+    let penumbra_spans = to_spans(&penumbra_windows);
+    let umbra_spans = to_spans(&umbra_windows);
+
+    let mut boundaries: Vec<(f64, T)> = vec![(0.0, start.clone()), (seconds(&end), end.clone())];
+    for window in penumbra_windows.iter().chain(umbra_windows.iter()) {
+        boundaries.push((seconds(window.start()), window.start().clone()));
+        boundaries.push((seconds(window.end()), window.end().clone()));
+    }
+    boundaries.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    boundaries.dedup_by(|(a, _), (b, _)| a == b);
+
+    let contains = |spans: &[(f64, f64)], t: f64| spans.iter().any(|&(t0, t1)| t0 <= t && t <= t1);
+    let mut intervals: Vec<EclipseInterval<T>> = vec![];
+    for ((t0, start), (t1, end)) in boundaries.into_iter().tuple_windows() {
+        let mid = (t0 + t1) / 2.0;
+        let eclipse = if contains(&umbra_spans, mid) {
+            Eclipse::Umbra
+        } else if contains(&penumbra_spans, mid) {
+            Eclipse::Penumbra
+        } else {
+            Eclipse::None
+        };
+        match intervals.last_mut() {
+            Some(last) if last.eclipse == eclipse => {
+                last.window = Window::new(last.start().clone(), end)
+            }
+            _ => intervals.push(EclipseInterval::new(Window::new(start, end), eclipse)),
+        }
+    }
+    Ok(intervals)
+}
+
+/// A complete observation of a spacecraft from a ground location at a single epoch, i.e. the
+/// record which a tracking station would log.
+#[derive(Clone, Debug)]
+pub struct Observation<T: TimeLike> {
+    time: T,
+    observables: Observables,
+    min_elevation: Radians,
+    eclipse: Eclipse,
+}
+
+impl<T: TimeLike> Observation<T> {
+    pub fn time(&self) -> &T {
+        &self.time
+    }
+
+    pub fn observables(&self) -> &Observables {
+        &self.observables
+    }
+
+    pub fn azimuth(&self) -> Radians {
+        self.observables.azimuth()
+    }
+
+    pub fn elevation(&self) -> Radians {
+        self.observables.elevation()
+    }
+
+    pub fn range(&self) -> f64 {
+        self.observables.range()
+    }
+
+    pub fn range_rate(&self) -> f64 {
+        self.observables.range_rate()
+    }
+
+    /// The illumination condition of the spacecraft with respect to the central body of the
+    /// ground location.
+    pub fn eclipse(&self) -> Eclipse {
+        self.eclipse
+    }
+
+    /// Returns `true` if the solar disk is fully visible from the spacecraft.
+    pub fn is_sunlit(&self) -> bool {
+        self.eclipse == Eclipse::None
+    }
+
+    /// Returns `true` if the spacecraft is at or above the elevation mask of the ground location.
+    pub fn is_visible(&self) -> bool {
+        self.elevation() >= self.min_elevation
+    }
+}
+
+impl<S: TimeScale> Observation<Time<S>> {
+    /// Converts the observation into CCSDS TDM observations of the azimuth (`ANGLE_1`) and the
+    /// elevation (`ANGLE_2`) in degrees, the range (`RANGE`) in km, and the range rate
+    /// (`DOPPLER_INSTANTANEOUS`) in km/s.
+    ///
+    /// The metadata of the enclosing TDM segment must declare `ANGLE_TYPE = AZEL`,
+    /// `RANGE_UNITS = km`, and the time scale of the observation as `TIME_SYSTEM`.
+    pub fn to_tdm(&self) -> Vec<TdmObservation> {
+        let epoch = format!("{}T{:.6}", self.time.date(), self.time.time());
+        [
+            ("ANGLE_1", self.azimuth().to_degrees().rem_euclid(360.0)),
+            ("ANGLE_2", self.elevation().to_degrees()),
+            ("RANGE", self.range()),
+            ("DOPPLER_INSTANTANEOUS", self.range_rate()),
+        ]
+        .into_iter()
+        .map(|(keyword, value)| TdmObservation {
+            epoch: EpochType(epoch.clone()),
+            keyword: keyword.to_string(),
+            value,
+        })
+        .collect()
+    }
+}
+
+/// Observes the spacecraft `sc` from the ground location `gs` at `time`.
+///
+/// The illumination is determined with the conical shadow model of [eclipse] and the central body
+/// of the ground location as the occulting body.
+///
+/// # Errors
+///
+/// Returns the error of the `ephemeris` if the position of the Sun is not available at `time`.
+pub fn observe<T, O, M, E, P>(
+    time: T,
+    gs: &GroundLocation<O>,
+    mask: &M,
+    sc: &Trajectory<T, O, Icrf>,
+    ephemeris: &E,
+    provider: &P,
+) -> Result<Observation<T>, E::Error>
+where
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    M: MinElevation + ?Sized,
+    E: Ephemeris,
+    P: FrameTransformationProvider,
+{
+    let state = sc.interpolate_at(time.clone());
+    let eclipse = ShadowGeometry::from_state(&state, &gs.origin(), ephemeris)?.eclipse();
+    let state = state
+        .try_to_frame(BodyFixed(gs.origin()), provider)
+        .unwrap();
+    let observables = gs.observables(state);
+    let min_elevation = mask.min_elevation(observables.azimuth());
+    Ok(Observation {
+        time,
+        observables,
+        min_elevation,
+        eclipse,
+    })
+}
+
+/// Samples observations of the spacecraft `sc` from the ground location `gs` across `pass` every
+/// `cadence`, see [observe].
+///
+/// The observations start at the acquisition of signal and the loss of signal is always included
+/// as the final observation, even if it does not fall on the cadence.
+///
+/// # Errors
+///
+/// Returns the error of the `ephemeris` if the position of the Sun is not available during the
+/// pass.
+///
+/// # Panics
+///
+/// Panics if `cadence` is not positive.
+pub fn observe_pass<T, O, M, E, P>(
+    gs: &GroundLocation<O>,
+    mask: &M,
+    sc: &Trajectory<T, O, Icrf>,
+    pass: &Window<T>,
+    cadence: TimeDelta,
+    ephemeris: &E,
+    provider: &P,
+) -> Result<Vec<Observation<T>>, E::Error>
+where
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    M: MinElevation + ?Sized,
+    E: Ephemeris,
+    P: FrameTransformationProvider,
+{
+    assert!(cadence.is_positive(), "cadence must be positive");
+    let duration = pass.duration().to_decimal_seconds();
+    let cadence = cadence.to_decimal_seconds();
+    let mut times: Vec<T> = (0..)
+        .map(|i| i as f64 * cadence)
+        .take_while(|&t| t < duration)
+        .map(|t| pass.start().clone() + TimeDelta::from_decimal_seconds(t).unwrap())
+        .collect();
+    times.push(pass.end().clone());
+    times
+        .into_iter()
+        .map(|time| observe(time, gs, mask, sc, ephemeris, provider))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq)]
+pub enum CollisionProbabilityError {
+    #[error("the combined hard-body radius must be positive but was {0}")]
+    InvalidRadius(f64),
+    #[error("the relative velocity of the objects is zero")]
+    ZeroRelativeVelocity,
+    #[error("the combined position covariance projected into the encounter plane is not positive definite")]
+    NonPositiveDefiniteCovariance,
+}
+
+/// Returns the probability of collision (Pc) of two objects at their time of closest approach
+/// with the two-dimensional method of Foster and Chan.
+///
+/// The combined position covariance of both objects is projected into the encounter plane
+/// perpendicular to the relative velocity and the resulting bivariate normal distribution of the
+/// miss vector is integrated over the disk with the combined hard-body `radius` in km. Positions
+/// are given in km and the covariances in km² and km²/s² in the inertial frame.
+///
+/// The distribution is integrated in its principal axes. The integral along the minor axis is
+/// evaluated analytically with the error function, so that highly elongated covariances and miss
+/// distances close to zero do not degrade the accuracy.
+pub fn collision_probability<T, O>(
+    state1: &State<T, O, Icrf>,
+    covariance1: &DMat6,
+    state2: &State<T, O, Icrf>,
+    covariance2: &DMat6,
+    radius: f64,
+) -> Result<f64, CollisionProbabilityError>
+where
+    T: TimeLike,
+    O: Origin,
+{
+    if radius.is_nan() || radius <= 0.0 {
+        return Err(CollisionProbabilityError::InvalidRadius(radius));
+    }
+    let r = state2.position() - state1.position();
+    let v = state2.velocity() - state1.velocity();
+    if v.length_squared() == 0.0 {
+        return Err(CollisionProbabilityError::ZeroRelativeVelocity);
+    }
+
+    // Encounter frame with the miss vector along the x-axis
+    let z = v.normalize();
+    let r_perp = r - r.dot(z) * z;
+    let x = if r_perp.length() > 1e-12 * r.length().max(radius) {
+        r_perp.normalize()
+    } else {
+        z.any_orthonormal_vector()
+    };
+    let y = z.cross(x);
+    let miss = r_perp.length();
+
+    let covariance = covariance1.block(0, 0) + covariance2.block(0, 0);
+    let cxx = x.dot(covariance * x);
+    let cyy = y.dot(covariance * y);
+    let cxy = x.dot(covariance * y);
+
+    // Principal axes of the projected covariance
+    let mean = (cxx + cyy) / 2.0;
+    let deviation = (((cxx - cyy) / 2.0).powi(2) + cxy.powi(2)).sqrt();
+    let (major, minor) = (mean + deviation, mean - deviation);
+    if minor.is_nan() || minor <= 0.0 {
+        return Err(CollisionProbabilityError::NonPositiveDefiniteCovariance);
+    }
+    let angle = 0.5 * (2.0 * cxy).atan2(cxx - cyy);
+    let (sigma_u, sigma_w) = (major.sqrt(), minor.sqrt());
+    let (mu_u, mu_w) = (miss * angle.cos(), -miss * angle.sin());
+
+    // Integrate along the major axis with `u = radius * sin(theta)` to remove the square-root
+    // singularities at the edges of the disk
+    let integrand = |theta: f64| {
+        let u = radius * theta.sin();
+        let half_width = radius * theta.cos();
+        let density = (-0.5 * ((u - mu_u) / sigma_u).powi(2)).exp() / (sigma_u * (TAU).sqrt());
+        let scale = SQRT_2 * sigma_w;
+        let strip = normal_interval((-half_width - mu_w) / scale, (half_width - mu_w) / scale);
+        density * strip * half_width
+    };
+    let mut breakpoints = vec![-FRAC_PI_2, FRAC_PI_2];
+    if mu_u.abs() < radius {
+        breakpoints.push((mu_u / radius).asin());
+    }
+    breakpoints.extend((1..16).map(|i| -FRAC_PI_2 + PI * i as f64 / 16.0));
+    breakpoints.sort_by(f64::total_cmp);
+    // Refine each interval relative to a coarse estimate of the total probability
+    let estimate: f64 = breakpoints
+        .iter()
+        .tuple_windows()
+        .map(|(&a, &b)| {
+            (b - a) / 6.0 * (integrand(a) + 4.0 * integrand((a + b) / 2.0) + integrand(b))
+        })
+        .sum();
+    let tolerance = 1e-12 * estimate.max(f64::MIN_POSITIVE) / breakpoints.len() as f64;
+    let probability: f64 = breakpoints
+        .iter()
+        .tuple_windows()
+        .map(|(&a, &b)| adaptive_simpson(&integrand, a, b, tolerance, 30))
+        .sum();
+    Ok(probability.clamp(0.0, 1.0))
+}
+
+/// Returns `(erf(b) - erf(a)) / 2` for `a <= b` without cancellation in the tails.
+fn normal_interval(a: f64, b: f64) -> f64 {
+    if a >= 0.0 {
+        0.5 * (erfc(a) - erfc(b))
+    } else if b <= 0.0 {
+        0.5 * (erfc(-b) - erfc(-a))
+    } else {
+        0.5 * (erf(b) - erf(a))
+    }
+}
+
+fn adaptive_simpson<F: Fn(f64) -> f64>(
+    func: &F,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    depth: u32,
+) -> f64 {
+    fn simpson<F: Fn(f64) -> f64>(
+        func: &F,
+        (a, fa): (f64, f64),
+        (b, fb): (f64, f64),
+        (m, fm): (f64, f64),
+        whole: f64,
+        tolerance: f64,
+        depth: u32,
+    ) -> f64 {
+        let (lm, rm) = ((a + m) / 2.0, (m + b) / 2.0);
+        let (flm, frm) = (func(lm), func(rm));
+        let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+        let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+        let delta = left + right - whole;
+        if depth == 0 || delta.abs() <= 15.0 * tolerance {
+            return left + right + delta / 15.0;
+        }
+        simpson(
+            func,
+            (a, fa),
+            (m, fm),
+            (lm, flm),
+            left,
+            tolerance / 2.0,
+            depth - 1,
+        ) + simpson(
+            func,
+            (m, fm),
+            (b, fb),
+            (rm, frm),
+            right,
+            tolerance / 2.0,
+            depth - 1,
+        )
+    }
+    let m = (a + b) / 2.0;
+    let (fa, fb, fm) = (func(a), func(b), func(m));
+    let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+    simpson(func, (a, fa), (b, fb), (m, fm), whole, tolerance, depth)
+}
+
 #[cfg(test)]
 mod tests {
+    use glam::DMat3;
     use lox_bodies::Earth;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
@@ -178,8 +966,15 @@ mod tests {
     use lox_time::transformations::ToTai;
     use lox_time::utc::Utc;
     use lox_time::Time;
+    use std::f64::consts::{FRAC_PI_2, TAU};
     use std::iter::zip;
 
+    use lox_bodies::PointMass;
+    use lox_ephem::EphemerisId;
+    use lox_io::ndm::kvn::KvnDeserializer;
+    use lox_io::ndm::tdm::{TdmBody, TdmData, TdmHeader, TdmMetadata, TdmSegment, TdmType};
+    use lox_math::constants::f64::physics::ASTRONOMICAL_UNIT;
+
     use crate::frames::NoOpFrameTransformationProvider;
 
     use super::*;
@@ -245,6 +1040,241 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_visibility_windows() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let expected = contacts();
+        let passes = gs.visibility_windows(&sc, 0.0, &NoOpFrameTransformationProvider);
+        assert_eq!(passes.len(), expected.len());
+        for (pass, expected) in zip(&passes, expected) {
+            assert_close!(pass.aos(), expected.start(), 0.0, 1e-4);
+            assert_close!(pass.los(), expected.end(), 0.0, 1e-4);
+            assert!(pass.tca() > pass.aos() && pass.tca() < pass.los());
+            let max_elevation = elevation(
+                *pass.tca(),
+                &gs,
+                &0.0,
+                &sc,
+                &NoOpFrameTransformationProvider,
+            );
+            assert_close!(pass.max_elevation(), max_elevation);
+            for time in sc.times() {
+                if time > *pass.aos() && time < *pass.los() {
+                    let el = elevation(time, &gs, &0.0, &sc, &NoOpFrameTransformationProvider);
+                    assert!(el <= pass.max_elevation());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_visibility_windows_variable_mask() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let fixed = gs.visibility_windows(&sc, 0.0, &NoOpFrameTransformationProvider);
+        // Terrain obstruction which is highest towards the north
+        let mask = |azimuth: Radians| (5.0 + 2.0 * azimuth.cos()).to_radians();
+        let masked = gs.visibility_windows(&sc, mask, &NoOpFrameTransformationProvider);
+        assert_eq!(masked.len(), fixed.len());
+        for (masked, fixed) in zip(&masked, &fixed) {
+            assert!(masked.window().duration() < fixed.window().duration());
+            assert_close!(masked.max_elevation(), fixed.max_elevation(), 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_doppler_shift() {
+        let frequency = 2.2e9;
+        assert_eq!(doppler_shift(frequency, 0.0), frequency);
+        // Approaching spacecraft
+        let approaching = doppler_shift(frequency, -7.0);
+        assert!(approaching > frequency);
+        assert_close!(approaching - frequency, 51366.3, 0.0, 0.1);
+        // Receding spacecraft
+        let receding = doppler_shift(frequency, 7.0);
+        assert!(receding < frequency);
+        assert_close!(frequency - receding, 51365.1, 0.0, 0.1);
+    }
+
+    #[test]
+    fn test_doppler() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let frequency = 8.4e9;
+        let times: Vec<Time<Tai>> = sc.times().into_iter().step_by(100).collect();
+        let actual = doppler(
+            frequency,
+            &times,
+            &gs,
+            &sc,
+            &NoOpFrameTransformationProvider,
+        );
+        assert_eq!(actual.len(), times.len());
+        for (time, actual) in zip(&times, actual) {
+            let state = sc
+                .interpolate_at(*time)
+                .try_to_frame(BodyFixed(Earth), &NoOpFrameTransformationProvider)
+                .unwrap();
+            let range_rate = gs.observables(state).range_rate();
+            assert_eq!(actual, doppler_shift(frequency, range_rate));
+            assert_eq!(actual > frequency, range_rate < 0.0);
+        }
+    }
+
+    #[test]
+    fn test_observe() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let pass = &contacts()[0];
+        let time = *pass.start() + TimeDelta::from_decimal_seconds(600.0).unwrap();
+        let obs = observe(
+            time,
+            &gs,
+            &0.0,
+            &sc,
+            &MockEphemeris,
+            &NoOpFrameTransformationProvider,
+        )
+        .unwrap();
+        assert_eq!(*obs.time(), time);
+
+        // Topocentric east-north-up coordinates of the spacecraft
+        let icrf = sc.interpolate_at(time);
+        let state = icrf
+            .try_to_frame(BodyFixed(Earth), &NoOpFrameTransformationProvider)
+            .unwrap();
+        let (lon_sin, lon_cos) = gs.longitude().sin_cos();
+        let (lat_sin, lat_cos) = gs.latitude().sin_cos();
+        let east = DVec3::new(-lon_sin, lon_cos, 0.0);
+        let north = DVec3::new(-lat_sin * lon_cos, -lat_sin * lon_sin, lat_cos);
+        let up = DVec3::new(lat_cos * lon_cos, lat_cos * lon_sin, lat_sin);
+        let rho = state.position() - gs.body_fixed_position();
+        let range = rho.length();
+        let elevation = (rho.dot(up) / range).asin();
+        let azimuth = rho.dot(east).atan2(rho.dot(north));
+        let range_rate = rho.dot(state.velocity()) / range;
+
+        assert_close!(obs.range(), range, 1e-10);
+        assert_close!(obs.range_rate(), range_rate, 1e-10);
+        assert_close!(obs.elevation(), elevation, 1e-10);
+        assert_close!(obs.azimuth().sin(), azimuth.sin(), 1e-10);
+        assert_close!(obs.azimuth().cos(), azimuth.cos(), 1e-10);
+        assert!(obs.elevation() > 0.0);
+        assert!(obs.is_visible());
+
+        // The Sun lies on the positive x-axis, so the spacecraft is sunlit unless it is behind
+        // the Earth.
+        let r = icrf.position();
+        let shadowed = r.x < 0.0 && r.y.hypot(r.z) < Earth.equatorial_radius();
+        assert!(!shadowed);
+        assert!(obs.is_sunlit());
+        assert_eq!(obs.eclipse(), Eclipse::None);
+
+        let masked = observe(
+            time,
+            &gs,
+            &FRAC_PI_2,
+            &sc,
+            &MockEphemeris,
+            &NoOpFrameTransformationProvider,
+        )
+        .unwrap();
+        assert!(!masked.is_visible());
+    }
+
+    #[test]
+    fn test_observe_pass() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let mask = ElevationMask::with_fixed_elevation(5f64.to_radians());
+        let passes = gs.visibility_windows(&sc, mask.clone(), &NoOpFrameTransformationProvider);
+        let pass = passes[0].window();
+        let cadence = TimeDelta::from_seconds(60);
+        let observations = observe_pass(
+            &gs,
+            &mask,
+            &sc,
+            pass,
+            cadence,
+            &MockEphemeris,
+            &NoOpFrameTransformationProvider,
+        )
+        .unwrap();
+        let duration = pass.duration().to_decimal_seconds();
+        assert_eq!(observations.len(), (duration / 60.0).ceil() as usize + 1);
+        assert_eq!(observations.first().unwrap().time(), pass.start());
+        assert_eq!(observations.last().unwrap().time(), pass.end());
+        for obs in &observations {
+            assert!(obs.elevation() - mask.min_elevation(obs.azimuth()) > -1e-6);
+        }
+        for (a, b) in observations.iter().tuple_windows() {
+            let step = (*b.time() - *a.time()).to_decimal_seconds();
+            assert!(step > 0.0 && step <= 60.0 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_observations_to_tdm() {
+        let gs = location();
+        let sc = spacecraft_trajectory();
+        let pass = contacts()[0];
+        let observations = observe_pass(
+            &gs,
+            &0.0,
+            &sc,
+            &pass,
+            TimeDelta::from_seconds(300),
+            &MockEphemeris,
+            &NoOpFrameTransformationProvider,
+        )
+        .unwrap();
+        let message = TdmType {
+            version: "2.0".to_string(),
+            header: TdmHeader {
+                creation_date: EpochType("2024-01-01T00:00:00".to_string()),
+                originator: "LOX".to_string(),
+                ..Default::default()
+            },
+            body: TdmBody {
+                segment_list: vec![TdmSegment {
+                    metadata: TdmMetadata {
+                        time_system: "TAI".to_string(),
+                        participant_1: "CEBREROS".to_string(),
+                        participant_2: Some("SPACECRAFT".to_string()),
+                        mode: Some("SEQUENTIAL".to_string()),
+                        path: Some("1,2,1".to_string()),
+                        range_units: Some("km".to_string()),
+                        angle_type: Some("AZEL".to_string()),
+                        ..Default::default()
+                    },
+                    data: TdmData {
+                        observation_list: observations.iter().flat_map(|o| o.to_tdm()).collect(),
+                        ..Default::default()
+                    },
+                }],
+            },
+            ..Default::default()
+        };
+        let kvn = message.to_kvn_string().unwrap();
+        let parsed: TdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+        let parsed = &parsed.body.segment_list[0].data.observation_list;
+        assert_eq!(parsed.len(), 4 * observations.len());
+        for (obs, tdm) in zip(&observations, parsed.chunks(4)) {
+            let epoch: Time<Tai> = Time::from_iso(Tai, &tdm[0].epoch.0).unwrap();
+            assert_close!((epoch - *obs.time()).to_decimal_seconds(), 0.0, 0.0, 1e-6);
+            let azimuth = tdm[0].value.to_radians();
+            assert_close!(azimuth.sin(), obs.azimuth().sin(), 1e-12);
+            assert_close!(azimuth.cos(), obs.azimuth().cos(), 1e-12);
+            assert!((0.0..360.0).contains(&tdm[0].value));
+            assert_close!(tdm[1].value.to_radians(), obs.elevation(), 1e-12);
+            assert_eq!(tdm[2].keyword, "RANGE");
+            assert_eq!(tdm[2].value, obs.range());
+            assert_eq!(tdm[3].keyword, "DOPPLER_INSTANTANEOUS");
+            assert_eq!(tdm[3].value, obs.range_rate());
+        }
+    }
+
     fn ground_station_trajectory() -> Trajectory<Time<Tai>, Earth, Icrf> {
         Trajectory::from_csv(
             include_str!("../../../data/trajectory_cebr.csv"),
@@ -281,4 +1311,429 @@ mod tests {
         }
         windows
     }
+
+    #[test]
+    fn test_beta_angle() {
+        let r = DVec3::new(RADIUS, 0.0, 0.0);
+        let v = DVec3::new(0.0, 7.5, 0.0);
+        let state = State::new(epoch(), r, v, Earth, Icrf);
+
+        // The Sun lies in the orbit plane
+        assert_close!(beta_angle(&state, DVec3::new(-1.0, 1.0, 0.0)), 0.0);
+        // The Sun lies along the orbit normal
+        assert_close!(
+            beta_angle(&state, DVec3::new(0.0, 0.0, ASTRONOMICAL_UNIT)),
+            FRAC_PI_2
+        );
+        assert_close!(
+            beta_angle(&state, DVec3::new(0.0, 0.0, -ASTRONOMICAL_UNIT)),
+            -FRAC_PI_2
+        );
+        assert_close!(
+            beta_angle(&state, DVec3::new(1.0, 0.0, 1.0)),
+            FRAC_PI_2 / 2.0
+        );
+    }
+
+    #[test]
+    fn test_solar_beta_angle() {
+        for beta in [0.0, 0.3, -1.2] {
+            let sc = circular_trajectory(beta, 60.0);
+            for state in sc.states() {
+                // The orbit normal of the trajectory is tilted away from the Sun by `beta`
+                assert_close!(
+                    solar_beta_angle(&state, &MockEphemeris).unwrap(),
+                    -beta,
+                    1e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_aberration() {
+        // The orbital velocity of the Earth yields an aberration of about 20.5″ perpendicular to
+        // the direction of motion
+        // `DVec3::angle_between` is inaccurate for small angles
+        let angle_between = |a: DVec3, b: DVec3| a.cross(b).length().atan2(a.dot(b));
+        let velocity = DVec3::new(0.0, 29.78, 0.0);
+        let star = DVec3::new(1.0, 0.0, 0.0);
+        let apparent = apply_aberration(star, velocity);
+        let angle = angle_between(star, apparent);
+        assert_close!(angle.sin(), 29.78 / SPEED_OF_LIGHT, 0.0, 1e-12);
+        assert_close!(angle.to_degrees() * 3600.0, 20.49, 1e-2);
+        // The star appears shifted towards the direction of motion
+        assert!(apparent.y > 0.0);
+
+        // Directions along the velocity are not affected
+        let ahead = apply_aberration(DVec3::new(0.0, 2.0, 0.0), velocity);
+        assert_close!(ahead, DVec3::new(0.0, 2.0, 0.0), 1e-15);
+
+        for direction in [
+            DVec3::new(1.0, 2.0, 3.0),
+            DVec3::new(-4.0, -1.0, 0.5),
+            DVec3::new(0.0, -1.0, 1e-3),
+        ] {
+            let apparent = apply_aberration(direction, velocity);
+            assert_close!(apparent.length(), direction.length(), 0.0, 1e-14);
+            // cos θ' = (cos θ + β) / (1 + β cos θ) in the plane of the direction and the velocity
+            let beta = 29.78 / SPEED_OF_LIGHT;
+            let gamma = 1.0 / (1.0 - beta.powi(2)).sqrt();
+            let theta = angle_between(direction, velocity);
+            let expected = theta - (theta.sin() / gamma).atan2(theta.cos() + beta);
+            assert_close!(angle_between(direction, apparent), expected, 1e-15, 1e-10);
+            // Collinear Lorentz boosts compose, so the aberration is reversible
+            let original = apply_aberration(apparent, -velocity);
+            assert_close!(original, direction, 0.0, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_eclipse() {
+        let beta = 0.0;
+        let sc = circular_trajectory(beta, 60.0);
+        let intervals = eclipse(&sc, &MockEphemeris, Earth).unwrap();
+        let kinds: Vec<Eclipse> = intervals.iter().map(|i| i.eclipse()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Eclipse::None,
+                Eclipse::Penumbra,
+                Eclipse::Umbra,
+                Eclipse::Penumbra,
+                Eclipse::None
+            ]
+        );
+        let (penumbra_half_width, umbra_half_width) = shadow_half_widths(beta);
+        let t0 = epoch();
+        assert_close!(
+            (*intervals[1].start() - t0).to_decimal_seconds(),
+            -penumbra_half_width,
+            0.0,
+            1e-1
+        );
+        assert_close!(
+            (*intervals[2].start() - t0).to_decimal_seconds(),
+            -umbra_half_width,
+            0.0,
+            1e-1
+        );
+        assert_close!(
+            (*intervals[2].end() - t0).to_decimal_seconds(),
+            umbra_half_width,
+            0.0,
+            1e-1
+        );
+        assert_close!(
+            (*intervals[3].end() - t0).to_decimal_seconds(),
+            penumbra_half_width,
+            0.0,
+            1e-1
+        );
+    }
+
+    #[test]
+    fn test_eclipse_grazing() {
+        // The orbit only skims the penumbra for about two minutes around the epoch which lies
+        // halfway between two samples.
+        let sun_radius = (Sun.equatorial_radius() / ASTRONOMICAL_UNIT).asin();
+        let earth_radius = (Earth.equatorial_radius() / RADIUS).asin();
+        let beta = earth_radius + 0.5 * sun_radius;
+        let sc = circular_trajectory(beta, 300.0);
+        let intervals = eclipse(&sc, &MockEphemeris, Earth).unwrap();
+        let kinds: Vec<Eclipse> = intervals.iter().map(|i| i.eclipse()).collect();
+        assert_eq!(kinds, vec![Eclipse::None, Eclipse::Penumbra, Eclipse::None]);
+        let (penumbra_half_width, _) = shadow_half_widths(beta);
+        assert!(penumbra_half_width < 150.0);
+        assert_close!(
+            intervals[1].window().duration().to_decimal_seconds(),
+            2.0 * penumbra_half_width,
+            0.0,
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_shadow_geometry_illumination() {
+        let geometry = |separation: f64| ShadowGeometry {
+            sun_radius: 0.01,
+            body_radius: 0.02,
+            separation,
+        };
+        assert_eq!(geometry(0.03).illumination(), 1.0);
+        assert_eq!(geometry(0.01).illumination(), 0.0);
+        // The limb of the larger occulting body curves away from the centre of the solar disk
+        let half = geometry(0.02).illumination();
+        assert!(half > 0.5 && half < 0.6);
+
+        // The illumination increases continuously across the penumbra
+        let samples: Vec<f64> = (0..=1000)
+            .map(|i| geometry(0.009 + 0.022 * i as f64 / 1000.0).illumination())
+            .collect();
+        for (f0, f1) in samples.iter().tuple_windows() {
+            assert!(f1 >= f0);
+            assert!(f1 - f0 < 5e-3);
+        }
+
+        let annular = ShadowGeometry {
+            sun_radius: 0.02,
+            body_radius: 0.01,
+            separation: 0.0,
+        };
+        assert_close!(annular.illumination(), 0.75, 0.0, 1e-12);
+        assert_eq!(annular.eclipse(), Eclipse::Penumbra);
+    }
+
+    fn conjunction(miss: DVec3, covariance: DMat3) -> f64 {
+        conjunction_with_radius(miss, covariance, 0.01).unwrap()
+    }
+
+    fn conjunction_with_radius(
+        miss: DVec3,
+        covariance: DMat3,
+        radius: f64,
+    ) -> Result<f64, CollisionProbabilityError> {
+        let time = Time::j2000(Tai);
+        let s1 = State::new(time, DVec3::ZERO, DVec3::new(0.0, 7.5, 0.0), Earth, Icrf);
+        let s2 = State::new(time, miss, DVec3::new(0.0, 15.0, 7.5), Earth, Icrf);
+        // Split the combined covariance between both objects
+        let c = DMat6::from_blocks(0.5 * covariance, DMat3::ZERO, DMat3::ZERO, DMat3::IDENTITY);
+        collision_probability(&s1, &c, &s2, &c, radius)
+    }
+
+    #[test]
+    fn test_collision_probability_isotropic() {
+        // For an isotropic covariance and a zero miss distance, the integral has the closed form
+        // 1 - exp(-R²/2σ²)
+        let sigma: f64 = 0.05;
+        let radius: f64 = 0.02;
+        let exp = 1.0 - (-radius.powi(2) / (2.0 * sigma.powi(2))).exp();
+        let act =
+            conjunction_with_radius(DVec3::ZERO, sigma.powi(2) * DMat3::IDENTITY, radius).unwrap();
+        assert_close!(act, exp, 0.0, 1e-10);
+
+        // The miss distance along the relative velocity does not contribute
+        let act = conjunction_with_radius(
+            DVec3::new(0.0, 1.0, 1.0),
+            sigma.powi(2) * DMat3::IDENTITY,
+            radius,
+        )
+        .unwrap();
+        assert_close!(act, exp, 0.0, 1e-10);
+
+        // Small hard-body radius approximation R²/2σ² exp(-d²/2σ²)
+        let miss: f64 = 0.1;
+        let radius: f64 = 0.001;
+        let exp =
+            radius.powi(2) / (2.0 * sigma.powi(2)) * (-miss.powi(2) / (2.0 * sigma.powi(2))).exp();
+        let act = conjunction_with_radius(
+            DVec3::new(miss, 0.0, 0.0),
+            sigma.powi(2) * DMat3::IDENTITY,
+            radius,
+        )
+        .unwrap();
+        assert_close!(act, exp, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn test_collision_probability_elongated() {
+        // Compare a highly elongated, rotated covariance in the encounter plane (x, y-z) with a
+        // brute-force quadrature over the hard-body disk
+        let (sigma_major, sigma_minor): (f64, f64) = (1.0, 0.002);
+        let angle: f64 = 0.3;
+        let u = DVec3::new(angle.cos(), 0.0, 0.0)
+            + angle.sin() * DVec3::new(0.0, 1.0, -1.0) / 2.0_f64.sqrt();
+        let w = DVec3::new(-angle.sin(), 0.0, 0.0)
+            + angle.cos() * DVec3::new(0.0, 1.0, -1.0) / 2.0_f64.sqrt();
+        let along = DVec3::new(0.0, 1.0, 1.0) / 2.0_f64.sqrt();
+        let covariance = sigma_major.powi(2) * DMat3::from_cols(u * u.x, u * u.y, u * u.z)
+            + sigma_minor.powi(2) * DMat3::from_cols(w * w.x, w * w.y, w * w.z)
+            + DMat3::from_cols(along * along.x, along * along.y, along * along.z);
+        let radius = 0.01;
+        let miss = 0.3 * u;
+        let act = conjunction_with_radius(miss, covariance, radius).unwrap();
+
+        let n = 1000;
+        let h = 2.0 * radius / n as f64;
+        let mut exp = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                let x = -radius + (i as f64 + 0.5) * h;
+                let y = -radius + (j as f64 + 0.5) * h;
+                if x * x + y * y > radius * radius {
+                    continue;
+                }
+                // Miss vector relative to a point of the disk in the principal axes
+                let d = miss - DVec3::new(x, y / 2.0_f64.sqrt(), -y / 2.0_f64.sqrt());
+                let (du, dw) = (d.dot(u), d.dot(w));
+                exp += (-0.5 * ((du / sigma_major).powi(2) + (dw / sigma_minor).powi(2))).exp()
+                    / (TAU * sigma_major * sigma_minor)
+                    * h
+                    * h;
+            }
+        }
+        assert!(act > 1e-4);
+        assert_close!(act, exp, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn test_collision_probability_chan_test_cases() {
+        // Test cases 1-7 of Chan (2008), Spacecraft Collision Probability, as tabulated in
+        // Alfano (2007), Review of Conjunction Probability Methods for Short-term Encounters,
+        // AAS 07-148: standard deviations and miss distance components in the encounter plane,
+        // hard-body radius in m, and the published Pc
+        let cases = [
+            (50.0, 25.0, 10.0, 0.0, 5.0, 9.742e-3),
+            (50.0, 25.0, 0.0, 10.0, 5.0, 9.181e-3),
+            (75.0, 25.0, 10.0, 0.0, 5.0, 6.571e-3),
+            (75.0, 25.0, 0.0, 10.0, 5.0, 6.125e-3),
+            (3000.0, 1000.0, 1000.0, 0.0, 10.0, 1.577e-5),
+            (3000.0, 1000.0, 0.0, 1000.0, 10.0, 1.011e-5),
+            (3000.0, 1000.0, 10000.0, 0.0, 10.0, 6.443e-8),
+        ];
+        // Encounter plane of the relative velocity of `conjunction_with_radius`
+        let x = DVec3::X;
+        let y = DVec3::new(0.0, 1.0, -1.0) / 2.0_f64.sqrt();
+        let along = DVec3::new(0.0, 1.0, 1.0) / 2.0_f64.sqrt();
+        let outer = |v: DVec3| DMat3::from_cols(v * v.x, v * v.y, v * v.z);
+        for (sigma_x, sigma_y, miss_x, miss_y, radius, exp) in cases {
+            let covariance =
+                1e-6 * (sigma_x * sigma_x * outer(x) + sigma_y * sigma_y * outer(y) + outer(along));
+            let miss = 1e-3 * (miss_x * x + miss_y * y);
+            let act = conjunction_with_radius(miss, covariance, 1e-3 * radius).unwrap();
+            assert_close!(act, exp, 0.0, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_collision_probability_tiny_miss_distance() {
+        let covariance = DMat3::from_diagonal(DVec3::new(0.04, 0.0001, 0.01));
+        let exp = conjunction(DVec3::ZERO, covariance);
+        let act = conjunction(DVec3::new(1e-15, 0.0, 0.0), covariance);
+        assert_close!(act, exp, 0.0, 1e-8);
+        assert!(exp > 0.0 && exp < 1.0);
+    }
+
+    #[test]
+    fn test_collision_probability_bounds() {
+        let covariance = 1e-6 * DMat3::IDENTITY;
+        assert_eq!(
+            conjunction_with_radius(DVec3::ZERO, covariance, 1.0).unwrap(),
+            1.0
+        );
+        assert_eq!(conjunction(DVec3::new(100.0, 0.0, 0.0), covariance), 0.0);
+    }
+
+    #[test]
+    fn test_collision_probability_errors() {
+        let covariance = DMat3::IDENTITY;
+        assert_eq!(
+            conjunction_with_radius(DVec3::ZERO, covariance, 0.0),
+            Err(CollisionProbabilityError::InvalidRadius(0.0))
+        );
+        assert_eq!(
+            conjunction_with_radius(DVec3::ZERO, DMat3::ZERO, 0.01),
+            Err(CollisionProbabilityError::NonPositiveDefiniteCovariance)
+        );
+        let time = Time::j2000(Tai);
+        let s = State::new(time, DVec3::ZERO, DVec3::new(0.0, 7.5, 0.0), Earth, Icrf);
+        let c = DMat6::IDENTITY;
+        assert_eq!(
+            collision_probability(&s, &c, &s, &c, 0.01),
+            Err(CollisionProbabilityError::ZeroRelativeVelocity)
+        );
+    }
+
+    const RADIUS: f64 = 7000.0;
+
+    /// The Sun is fixed on the positive x-axis of the Earth.
+    struct MockEphemeris;
+
+    impl MockEphemeris {
+        fn position(id: EphemerisId) -> DVec3 {
+            match id.0 {
+                10 => DVec3::new(ASTRONOMICAL_UNIT, 0.0, 0.0),
+                _ => DVec3::ZERO,
+            }
+        }
+    }
+
+    impl Ephemeris for MockEphemeris {
+        type Error = std::convert::Infallible;
+
+        fn position(
+            &self,
+            _epoch: f64,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            let r = Self::position(target) - Self::position(origin);
+            Ok((r.x, r.y, r.z))
+        }
+
+        fn velocity(
+            &self,
+            _epoch: f64,
+            _origin: EphemerisId,
+            _target: EphemerisId,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            Ok((0.0, 0.0, 0.0))
+        }
+
+        fn state(
+            &self,
+            epoch: f64,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<((f64, f64, f64), (f64, f64, f64)), Self::Error> {
+            Ok((
+                Ephemeris::position(self, epoch, origin, target)?,
+                self.velocity(epoch, origin, target)?,
+            ))
+        }
+    }
+
+    fn epoch() -> Time<Tai> {
+        Time::new(Tai, 0, Default::default())
+    }
+
+    fn mean_motion() -> f64 {
+        (Earth.gravitational_parameter() / RADIUS.powi(3)).sqrt()
+    }
+
+    /// Circular orbit whose point closest to the anti-Sun direction is passed at [epoch] and lies
+    /// `beta` radians away from it. The trajectory is sampled every `step` seconds, offset by half
+    /// a step from [epoch].
+    fn circular_trajectory(beta: f64, step: f64) -> Trajectory<Time<Tai>, Earth, Icrf> {
+        let u = DVec3::new(-beta.cos(), 0.0, beta.sin());
+        let v = DVec3::Y;
+        let n = mean_motion();
+        // Cover a little less than one revolution centred on the epoch.
+        let samples = (0.45 * TAU / n / step) as i64;
+        let states: Vec<State<Time<Tai>, Earth, Icrf>> = (-samples..samples)
+            .map(|i| {
+                let t = (i as f64 + 0.5) * step;
+                let (sin, cos) = (n * t).sin_cos();
+                let r = RADIUS * (cos * u + sin * v);
+                let v = RADIUS * n * (-sin * u + cos * v);
+                let time = epoch() + TimeDelta::from_decimal_seconds(t).unwrap();
+                State::new(time, r, v, Earth, Icrf)
+            })
+            .collect();
+        Trajectory::new(&states).unwrap()
+    }
+
+    /// Time from the epoch to the penumbra and umbra boundaries for the orbit returned by
+    /// [circular_trajectory], neglecting the distance between the Earth and the spacecraft
+    /// relative to the Sun.
+    fn shadow_half_widths(beta: f64) -> (f64, f64) {
+        let sun_radius = (Sun.equatorial_radius() / ASTRONOMICAL_UNIT).asin();
+        let earth_radius = (Earth.equatorial_radius() / RADIUS).asin();
+        let half_width =
+            |separation: f64| (separation.cos() / beta.cos()).min(1.0).acos() / mean_motion();
+        (
+            half_width(earth_radius + sun_radius),
+            half_width(earth_radius - sun_radius),
+        )
+    }
 }
@@ -6,6 +6,19 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+//! Conversions between the true anomaly and the eccentric, hyperbolic, parabolic, and mean
+//! anomalies.
+//!
+//! The mean anomaly is defined differently for each type of orbit, i.e. `M = E - e sin(E)` for
+//! elliptic orbits, `M = e sinh(H) - H` for hyperbolic orbits, and Barker's equation
+//! `M = D + D³/3` with `D = tan(ν/2)` for parabolic orbits. Multiplied by the respective mean
+//! motions `sqrt(μ/a³)`, `sqrt(μ/(-a)³)`, and `sqrt(μ/(2q³))`, they all equal the time since
+//! periapsis, which varies continuously across `e = 1`.
+
+use std::f64::consts::{PI, TAU};
+
+use thiserror::Error;
+
 pub fn hyperbolic_to_true(hyperbolic_anomaly: f64, eccentricity: f64) -> f64 {
     2.0 * (((1.0 + eccentricity) / (eccentricity - 1.0)).sqrt() * (hyperbolic_anomaly / 2.0).tanh())
         .atan()
@@ -16,11 +29,224 @@ pub fn eccentric_to_true(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
         .atan()
 }
 
+pub fn true_to_eccentric(true_anomaly: f64, eccentricity: f64) -> f64 {
+    2.0 * (((1.0 - eccentricity) / (1.0 + eccentricity)).sqrt() * (true_anomaly / 2.0).tan()).atan()
+}
+
+pub fn true_to_hyperbolic(true_anomaly: f64, eccentricity: f64) -> f64 {
+    2.0 * (((eccentricity - 1.0) / (eccentricity + 1.0)).sqrt() * (true_anomaly / 2.0).tan())
+        .atanh()
+}
+
+/// Returns the parabolic anomaly `D = tan(ν/2)` for the true anomaly `ν` of a parabolic orbit.
+pub fn true_to_parabolic(true_anomaly: f64) -> f64 {
+    (true_anomaly / 2.0).tan()
+}
+
+pub fn parabolic_to_true(parabolic_anomaly: f64) -> f64 {
+    2.0 * parabolic_anomaly.atan()
+}
+
+pub fn eccentric_to_mean(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
+    eccentric_anomaly - eccentricity * eccentric_anomaly.sin()
+}
+
+pub fn hyperbolic_to_mean(hyperbolic_anomaly: f64, eccentricity: f64) -> f64 {
+    eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly
+}
+
+/// Returns the mean anomaly of a parabolic orbit from Barker's equation `M = D + D³/3`.
+pub fn parabolic_to_mean(parabolic_anomaly: f64) -> f64 {
+    parabolic_anomaly + parabolic_anomaly.powi(3) / 3.0
+}
+
+/// Solves Barker's equation `M = D + D³/3` for the parabolic anomaly.
+pub fn mean_to_parabolic(mean_anomaly: f64) -> f64 {
+    // With D = 2 sinh(t), Barker's equation becomes M = 2/3 sinh(3t), which avoids the
+    // cancellation of the usual cubic root solution for small and negative mean anomalies
+    2.0 * ((1.5 * mean_anomaly).asinh() / 3.0).sinh()
+}
+
+/// Solves Kepler's equation `M = e sinh(H) - H` for the hyperbolic anomaly of a hyperbolic orbit.
+///
+/// The iteration starts from an upper bound of the solution. Since the equation is convex in `H`,
+/// Newton's method then converges monotonically and never overshoots, which keeps the hyperbolic
+/// functions from overflowing for large eccentricities and mean anomalies.
+pub fn mean_to_hyperbolic(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    // The equation is odd in `H`
+    let m = mean_anomaly.abs();
+    // From e sinh(H) - H >= (e - 1) sinh(H) and e sinh(H) - H >= e H³/6
+    let mut hyperbolic_anomaly = (m / (eccentricity - 1.0))
+        .asinh()
+        .min((6.0 * m / eccentricity).cbrt());
+    for _ in 0..100 {
+        let delta = (eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly - m)
+            / (eccentricity * hyperbolic_anomaly.cosh() - 1.0);
+        if !delta.is_finite() {
+            break;
+        }
+        hyperbolic_anomaly -= delta;
+        if delta.abs() < 1e-14 * hyperbolic_anomaly.abs().max(1.0) {
+            break;
+        }
+    }
+    hyperbolic_anomaly.copysign(mean_anomaly)
+}
+
+/// Solves Kepler's equation `M = E - e sin(E)` for the eccentric anomaly of an elliptic orbit with
+/// the default [KeplerSolver].
+///
+/// Returns NaN if the mean anomaly is not finite or the eccentricity is not within `[0, 1)`.
+pub fn mean_to_eccentric(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    KeplerSolver::default()
+        .mean_to_eccentric(mean_anomaly, eccentricity)
+        .unwrap_or(f64::NAN)
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum KeplerError {
+    #[error(
+        "Kepler's equation requires a finite mean anomaly and an eccentricity in [0, 1) but got M = {0} and e = {1}"
+    )]
+    InvalidArguments(f64, f64),
+    #[error("Kepler's equation did not converge for M = {0} and e = {1} after {2} iterations")]
+    NotConverged(f64, f64, u32),
+}
+
+/// Solves Kepler's equation `M = E - e sin(E)` for elliptic orbits.
+///
+/// The mean anomaly is reduced to `[0, π]` where the eccentric anomaly is bracketed by
+/// `[M, min(M + e, π)]`. Newton steps which would leave the bracket are replaced by bisection,
+/// which guarantees convergence for all eccentricities in `[0, 1)` and all finite mean anomalies,
+/// including the near-parabolic case of small mean anomalies and eccentricities close to one.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KeplerSolver {
+    max_iter: u32,
+    tolerance: f64,
+}
+
+impl Default for KeplerSolver {
+    fn default() -> Self {
+        Self {
+            max_iter: 100,
+            tolerance: 1e-15,
+        }
+    }
+}
+
+impl KeplerSolver {
+    /// Creates a solver which stops after `max_iter` iterations or once the relative change of the
+    /// eccentric anomaly drops below `tolerance`.
+    pub fn new(max_iter: u32, tolerance: f64) -> Self {
+        Self {
+            max_iter,
+            tolerance,
+        }
+    }
+
+    pub fn max_iter(&self) -> u32 {
+        self.max_iter
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn mean_to_eccentric(
+        &self,
+        mean_anomaly: f64,
+        eccentricity: f64,
+    ) -> Result<f64, KeplerError> {
+        if !mean_anomaly.is_finite() || !(0.0..1.0).contains(&eccentricity) {
+            return Err(KeplerError::InvalidArguments(mean_anomaly, eccentricity));
+        }
+        let revolutions = (mean_anomaly / TAU).round();
+        let reduced = mean_anomaly - revolutions * TAU;
+        // The equation is odd in `E`
+        let m = reduced.abs();
+        let e = eccentricity;
+        if m == 0.0 {
+            return Ok(revolutions * TAU);
+        }
+
+        let mut lower = m;
+        let mut upper = (m + e).min(PI);
+        let mut eccentric_anomaly = (m + 0.85 * e).min(upper);
+        for _ in 0..self.max_iter {
+            // Avoids the cancellation of `E - e sin(E)` for small `E` and `e` close to one
+            let f = (1.0 - e) * eccentric_anomaly + e * sin_residual(eccentric_anomaly) - m;
+            if f == 0.0 {
+                return Ok(revolutions * TAU + eccentric_anomaly.copysign(reduced));
+            }
+            if f > 0.0 {
+                upper = eccentric_anomaly;
+            } else {
+                lower = eccentric_anomaly;
+            }
+            let mut next = eccentric_anomaly - f / (1.0 - e * eccentric_anomaly.cos());
+            if !(next > lower && next < upper) {
+                next = 0.5 * (lower + upper);
+            }
+            let delta = next - eccentric_anomaly;
+            eccentric_anomaly = next;
+            if delta.abs() <= self.tolerance * eccentric_anomaly.abs() {
+                return Ok(revolutions * TAU + eccentric_anomaly.copysign(reduced));
+            }
+        }
+        Err(KeplerError::NotConverged(
+            mean_anomaly,
+            eccentricity,
+            self.max_iter,
+        ))
+    }
+}
+
+/// Returns `x - sin(x)` for `x` in `[0, π]` without cancellation for small `x`.
+fn sin_residual(x: f64) -> f64 {
+    if x > 1.0 {
+        return x - x.sin();
+    }
+    // x³/3! - x⁵/5! + x⁷/7! - ...
+    let x2 = x * x;
+    let mut term = x * x2 / 6.0;
+    let mut sum = term;
+    let mut n = 3.0;
+    while term.abs() > f64::EPSILON * sum.abs() {
+        term *= -x2 / ((n + 1.0) * (n + 2.0));
+        sum += term;
+        n += 2.0;
+    }
+    sum
+}
+
+/// Returns the true anomaly for the mean anomaly of an elliptic, parabolic, or hyperbolic orbit
+/// depending on `eccentricity`.
+pub fn mean_to_true(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        eccentric_to_true(mean_to_eccentric(mean_anomaly, eccentricity), eccentricity)
+    } else if eccentricity == 1.0 {
+        parabolic_to_true(mean_to_parabolic(mean_anomaly))
+    } else {
+        hyperbolic_to_true(mean_to_hyperbolic(mean_anomaly, eccentricity), eccentricity)
+    }
+}
+
+/// Returns the mean anomaly for the true anomaly of an elliptic, parabolic, or hyperbolic orbit
+/// depending on `eccentricity`.
+pub fn true_to_mean(true_anomaly: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        eccentric_to_mean(true_to_eccentric(true_anomaly, eccentricity), eccentricity)
+    } else if eccentricity == 1.0 {
+        parabolic_to_mean(true_to_parabolic(true_anomaly))
+    } else {
+        hyperbolic_to_mean(true_to_hyperbolic(true_anomaly, eccentricity), eccentricity)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::PI;
-
     use float_eq::assert_float_eq;
+    use rstest::rstest;
 
     use super::*;
 
@@ -41,4 +267,193 @@ mod tests {
             rel <= 1e-8
         );
     }
+
+    #[test]
+    fn test_mean() {
+        let eccentric_anomaly = mean_to_eccentric(1.2, 0.3);
+        assert_float_eq!(
+            eccentric_anomaly - 0.3 * eccentric_anomaly.sin(),
+            1.2,
+            abs <= 1e-12
+        );
+        assert_float_eq!(
+            mean_to_true(1.2, 0.3),
+            eccentric_to_true(eccentric_anomaly, 0.3),
+            rel <= 1e-12
+        );
+        assert_float_eq!(mean_to_true(0.7, 0.0), 0.7, rel <= 1e-12);
+    }
+
+    #[rstest]
+    #[case(0.1, 1e-8, 1.111111111111111e-8)]
+    #[case(0.1, 0.5, 0.5524799869065703)]
+    #[case(0.1, 3.0, 3.012839747166538)]
+    #[case(0.5, 1e-4, 0.0001999999986666667)]
+    #[case(0.5, 0.5, 0.887862211570866)]
+    #[case(0.5, 2.0, 2.3542427582227809)]
+    #[case(0.9, 1e-8, 9.99999999999985e-8)]
+    #[case(0.9, 0.01, 0.09856437752097705)]
+    #[case(0.9, 3.0, 3.0670374966306886)]
+    #[case(0.99, 1e-8, 9.999999999835e-7)]
+    #[case(0.99, 1e-4, 0.009983581221411532)]
+    #[case(0.99, 0.01, 0.34227031649177515)]
+    #[case(0.99, 0.5, 1.4864832827614295)]
+    #[case(0.99, 2.0, 2.5511563100658282)]
+    #[case(0.99, 3.0, 3.0704106691175017)]
+    #[case(0.999, 1e-8, 9.999999833500008e-6)]
+    #[case(0.999, 1e-4, 0.0614230944258937)]
+    #[case(0.999, 0.5, 1.4962235155119097)]
+    fn test_kepler_solver_reference(
+        #[case] eccentricity: f64,
+        #[case] mean_anomaly: f64,
+        #[case] expected: f64,
+    ) {
+        // Reference values from bisection with 40 significant digits
+        let solver = KeplerSolver::default();
+        let act = solver
+            .mean_to_eccentric(mean_anomaly, eccentricity)
+            .unwrap();
+        assert_float_eq!(act, expected, rel <= 1e-14);
+        let act = solver
+            .mean_to_eccentric(-mean_anomaly, eccentricity)
+            .unwrap();
+        assert_float_eq!(act, -expected, rel <= 1e-14);
+        // Adding full revolutions rounds the mean anomaly, which is amplified by dE/dM ≈ 1/(1 - e)
+        let act = solver
+            .mean_to_eccentric(mean_anomaly + 4.0 * TAU, eccentricity)
+            .unwrap();
+        assert_float_eq!(act, expected + 4.0 * TAU, rel <= 1e-13);
+    }
+
+    #[test]
+    fn test_kepler_solver_all_mean_anomalies() {
+        let solver = KeplerSolver::default();
+        for eccentricity in [0.0, 0.3, 0.7, 0.95, 0.99, 0.9999, 1.0 - 1e-12] {
+            for i in -100..=100 {
+                let mean_anomaly = i as f64 * PI / 100.0;
+                let eccentric_anomaly = solver
+                    .mean_to_eccentric(mean_anomaly, eccentricity)
+                    .unwrap();
+                assert_float_eq!(
+                    eccentric_to_mean(eccentric_anomaly, eccentricity),
+                    mean_anomaly,
+                    abs <= 1e-14
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sin_residual() {
+        for x in [1e-3, 0.1, 0.5, 0.999, 1.0, 2.0, PI] {
+            assert_float_eq!(sin_residual(x), x - x.sin(), rel <= 1e-6);
+        }
+        assert_float_eq!(
+            sin_residual(1e-5),
+            1e-15 / 6.0 - 1e-25 / 120.0,
+            rel <= 1e-15
+        );
+        assert_float_eq!(sin_residual(0.9), 0.11667309037251661, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_kepler_solver_errors() {
+        let solver = KeplerSolver::default();
+        assert_eq!(
+            solver.mean_to_eccentric(0.5, 1.0),
+            Err(KeplerError::InvalidArguments(0.5, 1.0))
+        );
+        assert!(solver.mean_to_eccentric(f64::NAN, 0.5).is_err());
+        assert!(mean_to_eccentric(0.5, -0.1).is_nan());
+
+        let solver = KeplerSolver::new(2, 1e-15);
+        assert_eq!(solver.max_iter(), 2);
+        assert_eq!(solver.tolerance(), 1e-15);
+        let err = solver.mean_to_eccentric(1e-4, 0.99).unwrap_err();
+        assert_eq!(err, KeplerError::NotConverged(1e-4, 0.99, 2));
+        assert_eq!(
+            err.to_string(),
+            "Kepler's equation did not converge for M = 0.0001 and e = 0.99 after 2 iterations"
+        );
+    }
+
+    #[test]
+    fn test_mean_to_hyperbolic() {
+        for eccentricity in [1.0 + 1e-9, 1.0001, 1.2, 2.0, 3.0, 50.0] {
+            for mean_anomaly in [0.0, 1e-9, 0.3, 2.0, 10.0, 1e3, 1e8] {
+                for m in [mean_anomaly, -mean_anomaly] {
+                    let h = mean_to_hyperbolic(m, eccentricity);
+                    assert!(h.is_finite(), "e = {eccentricity}, M = {m}");
+                    assert_float_eq!(
+                        hyperbolic_to_mean(h, eccentricity),
+                        m,
+                        abs <= 1e-12,
+                        rel <= 1e-13
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parabolic() {
+        // ν = 90° corresponds to D = 1 and M = 4/3
+        assert_float_eq!(true_to_parabolic(PI / 2.0), 1.0, rel <= 1e-15);
+        assert_float_eq!(parabolic_to_mean(1.0), 4.0 / 3.0, rel <= 1e-15);
+        assert_float_eq!(mean_to_parabolic(4.0 / 3.0), 1.0, rel <= 1e-15);
+        assert_float_eq!(mean_to_true(4.0 / 3.0, 1.0), PI / 2.0, rel <= 1e-15);
+        assert_float_eq!(true_to_mean(-PI / 2.0, 1.0), -4.0 / 3.0, rel <= 1e-15);
+
+        for mean_anomaly in [0.0, 1e-12, 0.5, 10.0, 1e6] {
+            for m in [mean_anomaly, -mean_anomaly] {
+                let d = mean_to_parabolic(m);
+                assert_float_eq!(parabolic_to_mean(d), m, abs <= 1e-15, rel <= 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_true_to_mean_round_trip() {
+        for eccentricity in [0.0, 0.3, 0.9, 1.0, 1.5, 3.0] {
+            for true_anomaly in [-1.2, 0.0, 0.4, 1.5] {
+                let m = true_to_mean(true_anomaly, eccentricity);
+                assert_float_eq!(mean_to_true(m, eccentricity), true_anomaly, abs <= 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_near_parabolic_continuity() {
+        // The same time since periapsis for a fixed periapsis radius must yield almost the same true
+        // anomaly on both sides of e = 1
+        let eps: f64 = 1e-6;
+        for mp in [-5.0, -0.2, 0.1, 1.0, 5.0] {
+            let nu_parabolic = mean_to_true(mp, 1.0);
+            let m_elliptic = mp * 2.0_f64.sqrt() * eps.powf(1.5);
+            let nu_elliptic = mean_to_true(m_elliptic, 1.0 - eps);
+            let m_hyperbolic = mp * 2.0_f64.sqrt() * eps.powf(1.5);
+            let nu_hyperbolic = mean_to_true(m_hyperbolic, 1.0 + eps);
+            assert_float_eq!(nu_elliptic, nu_parabolic, abs <= 1e-5);
+            assert_float_eq!(nu_hyperbolic, nu_parabolic, abs <= 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_true_to_mean() {
+        let eccentric_anomaly = true_to_eccentric(1.7721542475852272, 0.2);
+        assert_float_eq!(eccentric_anomaly, PI / 2.0, rel <= 1e-8);
+        assert_float_eq!(
+            mean_to_true(eccentric_to_mean(eccentric_anomaly, 0.2), 0.2),
+            1.7721542475852272,
+            rel <= 1e-12
+        );
+
+        let hyperbolic_anomaly = true_to_hyperbolic(2.2797028138935547, 1.2);
+        assert_float_eq!(hyperbolic_anomaly, PI / 2.0, rel <= 1e-8);
+        assert_float_eq!(
+            hyperbolic_to_mean(hyperbolic_anomaly, 1.2),
+            1.2 * (PI / 2.0).sinh() - PI / 2.0,
+            rel <= 1e-8
+        );
+    }
 }
@@ -0,0 +1,534 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `determination` provides batch and sequential orbit determination from range and angle
+    measurements of ground stations.
+
+    [BatchLeastSquares] refines an a priori Cartesian state at its epoch with a Gauss-Newton
+    differential corrector. The partial derivatives of the measurements with respect to the epoch
+    state are computed by central finite differences through an arbitrary [Propagator], so that
+    the force model of the orbit determination is the same as the one of the propagator.
+
+    [filter::Filter] processes the same observations sequentially with an extended Kalman filter.
+*/
+
+use std::fmt::Display;
+
+use glam::DVec3;
+use thiserror::Error;
+
+use lox_bodies::{Origin, RotationalElements, Spheroid};
+use lox_math::linear_algebra::dmat6::DMat6;
+use lox_math::linear_algebra::lu::{LinAlgError, Lu};
+use lox_math::math::wrap_pi;
+use lox_math::types::units::Radians;
+use lox_time::prelude::Tdb;
+use lox_time::transformations::TryToScale;
+use lox_time::TimeLike;
+
+use crate::frames::{
+    BodyFixed, FrameTransformationProvider, Icrf, NoOpFrameTransformationProvider, TryToFrame,
+};
+use crate::ground::{GroundLocation, Observables};
+use crate::propagators::numerical::{POSITION_STEP, VELOCITY_STEP};
+use crate::propagators::Propagator;
+use crate::states::State;
+
+pub mod filter;
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum OdError {
+    #[error("at least 2 observations are required but {0} were given")]
+    InsufficientObservations(usize),
+    #[error("the state is not observable: {0}")]
+    Unobservable(#[from] LinAlgError),
+    #[error("propagation failed: {0}")]
+    PropagationError(String),
+    #[error("frame transformation failed: {0}")]
+    FrameTransformationError(String),
+    #[error("no convergence after {iterations} iterations with a residual RMS of {rms}")]
+    NotConverged { iterations: usize, rms: f64 },
+}
+
+/// A simultaneous range and angles measurement of a spacecraft from a ground station.
+///
+/// The angles are defined as in [Observables], i.e. the elevation is
+/// measured from the geodetic horizon and the azimuth clockwise from north.
+#[derive(Clone, Debug)]
+pub struct Observation<T: TimeLike, O: Spheroid> {
+    time: T,
+    station: GroundLocation<O>,
+    range: f64,
+    azimuth: Radians,
+    elevation: Radians,
+}
+
+impl<T: TimeLike, O: Spheroid> Observation<T, O> {
+    pub fn new(
+        time: T,
+        station: GroundLocation<O>,
+        range: f64,
+        azimuth: Radians,
+        elevation: Radians,
+    ) -> Self {
+        Self {
+            time,
+            station,
+            range,
+            azimuth,
+            elevation,
+        }
+    }
+
+    pub fn time(&self) -> &T {
+        &self.time
+    }
+
+    pub fn station(&self) -> &GroundLocation<O> {
+        &self.station
+    }
+
+    pub fn range(&self) -> f64 {
+        self.range
+    }
+
+    pub fn azimuth(&self) -> Radians {
+        self.azimuth
+    }
+
+    pub fn elevation(&self) -> Radians {
+        self.elevation
+    }
+}
+
+/// The result of a converged [BatchLeastSquares] estimation.
+#[derive(Clone, Debug)]
+pub struct OdSolution<T: TimeLike, O: Origin> {
+    state: State<T, O, Icrf>,
+    covariance: DMat6,
+    rms: f64,
+    iterations: usize,
+}
+
+impl<T, O> OdSolution<T, O>
+where
+    T: TimeLike + Clone,
+    O: Origin + Clone,
+{
+    /// Returns the estimated state at the epoch of the a priori state.
+    pub fn state(&self) -> State<T, O, Icrf> {
+        self.state.clone()
+    }
+
+    /// Returns the formal covariance of the estimated state in km², km²/s, and km²/s², i.e. the
+    /// inverse of the normal matrix of the final iteration.
+    pub fn covariance(&self) -> DMat6 {
+        self.covariance
+    }
+
+    /// Returns the root mean square of the residuals weighted by the measurement noise.
+    ///
+    /// A value close to one indicates that the residuals are consistent with the assumed noise.
+    pub fn rms(&self) -> f64 {
+        self.rms
+    }
+
+    /// Returns the number of differential corrections applied to the a priori state.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+}
+
+/// A batch least-squares differential corrector for range and angles measurements.
+///
+/// The residuals are weighted with the standard deviations of the measurement noise, and the
+/// azimuth residuals are additionally scaled by the cosine of the elevation, so that they are not
+/// overweighted close to the zenith. The iteration has converged when the weighted RMS of the
+/// residuals changes by less than the tolerance relative to the RMS or, if the RMS is already
+/// below one, in absolute terms.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchLeastSquares {
+    max_iterations: usize,
+    tolerance: f64,
+    range_sigma: f64,
+    angle_sigma: Radians,
+}
+
+impl Default for BatchLeastSquares {
+    fn default() -> Self {
+        Self {
+            max_iterations: 20,
+            tolerance: 1e-6,
+            range_sigma: 0.01,
+            angle_sigma: 0.01f64.to_radians(),
+        }
+    }
+}
+
+impl BatchLeastSquares {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Sets the standard deviations of the range noise in km and the angle noise in radians.
+    pub fn with_sigmas(self, range_sigma: f64, angle_sigma: Radians) -> Self {
+        Self {
+            range_sigma,
+            angle_sigma,
+            ..self
+        }
+    }
+
+    /// Estimates the state at the epoch of `initial` from `observations`.
+    ///
+    /// `propagator` creates the propagator for a trial epoch state, e.g. `Vallado::new`, and
+    /// `provider` is used to rotate the propagated states into the body-fixed frame of the
+    /// stations.
+    ///
+    /// # Errors
+    ///
+    /// - [OdError::InsufficientObservations] if fewer than two observations are given.
+    /// - [OdError::Unobservable] if the normal equations are singular.
+    /// - [OdError::NotConverged] if the iteration does not converge within the maximum number of
+    ///   iterations or the residuals diverge.
+    /// - [OdError::PropagationError] and [OdError::FrameTransformationError] if a trial state
+    ///   cannot be propagated to or transformed at the time of an observation.
+    pub fn estimate<T, O, F, G, P>(
+        &self,
+        initial: State<T, O, Icrf>,
+        observations: &[Observation<T, O>],
+        propagator: F,
+        provider: &P,
+    ) -> Result<OdSolution<T, O>, OdError>
+    where
+        T: TimeLike + TryToScale<Tdb, P> + Clone,
+        O: Origin + Spheroid + RotationalElements + Clone,
+        F: Fn(State<T, O, Icrf>) -> G,
+        G: Propagator<T, O, Icrf>,
+        G::Error: Display,
+        P: FrameTransformationProvider,
+    {
+        if observations.len() < 2 {
+            return Err(OdError::InsufficientObservations(observations.len()));
+        }
+        let residuals = |x: &[f64; 6]| {
+            self.residuals(
+                &propagator(with_state_vector(&initial, x)),
+                observations,
+                provider,
+            )
+        };
+        let mut x = state_vector(&initial);
+        let mut r = residuals(&x)?;
+        let mut rms = root_mean_square(&r);
+        for iteration in 1..=self.max_iterations {
+            // Normal equations of the partials of the computed measurements
+            let mut normal = [[0.0; 6]; 6];
+            let mut rhs = [0.0; 6];
+            let mut partials = vec![[0.0; 6]; r.len()];
+            for j in 0..6 {
+                let step = finite_difference_step(j);
+                let (mut x_plus, mut x_minus) = (x, x);
+                x_plus[j] += step;
+                x_minus[j] -= step;
+                let (r_plus, r_minus) = (residuals(&x_plus)?, residuals(&x_minus)?);
+                for (row, (plus, minus)) in partials.iter_mut().zip(r_plus.iter().zip(r_minus)) {
+                    row[j] = (minus - plus) / (2.0 * step);
+                }
+            }
+            for (row, residual) in partials.iter().zip(&r) {
+                for i in 0..6 {
+                    rhs[i] += row[i] * residual;
+                    for j in 0..6 {
+                        normal[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+            let lu = Lu::new(&normal)?;
+            let correction = lu.solve(&rhs);
+            for (x, dx) in x.iter_mut().zip(correction) {
+                *x += dx;
+            }
+            let previous = rms;
+            r = residuals(&x)?;
+            rms = root_mean_square(&r);
+            if !rms.is_finite() {
+                break;
+            }
+            if (previous - rms).abs() <= self.tolerance * rms.max(1.0) {
+                return Ok(OdSolution {
+                    state: with_state_vector(&initial, &x),
+                    covariance: DMat6::from_rows(lu.inverse()),
+                    rms,
+                    iterations: iteration,
+                });
+            }
+        }
+        Err(OdError::NotConverged {
+            iterations: self.max_iterations,
+            rms,
+        })
+    }
+
+    // Returns the weighted observed-minus-computed residuals of range, azimuth, and elevation
+    fn residuals<T, O, G, P>(
+        &self,
+        propagator: &G,
+        observations: &[Observation<T, O>],
+        provider: &P,
+    ) -> Result<Vec<f64>, OdError>
+    where
+        T: TimeLike + TryToScale<Tdb, P> + Clone,
+        O: Origin + Spheroid + RotationalElements + Clone,
+        G: Propagator<T, O, Icrf>,
+        G::Error: Display,
+        P: FrameTransformationProvider,
+    {
+        let mut residuals = Vec::with_capacity(3 * observations.len());
+        for obs in observations {
+            let state = propagator
+                .propagate(obs.time.clone())
+                .map_err(|err| OdError::PropagationError(err.to_string()))?;
+            let computed = computed_observables(&state, &obs.station, provider)?;
+            residuals.push((obs.range - computed.range()) / self.range_sigma);
+            residuals.push(
+                wrap_pi(obs.azimuth - computed.azimuth()) * obs.elevation.cos() / self.angle_sigma,
+            );
+            residuals.push((obs.elevation - computed.elevation()) / self.angle_sigma);
+        }
+        Ok(residuals)
+    }
+}
+
+fn state_vector<T: TimeLike, O: Origin>(state: &State<T, O, Icrf>) -> [f64; 6] {
+    let (position, velocity) = (state.position(), state.velocity());
+    [
+        position.x, position.y, position.z, velocity.x, velocity.y, velocity.z,
+    ]
+}
+
+// Returns a state at the time of `state` with the position and velocity `x`
+fn with_state_vector<T, O>(state: &State<T, O, Icrf>, x: &[f64; 6]) -> State<T, O, Icrf>
+where
+    T: TimeLike + Clone,
+    O: Origin + Clone,
+{
+    State::new(
+        state.time(),
+        DVec3::new(x[0], x[1], x[2]),
+        DVec3::new(x[3], x[4], x[5]),
+        state.origin(),
+        Icrf,
+    )
+}
+
+fn finite_difference_step(component: usize) -> f64 {
+    if component < 3 {
+        POSITION_STEP
+    } else {
+        VELOCITY_STEP
+    }
+}
+
+// Returns the modelled measurement of `state` from `station`
+fn computed_observables<T, O, P>(
+    state: &State<T, O, Icrf>,
+    station: &GroundLocation<O>,
+    provider: &P,
+) -> Result<Observables, OdError>
+where
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    P: FrameTransformationProvider,
+{
+    let state = state
+        .try_to_frame(BodyFixed(station.origin()), provider)
+        .map_err(|err| OdError::FrameTransformationError(err.to_string()))?;
+    Ok(station.observables(state))
+}
+
+fn root_mean_square(residuals: &[f64]) -> f64 {
+    (residuals.iter().map(|r| r.powi(2)).sum::<f64>() / residuals.len() as f64).sqrt()
+}
+
+/// Estimates the state at the epoch of `initial` from `observations` with the default settings
+/// of [BatchLeastSquares] and without Earth orientation corrections.
+pub fn batch_ls<T, O, F, G>(
+    initial: State<T, O, Icrf>,
+    observations: &[Observation<T, O>],
+    propagator: F,
+) -> Result<OdSolution<T, O>, OdError>
+where
+    T: TimeLike + TryToScale<Tdb, NoOpFrameTransformationProvider> + Clone,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    F: Fn(State<T, O, Icrf>) -> G,
+    G: Propagator<T, O, Icrf>,
+    G::Error: Display,
+{
+    BatchLeastSquares::default().estimate(
+        initial,
+        observations,
+        propagator,
+        &NoOpFrameTransformationProvider,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use lox_bodies::Earth;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tai;
+    use lox_time::{time, Time};
+
+    use crate::elements::Keplerian;
+    use crate::propagators::semi_analytical::Vallado;
+
+    use super::*;
+
+    pub(super) fn truth() -> State<Time<Tai>, Earth, Icrf> {
+        Keplerian::builder(time!(Tai, 2024, 3, 1, 12).unwrap(), Earth)
+            .with_semi_major_axis(Earth.equatorial_radius() + 700.0)
+            .with_eccentricity(2e-3)
+            .with_inclination(98f64.to_radians())
+            .with_longitude_of_ascending_node(0.3)
+            .with_argument_of_periapsis(0.5)
+            .with_true_anomaly(0.1)
+            .build()
+            .unwrap()
+            .to_cartesian()
+    }
+
+    // Observations every 30 seconds from stations close to the ground track at the epoch
+    pub(super) fn observations(
+        state: State<Time<Tai>, Earth, Icrf>,
+    ) -> Vec<Observation<Time<Tai>, Earth>> {
+        let provider = &NoOpFrameTransformationProvider;
+        let sub_point = state
+            .try_to_frame(BodyFixed(Earth), provider)
+            .unwrap()
+            .to_ground_location()
+            .unwrap();
+        let stations = [(-0.05, 0.0), (0.05, 0.1)].map(|(dlon, dlat)| {
+            GroundLocation::new(
+                sub_point.longitude() + dlon,
+                sub_point.latitude() + dlat,
+                0.0,
+                Earth,
+            )
+        });
+        let propagator = Vallado::new(state);
+        (-10..=10)
+            .flat_map(|i| {
+                let time = state.time() + TimeDelta::from_seconds(30 * i);
+                let state = propagator
+                    .propagate(time)
+                    .unwrap()
+                    .try_to_frame(BodyFixed(Earth), provider)
+                    .unwrap();
+                stations.clone().map(|station| {
+                    let obs = station.observables(state);
+                    Observation::new(time, station, obs.range(), obs.azimuth(), obs.elevation())
+                })
+            })
+            .collect()
+    }
+
+    pub(super) fn perturbed(state: State<Time<Tai>, Earth, Icrf>) -> State<Time<Tai>, Earth, Icrf> {
+        State::new(
+            state.time(),
+            state.position() + DVec3::new(5.0, -3.0, 2.0),
+            state.velocity() + DVec3::new(-2e-3, 1e-3, 3e-3),
+            Earth,
+            Icrf,
+        )
+    }
+
+    #[test]
+    fn test_batch_ls() {
+        let truth = truth();
+        let observations = observations(truth);
+        assert!(observations.iter().all(|obs| obs.elevation() > 0.0));
+
+        let solution = batch_ls(perturbed(truth), &observations, Vallado::new).unwrap();
+        let state = solution.state();
+        assert_eq!(state.time(), truth.time());
+        assert_close!(state.position(), truth.position(), 1e-6);
+        assert_close!(state.velocity(), truth.velocity(), 1e-9);
+        assert!(solution.rms() < 1e-3);
+        assert!((2..=10).contains(&solution.iterations()));
+        // The formal uncertainty is of the order of the range noise for a ten-minute arc
+        let covariance = solution.covariance();
+        for i in 0..3 {
+            assert!(covariance[(i, i)] > 0.0 && covariance[(i, i)].sqrt() < 0.05);
+            assert!(covariance[(i + 3, i + 3)] > 0.0 && covariance[(i + 3, i + 3)].sqrt() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_batch_ls_noise() {
+        let truth = truth();
+        // Alternate the sign of one-sigma errors of all measurements
+        let observations: Vec<_> = observations(truth)
+            .into_iter()
+            .enumerate()
+            .map(|(i, obs)| {
+                let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                let angle = sign * 0.01f64.to_radians();
+                Observation::new(
+                    obs.time,
+                    obs.station,
+                    obs.range + sign * 0.01,
+                    obs.azimuth + angle,
+                    obs.elevation - angle,
+                )
+            })
+            .collect();
+        let solution = batch_ls(perturbed(truth), &observations, Vallado::new).unwrap();
+        assert!(solution.rms() > 0.5 && solution.rms() < 1.0);
+        assert!((solution.state().position() - truth.position()).length() < 0.1);
+    }
+
+    #[test]
+    fn test_batch_ls_not_converged() {
+        let truth = truth();
+        let observations = observations(truth);
+        let result = BatchLeastSquares::new().with_max_iterations(1).estimate(
+            perturbed(truth),
+            &observations,
+            Vallado::new,
+            &NoOpFrameTransformationProvider,
+        );
+        let Err(OdError::NotConverged { iterations, rms }) = result else {
+            panic!("expected non-convergence but got {:?}", result);
+        };
+        assert_eq!(iterations, 1);
+        assert!(rms.is_finite());
+    }
+
+    #[test]
+    fn test_batch_ls_insufficient_observations() {
+        let truth = truth();
+        let observations = observations(truth);
+        let result = batch_ls(truth, &observations[..1], Vallado::new);
+        assert_eq!(result.unwrap_err(), OdError::InsufficientObservations(1));
+    }
+}
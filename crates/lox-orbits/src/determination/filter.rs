@@ -0,0 +1,352 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module `filter` provides sequential orbit determination with an extended Kalman filter.
+
+use std::fmt::Display;
+
+use glam::DMat3;
+
+use lox_bodies::{Origin, RotationalElements, Spheroid};
+use lox_math::linear_algebra::dmat6::DMat6;
+use lox_math::linear_algebra::lu::Lu;
+use lox_math::math::wrap_pi;
+use lox_time::prelude::Tdb;
+use lox_time::transformations::TryToScale;
+use lox_time::TimeLike;
+
+use crate::determination::{
+    computed_observables, finite_difference_step, state_vector, with_state_vector, Observation,
+    OdError,
+};
+use crate::frames::{FrameTransformationProvider, Icrf};
+use crate::propagators::Propagator;
+use crate::states::State;
+
+/// An extended Kalman filter (EKF) for range and angles measurements of ground stations.
+///
+/// Between measurements the state is propagated with the propagator created by `propagator`, e.g.
+/// `NumericalPropagator::new`, and the covariance with the state transition matrix, which is
+/// computed by central finite differences through the same propagator. The process noise models
+/// unmodelled accelerations as white noise with a constant spectral density.
+///
+/// The covariance is updated in Joseph form and symmetrised after each step, so that it remains
+/// symmetric and positive-definite in the presence of rounding errors.
+pub struct Filter<T: TimeLike, O: Origin, F, P> {
+    state: State<T, O, Icrf>,
+    covariance: [[f64; 6]; 6],
+    propagator: F,
+    provider: P,
+    process_noise: f64,
+}
+
+impl<T, O, F, G, P> Filter<T, O, F, P>
+where
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    F: Fn(State<T, O, Icrf>) -> G,
+    G: Propagator<T, O, Icrf>,
+    G::Error: Display,
+    P: FrameTransformationProvider,
+{
+    /// Creates a filter from the a priori state and its covariance in km², km²/s, and km²/s².
+    ///
+    /// `provider` is used to rotate the states into the body-fixed frame of the stations.
+    pub fn new(state: State<T, O, Icrf>, covariance: DMat6, propagator: F, provider: P) -> Self {
+        Self {
+            state,
+            covariance: covariance.to_rows(),
+            propagator,
+            provider,
+            process_noise: 0.0,
+        }
+    }
+
+    /// Sets the spectral density of the white noise acceleration in km²/s³.
+    pub fn with_process_noise(self, spectral_density: f64) -> Self {
+        Self {
+            process_noise: spectral_density,
+            ..self
+        }
+    }
+
+    /// Returns the current estimate of the state.
+    pub fn state(&self) -> State<T, O, Icrf> {
+        self.state.clone()
+    }
+
+    /// Returns the covariance of the current estimate of the state.
+    pub fn covariance(&self) -> DMat6 {
+        DMat6::from_rows(self.covariance)
+    }
+
+    /// Propagates the state and its covariance to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [OdError::PropagationError] if the state cannot be propagated.
+    pub fn predict(&mut self, to: T) -> Result<(), OdError> {
+        let dt = (to.clone() - self.state.time()).to_decimal_seconds();
+        if dt == 0.0 {
+            return Ok(());
+        }
+        let propagate = |state: State<T, O, Icrf>| {
+            (self.propagator)(state)
+                .propagate(to.clone())
+                .map_err(|err| OdError::PropagationError(err.to_string()))
+        };
+        let state = propagate(self.state.clone())?;
+        let x = state_vector(&self.state);
+        let mut stm = [[0.0; 6]; 6];
+        for j in 0..6 {
+            let step = finite_difference_step(j);
+            let (mut x_plus, mut x_minus) = (x, x);
+            x_plus[j] += step;
+            x_minus[j] -= step;
+            let plus = state_vector(&propagate(with_state_vector(&self.state, &x_plus))?);
+            let minus = state_vector(&propagate(with_state_vector(&self.state, &x_minus))?);
+            for (row, (plus, minus)) in stm.iter_mut().zip(plus.iter().zip(minus)) {
+                row[j] = (plus - minus) / (2.0 * step);
+            }
+        }
+        let mut covariance = mul(&mul(&stm, &self.covariance), &transpose(&stm));
+        // Discrete process noise of a constant white noise acceleration, which is also positive
+        // semi-definite when predicting backwards.
+        let q = self.process_noise;
+        for i in 0..3 {
+            covariance[i][i] += q * dt.abs().powi(3) / 3.0;
+            covariance[i][i + 3] += q * dt * dt.abs() / 2.0;
+            covariance[i + 3][i] += q * dt * dt.abs() / 2.0;
+            covariance[i + 3][i + 3] += q * dt.abs();
+        }
+        self.state = state;
+        self.covariance = symmetrise(covariance);
+        Ok(())
+    }
+
+    /// Updates the estimate with `obs` and the covariance of its range in km, azimuth, and
+    /// elevation in radians, and returns the innovation, i.e. the pre-fit residuals.
+    ///
+    /// If the observation is not at the epoch of the current estimate, the filter is first
+    /// predicted to the time of the observation.
+    ///
+    /// # Errors
+    ///
+    /// - [OdError::PropagationError] if the state cannot be propagated to the observation.
+    /// - [OdError::FrameTransformationError] if the state cannot be rotated into the body-fixed
+    ///   frame of the station.
+    /// - [OdError::Unobservable] if the innovation covariance is singular.
+    pub fn update(
+        &mut self,
+        obs: &Observation<T, O>,
+        measurement_covariance: DMat3,
+    ) -> Result<[f64; 3], OdError> {
+        self.predict(obs.time().clone())?;
+        let measure = |x: &[f64; 6]| {
+            computed_observables(
+                &with_state_vector(&self.state, x),
+                obs.station(),
+                &self.provider,
+            )
+            .map(|computed| [computed.range(), computed.azimuth(), computed.elevation()])
+        };
+        let x = state_vector(&self.state);
+        let computed = measure(&x)?;
+        let innovation = [
+            obs.range() - computed[0],
+            wrap_pi(obs.azimuth() - computed[1]),
+            obs.elevation() - computed[2],
+        ];
+        let mut h = [[0.0; 6]; 3];
+        for j in 0..6 {
+            let step = finite_difference_step(j);
+            let (mut x_plus, mut x_minus) = (x, x);
+            x_plus[j] += step;
+            x_minus[j] -= step;
+            let (plus, minus) = (measure(&x_plus)?, measure(&x_minus)?);
+            for (i, row) in h.iter_mut().enumerate() {
+                let difference = plus[i] - minus[i];
+                let difference = if i == 1 {
+                    wrap_pi(difference)
+                } else {
+                    difference
+                };
+                row[j] = difference / (2.0 * step);
+            }
+        }
+        let r = measurement_covariance.transpose().to_cols_array_2d();
+        let pht = mul(&self.covariance, &transpose(&h));
+        let mut s = mul(&h, &pht);
+        for (s, r) in s.iter_mut().zip(r) {
+            for (s, r) in s.iter_mut().zip(r) {
+                *s += r;
+            }
+        }
+        let gain = mul(&pht, &Lu::new(&s)?.inverse());
+
+        let correction = mul(&gain, &innovation.map(|v| [v]));
+        let x: [f64; 6] = std::array::from_fn(|i| x[i] + correction[i][0]);
+        // Joseph form (I - KH) P (I - KH)ᵀ + K R Kᵀ
+        let mut a = mul(&gain, &h);
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = if i == j { 1.0 } else { 0.0 } - *value;
+            }
+        }
+        let mut covariance = mul(&mul(&a, &self.covariance), &transpose(&a));
+        let krk = mul(&mul(&gain, &r), &transpose(&gain));
+        for (p, krk) in covariance.iter_mut().zip(krk) {
+            for (p, krk) in p.iter_mut().zip(krk) {
+                *p += krk;
+            }
+        }
+        self.state = with_state_vector(&self.state, &x);
+        self.covariance = symmetrise(covariance);
+        Ok(innovation)
+    }
+}
+
+fn mul<const N: usize, const M: usize, const K: usize>(
+    a: &[[f64; M]; N],
+    b: &[[f64; K]; M],
+) -> [[f64; K]; N] {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..M).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn transpose<const N: usize, const M: usize>(a: &[[f64; M]; N]) -> [[f64; N]; M] {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[j][i]))
+}
+
+fn symmetrise(a: [[f64; 6]; 6]) -> [[f64; 6]; 6] {
+    std::array::from_fn(|i| std::array::from_fn(|j| 0.5 * (a[i][j] + a[j][i])))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+
+    use crate::determination::tests::{observations, perturbed, truth};
+    use crate::frames::NoOpFrameTransformationProvider;
+    use crate::propagators::numerical::NumericalPropagator;
+    use crate::propagators::semi_analytical::Vallado;
+
+    use super::*;
+
+    // Returns `true` if the Cholesky decomposition of `a` exists
+    fn is_positive_definite(a: &DMat6) -> bool {
+        let mut l = [[0.0; 6]; 6];
+        for j in 0..6 {
+            let d = a[(j, j)] - (0..j).map(|k| l[j][k] * l[j][k]).sum::<f64>();
+            if d <= 0.0 {
+                return false;
+            }
+            l[j][j] = d.sqrt();
+            for i in j + 1..6 {
+                l[i][j] = (a[(i, j)] - (0..j).map(|k| l[i][k] * l[j][k]).sum::<f64>()) / l[j][j];
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_filter() {
+        let truth = truth();
+        let observations = observations(truth);
+        let covariance = DMat6::from_blocks(
+            DMat3::from_diagonal(DVec3::splat(25.0)),
+            DMat3::ZERO,
+            DMat3::ZERO,
+            DMat3::from_diagonal(DVec3::splat(2.5e-5)),
+        );
+        let mut filter = Filter::new(
+            perturbed(truth),
+            covariance,
+            NumericalPropagator::new,
+            NoOpFrameTransformationProvider,
+        )
+        .with_process_noise(1e-14);
+        let noise = DMat3::from_diagonal(DVec3::new(
+            1e-4,
+            0.01f64.to_radians().powi(2),
+            0.01f64.to_radians().powi(2),
+        ));
+
+        let mut innovations = vec![];
+        for obs in &observations {
+            innovations.push(filter.update(obs, noise).unwrap());
+            let covariance = filter.covariance();
+            assert_eq!(covariance, covariance.transpose());
+            assert!(is_positive_definite(&covariance));
+        }
+
+        // The first innovation reflects the a priori error and the last one the noise level
+        assert!(innovations.first().unwrap()[0].abs() > 1.0);
+        assert!(innovations.last().unwrap()[0].abs() < 0.01);
+
+        let state = filter.state();
+        let expected = Vallado::new(truth).propagate(state.time()).unwrap();
+        assert_eq!(state.time(), *observations.last().unwrap().time());
+        let error = (state.position() - expected.position()).length();
+        let sigma = (0..3)
+            .map(|i| filter.covariance()[(i, i)])
+            .sum::<f64>()
+            .sqrt();
+        assert!(error < 0.01);
+        assert!(sigma < 0.05);
+    }
+
+    #[test]
+    fn test_filter_predict() {
+        let truth = truth();
+        let q = 1e-10;
+        let mut filter = Filter::new(
+            truth,
+            DMat6::ZERO,
+            Vallado::new,
+            NoOpFrameTransformationProvider,
+        )
+        .with_process_noise(q);
+        let time = truth.time() + TimeDelta::from_seconds(60);
+        filter.predict(time).unwrap();
+
+        let expected = Vallado::new(truth).propagate(time).unwrap();
+        assert_eq!(filter.state().time(), time);
+        assert_close!(filter.state().position(), expected.position(), 1e-12);
+        // Without prior uncertainty only the process noise remains
+        let covariance = filter.covariance();
+        assert_close!(covariance[(0, 0)], q * 60f64.powi(3) / 3.0, 1e-15);
+        assert_close!(covariance[(1, 4)], q * 60f64.powi(2) / 2.0, 1e-15);
+        assert_close!(covariance[(5, 2)], q * 60f64.powi(2) / 2.0, 1e-15);
+        assert_close!(covariance[(3, 3)], q * 60.0, 1e-15);
+        assert_eq!(covariance[(0, 1)], 0.0);
+
+        // The uncertainty of the position grows along the orbit
+        let initial = DMat6::from_blocks(
+            DMat3::IDENTITY,
+            DMat3::ZERO,
+            DMat3::ZERO,
+            DMat3::from_diagonal(DVec3::splat(1e-6)),
+        );
+        let mut filter = Filter::new(
+            truth,
+            initial,
+            Vallado::new,
+            NoOpFrameTransformationProvider,
+        );
+        filter
+            .predict(truth.time() + TimeDelta::from_seconds(3000))
+            .unwrap();
+        let covariance = filter.covariance();
+        let trace: f64 = (0..3).map(|i| covariance[(i, i)]).sum();
+        assert!(trace > 3.0);
+        assert!(is_positive_definite(&covariance));
+    }
+}
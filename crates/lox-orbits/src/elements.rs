@@ -6,15 +6,21 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::f64::consts::TAU;
+use std::f64::consts::{PI, TAU};
 
 use float_eq::float_eq;
 use glam::{DMat3, DVec3};
+use thiserror::Error;
 
 use lox_bodies::{DynOrigin, PointMass, TryPointMass, UndefinedOriginPropertyError};
+use lox_io::ndm::omm::MeanElementsType;
+use lox_math::constants::f64::time::SECONDS_PER_DAY;
+use lox_math::math::{mod_two_pi, normalize_two_pi, unwrap_angles};
+use lox_math::series::{Series, SeriesError};
 use lox_time::deltas::TimeDelta;
 use lox_time::TimeLike;
 
+use crate::anomalies::mean_to_true;
 use crate::frames::{CoordinateSystem, DynFrame, Icrf, ReferenceFrame};
 use crate::states::State;
 
@@ -43,11 +49,199 @@ pub struct Keplerian<T: TimeLike, O: TryPointMass, R: ReferenceFrame> {
 
 pub type DynKeplerian<T> = Keplerian<T, DynOrigin, DynFrame>;
 
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ElementsError {
+    #[error("semi-major axis is required")]
+    MissingSemiMajorAxis,
+    #[error("semi-major axis {semi_major_axis} is invalid for eccentricity {eccentricity}, it must be positive for elliptic and negative for hyperbolic orbits")]
+    InvalidSemiMajorAxis {
+        semi_major_axis: f64,
+        eccentricity: f64,
+    },
+    #[error("eccentricity must be in [0, 1) for elliptic or greater than 1 for hyperbolic orbits but was {0}")]
+    InvalidEccentricity(f64),
+    #[error("inclination must be in [0, π] but was {0}")]
+    InvalidInclination(f64),
+    #[error("{field} must be finite but was {value}")]
+    NonFiniteAngle { field: &'static str, value: f64 },
+}
+
+/// Builder for [Keplerian] elements with validation of the individual elements.
+///
+/// All angles default to zero and the eccentricity defaults to zero, i.e. a circular, equatorial
+/// orbit. The semi-major axis must always be provided.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeplerianBuilder<T: TimeLike, O: PointMass> {
+    time: T,
+    origin: O,
+    semi_major_axis: Option<f64>,
+    eccentricity: f64,
+    inclination: f64,
+    longitude_of_ascending_node: f64,
+    argument_of_periapsis: f64,
+    true_anomaly: f64,
+}
+
+impl<T, O> KeplerianBuilder<T, O>
+where
+    T: TimeLike,
+    O: PointMass,
+{
+    pub fn new(time: T, origin: O) -> Self {
+        Self {
+            time,
+            origin,
+            semi_major_axis: None,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            true_anomaly: 0.0,
+        }
+    }
+
+    pub fn with_semi_major_axis(self, semi_major_axis: f64) -> Self {
+        Self {
+            semi_major_axis: Some(semi_major_axis),
+            ..self
+        }
+    }
+
+    pub fn with_eccentricity(self, eccentricity: f64) -> Self {
+        Self {
+            eccentricity,
+            ..self
+        }
+    }
+
+    pub fn with_inclination(self, inclination: f64) -> Self {
+        Self {
+            inclination,
+            ..self
+        }
+    }
+
+    pub fn with_longitude_of_ascending_node(self, longitude_of_ascending_node: f64) -> Self {
+        Self {
+            longitude_of_ascending_node,
+            ..self
+        }
+    }
+
+    pub fn with_argument_of_periapsis(self, argument_of_periapsis: f64) -> Self {
+        Self {
+            argument_of_periapsis,
+            ..self
+        }
+    }
+
+    pub fn with_true_anomaly(self, true_anomaly: f64) -> Self {
+        Self {
+            true_anomaly,
+            ..self
+        }
+    }
+
+    /// Builds the [Keplerian] elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [ElementsError] identifying the first element which is missing or out of range.
+    pub fn build(self) -> Result<Keplerian<T, O, Icrf>, ElementsError> {
+        let semi_major_axis = self
+            .semi_major_axis
+            .ok_or(ElementsError::MissingSemiMajorAxis)?;
+        let eccentricity = self.eccentricity;
+        if !eccentricity.is_finite() || eccentricity < 0.0 || eccentricity == 1.0 {
+            return Err(ElementsError::InvalidEccentricity(eccentricity));
+        }
+        let is_valid_semi_major_axis = if eccentricity < 1.0 {
+            semi_major_axis > 0.0
+        } else {
+            semi_major_axis < 0.0
+        };
+        if !semi_major_axis.is_finite() || !is_valid_semi_major_axis {
+            return Err(ElementsError::InvalidSemiMajorAxis {
+                semi_major_axis,
+                eccentricity,
+            });
+        }
+        if !(0.0..=PI).contains(&self.inclination) {
+            return Err(ElementsError::InvalidInclination(self.inclination));
+        }
+        for (field, value) in [
+            (
+                "longitude of ascending node",
+                self.longitude_of_ascending_node,
+            ),
+            ("argument of periapsis", self.argument_of_periapsis),
+            ("true anomaly", self.true_anomaly),
+        ] {
+            if !value.is_finite() {
+                return Err(ElementsError::NonFiniteAngle { field, value });
+            }
+        }
+        Ok(Keplerian::new(
+            self.time,
+            self.origin,
+            semi_major_axis,
+            eccentricity,
+            self.inclination,
+            self.longitude_of_ascending_node,
+            self.argument_of_periapsis,
+            self.true_anomaly,
+        ))
+    }
+}
+
 impl<T, O> Keplerian<T, O, Icrf>
 where
     T: TimeLike,
     O: PointMass,
 {
+    /// Returns a new [KeplerianBuilder] for the given epoch and central body.
+    pub fn builder(time: T, origin: O) -> KeplerianBuilder<T, O> {
+        KeplerianBuilder::new(time, origin)
+    }
+
+    /// Creates [Keplerian] elements from the mean elements of a CCSDS Orbit Mean-Elements Message.
+    ///
+    /// The NDM angles are given in degrees and are converted to radians. If the message only
+    /// provides the mean motion in revolutions per day, the semi-major axis is derived from it
+    /// using the gravitational parameter of `origin`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [ElementsError] if the elements are incomplete or out of range, e.g. if the
+    /// inclination is not within [0°, 180°].
+    pub fn from_omm_mean_elements(
+        time: T,
+        origin: O,
+        mean_elements: &MeanElementsType,
+    ) -> Result<Self, ElementsError> {
+        let eccentricity = mean_elements.eccentricity.0;
+        if !(0.0..1.0).contains(&eccentricity) {
+            return Err(ElementsError::InvalidEccentricity(eccentricity));
+        }
+        let semi_major_axis = match (&mean_elements.semi_major_axis, &mean_elements.mean_motion) {
+            (Some(semi_major_axis), _) => semi_major_axis.base,
+            (None, Some(mean_motion)) => {
+                let mean_motion = mean_motion.base * TAU / SECONDS_PER_DAY;
+                (origin.gravitational_parameter() / mean_motion.powi(2)).cbrt()
+            }
+            (None, None) => return Err(ElementsError::MissingSemiMajorAxis),
+        };
+        let mean_anomaly = mean_elements.mean_anomaly.base.to_radians();
+        Self::builder(time, origin)
+            .with_semi_major_axis(semi_major_axis)
+            .with_eccentricity(eccentricity)
+            .with_inclination(mean_elements.inclination.base.to_radians())
+            .with_longitude_of_ascending_node(mean_elements.ra_of_asc_node.base.to_radians())
+            .with_argument_of_periapsis(mean_elements.arg_of_pericenter.base.to_radians())
+            .with_true_anomaly(mean_to_true(mean_anomaly, eccentricity))
+            .build()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         time: T,
@@ -194,7 +388,7 @@ where
     O: TryPointMass + Clone,
     R: ReferenceFrame + Clone,
 {
-    pub(crate) fn to_cartesian(&self) -> State<T, O, R> {
+    pub fn to_cartesian(&self) -> State<T, O, R> {
         let (pos, vel) = self.to_perifocal();
         let rot = DMat3::from_rotation_z(self.longitude_of_ascending_node)
             * DMat3::from_rotation_x(self.inclination)
@@ -209,6 +403,90 @@ where
     }
 }
 
+/// Interpolates a time series of [Keplerian] elements, e.g. for plotting element histories.
+///
+/// The longitude of the ascending node, the argument of periapsis, and the true anomaly are
+/// unwrapped before interpolation and wrapped again afterwards, so that the interpolated angles
+/// pass smoothly through 0/2π instead of sweeping across the whole circle. Unwrapping assumes that
+/// consecutive samples are less than half a revolution apart in each angle.
+///
+/// This is intended for visualization only. The interpolated elements are not a solution of the
+/// equations of motion; use a propagator or a [Trajectory](crate::trajectories::Trajectory) for
+/// dynamics.
+#[derive(Clone, Debug)]
+pub struct KeplerianInterpolator<T: TimeLike, O: TryPointMass, R: ReferenceFrame> {
+    start_time: T,
+    origin: O,
+    frame: R,
+    semi_major_axis: Series<Vec<f64>, Vec<f64>>,
+    eccentricity: Series<Vec<f64>, Vec<f64>>,
+    inclination: Series<Vec<f64>, Vec<f64>>,
+    longitude_of_ascending_node: Series<Vec<f64>, Vec<f64>>,
+    argument_of_periapsis: Series<Vec<f64>, Vec<f64>>,
+    true_anomaly: Series<Vec<f64>, Vec<f64>>,
+}
+
+impl<T, O, R> KeplerianInterpolator<T, O, R>
+where
+    T: TimeLike + Clone,
+    O: TryPointMass + Clone,
+    R: ReferenceFrame + Clone,
+{
+    /// Creates a new interpolator from at least four [Keplerian] element sets with strictly
+    /// increasing times. The origin and frame are taken from the first element set.
+    pub fn new(elements: &[Keplerian<T, O, R>]) -> Result<Self, SeriesError> {
+        let Some(first) = elements.first() else {
+            return Err(SeriesError::InsufficientPoints(0, 4));
+        };
+        let start_time = first.time();
+        let t: Vec<f64> = elements
+            .iter()
+            .map(|k| (k.time() - start_time.clone()).to_decimal_seconds())
+            .collect();
+        let series = |values: Vec<f64>| Series::with_cubic_spline(t.clone(), values);
+        let angles = |angle: fn(&Keplerian<T, O, R>) -> f64| {
+            unwrap_angles(&elements.iter().map(angle).collect::<Vec<f64>>())
+        };
+        Ok(Self {
+            semi_major_axis: series(elements.iter().map(|k| k.semi_major_axis).collect())?,
+            eccentricity: series(elements.iter().map(|k| k.eccentricity).collect())?,
+            inclination: series(elements.iter().map(|k| k.inclination).collect())?,
+            longitude_of_ascending_node: series(angles(|k| k.longitude_of_ascending_node))?,
+            argument_of_periapsis: series(angles(|k| k.argument_of_periapsis))?,
+            true_anomaly: series(angles(|k| k.true_anomaly))?,
+            start_time,
+            origin: first.origin(),
+            frame: first.reference_frame(),
+        })
+    }
+
+    pub fn start_time(&self) -> T {
+        self.start_time.clone()
+    }
+
+    /// Returns the interpolated elements at `dt` after the first element set.
+    pub fn interpolate(&self, dt: TimeDelta) -> Keplerian<T, O, R> {
+        let t = dt.to_decimal_seconds();
+        Keplerian {
+            time: self.start_time() + dt,
+            origin: self.origin.clone(),
+            frame: self.frame.clone(),
+            semi_major_axis: self.semi_major_axis.interpolate(t),
+            eccentricity: self.eccentricity.interpolate(t),
+            inclination: self.inclination.interpolate(t),
+            longitude_of_ascending_node: mod_two_pi(
+                self.longitude_of_ascending_node.interpolate(t),
+            ),
+            argument_of_periapsis: mod_two_pi(self.argument_of_periapsis.interpolate(t)),
+            true_anomaly: normalize_two_pi(self.true_anomaly.interpolate(t), 0.0),
+        }
+    }
+
+    pub fn interpolate_at(&self, time: T) -> Keplerian<T, O, R> {
+        self.interpolate(time - self.start_time())
+    }
+}
+
 pub fn is_equatorial(inclination: f64) -> bool {
     float_eq!(inclination.abs(), 0.0, abs <= 1e-8)
 }
@@ -283,4 +561,252 @@ mod tests {
             rel <= 1e-6
         );
     }
+
+    #[test]
+    fn test_keplerian_builder() {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).expect("time should be valid");
+        let keplerian = Keplerian::builder(time, Earth)
+            .with_semi_major_axis(24464.560)
+            .with_eccentricity(0.7311)
+            .with_inclination(0.122138)
+            .with_longitude_of_ascending_node(1.00681)
+            .with_argument_of_periapsis(3.10686)
+            .with_true_anomaly(0.44369564302687126)
+            .build()
+            .expect("elements should be valid");
+        let exp = Keplerian::new(
+            time,
+            Earth,
+            24464.560,
+            0.7311,
+            0.122138,
+            1.00681,
+            3.10686,
+            0.44369564302687126,
+        );
+        assert_eq!(keplerian, exp);
+
+        let keplerian1 = keplerian.to_cartesian().to_keplerian();
+        assert_float_eq!(
+            keplerian.semi_major_axis(),
+            keplerian1.semi_major_axis(),
+            rel <= 1e-6
+        );
+        assert_float_eq!(
+            keplerian.eccentricity(),
+            keplerian1.eccentricity(),
+            abs <= 1e-6
+        );
+    }
+
+    #[test]
+    fn test_keplerian_builder_defaults() {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).expect("time should be valid");
+        let keplerian = Keplerian::builder(time, Earth)
+            .with_semi_major_axis(7000.0)
+            .build()
+            .expect("elements should be valid");
+        assert_eq!(keplerian.eccentricity(), 0.0);
+        assert_eq!(keplerian.inclination(), 0.0);
+        assert_eq!(keplerian.longitude_of_ascending_node(), 0.0);
+        assert_eq!(keplerian.argument_of_periapsis(), 0.0);
+        assert_eq!(keplerian.true_anomaly(), 0.0);
+        let state = keplerian.to_cartesian();
+        assert_float_eq!(state.position().length(), 7000.0, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_keplerian_builder_errors() {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).expect("time should be valid");
+        let builder = Keplerian::builder(time, Earth);
+        assert_eq!(
+            builder.clone().build(),
+            Err(ElementsError::MissingSemiMajorAxis)
+        );
+        let builder = builder.with_semi_major_axis(7000.0);
+        assert_eq!(
+            builder.clone().with_eccentricity(-0.1).build(),
+            Err(ElementsError::InvalidEccentricity(-0.1))
+        );
+        assert_eq!(
+            builder.clone().with_eccentricity(1.0).build(),
+            Err(ElementsError::InvalidEccentricity(1.0))
+        );
+        assert_eq!(
+            builder.clone().with_eccentricity(1.5).build(),
+            Err(ElementsError::InvalidSemiMajorAxis {
+                semi_major_axis: 7000.0,
+                eccentricity: 1.5
+            })
+        );
+        assert_eq!(
+            builder.clone().with_inclination(4.0).build(),
+            Err(ElementsError::InvalidInclination(4.0))
+        );
+        assert!(matches!(
+            builder.with_true_anomaly(f64::NAN).build(),
+            Err(ElementsError::NonFiniteAngle {
+                field: "true anomaly",
+                ..
+            })
+        ));
+    }
+
+    const OMM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<omm id="CCSDS_OMM_VERS" version="2.0">
+<header>
+    <CREATION_DATE>2021-03-24T23:00:00.000</CREATION_DATE>
+    <ORIGINATOR>CelesTrak</ORIGINATOR>
+</header>
+<body>
+<segment>
+    <metadata>
+        <OBJECT_NAME>STARLETTE</OBJECT_NAME>
+        <OBJECT_ID>1975-010A</OBJECT_ID>
+        <CENTER_NAME>EARTH</CENTER_NAME>
+        <REF_FRAME>TEME</REF_FRAME>
+        <TIME_SYSTEM>UTC</TIME_SYSTEM>
+        <MEAN_ELEMENT_THEORY>SGP4</MEAN_ELEMENT_THEORY>
+    </metadata>
+    <data>
+        <meanElements>
+            <EPOCH>2008-09-20T12:25:40.104192</EPOCH>
+            <MEAN_MOTION units="rev/day">15.72125391</MEAN_MOTION>
+            <ECCENTRICITY>0.0006703</ECCENTRICITY>
+            <INCLINATION units="deg">51.6416</INCLINATION>
+            <RA_OF_ASC_NODE units="deg">247.4627</RA_OF_ASC_NODE>
+            <ARG_OF_PERICENTER units="deg">130.5360</ARG_OF_PERICENTER>
+            <MEAN_ANOMALY units="deg">325.0288</MEAN_ANOMALY>
+        </meanElements>
+    </data>
+</segment>
+</body>
+</omm>"#;
+
+    fn omm_mean_elements() -> MeanElementsType {
+        use lox_io::ndm::omm::OmmType;
+        use lox_io::ndm::xml::FromXmlStr;
+
+        OmmType::from_xml_str(OMM_XML)
+            .expect("OMM should be valid")
+            .body
+            .segment
+            .data
+            .mean_elements
+    }
+
+    #[test]
+    fn test_keplerian_from_omm_mean_elements() {
+        let time = time!(Tdb, 2008, 9, 20, 12, 25, 40.104192).expect("time should be valid");
+        let keplerian = Keplerian::from_omm_mean_elements(time, Earth, &omm_mean_elements())
+            .expect("elements should be valid");
+
+        assert_float_eq!(keplerian.inclination(), 0.901316, abs <= 1e-6);
+        assert_float_eq!(
+            keplerian.inclination(),
+            51.6416f64.to_radians(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(
+            keplerian.longitude_of_ascending_node(),
+            247.4627f64.to_radians(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(
+            keplerian.argument_of_periapsis(),
+            130.5360f64.to_radians(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(keplerian.eccentricity(), 0.0006703, rel <= 1e-15);
+        // 15.72 rev/day corresponds to a semi-major axis of roughly 6731 km
+        assert_float_eq!(keplerian.semi_major_axis(), 6731.0, abs <= 1.0);
+        assert_float_eq!(
+            keplerian.orbital_period().to_decimal_seconds(),
+            86400.0 / 15.72125391,
+            rel <= 1e-10
+        );
+        // The mean anomaly is close to the true anomaly for a near-circular orbit
+        assert_float_eq!(
+            keplerian.true_anomaly().rem_euclid(TAU),
+            325.0288f64.to_radians(),
+            abs <= 2.0 * 0.0006703
+        );
+    }
+
+    #[test]
+    fn test_keplerian_from_omm_mean_elements_errors() {
+        let time = time!(Tdb, 2008, 9, 20, 12, 25, 40.104192).expect("time should be valid");
+
+        let mut mean_elements = omm_mean_elements();
+        mean_elements.inclination.base = 190.0;
+        assert_eq!(
+            Keplerian::from_omm_mean_elements(time, Earth, &mean_elements),
+            Err(ElementsError::InvalidInclination(190f64.to_radians()))
+        );
+
+        let mut mean_elements = omm_mean_elements();
+        mean_elements.mean_motion = None;
+        assert_eq!(
+            Keplerian::from_omm_mean_elements(time, Earth, &mean_elements),
+            Err(ElementsError::MissingSemiMajorAxis)
+        );
+    }
+
+    #[test]
+    fn test_keplerian_interpolator_node_wrap() {
+        let t0 = time!(Tdb, 2024, 1, 1).expect("time should be valid");
+        let raan0 = TAU - 0.025;
+        let raan_rate = 0.01 / 60.0;
+        let elements: Vec<_> = (0..6)
+            .map(|i| {
+                let dt = TimeDelta::from_seconds(60 * i);
+                Keplerian::new(
+                    t0 + dt,
+                    Earth,
+                    7000.0,
+                    0.01,
+                    0.9,
+                    mod_two_pi(raan0 + raan_rate * dt.to_decimal_seconds()),
+                    1.0,
+                    0.5,
+                )
+            })
+            .collect();
+        // The sampled node wraps from just below 2π to just above zero
+        assert!(elements[2].longitude_of_ascending_node() > 6.2);
+        assert!(elements[3].longitude_of_ascending_node() < 0.01);
+
+        let interpolator = KeplerianInterpolator::new(&elements).unwrap();
+        let raan: Vec<f64> = (0..=300)
+            .map(|i| {
+                let k = interpolator.interpolate(TimeDelta::from_seconds(i));
+                assert_eq!(k.time(), t0 + TimeDelta::from_seconds(i));
+                assert_float_eq!(k.semi_major_axis(), 7000.0, rel <= 1e-12);
+                k.longitude_of_ascending_node()
+            })
+            .collect();
+        for (i, &raan) in raan.iter().enumerate() {
+            let expected = mod_two_pi(raan0 + raan_rate * i as f64);
+            assert_float_eq!(normalize_two_pi(raan - expected, 0.0), 0.0, abs <= 1e-12);
+        }
+        // No spike through the middle of the circle, only small forward steps across the wrap
+        for pair in raan.windows(2) {
+            let step = normalize_two_pi(pair[1] - pair[0], 0.0);
+            assert!(step > 0.0 && step < 2.0 * raan_rate);
+        }
+    }
+
+    #[test]
+    fn test_keplerian_interpolator_insufficient_elements() {
+        let time = time!(Tdb, 2024, 1, 1).expect("time should be valid");
+        let elements = vec![Keplerian::new(time, Earth, 7000.0, 0.0, 0.0, 0.0, 0.0, 0.0)];
+        assert_eq!(
+            KeplerianInterpolator::new(&elements).unwrap_err(),
+            SeriesError::InsufficientPoints(1, 4)
+        );
+        assert_eq!(
+            KeplerianInterpolator::<Time<Tdb>, Earth, Icrf>::new(&[]).unwrap_err(),
+            SeriesError::InsufficientPoints(0, 4)
+        );
+    }
 }
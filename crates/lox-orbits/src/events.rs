@@ -4,10 +4,16 @@ use std::fmt::Display;
 use std::iter::zip;
 use thiserror::Error;
 
+use lox_bodies::{Origin, PointMass};
 use lox_math::roots::FindBracketedRoot;
 use lox_time::deltas::TimeDelta;
 use lox_time::TimeLike;
 
+use crate::elements::is_circular;
+use crate::frames::ReferenceFrame;
+use crate::states::eccentricity_vector;
+use crate::trajectories::Trajectory;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ZeroCrossing {
     Up,
@@ -97,6 +103,72 @@ pub fn find_events<F: Fn(f64) -> f64 + Copy, T: TimeLike + Clone, R: FindBracket
     Ok(events)
 }
 
+/// Maximises the unimodal function `func` within `bracket` by golden-section search.
+pub(crate) fn golden_section_max<F: Fn(f64) -> f64>(func: F, bracket: (f64, f64)) -> (f64, f64) {
+    const TOLERANCE: f64 = 1e-3;
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = bracket;
+    let mut c = b - inv_phi * (b - a);
+    let mut d = a + inv_phi * (b - a);
+    let mut fc = func(c);
+    let mut fd = func(d);
+    while (b - a).abs() > TOLERANCE {
+        if fc > fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - inv_phi * (b - a);
+            fc = func(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + inv_phi * (b - a);
+            fd = func(d);
+        }
+    }
+    let t = (a + b) / 2.0;
+    (t, func(t))
+}
+
+/// Augments `steps` with the maxima of `func` which lie between samples where `func` is negative
+/// but reaches positive values, i.e. intervals which would be missed by sampling alone.
+pub(crate) fn with_grazing_maxima<F: Fn(f64) -> f64 + Copy>(func: F, steps: &[f64]) -> Vec<f64> {
+    let values: Vec<f64> = steps.iter().map(|&t| func(t)).collect();
+    let mut augmented = Vec::with_capacity(steps.len());
+    augmented.push(steps[0]);
+    for ((_, f0), (&t1, f1), (&t2, f2)) in steps.iter().zip(&values).tuple_windows() {
+        if *f1 >= *f0 && *f1 >= *f2 && *f1 < 0.0 {
+            let (t, f) = golden_section_max(func, (augmented[augmented.len() - 1], t2));
+            if f > 0.0 && t > augmented[augmented.len() - 1] && t < t2 {
+                if t < t1 {
+                    augmented.push(t);
+                    augmented.push(t1);
+                } else {
+                    augmented.push(t1);
+                    augmented.push(t);
+                }
+                continue;
+            }
+        }
+        augmented.push(t1);
+    }
+    augmented.push(steps[steps.len() - 1]);
+    augmented.dedup();
+    augmented
+}
+
+/// Augments `steps` with the extrema of `func` between samples that could hide a pair of zero
+/// crossings, i.e. maxima above zero between negative samples and minima below zero between
+/// positive samples.
+pub(crate) fn with_grazing_extrema<F: Fn(f64) -> f64 + Copy>(func: F, steps: &[f64]) -> Vec<f64> {
+    let mut augmented = with_grazing_maxima(func, steps);
+    augmented.extend(with_grazing_maxima(|t| -func(t), steps));
+    augmented.sort_by(f64::total_cmp);
+    augmented.dedup();
+    augmented
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Window<T: TimeLike> {
     start: T,
@@ -178,13 +250,103 @@ pub fn find_windows<F: Fn(f64) -> f64 + Copy, T: TimeLike + Clone, R: FindBracke
     }
 }
 
+/// The kind of an [Apsis].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApsisKind {
+    Periapsis,
+    Apoapsis,
+}
+
+impl Display for ApsisKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApsisKind::Periapsis => write!(f, "periapsis"),
+            ApsisKind::Apoapsis => write!(f, "apoapsis"),
+        }
+    }
+}
+
+/// A passage through the periapsis or apoapsis of an orbit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Apsis<T: TimeLike> {
+    kind: ApsisKind,
+    time: T,
+    radius: f64,
+}
+
+impl<T: TimeLike> Apsis<T> {
+    pub fn kind(&self) -> ApsisKind {
+        self.kind
+    }
+
+    pub fn time(&self) -> &T {
+        &self.time
+    }
+
+    /// Returns the distance from the origin of the trajectory in km.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+/// The time tolerance in seconds for locating apsides.
+const APSIS_TOLERANCE: f64 = 1e-6;
+
+/// Finds the periapsis and apoapsis passages along `trajectory`.
+///
+/// Apsides are the zero crossings of the radial velocity `r·v`, which turns positive at periapsis
+/// and negative at apoapsis. The crossings are refined by root-finding. Since the apsides of a
+/// circular orbit are undefined, no apsides are returned if the osculating eccentricity of all
+/// states of the trajectory is circular to within the tolerance of [is_circular].
+pub fn find_apsides<T, O, R>(trajectory: &Trajectory<T, O, R>) -> Vec<Apsis<T>>
+where
+    T: TimeLike + Clone,
+    O: Origin + PointMass + Clone,
+    R: ReferenceFrame + Clone,
+{
+    let circular = trajectory.states().iter().all(|s| {
+        let mu = s.origin().gravitational_parameter();
+        is_circular(eccentricity_vector(s.position(), s.velocity(), mu).length())
+    });
+    if circular {
+        return vec![];
+    }
+
+    trajectory
+        .find_events_with_tolerance(|s| s.position().dot(s.velocity()), APSIS_TOLERANCE)
+        .into_iter()
+        .map(|event| {
+            let kind = match event.crossing() {
+                ZeroCrossing::Up => ApsisKind::Periapsis,
+                ZeroCrossing::Down => ApsisKind::Apoapsis,
+            };
+            let radius = trajectory
+                .interpolate_at(event.time().clone())
+                .position()
+                .length();
+            Apsis {
+                kind,
+                time: event.time,
+                radius,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::anomalies::{eccentric_to_mean, true_to_eccentric};
+    use crate::elements::Keplerian;
+    use crate::frames::Icrf;
+    use crate::propagators::semi_analytical::Vallado;
+    use crate::propagators::Propagator;
+    use lox_bodies::Earth;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
     use lox_math::roots::Brent;
     use lox_time::time_scales::Tai;
+    use lox_time::time_scales::Tdb;
     use lox_time::{time, Time};
     use std::f64::consts::{PI, TAU};
 
@@ -262,4 +424,111 @@ mod tests {
         assert_eq!(windows[0].start, start);
         assert_eq!(windows[0].end, end);
     }
+
+    fn keplerian(eccentricity: f64) -> Keplerian<Time<Tdb>, Earth, Icrf> {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).unwrap();
+        Keplerian::new(
+            time,
+            Earth,
+            24464.560,
+            eccentricity,
+            0.122138,
+            1.00681,
+            3.10686,
+            0.44369564302687126,
+        )
+    }
+
+    fn trajectory(k0: &Keplerian<Time<Tdb>, Earth, Icrf>) -> Trajectory<Time<Tdb>, Earth, Icrf> {
+        let t_end = (1.5 * k0.orbital_period().to_decimal_seconds()) as i64;
+        let steps = TimeDelta::range(0..=t_end)
+            .step_by(60)
+            .map(|dt| k0.time() + dt);
+        Vallado::new(k0.to_cartesian())
+            .propagate_all(steps)
+            .unwrap()
+    }
+
+    // Returns the time of the last periapsis passage before the epoch of `k0` in seconds
+    fn time_of_periapsis(k0: &Keplerian<Time<Tdb>, Earth, Icrf>) -> f64 {
+        let eccentricity = k0.eccentricity();
+        let n = TAU / k0.orbital_period().to_decimal_seconds();
+        let mean_anomaly = eccentric_to_mean(
+            true_to_eccentric(k0.true_anomaly(), eccentricity),
+            eccentricity,
+        );
+        -mean_anomaly / n
+    }
+
+    #[test]
+    fn test_find_apsides() {
+        let eccentricity = 0.7311;
+        let k0 = keplerian(eccentricity);
+        let a = k0.semi_major_axis();
+        let t_periapsis = time_of_periapsis(&k0);
+        let period = k0.orbital_period().to_decimal_seconds();
+
+        let apsides = find_apsides(&trajectory(&k0));
+
+        let expected = [
+            (
+                ApsisKind::Apoapsis,
+                t_periapsis + period / 2.0,
+                a * (1.0 + eccentricity),
+            ),
+            (
+                ApsisKind::Periapsis,
+                t_periapsis + period,
+                a * (1.0 - eccentricity),
+            ),
+            (
+                ApsisKind::Apoapsis,
+                t_periapsis + 1.5 * period,
+                a * (1.0 + eccentricity),
+            ),
+        ];
+        assert_eq!(apsides.len(), expected.len());
+        for (apsis, (kind, t, radius)) in apsides.iter().zip(expected) {
+            assert_eq!(apsis.kind(), kind);
+            let dt = (*apsis.time() - k0.time()).to_decimal_seconds();
+            assert!((dt - t).abs() < 1e-2, "{dt} != {t}");
+            assert!((apsis.radius() - radius).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_find_apsides_short_arc() {
+        let eccentricity = 0.7311;
+        let k0 = keplerian(eccentricity);
+        let period = k0.orbital_period().to_decimal_seconds();
+        let t_periapsis = time_of_periapsis(&k0) + period;
+
+        // The radius varies by less than a metre over the arc around periapsis although the
+        // orbit is highly eccentric
+        let steps = (-5..=5).map(|i| {
+            k0.time() + TimeDelta::from_decimal_seconds(t_periapsis + 1e-3 * i as f64).unwrap()
+        });
+        let trajectory = Vallado::new(k0.to_cartesian())
+            .propagate_all(steps)
+            .unwrap();
+        let radii: Vec<f64> = trajectory
+            .states()
+            .iter()
+            .map(|s| s.position().length())
+            .collect();
+        let r_min = radii.iter().copied().fold(f64::INFINITY, f64::min);
+        let r_max = radii.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert!(is_circular((r_max - r_min) / (r_max + r_min)));
+
+        let apsides = find_apsides(&trajectory);
+        assert_eq!(apsides.len(), 1);
+        assert_eq!(apsides[0].kind(), ApsisKind::Periapsis);
+        let dt = (*apsides[0].time() - k0.time()).to_decimal_seconds();
+        assert!((dt - t_periapsis).abs() < 1e-3, "{dt} != {t_periapsis}");
+    }
+
+    #[test]
+    fn test_find_apsides_circular() {
+        assert!(find_apsides(&trajectory(&keplerian(0.0))).is_empty());
+    }
 }
@@ -6,16 +6,17 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::frames::iau::{icrf_to_bodyfixed, IcrfToBodyFixedError};
+use crate::frames::iau::{icrf_to_bodyfixed, icrf_to_iau, IcrfToBodyFixedError};
 use crate::frames::iers::{cirf_to_tirf, icrf_to_cirf, tirf_to_itrf};
 use crate::rotations::Rotation;
-use glam::{DMat3, DVec3};
+use glam::DMat3;
 use lox_bodies::{DynOrigin, Origin, RotationalElements, TryRotationalElements};
 use lox_math::types::units::Seconds;
+use lox_time::julian_dates::JulianDate;
 use lox_time::time_scales::Tdb;
 use lox_time::transformations::{OffsetProvider, TryToScale};
 use lox_time::TimeLike;
-use std::f64::consts::{FRAC_PI_2, TAU};
+use std::f64::consts::PI;
 use std::{convert::Infallible, str::FromStr};
 use thiserror::Error;
 
@@ -49,6 +50,10 @@ pub trait TryToFrame<R: ReferenceFrame, P: FrameTransformationProvider> {
     fn try_to_frame(&self, frame: R, provider: &P) -> Result<Self::Output, Self::Error>;
 }
 
+/// The axes of the International Celestial Reference Frame.
+///
+/// The frame can be combined with any origin to form a body-centered inertial
+/// frame, e.g. the Mars-centered ICRF.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Icrf;
 
@@ -66,6 +71,33 @@ impl ReferenceFrame for Icrf {
     }
 }
 
+/// The mean ecliptic and equinox of J2000 as used by JPL Horizons and SPICE (`ECLIPJ2000`).
+///
+/// The frame is obtained by rotating the ICRF about its x-axis by the IAU 1976 obliquity of the
+/// ecliptic at J2000 (84381.448 arcseconds).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
+pub struct EclipticJ2000;
+
+impl ReferenceFrame for EclipticJ2000 {
+    fn name(&self) -> String {
+        "Mean Ecliptic and Equinox of J2000".to_string()
+    }
+
+    fn abbreviation(&self) -> String {
+        "ECLIPJ2000".to_string()
+    }
+
+    fn is_rotating(&self) -> bool {
+        false
+    }
+}
+
+const OBLIQUITY_J2000: f64 = 84381.448 / 3600.0 * PI / 180.0;
+
+fn icrf_to_ecliptic_j2000() -> Rotation {
+    Rotation::new(DMat3::from_rotation_x(-OBLIQUITY_J2000))
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Cirf;
 
@@ -122,15 +154,10 @@ pub struct BodyFixed<T: RotationalElements>(pub T);
 
 impl<T: RotationalElements> BodyFixed<T> {
     pub fn rotation(&self, seconds: Seconds) -> Rotation {
-        let (right_ascension, declination, rotation_angle) = self.0.rotational_elements(seconds);
-        let (right_ascension_rate, declination_rate, rotation_rate) =
-            self.0.rotational_element_rates(seconds);
-        let m1 = DMat3::from_rotation_z(-(right_ascension + FRAC_PI_2));
-        let m2 = DMat3::from_rotation_x(-(FRAC_PI_2 - declination));
-        let m3 = DMat3::from_rotation_z(-(rotation_angle % TAU));
-        let m = m3 * m2 * m1;
-        let v = DVec3::new(right_ascension_rate, -declination_rate, rotation_rate);
-        Rotation::new(m).with_angular_velocity(v)
+        icrf_to_iau(
+            self.0.rotational_elements(seconds),
+            self.0.rotational_element_rates(seconds),
+        )
     }
 }
 
@@ -157,6 +184,7 @@ impl<T: RotationalElements> ReferenceFrame for BodyFixed<T> {
 pub enum DynFrame {
     #[default]
     Icrf,
+    EclipticJ2000,
     Cirf,
     Tirf,
     Itrf,
@@ -167,6 +195,7 @@ impl ReferenceFrame for DynFrame {
     fn name(&self) -> String {
         match self {
             DynFrame::Icrf => Icrf.name(),
+            DynFrame::EclipticJ2000 => EclipticJ2000.name(),
             DynFrame::Cirf => Cirf.name(),
             DynFrame::Tirf => Tirf.name(),
             DynFrame::Itrf => Itrf.name(),
@@ -183,6 +212,7 @@ impl ReferenceFrame for DynFrame {
     fn abbreviation(&self) -> String {
         match self {
             DynFrame::Icrf => Icrf.abbreviation(),
+            DynFrame::EclipticJ2000 => EclipticJ2000.abbreviation(),
             DynFrame::Cirf => Cirf.abbreviation(),
             DynFrame::Tirf => Tirf.abbreviation(),
             DynFrame::Itrf => Itrf.abbreviation(),
@@ -195,7 +225,7 @@ impl ReferenceFrame for DynFrame {
 
     fn is_rotating(&self) -> bool {
         match self {
-            DynFrame::Icrf | DynFrame::Cirf => false,
+            DynFrame::Icrf | DynFrame::EclipticJ2000 | DynFrame::Cirf => false,
             DynFrame::Tirf | DynFrame::Itrf | DynFrame::BodyFixed(_) => true,
         }
     }
@@ -221,6 +251,7 @@ impl FromStr for DynFrame {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "icrf" | "ICRF" => Ok(DynFrame::Icrf),
+            "eclipj2000" | "ECLIPJ2000" => Ok(DynFrame::EclipticJ2000),
             "cirf" | "CIRF" => Ok(DynFrame::Cirf),
             "tirf" | "TIRF" => Ok(DynFrame::Tirf),
             "itrf" | "ITRF" => Ok(DynFrame::Itrf),
@@ -256,75 +287,190 @@ impl<P: FrameTransformationProvider> TryRotateTo<DynFrame, P> for DynFrame {
         time: T,
         provider: &P,
     ) -> Result<Rotation, Self::Error> {
-        // FIXME
+        self.try_rotation_with(frame, time, provider, &mut icrf_to_cirf)
+    }
+}
+
+impl DynFrame {
+    /// Like [TryRotateTo::try_rotation] but obtains the precession-nutation rotation from the
+    /// ICRF to the CIRF at a given number of Julian centuries since J2000 from
+    /// `precession_nutation`, which allows callers to reuse it for multiple epochs.
+    pub(crate) fn try_rotation_with<T, P, F>(
+        &self,
+        frame: &DynFrame,
+        time: T,
+        provider: &P,
+        precession_nutation: &mut F,
+    ) -> Result<Rotation, IcrfToBodyFixedError>
+    where
+        T: TimeLike + TryToScale<Tdb, P> + Clone,
+        P: FrameTransformationProvider,
+        F: FnMut(f64) -> Rotation,
+    {
+        // Precession-nutation and the TIO locator are evaluated in TDB. The Earth rotation angle
+        // requires UT1, which a generic provider cannot supply, and is therefore evaluated in the
+        // time scale of `time`, see `iers::icrf_to_itrf` for UT1-based rotations.
         let seconds_j2000 = time.seconds_since_j2000();
-        let centuries_j2000 = time.centuries_since_j2000();
+        let centuries_j2000 = time
+            .try_to_scale(Tdb, provider)
+            .map_err(|err| IcrfToBodyFixedError::TimeError(err.to_string()))?
+            .centuries_since_j2000();
         match self {
             DynFrame::Icrf => match frame {
                 DynFrame::Icrf => Ok(Rotation::IDENTITY),
-                DynFrame::Cirf => Ok(icrf_to_cirf(centuries_j2000)),
+                DynFrame::EclipticJ2000 => Ok(icrf_to_ecliptic_j2000()),
+                DynFrame::Cirf => Ok(precession_nutation(centuries_j2000)),
                 DynFrame::Tirf => {
-                    Ok(icrf_to_cirf(centuries_j2000).compose(&cirf_to_tirf(seconds_j2000)))
+                    Ok(precession_nutation(centuries_j2000).compose(&cirf_to_tirf(seconds_j2000)))
                 }
-                DynFrame::Itrf => Ok(icrf_to_cirf(centuries_j2000)
+                DynFrame::Itrf => Ok(precession_nutation(centuries_j2000)
                     .compose(&cirf_to_tirf(seconds_j2000))
                     .compose(&tirf_to_itrf(centuries_j2000))),
                 DynFrame::BodyFixed(target) => icrf_to_bodyfixed(time, target, provider),
             },
+            DynFrame::EclipticJ2000 => match frame {
+                DynFrame::Icrf => Ok(icrf_to_ecliptic_j2000().transpose()),
+                DynFrame::EclipticJ2000 => Ok(Rotation::IDENTITY),
+                DynFrame::Cirf | DynFrame::Tirf | DynFrame::Itrf | DynFrame::BodyFixed(_) => {
+                    Ok(self
+                        .try_rotation_with(
+                            &DynFrame::Icrf,
+                            time.clone(),
+                            provider,
+                            precession_nutation,
+                        )?
+                        .compose(&DynFrame::Icrf.try_rotation_with(
+                            frame,
+                            time,
+                            provider,
+                            precession_nutation,
+                        )?))
+                }
+            },
             DynFrame::Cirf => match frame {
-                DynFrame::Icrf => Ok(icrf_to_cirf(centuries_j2000).transpose()),
+                DynFrame::Icrf => Ok(precession_nutation(centuries_j2000).transpose()),
                 DynFrame::Cirf => Ok(Rotation::IDENTITY),
                 DynFrame::Tirf => Ok(cirf_to_tirf(seconds_j2000)),
                 DynFrame::Itrf => {
                     Ok(cirf_to_tirf(seconds_j2000).compose(&tirf_to_itrf(centuries_j2000)))
                 }
-                DynFrame::BodyFixed(_) => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                DynFrame::EclipticJ2000 | DynFrame::BodyFixed(_) => Ok(self
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
             },
             DynFrame::Tirf => match frame {
                 DynFrame::Icrf => Ok(cirf_to_tirf(seconds_j2000)
                     .transpose()
-                    .compose(&icrf_to_cirf(centuries_j2000).transpose())),
+                    .compose(&precession_nutation(centuries_j2000).transpose())),
                 DynFrame::Cirf => Ok(cirf_to_tirf(seconds_j2000).transpose()),
                 DynFrame::Tirf => Ok(Rotation::IDENTITY),
                 DynFrame::Itrf => Ok(tirf_to_itrf(centuries_j2000)),
-                DynFrame::BodyFixed(_) => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                DynFrame::EclipticJ2000 | DynFrame::BodyFixed(_) => Ok(self
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
             },
             DynFrame::Itrf => match frame {
                 DynFrame::Icrf => Ok(tirf_to_itrf(centuries_j2000)
                     .transpose()
                     .compose(&cirf_to_tirf(seconds_j2000).transpose())
-                    .compose(&icrf_to_cirf(centuries_j2000).transpose())),
+                    .compose(&precession_nutation(centuries_j2000).transpose())),
                 DynFrame::Cirf => Ok(tirf_to_itrf(centuries_j2000)
                     .transpose()
                     .compose(&cirf_to_tirf(seconds_j2000).transpose())),
                 DynFrame::Tirf => Ok(tirf_to_itrf(centuries_j2000).transpose()),
                 DynFrame::Itrf => Ok(Rotation::IDENTITY),
-                DynFrame::BodyFixed(_) => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                DynFrame::EclipticJ2000 | DynFrame::BodyFixed(_) => Ok(self
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
             },
             DynFrame::BodyFixed(origin) => match frame {
                 DynFrame::Icrf => Ok(icrf_to_bodyfixed(time, origin, provider)?.transpose()),
-                DynFrame::Cirf => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                DynFrame::EclipticJ2000 | DynFrame::Cirf => Ok(self
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
                 DynFrame::Tirf => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
                 DynFrame::Itrf => Ok(self
-                    .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                    .try_rotation_with(
+                        &DynFrame::Icrf,
+                        time.clone(),
+                        provider,
+                        precession_nutation,
+                    )?
+                    .compose(&DynFrame::Icrf.try_rotation_with(
+                        frame,
+                        time,
+                        provider,
+                        precession_nutation,
+                    )?)),
                 DynFrame::BodyFixed(target) => {
                     if origin == target {
                         Ok(Rotation::IDENTITY)
                     } else {
                         Ok(self
-                            .try_rotation(&DynFrame::Icrf, time.clone(), provider)?
-                            .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?))
+                            .try_rotation_with(
+                                &DynFrame::Icrf,
+                                time.clone(),
+                                provider,
+                                precession_nutation,
+                            )?
+                            .compose(&DynFrame::Icrf.try_rotation_with(
+                                frame,
+                                time,
+                                provider,
+                                precession_nutation,
+                            )?))
                     }
                 }
             },
@@ -335,6 +481,7 @@ impl<P: FrameTransformationProvider> TryRotateTo<DynFrame, P> for DynFrame {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use glam::DVec3;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
     use lox_time::transformations::ToTai;
@@ -351,6 +498,30 @@ mod tests {
         assert_eq!(act, exp)
     }
 
+    #[test]
+    fn test_icrf_to_ecliptic_j2000() {
+        let time = Utc::from_iso("2024-07-05T09:09:18.173").unwrap().to_tai();
+        let provider = &NoOpFrameTransformationProvider;
+
+        // The ecliptic pole lies in the ICRF y-z plane, tilted by the obliquity.
+        let pole = DVec3::new(0.0, -OBLIQUITY_J2000.sin(), OBLIQUITY_J2000.cos());
+        let rot = DynFrame::Icrf
+            .try_rotation(&DynFrame::EclipticJ2000, time, provider)
+            .unwrap();
+        assert_close!(rot.rotate_position(pole), DVec3::Z, 1e-15);
+        assert_close!(rot.rotate_position(DVec3::X), DVec3::X, 1e-15);
+
+        let r = DVec3::new(-5530.01774359, -3487.0895338, -1850.03476185);
+        let v = DVec3::new(1.29534407, -5.02456882, 5.6391936);
+        let (r_ecl, v_ecl) = rot.rotate_state(r, v);
+        let back = DynFrame::EclipticJ2000
+            .try_rotation(&DynFrame::Icrf, time, provider)
+            .unwrap();
+        let (r_act, v_act) = back.rotate_state(r_ecl, v_ecl);
+        assert_close!(r_act, r, 1e-9);
+        assert_close!(v_act, v, 1e-12);
+    }
+
     #[rstest]
     #[case(
         DynFrame::BodyFixed(DynOrigin::Earth),
@@ -7,7 +7,7 @@
  */
 use crate::rotations::Rotation;
 use glam::{DMat3, DVec3};
-use lox_bodies::{TryRotationalElements, UndefinedOriginPropertyError};
+use lox_bodies::{Elements, TryRotationalElements, UndefinedOriginPropertyError};
 use lox_time::julian_dates::JulianDate;
 use lox_time::time_scales::Tdb;
 use lox_time::transformations::{OffsetProvider, TryToScale};
@@ -36,16 +36,35 @@ pub(crate) fn icrf_to_bodyfixed<
         .try_to_scale(Tdb, provider)
         .map_err(|err| IcrfToBodyFixedError::TimeError(err.to_string()))?
         .seconds_since_j2000();
-    let (right_ascension, declination, rotation_angle) = body.try_rotational_elements(seconds)?;
-    let (right_ascension_rate, declination_rate, rotation_rate) =
-        body.try_rotational_element_rates(seconds)?;
+    let elements = body.try_rotational_elements(seconds)?;
+    let rates = body.try_rotational_element_rates(seconds)?;
+    Ok(icrf_to_iau(elements, rates))
+}
+
+/// Returns the rotation from the ICRF to the IAU body-fixed frame of a body from its rotational
+/// elements, i.e. the right ascension and declination of the pole and the prime meridian angle,
+/// and their rates.
+///
+/// The rotation is composed of the 3-1-3 Euler sequence with the angles `α + 90°`, `90° - δ` and
+/// `W`. Its time derivative is obtained from the derivatives of all three elementary rotations,
+/// so that the velocity transformation is also correct for bodies with a moving pole.
+pub fn icrf_to_iau(elements: Elements, rates: Elements) -> Rotation {
+    let (right_ascension, declination, rotation_angle) = elements;
+    let (right_ascension_rate, declination_rate, rotation_rate) = rates;
 
     let m1 = DMat3::from_rotation_z(-(right_ascension + FRAC_PI_2));
     let m2 = DMat3::from_rotation_x(-(FRAC_PI_2 - declination));
     let m3 = DMat3::from_rotation_z(-(rotation_angle % TAU));
     let m = m3 * m2 * m1;
-    let v = DVec3::new(right_ascension_rate, -declination_rate, rotation_rate);
-    Ok(Rotation::new(m).with_angular_velocity(v))
+
+    // The derivative of a rotation by the angle `a` about the axis `e` is `[e]x R(a) da/dt`
+    let skew_x = DMat3::from_cols(DVec3::ZERO, DVec3::Z, -DVec3::Y);
+    let skew_z = DMat3::from_cols(DVec3::Y, -DVec3::X, DVec3::ZERO);
+    let dm1 = -right_ascension_rate * skew_z * m1;
+    let dm2 = declination_rate * skew_x * m2;
+    let dm3 = -rotation_rate * skew_z * m3;
+    let dm = dm3 * m2 * m1 + m3 * dm2 * m1 + m3 * m2 * dm1;
+    Rotation::new(m).with_derivative(dm)
 }
 //
 // impl<O: RotationalElements, P: FrameTransformationProvider> TryRotateTo<BodyFixed<O>, P> for Icrf {
@@ -62,3 +81,51 @@ pub(crate) fn icrf_to_bodyfixed<
 //         Ok(icrf_to_bodyfixed(&frame.0, seconds).unwrap())
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use lox_bodies::{Io, Jupiter, Mars, RotationalElements};
+
+    use super::*;
+
+    fn rotation<O: RotationalElements>(body: &O, seconds: f64) -> Rotation {
+        icrf_to_iau(
+            body.rotational_elements(seconds),
+            body.rotational_element_rates(seconds),
+        )
+    }
+
+    fn assert_derivative<O: RotationalElements>(body: O) {
+        // Ten years after J2000, so that the secular terms of the pole have an effect
+        let seconds = 3.15576e8;
+        let h = 1.0;
+        let m0 = rotation(&body, seconds - h).position_matrix();
+        let m1 = rotation(&body, seconds + h).position_matrix();
+        let exp = (m1 - m0) / (2.0 * h);
+        let act = rotation(&body, seconds).velocity_matrix();
+        assert!(
+            act.abs_diff_eq(exp, 1e-12),
+            "{}: {act} != {exp}",
+            body.name()
+        );
+    }
+
+    #[test]
+    fn test_icrf_to_iau_derivative() {
+        assert_derivative(Mars);
+        assert_derivative(Jupiter);
+        // The pole of Io precesses with the nodes of its orbit
+        assert_derivative(Io);
+    }
+
+    #[test]
+    fn test_icrf_to_iau_pole() {
+        let seconds = 3.15576e8;
+        let (ra, dec, _) = Mars.rotational_elements(seconds);
+        let pole = DVec3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin());
+        let rot = rotation(&Mars, seconds);
+        assert!(rot.rotate_position(pole).abs_diff_eq(DVec3::Z, 1e-15));
+        let m = rot.position_matrix();
+        assert!((m * m.transpose()).abs_diff_eq(DMat3::IDENTITY, 1e-15));
+    }
+}
@@ -5,7 +5,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
+use crate::frames::{DynFrame, Icrf, NoOpFrameTransformationProvider};
 use crate::rotations::Rotation;
+use crate::states::State;
 use glam::{DMat3, DVec2, DVec3};
 use lox_bodies::{Earth, RotationalElements};
 use lox_earth::cio::s06::s;
@@ -15,7 +17,52 @@ use lox_earth::coordinate_transformations::{
 };
 use lox_earth::rotation_angle::RotationAngle;
 use lox_earth::tio::sp_00;
-use lox_math::constants::f64::time::SECONDS_PER_DAY;
+use lox_io::iers::EarthOrientationParams;
+use lox_math::constants::f64::time::{MJD_J2000, SECONDS_PER_DAY};
+use lox_math::math::RADIANS_IN_ARCSECOND;
+use lox_math::types::units::Seconds;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::{Tt, Ut1};
+use lox_time::transformations::TryToScale;
+use lox_time::ut1::DeltaUt1TaiProvider;
+use lox_time::TimeLike;
+
+/// Provides the coordinates of the Celestial Intermediate Pole with respect to the ITRF.
+pub trait PolarMotionProvider {
+    /// Returns the pole coordinates `(xp, yp)` in radians at `seconds` since J2000 or `None` if
+    /// no Earth orientation parameters are available for this epoch.
+    fn pole_coordinates(&self, seconds: Seconds) -> Option<DVec2>;
+}
+
+impl PolarMotionProvider for NoOpFrameTransformationProvider {
+    fn pole_coordinates(&self, _seconds: Seconds) -> Option<DVec2> {
+        None
+    }
+}
+
+impl PolarMotionProvider for EarthOrientationParams {
+    /// Interpolates linearly between the daily values of the series.
+    fn pole_coordinates(&self, seconds: Seconds) -> Option<DVec2> {
+        let mjd = MJD_J2000 + seconds / SECONDS_PER_DAY;
+        let records = self.mjd();
+        let first = *records.first()? as f64;
+        let last = *records.last()? as f64;
+        if !(first..=last).contains(&mjd) {
+            return None;
+        }
+        let upper = records
+            .partition_point(|&record| (record as f64) < mjd)
+            .max(1)
+            .min(records.len() - 1);
+        let lower = upper.saturating_sub(1);
+        let pole = |i: usize| DVec2::new(self.x_pole()[i], self.y_pole()[i]);
+        if lower == upper {
+            return Some(pole(lower) * RADIANS_IN_ARCSECOND);
+        }
+        let t = (mjd - records[lower] as f64) / (records[upper] - records[lower]) as f64;
+        Some(pole(lower).lerp(pole(upper), t) * RADIANS_IN_ARCSECOND)
+    }
+}
 
 pub fn icrf_to_cirf(centuries: f64) -> Rotation {
     // TODO: Add IERS corrections
@@ -35,8 +82,155 @@ pub fn cirf_to_tirf(seconds: f64) -> Rotation {
 
 pub fn tirf_to_itrf(centuries: f64) -> Rotation {
     // TODO: Add IERS corrections
-    let pole_coords: DVec2 = (0.0, 0.0).into();
+    tirf_to_itrf_with_pole_coordinates(centuries, DVec2::ZERO)
+}
+
+pub fn tirf_to_itrf_with_pole_coordinates(centuries: f64, pole_coords: DVec2) -> Rotation {
     let tio_locator = sp_00(centuries);
     let m = polar_motion_matrix(pole_coords, tio_locator);
     Rotation::new(m)
 }
+
+/// Transforms an Earth-centred state from the ICRF to the ITRF.
+///
+/// The transformation is composed of the IAU 2006/2000A precession-nutation (ICRF to CIRF), the
+/// Earth rotation angle (CIRF to TIRF), which also accounts for the rotation of the velocity, and
+/// polar motion (TIRF to ITRF). The epoch of the state is converted to TT for precession-nutation
+/// and to UT1 for the Earth rotation angle with the UT1-TAI offsets from `provider`. If `eop`
+/// cannot provide the pole coordinates for the epoch of the state, polar motion is skipped and the
+/// resulting state is expressed in the TIRF, which deviates from the ITRF by less than an
+/// arcsecond.
+///
+/// # Errors
+///
+/// Returns an error if `provider` cannot provide UT1-TAI for the epoch of the state.
+pub fn icrf_to_itrf<T, P, E>(
+    state: &State<T, Earth, Icrf>,
+    provider: &P,
+    eop: &E,
+) -> Result<State<T, Earth, DynFrame>, P::Error>
+where
+    T: TimeLike + TryToScale<Tt, P> + TryToScale<Ut1, P> + Clone,
+    P: DeltaUt1TaiProvider,
+    E: PolarMotionProvider,
+{
+    let time = state.time();
+    let centuries = time.try_to_scale(Tt, provider)?.centuries_since_j2000();
+    let seconds = time.try_to_scale(Ut1, provider)?.seconds_since_j2000();
+    let rot = icrf_to_cirf(centuries).compose(&cirf_to_tirf(seconds));
+    let (rot, frame) = match eop.pole_coordinates(seconds) {
+        Some(pole_coords) => (
+            rot.compose(&tirf_to_itrf_with_pole_coordinates(centuries, pole_coords)),
+            DynFrame::Itrf,
+        ),
+        None => (rot, DynFrame::Tirf),
+    };
+    let (pos, vel) = rot.rotate_state(state.position(), state.velocity());
+    Ok(State::new(time, pos, vel, Earth, frame))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::time_scales::{Tai, Tdb};
+    use lox_time::ut1::DeltaUt1Tai;
+    use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
+    use lox_time::Time;
+    use rstest::rstest;
+
+    use crate::frames::CoordinateSystem;
+
+    use super::*;
+
+    fn eop() -> EarthOrientationParams {
+        let path = PathBuf::from(format!(
+            "{}/../../data/finals2000A.all.csv",
+            env!("CARGO_MANIFEST_DIR")
+        ));
+        EarthOrientationParams::parse_finals_csv(path).unwrap()
+    }
+
+    fn delta_ut1_tai() -> DeltaUt1Tai {
+        DeltaUt1Tai::from_eop(&eop(), &BuiltinLeapSeconds).unwrap()
+    }
+
+    fn vallado_time() -> Time<Ut1> {
+        // Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., Example 3-14
+        // 2004-04-06T07:51:28.386009 UTC with ΔUT1 = -0.4399619 s
+        Time::from_iso(Ut1, "2004-04-06T07:51:27.946047").unwrap()
+    }
+
+    fn vallado_state<T: TimeLike>(time: T) -> State<T, Earth, Icrf> {
+        let r = DVec3::new(5102.508958, 6123.011401, 6378.136928);
+        let v = DVec3::new(-4.743220157, 0.790536497, 5.533755727);
+        State::new(time, r, v, Earth, Icrf)
+    }
+
+    #[rstest]
+    #[case::ut1(vallado_state(vallado_time()))]
+    #[case::tai(vallado_state(vallado_time().try_to_scale(Tai, &delta_ut1_tai()).unwrap()))]
+    #[case::tdb(vallado_state(vallado_time().try_to_scale(Tdb, &delta_ut1_tai()).unwrap()))]
+    fn test_icrf_to_itrf<T>(#[case] state: State<T, Earth, Icrf>)
+    where
+        T: TimeLike + TryToScale<Tt, DeltaUt1Tai> + TryToScale<Ut1, DeltaUt1Tai> + Clone,
+    {
+        let itrf = icrf_to_itrf(&state, &delta_ut1_tai(), &eop()).unwrap();
+        assert_eq!(itrf.reference_frame(), DynFrame::Itrf);
+        let r_exp = DVec3::new(-1033.4793830, 7901.2952754, 6380.3565958);
+        let v_exp = DVec3::new(-3.225636520, -2.872451450, 5.531924446);
+        assert_close!(itrf.position(), r_exp, 1e-3);
+        assert_close!(itrf.velocity(), v_exp, 1e-6);
+    }
+
+    #[test]
+    fn test_icrf_to_itrf_without_eop() {
+        let state = vallado_state(vallado_time());
+        let provider = delta_ut1_tai();
+        let tirf = icrf_to_itrf(&state, &provider, &NoOpFrameTransformationProvider).unwrap();
+        assert_eq!(tirf.reference_frame(), DynFrame::Tirf);
+        let time = state.time();
+        let centuries = time
+            .try_to_scale(Tt, &provider)
+            .unwrap()
+            .centuries_since_j2000();
+        let rot = icrf_to_cirf(centuries).compose(&cirf_to_tirf(time.seconds_since_j2000()));
+        let (r_exp, v_exp) = rot.rotate_state(state.position(), state.velocity());
+        assert_close!(tirf.position(), r_exp, 1e-9);
+        assert_close!(tirf.velocity(), v_exp, 1e-12);
+
+        // Polar motion displaces the position by a few metres
+        let itrf = icrf_to_itrf(&state, &provider, &eop()).unwrap();
+        let offset = (itrf.position() - tirf.position()).length();
+        assert!(offset > 1e-3 && offset < 3e-2, "offset = {offset}");
+    }
+
+    #[test]
+    fn test_icrf_to_itrf_extrapolated_delta_ut1_tai() {
+        let time = Time::from_iso(Ut1, "1960-01-01T00:00:00").unwrap();
+        let state = vallado_state(time);
+        let result = icrf_to_itrf(&state, &delta_ut1_tai(), &NoOpFrameTransformationProvider);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_eop_pole_coordinates() {
+        let eop = eop();
+        let seconds = |mjd: f64| (mjd - MJD_J2000) * SECONDS_PER_DAY;
+        let mjd = eop.mjd()[100] as f64;
+        let exp = DVec2::new(eop.x_pole()[100], eop.y_pole()[100]) * RADIANS_IN_ARCSECOND;
+        assert_eq!(eop.pole_coordinates(seconds(mjd)), Some(exp));
+        let next = DVec2::new(eop.x_pole()[101], eop.y_pole()[101]) * RADIANS_IN_ARCSECOND;
+        let act = eop.pole_coordinates(seconds(mjd + 0.5)).unwrap();
+        assert_close!(act.x, (exp.x + next.x) / 2.0, 1e-18);
+        assert_close!(act.y, (exp.y + next.y) / 2.0, 1e-18);
+        let first = *eop.mjd().first().unwrap() as f64;
+        assert_eq!(eop.pole_coordinates(seconds(first - 1.0)), None);
+        assert_eq!(
+            NoOpFrameTransformationProvider.pole_coordinates(seconds(mjd)),
+            None
+        );
+    }
+}
@@ -6,18 +6,21 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 
 use glam::{DMat3, DVec3};
 use thiserror::Error;
 
-use lox_bodies::{DynOrigin, RotationalElements, Spheroid, TrySpheroid};
+use lox_bodies::ellipsoids::ReferenceEllipsoid;
+use lox_bodies::{DynOrigin, Earth, RotationalElements, Spheroid, TrySpheroid};
 use lox_math::types::units::Radians;
-use lox_time::prelude::Tdb;
+use lox_time::prelude::{Tdb, Tt, Ut1};
 use lox_time::transformations::TryToScale;
+use lox_time::ut1::DeltaUt1TaiProvider;
 use lox_time::TimeLike;
 
 use crate::frames::iau::IcrfToBodyFixedError;
+use crate::frames::iers::{icrf_to_itrf, PolarMotionProvider};
 use crate::frames::{
     BodyFixed, CoordinateSystem, DynFrame, FrameTransformationProvider, Icrf, ReferenceFrame,
     TryRotateTo, TryToFrame,
@@ -66,6 +69,7 @@ pub struct GroundLocation<B: TrySpheroid> {
     latitude: f64,
     altitude: f64,
     body: B,
+    ellipsoid: Option<ReferenceEllipsoid>,
 }
 
 pub type DynGroundLocation = GroundLocation<DynOrigin>;
@@ -77,10 +81,19 @@ impl<B: Spheroid> GroundLocation<B> {
             latitude,
             altitude,
             body,
+            ellipsoid: None,
         }
     }
 }
 
+impl<B: Spheroid> GroundLocation<B> {
+    /// Creates a ground location from a body-fixed position in km, see [geodetic_coordinates].
+    pub fn from_body_fixed_position(position: DVec3, body: B) -> Self {
+        let (latitude, longitude, altitude) = geodetic_coordinates(position, &body);
+        GroundLocation::new(longitude, latitude, altitude, body)
+    }
+}
+
 impl DynGroundLocation {
     pub fn with_dynamic(
         longitude: f64,
@@ -96,6 +109,7 @@ impl DynGroundLocation {
             latitude,
             altitude,
             body,
+            ellipsoid: None,
         })
     }
 }
@@ -120,30 +134,30 @@ impl<B: TrySpheroid> GroundLocation<B> {
         self.altitude
     }
 
-    fn equatorial_radius(&self) -> f64 {
-        self.body
-            .try_equatorial_radius()
-            .expect("equatorial radius should be available")
+    /// Uses `ellipsoid` instead of the PCK shape of the body as the reference surface of the
+    /// geodetic coordinates, e.g. [WGS84](lox_bodies::ellipsoids::WGS84) for GNSS-derived
+    /// locations.
+    pub fn with_ellipsoid(mut self, ellipsoid: ReferenceEllipsoid) -> Self {
+        self.ellipsoid = Some(ellipsoid);
+        self
     }
 
-    fn flattening(&self) -> f64 {
-        self.body
-            .try_flattening()
-            .expect("flattening should be available")
+    /// Returns the reference surface of the geodetic coordinates, which is derived from the PCK
+    /// radii of the body unless overridden by [GroundLocation::with_ellipsoid].
+    pub fn ellipsoid(&self) -> ReferenceEllipsoid {
+        self.ellipsoid.unwrap_or_else(|| {
+            ReferenceEllipsoid::try_from_spheroid(&self.body)
+                .expect("equatorial radius and flattening should be available")
+        })
     }
 
     pub fn body_fixed_position(&self) -> DVec3 {
-        let alt = self.altitude;
-        let (lon_sin, lon_cos) = self.longitude.sin_cos();
-        let (lat_sin, lat_cos) = self.latitude.sin_cos();
-        let f = self.flattening();
-        let r_eq = self.equatorial_radius();
-        let e = (2.0 * f - f.powi(2)).sqrt();
-        let c = r_eq / (1.0 - e.powi(2) * lat_sin.powi(2)).sqrt();
-        let s = c * (1.0 - e.powi(2));
-        let r_delta = (c + alt) * lat_cos;
-        let r_kappa = (s + alt) * lat_sin;
-        DVec3::new(r_delta * lon_cos, r_delta * lon_sin, r_kappa)
+        body_fixed_position_from_geodetic(
+            self.longitude,
+            self.latitude,
+            self.altitude,
+            &self.ellipsoid(),
+        )
     }
 
     pub fn rotation_to_topocentric(&self) -> DMat3 {
@@ -156,25 +170,19 @@ impl<B: TrySpheroid> GroundLocation<B> {
     where
         B: RotationalElements + Clone,
     {
-        let rot = self.rotation_to_topocentric();
-        let position = rot * (state.position() - self.body_fixed_position());
-        let velocity = rot * state.velocity();
-        let range = position.length();
-        let range_rate = position.dot(velocity) / range;
-        let elevation = (position.z / range).asin();
-        let azimuth = position.y.atan2(-position.x);
-        Observables {
-            azimuth,
-            elevation,
-            range,
-            range_rate,
-        }
+        self.body_fixed_observables(state.position(), state.velocity())
     }
 
     pub fn observables_dyn<T: TimeLike + Clone>(&self, state: DynState<T>) -> Observables {
+        self.body_fixed_observables(state.position(), state.velocity())
+    }
+
+    /// Returns the observables of a body-fixed position and velocity in the topocentric frame of
+    /// the ground location, whose vertical is the normal of the reference spheroid.
+    fn body_fixed_observables(&self, position: DVec3, velocity: DVec3) -> Observables {
         let rot = self.rotation_to_topocentric();
-        let position = rot * (state.position() - self.body_fixed_position());
-        let velocity = rot * state.velocity();
+        let position = rot * (position - self.body_fixed_position());
+        let velocity = rot * velocity;
         let range = position.length();
         let range_rate = position.dot(velocity) / range;
         let elevation = (position.z / range).asin();
@@ -188,6 +196,208 @@ impl<B: TrySpheroid> GroundLocation<B> {
     }
 }
 
+impl GroundLocation<Earth> {
+    /// Returns the observables of the Earth-centred inertial `state` from the ground location.
+    ///
+    /// The state is rotated into the ITRF with [icrf_to_itrf], i.e. based on the Earth rotation
+    /// angle rather than the IAU rotational elements of the Earth, with the UT1-TAI offsets from
+    /// `provider`. Polar motion is applied if `eop` provides the pole coordinates for the epoch of
+    /// the state.
+    ///
+    /// The elevation is measured from the geodetic horizon, the azimuth clockwise from north, and
+    /// the range rate is positive if the spacecraft recedes from the ground location, consistent
+    /// with [doppler_shift](crate::analysis::doppler_shift).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `provider` cannot provide UT1-TAI for the epoch of the state.
+    pub fn observables_icrf<T, P, E>(
+        &self,
+        state: &State<T, Earth, Icrf>,
+        provider: &P,
+        eop: &E,
+    ) -> Result<Observables, P::Error>
+    where
+        T: TimeLike + TryToScale<Tt, P> + TryToScale<Ut1, P> + Clone,
+        P: DeltaUt1TaiProvider,
+        E: PolarMotionProvider,
+    {
+        let state = icrf_to_itrf(state, provider, eop)?;
+        Ok(self.body_fixed_observables(state.position(), state.velocity()))
+    }
+}
+
+/// Converts a body-fixed position in km into geodetic latitude, longitude, and altitude above the
+/// reference spheroid of `body`.
+///
+/// The latitude is found with Bowring's iteration on the reduced latitude, which converges within
+/// a few iterations for points near the poles as well as far above or below the surface.
+pub fn geodetic_coordinates<B: Spheroid>(position: DVec3, body: &B) -> (f64, f64, f64) {
+    geodetic_coordinates_on_ellipsoid(position, &ReferenceEllipsoid::from_spheroid(body))
+}
+
+/// Converts a body-fixed position in km into geodetic latitude, longitude, and altitude above
+/// `ellipsoid`, see [geodetic_coordinates].
+pub fn geodetic_coordinates_on_ellipsoid(
+    position: DVec3,
+    ellipsoid: &ReferenceEllipsoid,
+) -> (f64, f64, f64) {
+    geodetic_from_cartesian(position, ellipsoid)
+}
+
+/// Converts geodetic longitude, latitude, and altitude above `ellipsoid` into a body-fixed
+/// position in km.
+pub fn body_fixed_position_from_geodetic(
+    longitude: f64,
+    latitude: f64,
+    altitude: f64,
+    ellipsoid: &ReferenceEllipsoid,
+) -> DVec3 {
+    let (lon_sin, lon_cos) = longitude.sin_cos();
+    let (lat_sin, lat_cos) = latitude.sin_cos();
+    let e2 = ellipsoid.eccentricity_sq();
+    let c = ellipsoid.semi_major() / (1.0 - e2 * lat_sin.powi(2)).sqrt();
+    let s = c * (1.0 - e2);
+    let r_delta = (c + altitude) * lat_cos;
+    let r_kappa = (s + altitude) * lat_sin;
+    DVec3::new(r_delta * lon_cos, r_delta * lon_sin, r_kappa)
+}
+
+/// Returns the geodetic latitude and longitude of the sub-satellite point of `trajectory` at each
+/// of `times`.
+///
+/// The trajectory is rotated into the IAU body-fixed frame of its origin, so that the longitude
+/// accounts for the rotation of the body. The longitude is wrapped to `(-π, π]`.
+///
+/// # Errors
+///
+/// Returns the error of the `provider` if the times cannot be converted to TDB.
+pub fn ground_track<T, B, P>(
+    trajectory: &Trajectory<T, B, Icrf>,
+    times: impl IntoIterator<Item = T>,
+    provider: &P,
+) -> Result<Vec<(Radians, Radians)>, P::Error>
+where
+    T: TimeLike + TryToScale<Tdb, P> + Clone,
+    B: Spheroid + RotationalElements + Clone,
+    P: FrameTransformationProvider,
+{
+    let body = trajectory.origin();
+    times
+        .into_iter()
+        .map(|time| {
+            let state = trajectory
+                .interpolate_at(time)
+                .try_to_frame(BodyFixed(body.clone()), provider)?;
+            let (latitude, longitude, _) = geodetic_coordinates(state.position(), &body);
+            Ok((latitude, wrap_longitude(longitude)))
+        })
+        .collect()
+}
+
+/// Returns the geodetic latitude and longitude of `samples` points on the boundary of the
+/// footprint of a conical sensor on the surface of the origin of `state`.
+///
+/// The sensor points towards the centre of the body and `half_angle` is the half-angle of its
+/// cone. The boundary is sampled at equally spaced azimuths around the nadir direction, starting
+/// north of the sub-satellite point and proceeding through the east. If the cone extends beyond
+/// the horizon, the respective points of the horizon are returned instead, i.e. the footprint
+/// degenerates to the visible disk of the body. The longitude is wrapped to `(-π, π]`.
+///
+/// The cone is intersected with the reference spheroid after scaling it to the unit sphere,
+/// which avoids the singularities of spherical trigonometry at the poles.
+pub fn footprint<T, B>(
+    state: &State<T, B, BodyFixed<B>>,
+    half_angle: Radians,
+    samples: usize,
+) -> Vec<(Radians, Radians)>
+where
+    T: TimeLike,
+    B: Spheroid + RotationalElements + Clone,
+{
+    let body = state.origin();
+    let a = body.equatorial_radius();
+    let b = body.polar_radius();
+    let to_unit_sphere = DVec3::new(1.0 / a, 1.0 / a, 1.0 / b);
+    let from_unit_sphere = DVec3::new(a, a, b);
+
+    let position = state.position();
+    let nadir = -position.normalize();
+    let east = if nadir.cross(DVec3::Z).length_squared() > 0.0 {
+        DVec3::Z.cross(-nadir).normalize()
+    } else {
+        // Above the poles, any direction perpendicular to the nadir will do
+        DVec3::X
+    };
+    let north = (-nadir).cross(east);
+
+    let s = position * to_unit_sphere;
+    let s2 = s.length_squared();
+    let (sin_eta, cos_eta) = half_angle.sin_cos();
+    (0..samples)
+        .map(|i| {
+            let azimuth = TAU * i as f64 / samples as f64;
+            let (sin_az, cos_az) = azimuth.sin_cos();
+            let direction = cos_eta * nadir + sin_eta * (cos_az * north + sin_az * east);
+            let d = direction * to_unit_sphere;
+            // Solve |s + t d|² = 1 for the nearest intersection
+            let d2 = d.length_squared();
+            let sd = s.dot(d);
+            let discriminant = sd.powi(2) - d2 * (s2 - 1.0);
+            let point = if discriminant >= 0.0 && sd < 0.0 {
+                s + (-sd - discriminant.sqrt()) / d2 * d
+            } else {
+                // The tangent point of the horizon in the plane of the ray
+                let w = (d - d.dot(s) / s2 * s).normalize();
+                s / s2 + (1.0 - 1.0 / s2).sqrt() * w
+            };
+            let (latitude, longitude, _) = geodetic_coordinates(point * from_unit_sphere, &body);
+            (latitude, wrap_longitude(longitude))
+        })
+        .collect()
+}
+
+/// Wraps a longitude in `[-π, π]` as returned by `atan2` to `(-π, π]`.
+fn wrap_longitude(longitude: Radians) -> Radians {
+    if longitude <= -PI {
+        PI
+    } else {
+        longitude
+    }
+}
+
+const MAX_GEODETIC_ITERATIONS: usize = 10;
+
+fn geodetic_from_cartesian(position: DVec3, ellipsoid: &ReferenceEllipsoid) -> (f64, f64, f64) {
+    let a = ellipsoid.semi_major();
+    let b = ellipsoid.semi_minor();
+    let flattening = ellipsoid.flattening();
+    let e2 = ellipsoid.eccentricity_sq();
+    let ep2 = e2 / (1.0 - e2);
+    let p = position.x.hypot(position.y);
+    let z = position.z;
+    let longitude = position.y.atan2(position.x);
+
+    let mut beta = z.atan2((1.0 - flattening) * p);
+    let mut latitude = 0.0;
+    for _ in 0..MAX_GEODETIC_ITERATIONS {
+        let (sin_beta, cos_beta) = beta.sin_cos();
+        let next = (z + ep2 * b * sin_beta.powi(3)).atan2(p - e2 * a * cos_beta.powi(3));
+        let converged = (next - latitude).abs() < 1e-15;
+        latitude = next;
+        if converged {
+            break;
+        }
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        beta = ((1.0 - flattening) * sin_lat).atan2(cos_lat);
+    }
+
+    // This form of the altitude is well-conditioned at all latitudes, including the poles
+    let (sin_lat, cos_lat) = latitude.sin_cos();
+    let altitude = p * cos_lat + z * sin_lat - a * (1.0 - e2 * sin_lat.powi(2)).sqrt();
+    (latitude, longitude, altitude)
+}
+
 #[derive(Debug, Error)]
 pub enum GroundPropagatorError {
     #[error("frame transformation error: {0}")]
@@ -297,13 +507,21 @@ where
 mod tests {
     use float_eq::assert_float_eq;
 
-    use lox_bodies::Earth;
+    use itertools::Itertools;
+    use lox_bodies::ellipsoids::WGS84;
+    use lox_bodies::{Earth, PointMass};
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
-    use lox_time::transformations::ToTai;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::transformations::{ToTai, ToTt, ToUt1};
+    use lox_time::ut1::DeltaUt1Tai;
+    use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
     use lox_time::utc::Utc;
     use lox_time::{time, utc, Time};
 
+    use lox_time::julian_dates::JulianDate;
+
+    use crate::frames::iers::{cirf_to_tirf, icrf_to_cirf};
     use crate::frames::NoOpFrameTransformationProvider;
 
     use super::*;
@@ -317,6 +535,71 @@ mod tests {
         assert_close!(location.body_fixed_position(), expected);
     }
 
+    #[test]
+    fn test_geodetic_coordinates() {
+        let b = WGS84.semi_minor();
+        // On the equator, at the poles, and on the polar axis below the surface
+        let cases = [
+            (DVec3::new(6378.137, 0.0, 0.0), (0.0, 0.0, 0.0)),
+            (DVec3::new(0.0, 7000.0, 0.0), (0.0, FRAC_PI_2, 621.863)),
+            (DVec3::new(0.0, 0.0, b), (FRAC_PI_2, 0.0, 0.0)),
+            (DVec3::new(0.0, 0.0, -b - 500.0), (-FRAC_PI_2, 0.0, 500.0)),
+            (DVec3::new(0.0, 0.0, 1000.0), (FRAC_PI_2, 0.0, 1000.0 - b)),
+        ];
+        for (position, (lat_exp, lon_exp, alt_exp)) in cases {
+            let (lat_act, lon_act, alt_act) = geodetic_coordinates_on_ellipsoid(position, &WGS84);
+            assert_float_eq!(lat_act, lat_exp, abs <= 1e-14);
+            assert_float_eq!(lon_act, lon_exp, abs <= 1e-14);
+            assert_float_eq!(alt_act, alt_exp, abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_geodetic_coordinates_round_trip() {
+        let latitudes = [
+            -90.0, -89.9999, -60.0, -1e-6, 0.0, 30.0, 45.0, 89.9999, 90.0,
+        ];
+        let altitudes = [-3000.0, -100.0, 0.0, 0.5, 400.0, 35786.0, 384400.0];
+        for latitude in latitudes {
+            for altitude in altitudes {
+                let latitude = f64::to_radians(latitude);
+                let longitude = -4.3676f64.to_radians();
+                let location =
+                    GroundLocation::new(longitude, latitude, altitude, Earth).with_ellipsoid(WGS84);
+                let (lat_act, lon_act, alt_act) =
+                    geodetic_coordinates_on_ellipsoid(location.body_fixed_position(), &WGS84);
+                assert_float_eq!(lat_act, latitude, abs <= 1e-12);
+                assert_float_eq!(lon_act, longitude, abs <= 1e-12);
+                assert_float_eq!(alt_act, altitude, abs <= 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_geodetic_coordinates_earth() {
+        // Uses the IAU radii of the Earth rather than WGS84
+        let longitude = -4.3676f64.to_radians();
+        let latitude = 40.4527f64.to_radians();
+        let position = DVec3::new(4846.130017870638, -370.1328551351891, 4116.364272747229);
+        let (lat, lon, alt) = geodetic_coordinates(position, &Earth);
+        assert_float_eq!(lat, latitude, abs <= 1e-12);
+        assert_float_eq!(lon, longitude, abs <= 1e-12);
+        assert_float_eq!(alt, 0.0, abs <= 1e-8);
+    }
+
+    #[test]
+    fn test_ground_location_ellipsoid() {
+        let longitude = -4.3676f64.to_radians();
+        let latitude = 40.4527f64.to_radians();
+        let iau = GroundLocation::new(longitude, latitude, 0.0, Earth);
+        assert_eq!(iau.ellipsoid(), ReferenceEllipsoid::from_spheroid(&Earth));
+        let wgs84 = iau.clone().with_ellipsoid(WGS84);
+        assert_eq!(wgs84.ellipsoid(), WGS84);
+        // The WGS84 ellipsoid is about 0.4 m larger than the IAU radii of the Earth
+        let (_, _, altitude) = geodetic_coordinates(wgs84.body_fixed_position(), &Earth);
+        assert_float_eq!(altitude, 4.06e-4, abs <= 1e-6);
+    }
+
     #[test]
     fn test_ground_location_rotation_to_topocentric() {
         let longitude = -4.3676f64.to_radians();
@@ -357,6 +640,185 @@ mod tests {
         assert_float_eq!(observables.elevation, expected_elevation, rel <= 1e-2);
     }
 
+    #[test]
+    fn test_ground_track() {
+        // Circular orbit with an inclination of 60° starting at the ascending node at J2000
+        let epoch = time!(Tdb, 2000, 1, 1, 12).unwrap();
+        let radius = 7000f64;
+        let inclination = 60f64.to_radians();
+        let n = (Earth.gravitational_parameter() / radius.powi(3)).sqrt();
+        let normal = DVec3::new(0.0, -inclination.sin(), inclination.cos());
+        let states: Vec<State<Time<Tdb>, Earth, Icrf>> = (0..=90)
+            .map(|i| {
+                let t = 60.0 * i as f64;
+                let (sin, cos) = (n * t).sin_cos();
+                let u = DVec3::X;
+                let v = normal.cross(u);
+                let time = epoch + TimeDelta::from_decimal_seconds(t).unwrap();
+                State::new(
+                    time,
+                    radius * (cos * u + sin * v),
+                    radius * n * (-sin * u + cos * v),
+                    Earth,
+                    Icrf,
+                )
+            })
+            .collect();
+        let trajectory = Trajectory::new(&states).unwrap();
+        let provider = &NoOpFrameTransformationProvider;
+
+        let times = trajectory.times();
+        let track = ground_track(&trajectory, times.clone(), provider).unwrap();
+
+        // At J2000 the prime meridian of the Earth is 190.147° east of the ascending node of the
+        // body equator, which lies 90° east of the x-axis of the ICRF
+        let (latitude, longitude) = track[0];
+        assert_float_eq!(latitude, 0.0, abs <= 1e-12);
+        assert_float_eq!(
+            longitude,
+            (360.0 - 90.0 - 190.147f64).to_radians(),
+            abs <= 1e-12
+        );
+
+        for ((latitude, longitude), time) in track.iter().zip(times) {
+            assert!(*longitude > -PI && *longitude <= PI);
+            // The geodetic latitude exceeds the geocentric latitude by less than 0.2°
+            assert!(latitude.abs() <= inclination + 0.2f64.to_radians());
+            let state = trajectory
+                .interpolate_at(time)
+                .try_to_frame(BodyFixed(Earth), provider)
+                .unwrap();
+            let (lat, lon, _) = geodetic_coordinates(state.position(), &Earth);
+            assert_eq!(*latitude, lat);
+            assert_float_eq!(longitude.sin(), lon.sin(), abs <= 1e-15);
+            assert_float_eq!(longitude.cos(), lon.cos(), abs <= 1e-15);
+        }
+
+        // The ground track crosses the equator northbound at the ascending node, reaches the
+        // maximum latitude after a quarter revolution, and the longitude wraps around
+        assert!(track[1].0 > 0.0);
+        let quarter = (FRAC_PI_2 / n / 60.0).round() as usize;
+        assert_float_eq!(track[quarter].0, inclination, abs <= 1e-2);
+        assert!(track
+            .iter()
+            .tuple_windows()
+            .any(|((_, lon0), (_, lon1))| (lon1 - lon0).abs() > PI));
+    }
+
+    fn body_fixed_state(position: DVec3) -> State<Time<Tdb>, Earth, BodyFixed<Earth>> {
+        let time = time!(Tdb, 2000, 1, 1, 12).unwrap();
+        State::new(time, position, DVec3::ZERO, Earth, BodyFixed(Earth))
+    }
+
+    fn surface_point((latitude, longitude): (f64, f64)) -> DVec3 {
+        GroundLocation::new(longitude, latitude, 0.0, Earth).body_fixed_position()
+    }
+
+    #[test]
+    fn test_footprint() {
+        let position =
+            GroundLocation::new(0.3, 35f64.to_radians(), 700.0, Earth).body_fixed_position();
+        let state = body_fixed_state(position);
+        let half_angle = 20f64.to_radians();
+
+        let points = footprint(&state, half_angle, 36);
+
+        assert_eq!(points.len(), 36);
+        for point in points {
+            let line_of_sight = surface_point(point) - position;
+            assert_float_eq!(
+                line_of_sight.angle_between(-position),
+                half_angle,
+                abs <= 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_footprint_beyond_horizon() {
+        let position =
+            GroundLocation::new(-2.0, -60f64.to_radians(), 700.0, Earth).body_fixed_position();
+        let state = body_fixed_state(position);
+
+        // The cone contains the whole visible disk, so the footprint is the horizon
+        for point @ (latitude, longitude) in footprint(&state, 80f64.to_radians(), 36) {
+            let up = DVec3::new(
+                latitude.cos() * longitude.cos(),
+                latitude.cos() * longitude.sin(),
+                latitude.sin(),
+            );
+            let line_of_sight = (position - surface_point(point)).normalize();
+            assert_float_eq!(line_of_sight.dot(up), 0.0, abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_footprint_above_pole() {
+        let position = DVec3::new(0.0, 0.0, Earth.polar_radius() + 1000.0);
+        let state = body_fixed_state(position);
+
+        let points = footprint(&state, 30f64.to_radians(), 8);
+
+        let latitude = points[0].0;
+        assert!(latitude < FRAC_PI_2 && latitude > 0.0);
+        for (i, (lat, lon)) in points.iter().enumerate() {
+            assert_float_eq!(*lat, latitude, abs <= 1e-12);
+            assert!(*lon > -PI && *lon <= PI);
+            // "North" is the y-axis and "east" is the x-axis above the pole
+            let expected = FRAC_PI_2 - PI / 4.0 * i as f64;
+            assert_float_eq!(lon.sin(), expected.sin(), abs <= 1e-12);
+            assert_float_eq!(lon.cos(), expected.cos(), abs <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ground_location_observables_icrf() {
+        let location =
+            GroundLocation::new(-4.3676f64.to_radians(), 40.4527f64.to_radians(), 0.8, Earth);
+        let time = time!(Tdb, 2024, 3, 1, 6).unwrap();
+        let path = format!(
+            "{}/../../data/finals2000A.all.csv",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let provider = DeltaUt1Tai::new(path, &BuiltinLeapSeconds).unwrap();
+        let centuries = time.to_tt().centuries_since_j2000();
+        let seconds = time.try_to_ut1(&provider).unwrap().seconds_since_j2000();
+        let itrf_to_icrf = icrf_to_cirf(centuries)
+            .compose(&cirf_to_tirf(seconds))
+            .transpose();
+        let station = location.body_fixed_position();
+        let (lat_sin, lat_cos) = location.latitude().sin_cos();
+        let (lon_sin, lon_cos) = location.longitude().sin_cos();
+        let up = DVec3::new(lat_cos * lon_cos, lat_cos * lon_sin, lat_sin);
+        let east = DVec3::new(-lon_sin, lon_cos, 0.0);
+        let north = up.cross(east);
+
+        // (direction, azimuth, elevation)
+        let cases = [
+            (up, None, FRAC_PI_2),
+            (north, Some(0.0), 0.0),
+            (east, Some(FRAC_PI_2), 0.0),
+            (-north, Some(PI), 0.0),
+            ((north + up).normalize(), Some(0.0), FRAC_PI_2 / 2.0),
+        ];
+        for (direction, azimuth, elevation) in cases {
+            // Receding along the line of sight at 2 km/s
+            let (position, velocity) =
+                itrf_to_icrf.rotate_state(station + 1000.0 * direction, 2.0 * direction);
+            let state = State::new(time, position, velocity, Earth, Icrf);
+            let obs = location
+                .observables_icrf(&state, &provider, &NoOpFrameTransformationProvider)
+                .unwrap();
+            assert_float_eq!(obs.range(), 1000.0, rel <= 1e-12);
+            assert_float_eq!(obs.range_rate(), 2.0, rel <= 1e-12);
+            assert_float_eq!(obs.elevation(), elevation, abs <= 1e-12);
+            if let Some(azimuth) = azimuth {
+                assert_float_eq!(obs.azimuth().sin(), azimuth.sin(), abs <= 1e-12);
+                assert_float_eq!(obs.azimuth().cos(), azimuth.cos(), abs <= 1e-12);
+            }
+        }
+    }
+
     #[test]
     fn test_ground_propagator() {
         let longitude = -4.3676f64.to_radians();
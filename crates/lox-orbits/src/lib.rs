@@ -10,6 +10,7 @@ pub use glam::DVec3;
 
 pub mod analysis;
 pub mod anomalies;
+pub mod determination;
 pub mod elements;
 pub mod ensembles;
 pub mod events;
@@ -4,6 +4,9 @@ use lox_time::TimeLike;
 use crate::trajectories::TrajectoryError;
 use crate::{frames::ReferenceFrame, states::State, trajectories::Trajectory};
 
+pub mod clohessy_wiltshire;
+pub mod j2;
+pub mod numerical;
 pub mod semi_analytical;
 pub mod sgp4;
 mod stumpff;
@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `clohessy_wiltshire` provides the closed-form solution of the Clohessy-Wiltshire (Hill's)
+    equations for the motion of a deputy spacecraft relative to a chief.
+
+    Relative states are given in the rotating RTN frame of the chief as returned by
+    [relative_state_rtn](crate::states::relative_state_rtn), i.e. the radial, along-track, and
+    normal components of the relative position in km and of the velocity observed in the rotating
+    frame in km/s.
+
+    The equations are linearised about a **circular** chief orbit in a central gravity field. They
+    are accurate to first order in the ratio of the separation to the orbital radius of the chief
+    and degrade with the eccentricity of the chief orbit, so they are suited for proximity
+    operations and rendezvous planning over a few orbits rather than for long-term predictions.
+*/
+
+use glam::{DMat3, DVec3};
+
+use lox_math::linear_algebra::dmat6::DMat6;
+
+/// Returns the Clohessy-Wiltshire state transition matrix, which maps a relative state in the RTN
+/// frame of the chief to the relative state `dt` seconds later for a chief with the mean motion
+/// `n` in rad/s.
+///
+/// Unless the along-track velocity is `-2n` times the radial offset, the relative motion contains
+/// a secular along-track drift of `-3/2 n` times the radial offset of the centre of the relative
+/// ellipse per unit time.
+pub fn cw_stm(n: f64, dt: f64) -> DMat6 {
+    let nt = n * dt;
+    let (s, c) = nt.sin_cos();
+    // The blocks are given column by column
+    let rr = DMat3::from_cols(
+        DVec3::new(4.0 - 3.0 * c, 6.0 * (s - nt), 0.0),
+        DVec3::Y,
+        DVec3::new(0.0, 0.0, c),
+    );
+    let rv = DMat3::from_cols(
+        DVec3::new(s / n, -2.0 * (1.0 - c) / n, 0.0),
+        DVec3::new(2.0 * (1.0 - c) / n, (4.0 * s - 3.0 * nt) / n, 0.0),
+        DVec3::new(0.0, 0.0, s / n),
+    );
+    let vr = DMat3::from_cols(
+        DVec3::new(3.0 * n * s, -6.0 * n * (1.0 - c), 0.0),
+        DVec3::ZERO,
+        DVec3::new(0.0, 0.0, -n * s),
+    );
+    let vv = DMat3::from_cols(
+        DVec3::new(c, -2.0 * s, 0.0),
+        DVec3::new(2.0 * s, 4.0 * c - 3.0, 0.0),
+        DVec3::new(0.0, 0.0, c),
+    );
+    DMat6::from_blocks(rr, rv, vr, vv)
+}
+
+/// Propagates the relative position and velocity `rel_state` in the RTN frame of a chief with
+/// the mean motion `n` in rad/s by `dt` seconds, see [cw_stm].
+pub fn cw_propagate(rel_state: (DVec3, DVec3), n: f64, dt: f64) -> (DVec3, DVec3) {
+    let stm = cw_stm(n, dt);
+    let (position, velocity) = rel_state;
+    (
+        stm.block(0, 0) * position + stm.block(0, 1) * velocity,
+        stm.block(1, 0) * position + stm.block(1, 1) * velocity,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use lox_bodies::{Earth, PointMass, Spheroid};
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use crate::elements::Keplerian;
+    use crate::frames::Icrf;
+    use crate::propagators::semi_analytical::Vallado;
+    use crate::propagators::Propagator;
+    use crate::states::{relative_state_rtn, State};
+
+    use super::*;
+
+    const N: f64 = 1.1e-3;
+
+    #[test]
+    fn test_cw_stm_identity_and_composition() {
+        assert!(cw_stm(N, 0.0).max_abs_diff(&DMat6::IDENTITY) < 1e-15);
+        let composed = cw_stm(N, 700.0) * cw_stm(N, 500.0);
+        assert!(composed.max_abs_diff(&cw_stm(N, 1200.0)) < 1e-9);
+    }
+
+    #[test]
+    fn test_cw_secular_drift() {
+        let period = TAU / N;
+        // A radial offset without velocity drifts behind by 12π times the offset per orbit
+        let (r, v) = cw_propagate((DVec3::X, DVec3::ZERO), N, period);
+        assert_close!(r, DVec3::new(1.0, -6.0 * TAU, 0.0), 1e-12);
+        assert_close!(v, DVec3::ZERO, 1e-12);
+        // The drift vanishes for an along-track velocity of -2n times the radial offset
+        let initial = (DVec3::new(1.0, 0.5, 0.2), DVec3::new(1e-4, -2.0 * N, 3e-4));
+        let (r, v) = cw_propagate(initial, N, period);
+        assert_close!(r, initial.0, 1e-9);
+        assert_close!(v, initial.1, 1e-12);
+        // Half an orbit later the along-track offset is -3π/n times the along-track velocity
+        let (r, _) = cw_propagate((DVec3::ZERO, DVec3::new(0.0, 1e-3, 0.0)), N, period / 2.0);
+        assert_close!(
+            r,
+            DVec3::new(4e-3 / N, -3.0 * TAU / 2.0 * 1e-3 / N, 0.0),
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_cw_propagate_two_body() {
+        let chief = Keplerian::builder(Time::j2000(Tdb), Earth)
+            .with_semi_major_axis(Earth.equatorial_radius() + 500.0)
+            .with_inclination(51.6f64.to_radians())
+            .with_true_anomaly(0.3)
+            .build()
+            .unwrap()
+            .to_cartesian();
+        let n = (Earth.gravitational_parameter() / chief.position().length().powi(3)).sqrt();
+        let (dr, dv) = (DVec3::new(0.1, -0.5, 0.2), DVec3::new(1e-4, -2e-4, 5e-5));
+        let rot = chief.rotation_rtn();
+        let omega = chief.position().cross(chief.velocity()) / chief.position().length_squared();
+        let deputy = State::new(
+            chief.time(),
+            chief.position() + rot * dr,
+            chief.velocity() + rot * dv + omega.cross(rot * dr),
+            Earth,
+            Icrf,
+        );
+        assert_close!(relative_state_rtn(&chief, &deputy).0, dr, 1e-12);
+
+        let dt = 3000.0;
+        let time = chief.time() + TimeDelta::from_decimal_seconds(dt).unwrap();
+        let chief = Vallado::new(chief).propagate(time).unwrap();
+        let deputy = Vallado::new(deputy).propagate(time).unwrap();
+        let (r_exp, v_exp) = relative_state_rtn(&chief, &deputy);
+        let (r_act, v_act) = cw_propagate((dr, dv), n, dt);
+        // The linearisation error grows with the square of the separation
+        assert!((r_act - r_exp).length() < 1e-3);
+        assert!((v_act - v_exp).length() < 1e-6);
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use glam::{DMat3, DVec3};
+use thiserror::Error;
+
+use lox_bodies::{PointMass, RotationalElements, Spheroid, ZonalHarmonics};
+use lox_math::math::mod_two_pi;
+use lox_time::TimeLike;
+
+use crate::anomalies::{eccentric_to_mean, mean_to_true, true_to_eccentric};
+use crate::elements::Keplerian;
+use crate::frames::{CoordinateSystem, Icrf};
+use crate::propagators::Propagator;
+use crate::states::{rv_to_keplerian, State};
+use crate::trajectories::TrajectoryError;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum J2Error {
+    #[error("the J2 propagator requires an elliptic orbit but the eccentricity is {0}")]
+    NonEllipticOrbit(f64),
+    #[error(transparent)]
+    TrajectoryError(#[from] TrajectoryError),
+}
+
+/// Propagates the mean elements of an orbit under the secular effects of the J2 zonal harmonic.
+///
+/// The semi-major axis, eccentricity, and inclination are constant, while the longitude of the
+/// ascending node, the argument of periapsis, and the mean anomaly advance at constant rates. The
+/// rates refer to the mean equator of the central body at the initial epoch, which is derived from
+/// its IAU pole orientation. The elements of the initial state are treated as mean elements, i.e.
+/// short-periodic perturbations are neglected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct J2Propagator<T: TimeLike, O: PointMass> {
+    initial_state: State<T, O, Icrf>,
+    equator: DMat3,
+    semi_major_axis: f64,
+    eccentricity: f64,
+    inclination: f64,
+    longitude_of_ascending_node: f64,
+    argument_of_periapsis: f64,
+    mean_anomaly: f64,
+    node_rate: f64,
+    periapsis_rate: f64,
+    mean_motion: f64,
+}
+
+impl<T, O> J2Propagator<T, O>
+where
+    T: TimeLike + Clone,
+    O: PointMass + Spheroid + ZonalHarmonics + RotationalElements + Clone,
+{
+    pub fn new(initial_state: State<T, O, Icrf>) -> Result<Self, J2Error> {
+        let origin = initial_state.origin();
+        let mu = origin.gravitational_parameter();
+
+        // Rotation from the ICRF to the mean equator of the body at the initial epoch
+        let (ra, dec, _) = origin.rotational_elements(initial_state.time().seconds_since_j2000());
+        let pole = DVec3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin());
+        let node = DVec3::new(-ra.sin(), ra.cos(), 0.0);
+        let equator = DMat3::from_cols(node, pole.cross(node), pole).transpose();
+
+        let elements = rv_to_keplerian(
+            equator * initial_state.position(),
+            equator * initial_state.velocity(),
+            mu,
+        );
+        let a = elements.semi_major_axis;
+        let e = elements.eccentricity;
+        let i = elements.inclination;
+        if !(0.0..1.0).contains(&e) {
+            return Err(J2Error::NonEllipticOrbit(e));
+        }
+
+        let n = (mu / a.powi(3)).sqrt();
+        let p = a * (1.0 - e.powi(2));
+        let k = 1.5 * n * origin.j2() * (origin.equatorial_radius() / p).powi(2);
+        let sin_i_2 = i.sin().powi(2);
+
+        Ok(Self {
+            equator,
+            semi_major_axis: a,
+            eccentricity: e,
+            inclination: i,
+            longitude_of_ascending_node: elements.longitude_of_ascending_node,
+            argument_of_periapsis: elements.argument_of_periapsis,
+            mean_anomaly: eccentric_to_mean(true_to_eccentric(elements.true_anomaly, e), e),
+            node_rate: -k * i.cos(),
+            periapsis_rate: k * (2.0 - 2.5 * sin_i_2),
+            mean_motion: n + k * (1.0 - e.powi(2)).sqrt() * (1.0 - 1.5 * sin_i_2),
+            initial_state,
+        })
+    }
+
+    /// Returns the secular rate of the longitude of the ascending node in rad/s.
+    pub fn node_rate(&self) -> f64 {
+        self.node_rate
+    }
+
+    /// Returns the secular rate of the argument of periapsis in rad/s.
+    pub fn periapsis_rate(&self) -> f64 {
+        self.periapsis_rate
+    }
+
+    /// Returns the perturbed mean motion in rad/s.
+    pub fn mean_motion(&self) -> f64 {
+        self.mean_motion
+    }
+}
+
+impl<T, O> CoordinateSystem<Icrf> for J2Propagator<T, O>
+where
+    T: TimeLike,
+    O: PointMass,
+{
+    fn reference_frame(&self) -> Icrf {
+        Icrf
+    }
+}
+
+impl<T, O> Propagator<T, O, Icrf> for J2Propagator<T, O>
+where
+    T: TimeLike + Clone,
+    O: PointMass + Spheroid + ZonalHarmonics + RotationalElements + Clone,
+{
+    type Error = J2Error;
+
+    fn propagate(&self, time: T) -> Result<State<T, O, Icrf>, Self::Error> {
+        let dt = (time.clone() - self.initial_state.time()).to_decimal_seconds();
+        let mean_anomaly = mod_two_pi(self.mean_anomaly + self.mean_motion * dt);
+        let elements = Keplerian::new(
+            time,
+            self.initial_state.origin(),
+            self.semi_major_axis,
+            self.eccentricity,
+            self.inclination,
+            mod_two_pi(self.longitude_of_ascending_node + self.node_rate * dt),
+            mod_two_pi(self.argument_of_periapsis + self.periapsis_rate * dt),
+            mean_to_true(mean_anomaly, self.eccentricity),
+        );
+        let state = elements.to_cartesian();
+        let to_icrf = self.equator.transpose();
+        Ok(State::new(
+            state.time(),
+            to_icrf * state.position(),
+            to_icrf * state.velocity(),
+            state.origin(),
+            Icrf,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use std::f64::consts::TAU;
+
+    use lox_bodies::Earth;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use super::*;
+
+    const SECONDS_PER_DAY: f64 = 86400.0;
+    const DAYS_PER_YEAR: f64 = 365.2422;
+
+    // At J2000 the IAU pole of the Earth coincides with the z-axis of the ICRF
+    fn sun_synchronous_orbit() -> State<Time<Tdb>, Earth, Icrf> {
+        let time = Time::j2000(Tdb);
+        let a = Earth.equatorial_radius() + 700.0;
+        let n = (Earth.gravitational_parameter() / a.powi(3)).sqrt();
+        let node_rate = TAU / (DAYS_PER_YEAR * SECONDS_PER_DAY);
+        let cos_i = -node_rate / (1.5 * n * Earth.j2() * (Earth.equatorial_radius() / a).powi(2));
+        Keplerian::builder(time, Earth)
+            .with_semi_major_axis(a)
+            .with_eccentricity(1e-3)
+            .with_inclination(cos_i.acos())
+            .with_longitude_of_ascending_node(0.5)
+            .with_argument_of_periapsis(1.0)
+            .with_true_anomaly(2.0)
+            .build()
+            .unwrap()
+            .to_cartesian()
+    }
+
+    #[test]
+    fn test_j2_propagator_sun_synchronous() {
+        let s0 = sun_synchronous_orbit();
+        let propagator = J2Propagator::new(s0).unwrap();
+
+        // A sun-synchronous orbit precesses eastward by ~0.9856°/day
+        let node_rate = propagator.node_rate().to_degrees() * SECONDS_PER_DAY;
+        assert_float_eq!(node_rate, 360.0 / DAYS_PER_YEAR, rel <= 1e-3);
+        assert_float_eq!(node_rate, 0.9856, abs <= 1e-4);
+
+        let s1 = propagator
+            .propagate(s0.time() + TimeDelta::from_days(1.0).unwrap())
+            .unwrap();
+        let k0 = s0.to_keplerian();
+        let k1 = s1.to_keplerian();
+        let node_change =
+            (k1.longitude_of_ascending_node() - k0.longitude_of_ascending_node()).to_degrees();
+        assert_float_eq!(node_change, 0.9856, abs <= 1e-3);
+        assert_float_eq!(k1.semi_major_axis(), k0.semi_major_axis(), rel <= 1e-9);
+        assert_float_eq!(k1.eccentricity(), k0.eccentricity(), abs <= 1e-9);
+        assert_float_eq!(k1.inclination(), k0.inclination(), abs <= 1e-6);
+    }
+
+    #[test]
+    fn test_j2_propagator_rates() {
+        let time = Time::j2000(Tdb);
+        let orbit = |inclination: f64| {
+            let s0 = Keplerian::builder(time, Earth)
+                .with_semi_major_axis(7000.0)
+                .with_eccentricity(0.01)
+                .with_inclination(inclination.to_radians())
+                .build()
+                .unwrap()
+                .to_cartesian();
+            J2Propagator::new(s0).unwrap()
+        };
+
+        // Prograde orbits regress, retrograde orbits precess, polar orbits keep their node
+        assert!(orbit(51.6).node_rate() < 0.0);
+        assert!(orbit(98.0).node_rate() > 0.0);
+        assert_float_eq!(orbit(90.0).node_rate(), 0.0, abs <= 1e-10);
+
+        // The apsidal line is frozen at the critical inclination
+        let critical = 0.8f64.sqrt().asin().to_degrees();
+        assert_float_eq!(orbit(critical).periapsis_rate(), 0.0, abs <= 1e-12);
+        assert_float_eq!(orbit(180.0 - critical).periapsis_rate(), 0.0, abs <= 1e-12);
+
+        let propagator = orbit(51.6);
+        let n = (Earth.gravitational_parameter() / 7000.0f64.powi(3)).sqrt();
+        assert!(propagator.mean_motion() > n);
+    }
+
+    #[test]
+    fn test_j2_propagator_initial_state() {
+        let s0 = sun_synchronous_orbit();
+        let propagator = J2Propagator::new(s0).unwrap();
+        let act = propagator.propagate(s0.time()).unwrap();
+        assert_close!(act.position(), s0.position(), 1e-8);
+        assert_close!(act.velocity(), s0.velocity(), 1e-8);
+    }
+
+    #[test]
+    fn test_j2_propagator_hyperbolic() {
+        let time = Time::j2000(Tdb);
+        let s0 = State::new(
+            time,
+            DVec3::new(7000.0, 0.0, 0.0),
+            DVec3::new(0.0, 12.0, 0.0),
+            Earth,
+            Icrf,
+        );
+        assert!(matches!(
+            J2Propagator::new(s0),
+            Err(J2Error::NonEllipticOrbit(_))
+        ));
+    }
+}
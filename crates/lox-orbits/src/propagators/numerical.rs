@@ -0,0 +1,712 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+
+use glam::{DMat3, DVec3};
+use itertools::Itertools;
+use thiserror::Error;
+
+use lox_bodies::{NaifId, PointMass, RotationalElements, Spheroid, ZonalHarmonics};
+use lox_ephem::{path_from_ids, Ephemeris};
+use lox_math::integrators::{IntegrationError, Rk45, Solution};
+use lox_math::linear_algebra::dmat6::DMat6;
+use lox_time::deltas::TimeDelta;
+use lox_time::TimeLike;
+
+use crate::frames::{CoordinateSystem, Icrf};
+use crate::propagators::Propagator;
+use crate::states::State;
+use crate::trajectories::{Trajectory, TrajectoryError};
+
+pub mod drag;
+pub mod srp;
+pub mod third_body;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum NumericalPropagatorError {
+    #[error(transparent)]
+    IntegrationError(#[from] IntegrationError),
+    #[error(transparent)]
+    TrajectoryError(#[from] TrajectoryError),
+    #[error(transparent)]
+    ForceModelError(#[from] ForceModelError),
+    #[error("step must be positive and finite but was {0} s")]
+    InvalidStep(f64),
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ForceModelError {
+    #[error("ephemeris lookup failed: {0}")]
+    EphemerisError(String),
+}
+
+/// A perturbing acceleration acting on a spacecraft in addition to the two-body gravity of the
+/// central body.
+///
+/// Positions and velocities are given in km and km/s relative to the central body in the ICRF,
+/// the time in seconds since J2000, and the acceleration is returned in km/s².
+///
+/// Force models which depend on external data, e.g. an ephemeris, return a [ForceModelError] if
+/// the data is not available at the evaluated time, which aborts the propagation.
+pub trait ForceModel: Send + Sync {
+    fn acceleration(
+        &self,
+        position: DVec3,
+        velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError>;
+
+    /// Returns the partial derivatives of the acceleration with respect to the position and the
+    /// velocity in 1/s² and 1/s, which are required for the variational equations.
+    ///
+    /// The default implementation uses central finite differences of [ForceModel::acceleration].
+    fn partials(
+        &self,
+        position: DVec3,
+        velocity: DVec3,
+        time: f64,
+    ) -> Result<(DMat3, DMat3), ForceModelError> {
+        let column = |dr: DVec3, dv: DVec3, step: f64| {
+            Ok((self.acceleration(position + dr, velocity + dv, time)?
+                - self.acceleration(position - dr, velocity - dv, time)?)
+                / (2.0 * step))
+        };
+        let mut wrt_position = DMat3::ZERO;
+        let mut wrt_velocity = DMat3::ZERO;
+        for (k, axis) in [DVec3::X, DVec3::Y, DVec3::Z].into_iter().enumerate() {
+            *wrt_position.col_mut(k) = column(POSITION_STEP * axis, DVec3::ZERO, POSITION_STEP)?;
+            *wrt_velocity.col_mut(k) = column(DVec3::ZERO, VELOCITY_STEP * axis, VELOCITY_STEP)?;
+        }
+        Ok((wrt_position, wrt_velocity))
+    }
+}
+
+// Finite difference steps of the position in km and the velocity in km/s
+pub(crate) const POSITION_STEP: f64 = 1e-3;
+pub(crate) const VELOCITY_STEP: f64 = 1e-6;
+
+/// Returns the gravity gradient of a point mass, i.e. the partial derivatives of the two-body
+/// acceleration with respect to the position.
+fn two_body_partials(gravitational_parameter: f64, position: DVec3) -> DMat3 {
+    let r = position.length();
+    let u = position / r;
+    let outer = DMat3::from_cols(u * u.x, u * u.y, u * u.z);
+    gravitational_parameter / r.powi(3) * (3.0 * outer - DMat3::IDENTITY)
+}
+
+/// The gravitational attraction of a third body, which is modelled as a point mass.
+pub struct PointMassGravity {
+    gravitational_parameter: f64,
+    position: Box<dyn Fn(f64) -> DVec3 + Send + Sync>,
+}
+
+impl PointMassGravity {
+    /// Creates the force model from the gravitational parameter of the third body in km³/s² and
+    /// a function returning its position relative to the central body in km at a given time in
+    /// seconds since J2000.
+    pub fn new<F>(gravitational_parameter: f64, position: F) -> Self
+    where
+        F: Fn(f64) -> DVec3 + Send + Sync + 'static,
+    {
+        Self {
+            gravitational_parameter,
+            position: Box::new(position),
+        }
+    }
+}
+
+impl ForceModel for PointMassGravity {
+    fn acceleration(
+        &self,
+        position: DVec3,
+        _velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError> {
+        Ok(third_body_acceleration(
+            self.gravitational_parameter,
+            position,
+            (self.position)(time),
+        ))
+    }
+}
+
+/// Returns the perturbing acceleration of a third body at `body` on a spacecraft at `position`,
+/// i.e. the direct attraction of the spacecraft minus the attraction of the central body.
+///
+/// Battin's formulation is used to avoid the cancellation between both terms when the spacecraft
+/// is close to the central body compared to the third body.
+pub(crate) fn third_body_acceleration(
+    gravitational_parameter: f64,
+    position: DVec3,
+    body: DVec3,
+) -> DVec3 {
+    let q = position.dot(position - 2.0 * body) / body.length_squared();
+    let f = q * (3.0 + 3.0 * q + q.powi(2)) / (1.0 + (1.0 + q).powf(1.5));
+    -gravitational_parameter / (body - position).length().powi(3) * (position + f * body)
+}
+
+/// Returns the position of `target` relative to `origin` in km from `ephemeris`.
+pub(crate) fn ephemeris_position<E: Ephemeris>(
+    ephemeris: &E,
+    time: f64,
+    origin: NaifId,
+    target: NaifId,
+) -> Result<DVec3, E::Error> {
+    let mut position = DVec3::ZERO;
+    for (origin, target) in path_from_ids(origin.0, target.0)
+        .into_iter()
+        .tuple_windows()
+    {
+        let (x, y, z) = ephemeris.position(time, origin.into(), target.into())?;
+        position += DVec3::new(x, y, z);
+    }
+    Ok(position)
+}
+
+/// The perturbation due to the oblateness of the central body described by its J2 zonal
+/// harmonic. The symmetry axis is the IAU pole of the body at the evaluated time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct J2Gravity<O> {
+    body: O,
+}
+
+impl<O> J2Gravity<O>
+where
+    O: PointMass + Spheroid + ZonalHarmonics + RotationalElements,
+{
+    pub fn new(body: O) -> Self {
+        Self { body }
+    }
+}
+
+impl<O> ForceModel for J2Gravity<O>
+where
+    O: PointMass + Spheroid + ZonalHarmonics + RotationalElements + Send + Sync,
+{
+    fn acceleration(
+        &self,
+        position: DVec3,
+        _velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError> {
+        let (ra, dec, _) = self.body.rotational_elements(time);
+        let pole = DVec3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin());
+        let r = position.length();
+        let z = position.dot(pole);
+        let k = 1.5
+            * self.body.j2()
+            * self.body.gravitational_parameter()
+            * self.body.equatorial_radius().powi(2)
+            / r.powi(5);
+        Ok(k * ((5.0 * z.powi(2) / r.powi(2) - 1.0) * position - 2.0 * z * pole))
+    }
+}
+
+/// Cowell propagator, which numerically integrates the equations of motion consisting of the
+/// two-body gravity of the central body and an arbitrary number of [ForceModel]s.
+pub struct NumericalPropagator<T: TimeLike, O: PointMass> {
+    initial_state: State<T, O, Icrf>,
+    force_models: Vec<Box<dyn ForceModel>>,
+    integrator: Rk45,
+}
+
+impl<T, O> NumericalPropagator<T, O>
+where
+    T: TimeLike + Clone,
+    O: PointMass + Clone,
+{
+    pub fn new(initial_state: State<T, O, Icrf>) -> Self {
+        Self {
+            initial_state,
+            force_models: vec![],
+            integrator: Rk45::default().with_tolerances(1e-10, 1e-12),
+        }
+    }
+
+    pub fn with_force_model(mut self, force_model: impl ForceModel + 'static) -> Self {
+        self.force_models.push(Box::new(force_model));
+        self
+    }
+
+    pub fn with_force_models(self, force_models: Vec<Box<dyn ForceModel>>) -> Self {
+        Self {
+            force_models,
+            ..self
+        }
+    }
+
+    pub fn with_integrator(self, integrator: Rk45) -> Self {
+        Self { integrator, ..self }
+    }
+
+    pub fn force_models(&self) -> &[Box<dyn ForceModel>] {
+        &self.force_models
+    }
+
+    fn acceleration(
+        &self,
+        time: f64,
+        position: DVec3,
+        velocity: DVec3,
+    ) -> Result<DVec3, ForceModelError> {
+        let mu = self.initial_state.origin().gravitational_parameter();
+        self.force_models
+            .iter()
+            .try_fold(-mu * position / position.length().powi(3), |a, force| {
+                Ok(a + force.acceleration(position, velocity, time)?)
+            })
+    }
+
+    fn derivative(&self, epoch: f64, t: f64, y: &[f64]) -> Result<Vec<f64>, ForceModelError> {
+        let position = DVec3::new(y[0], y[1], y[2]);
+        let velocity = DVec3::new(y[3], y[4], y[5]);
+        let acceleration = self.acceleration(epoch + t, position, velocity)?;
+        Ok(vec![
+            velocity.x,
+            velocity.y,
+            velocity.z,
+            acceleration.x,
+            acceleration.y,
+            acceleration.z,
+        ])
+    }
+
+    // The state is followed by the state transition matrix in row-major order, whose derivative
+    // is given by the variational equations dΦ/dt = AΦ with A = [[0, I], [∂a/∂r, ∂a/∂v]].
+    fn derivative_with_stm(
+        &self,
+        epoch: f64,
+        t: f64,
+        y: &[f64],
+    ) -> Result<Vec<f64>, ForceModelError> {
+        let time = epoch + t;
+        let position = DVec3::new(y[0], y[1], y[2]);
+        let velocity = DVec3::new(y[3], y[4], y[5]);
+        let mu = self.initial_state.origin().gravitational_parameter();
+        let (wrt_position, wrt_velocity) = self.force_models.iter().try_fold(
+            (two_body_partials(mu, position), DMat3::ZERO),
+            |(g, d), force| {
+                let (dg, dd) = force.partials(position, velocity, time)?;
+                Ok::<_, ForceModelError>((g + dg, d + dd))
+            },
+        )?;
+        let mut dy = self.derivative(epoch, t, y)?;
+        let stm = &y[6..];
+        // The upper rows of AΦ are the lower rows of Φ
+        dy.extend_from_slice(&stm[18..]);
+        for i in 0..3 {
+            for j in 0..6 {
+                dy.push(
+                    (0..3)
+                        .map(|k| {
+                            wrt_position.col(k)[i] * stm[6 * k + j]
+                                + wrt_velocity.col(k)[i] * stm[6 * (k + 3) + j]
+                        })
+                        .sum(),
+                );
+            }
+        }
+        Ok(dy)
+    }
+
+    // The integrator requires an infallible derivative. Therefore, the first error of a force
+    // model is stored and the integration is aborted by returning a non-finite derivative.
+    fn solve<F>(
+        &self,
+        derivative: F,
+        t0: f64,
+        y0: &[f64],
+        t1: f64,
+    ) -> Result<Solution, NumericalPropagatorError>
+    where
+        F: Fn(f64, &[f64]) -> Result<Vec<f64>, ForceModelError>,
+    {
+        let error = RefCell::new(None);
+        let solution = self.integrator.integrate(
+            |t, y| {
+                derivative(t, y).unwrap_or_else(|err| {
+                    error.borrow_mut().get_or_insert(err);
+                    vec![f64::NAN; y.len()]
+                })
+            },
+            t0,
+            y0,
+            t1,
+        );
+        if let Some(err) = error.into_inner() {
+            return Err(err.into());
+        }
+        Ok(solution?)
+    }
+
+    // Integrates from `t0` to `t1` in seconds since the initial epoch
+    fn integrate(
+        &self,
+        t0: f64,
+        y0: &[f64],
+        t1: f64,
+    ) -> Result<Vec<f64>, NumericalPropagatorError> {
+        let epoch = self.initial_state.time().seconds_since_j2000();
+        let solution = self.solve(|t, y| self.derivative(epoch, t, y), t0, y0, t1)?;
+        Ok(solution.last().y.clone())
+    }
+
+    fn to_state(&self, time: T, y: &[f64]) -> State<T, O, Icrf> {
+        State::new(
+            time,
+            DVec3::new(y[0], y[1], y[2]),
+            DVec3::new(y[3], y[4], y[5]),
+            self.initial_state.origin(),
+            Icrf,
+        )
+    }
+}
+
+fn state_vector<T: TimeLike, O: PointMass>(state: &State<T, O, Icrf>) -> Vec<f64> {
+    let (position, velocity) = (state.position(), state.velocity());
+    vec![
+        position.x, position.y, position.z, velocity.x, velocity.y, velocity.z,
+    ]
+}
+
+impl<T, O> CoordinateSystem<Icrf> for NumericalPropagator<T, O>
+where
+    T: TimeLike,
+    O: PointMass,
+{
+    fn reference_frame(&self) -> Icrf {
+        Icrf
+    }
+}
+
+impl<T, O> Propagator<T, O, Icrf> for NumericalPropagator<T, O>
+where
+    T: TimeLike + Clone,
+    O: PointMass + Clone,
+{
+    type Error = NumericalPropagatorError;
+
+    fn propagate(&self, time: T) -> Result<State<T, O, Icrf>, Self::Error> {
+        let dt = (time.clone() - self.initial_state.time()).to_decimal_seconds();
+        let y = self.integrate(0.0, &state_vector(&self.initial_state), dt)?;
+        Ok(self.to_state(time, &y))
+    }
+
+    // Each state is integrated from the previous one instead of the initial state
+    fn propagate_all(
+        &self,
+        times: impl IntoIterator<Item = T>,
+    ) -> Result<Trajectory<T, O, Icrf>, Self::Error> {
+        let t0 = self.initial_state.time();
+        let mut t = 0.0;
+        let mut y = state_vector(&self.initial_state);
+        let mut states = vec![];
+        for time in times {
+            let dt = (time.clone() - t0.clone()).to_decimal_seconds();
+            y = self.integrate(t, &y, dt)?;
+            t = dt;
+            states.push(self.to_state(time, &y));
+        }
+        Ok(Trajectory::new(&states)?)
+    }
+}
+
+impl<T, O> NumericalPropagator<T, O>
+where
+    T: TimeLike + Clone,
+    O: PointMass + Clone,
+{
+    /// Propagates the initial state to `time` together with the state transition matrix `Φ`,
+    /// which maps deviations of the initial position and velocity to deviations at `time`.
+    ///
+    /// `Φ` is obtained by integrating the variational equations alongside the equations of
+    /// motion. The partial derivatives of the two-body gravity are analytical, while those of the
+    /// force models are provided by [ForceModel::partials].
+    pub fn propagate_with_stm(
+        &self,
+        time: T,
+    ) -> Result<(State<T, O, Icrf>, DMat6), NumericalPropagatorError> {
+        let epoch = self.initial_state.time().seconds_since_j2000();
+        let dt = (time.clone() - self.initial_state.time()).to_decimal_seconds();
+        let mut y0 = state_vector(&self.initial_state);
+        y0.extend(DMat6::IDENTITY.to_rows().iter().flatten());
+        let solution = self.solve(|t, y| self.derivative_with_stm(epoch, t, y), 0.0, &y0, dt)?;
+        let y = &solution.last().y;
+        let stm = std::array::from_fn(|i| std::array::from_fn(|j| y[6 + 6 * i + j]));
+        Ok((self.to_state(time, y), DMat6::from_rows(stm)))
+    }
+
+    /// Propagates the initial state in steps of `step` until `end` and returns the trajectory.
+    ///
+    /// The direction of propagation follows from `end`, so `step` must be positive.
+    pub fn propagate_with_step(
+        &self,
+        end: T,
+        step: TimeDelta,
+    ) -> Result<Trajectory<T, O, Icrf>, NumericalPropagatorError> {
+        let step = step.to_decimal_seconds();
+        if !step.is_finite() || step <= 0.0 {
+            return Err(NumericalPropagatorError::InvalidStep(step));
+        }
+        let t0 = self.initial_state.time();
+        let duration = (end.clone() - t0.clone()).to_decimal_seconds();
+        let step = step * duration.signum();
+        let n = (duration / step).ceil() as usize;
+        let times = (0..=n).map(|i| {
+            if i == n {
+                end.clone()
+            } else {
+                t0.clone() + TimeDelta::from_decimal_seconds(step * i as f64).unwrap()
+            }
+        });
+        self.propagate_all(times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use lox_bodies::Earth;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use crate::elements::Keplerian;
+    use crate::propagators::j2::J2Propagator;
+    use crate::propagators::semi_analytical::Vallado;
+
+    use super::drag::{Drag, ExponentialAtmosphere};
+    use super::*;
+
+    fn leo() -> State<Time<Tdb>, Earth, Icrf> {
+        Keplerian::builder(Time::j2000(Tdb), Earth)
+            .with_semi_major_axis(Earth.equatorial_radius() + 500.0)
+            .with_eccentricity(1e-3)
+            .with_inclination(51.6f64.to_radians())
+            .with_longitude_of_ascending_node(0.5)
+            .with_argument_of_periapsis(1.0)
+            .with_true_anomaly(2.0)
+            .build()
+            .unwrap()
+            .to_cartesian()
+    }
+
+    fn energy(state: &State<Time<Tdb>, Earth, Icrf>) -> f64 {
+        state.velocity().length_squared() / 2.0
+            - Earth.gravitational_parameter() / state.position().length()
+    }
+
+    #[test]
+    fn test_numerical_propagator_two_body() {
+        let s0 = leo();
+        let period = s0.to_keplerian().orbital_period().to_decimal_seconds();
+        let propagator = NumericalPropagator::new(s0);
+
+        let time = s0.time() + TimeDelta::from_decimal_seconds(2.5 * period).unwrap();
+        let act = propagator.propagate(time).unwrap();
+        let exp = Vallado::new(s0).propagate(time).unwrap();
+        assert_close!(act.position(), exp.position(), 1e-5);
+        assert_close!(act.velocity(), exp.velocity(), 1e-8);
+    }
+
+    #[test]
+    fn test_numerical_propagator_energy_conservation() {
+        let s0 = leo();
+        let period = s0.to_keplerian().orbital_period().to_decimal_seconds();
+        let propagator = NumericalPropagator::new(s0);
+        let trajectory = propagator
+            .propagate_with_step(
+                s0.time() + TimeDelta::from_decimal_seconds(100.0 * period).unwrap(),
+                TimeDelta::from_decimal_seconds(period).unwrap(),
+            )
+            .unwrap();
+
+        let e0 = energy(&s0);
+        let states = trajectory.states();
+        assert_eq!(states.len(), 101);
+        for state in states {
+            assert_float_eq!(energy(&state), e0, r2nd <= 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_numerical_propagator_invalid_step() {
+        let s0 = leo();
+        let propagator = NumericalPropagator::new(s0);
+        let end = s0.time() + TimeDelta::from_decimal_seconds(600.0).unwrap();
+        assert_eq!(
+            propagator
+                .propagate_with_step(end, TimeDelta::default())
+                .unwrap_err(),
+            NumericalPropagatorError::InvalidStep(0.0)
+        );
+        assert_eq!(
+            propagator
+                .propagate_with_step(end, TimeDelta::from_decimal_seconds(-60.0).unwrap())
+                .unwrap_err(),
+            NumericalPropagatorError::InvalidStep(-60.0)
+        );
+    }
+
+    #[test]
+    fn test_numerical_propagator_j2() {
+        let s0 = leo();
+        let time = s0.time() + TimeDelta::from_days(1.0).unwrap();
+        let numerical = NumericalPropagator::new(s0)
+            .with_force_model(J2Gravity::new(Earth))
+            .propagate(time)
+            .unwrap();
+        let analytical = J2Propagator::new(s0).unwrap();
+
+        // The node regresses at the secular rate up to short-periodic variations
+        let node_change = numerical.to_keplerian().longitude_of_ascending_node()
+            - s0.to_keplerian().longitude_of_ascending_node();
+        assert_float_eq!(node_change, analytical.node_rate() * 86400.0, abs <= 1e-3);
+        assert!(node_change < 0.0);
+    }
+
+    #[test]
+    fn test_j2_gravity() {
+        let j2 = J2Gravity::new(Earth);
+        let r = Earth.equatorial_radius();
+        let k = 1.5 * Earth.j2() * Earth.gravitational_parameter() / r.powi(2);
+        // At J2000 the IAU pole of the Earth is aligned with the z-axis
+        let equator = j2
+            .acceleration(DVec3::new(r, 0.0, 0.0), DVec3::ZERO, 0.0)
+            .unwrap();
+        assert_close!(equator, DVec3::new(-k, 0.0, 0.0), 1e-12);
+        let pole = j2
+            .acceleration(DVec3::new(0.0, 0.0, r), DVec3::ZERO, 0.0)
+            .unwrap();
+        assert_close!(pole, DVec3::new(0.0, 0.0, 2.0 * k), 1e-12);
+    }
+
+    #[test]
+    fn test_point_mass_gravity() {
+        let moon = DVec3::new(384400.0, 0.0, 0.0);
+        let gravity = PointMassGravity::new(4902.8, move |_| moon);
+        // The central body is accelerated in the same way as a spacecraft at its centre
+        let centre = gravity.acceleration(DVec3::ZERO, DVec3::ZERO, 0.0).unwrap();
+        assert_close!(centre, DVec3::ZERO, 1e-20);
+        // Tidal acceleration along the Earth-Moon line
+        let a = gravity
+            .acceleration(DVec3::new(7000.0, 0.0, 0.0), DVec3::ZERO, 0.0)
+            .unwrap();
+        let exp = 2.0 * 4902.8 * 7000.0 / moon.x.powi(3);
+        assert_float_eq!(a.x, exp, rel <= 0.1);
+        assert!(a.y.abs() < 1e-20 && a.z.abs() < 1e-20);
+    }
+
+    #[test]
+    fn test_numerical_propagator_force_models() {
+        let s0 = leo();
+        let propagator = |s| {
+            NumericalPropagator::new(s)
+                .with_force_model(J2Gravity::new(Earth))
+                .with_force_model(Drag::new(Earth, ExponentialAtmosphere::earth(), 2.2, 0.01))
+        };
+        assert_eq!(propagator(s0).force_models().len(), 2);
+        assert!(propagator(s0)
+            .with_force_models(vec![])
+            .force_models()
+            .is_empty());
+
+        // Propagating backwards returns to the initial state
+        let time = s0.time() + TimeDelta::from_decimal_seconds(6000.0).unwrap();
+        let s1 = propagator(s0).propagate(time).unwrap();
+        let s2 = propagator(s1).propagate(s0.time()).unwrap();
+        assert_close!(s2.position(), s0.position(), 1e-6);
+    }
+
+    #[test]
+    fn test_numerical_propagator_stm_symplectic() {
+        let s0 = leo();
+        let period = s0.to_keplerian().orbital_period().to_decimal_seconds();
+        let time = s0.time() + TimeDelta::from_decimal_seconds(period).unwrap();
+        let propagator = NumericalPropagator::new(s0);
+        let (state, stm) = propagator.propagate_with_stm(time).unwrap();
+        let expected = propagator.propagate(time).unwrap();
+        assert_close!(state.position(), expected.position(), 1e-9);
+
+        // Φᵀ J Φ = J for the Hamiltonian two-body dynamics
+        let j = DMat6::from_blocks(DMat3::ZERO, DMat3::IDENTITY, -DMat3::IDENTITY, DMat3::ZERO);
+        assert!((stm.transpose() * j * stm).max_abs_diff(&j) < 1e-6);
+        // The along-track drift of a velocity deviation is secular
+        assert!(stm.max_abs_diff(&DMat6::IDENTITY) > 1.0);
+    }
+
+    #[test]
+    fn test_numerical_propagator_stm_finite_differences() {
+        let s0 = leo();
+        let time = s0.time() + TimeDelta::from_decimal_seconds(3000.0).unwrap();
+        let propagator = |s0| {
+            NumericalPropagator::new(s0)
+                .with_force_model(J2Gravity::new(Earth))
+                .with_force_model(Drag::new(Earth, ExponentialAtmosphere::earth(), 2.2, 0.01))
+        };
+        let (_, stm) = propagator(s0).propagate_with_stm(time).unwrap();
+        for (j, step) in [(0, 1e-2), (4, 1e-5)] {
+            let (mut dr, mut dv) = (DVec3::ZERO, DVec3::ZERO);
+            if j < 3 {
+                dr[j] = step;
+            } else {
+                dv[j - 3] = step;
+            }
+            let perturbed = |sign: f64| {
+                let s = State::new(
+                    s0.time(),
+                    s0.position() + sign * dr,
+                    s0.velocity() + sign * dv,
+                    Earth,
+                    Icrf,
+                );
+                propagator(s).propagate(time).unwrap()
+            };
+            let (plus, minus) = (perturbed(1.0), perturbed(-1.0));
+            let dr = (plus.position() - minus.position()) / (2.0 * step);
+            let dv = (plus.velocity() - minus.velocity()) / (2.0 * step);
+            for i in 0..3 {
+                assert_float_eq!(stm[(i, j)], dr[i], abs <= 1e-4 * stm[(i, j)].abs().max(1.0));
+                assert_float_eq!(
+                    stm[(i + 3, j)],
+                    dv[i],
+                    abs <= 1e-4 * stm[(i + 3, j)].abs().max(1e-3)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_force_model_partials() {
+        // The default finite differences agree with the analytical gravity gradient
+        struct TwoBody;
+        impl ForceModel for TwoBody {
+            fn acceleration(
+                &self,
+                position: DVec3,
+                _velocity: DVec3,
+                _time: f64,
+            ) -> Result<DVec3, ForceModelError> {
+                Ok(-Earth.gravitational_parameter() * position / position.length().powi(3))
+            }
+        }
+        let position = DVec3::new(4000.0, -5000.0, 3000.0);
+        let (g, d) = TwoBody
+            .partials(position, DVec3::new(1.0, 2.0, 3.0), 0.0)
+            .unwrap();
+        let exp = two_body_partials(Earth.gravitational_parameter(), position);
+        for k in 0..3 {
+            assert_close!(g.col(k), exp.col(k), 1e-9);
+        }
+        assert_eq!(d, DMat3::ZERO);
+        assert_close!(exp.col(0)[1], exp.col(1)[0], 1e-15);
+    }
+}
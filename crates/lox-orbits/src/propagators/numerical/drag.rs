@@ -0,0 +1,297 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use glam::DVec3;
+
+use lox_bodies::{RotationalElements, Spheroid};
+
+use crate::ground::geodetic_coordinates;
+use crate::propagators::numerical::{ForceModel, ForceModelError};
+
+pub trait AtmosphereModel: Send + Sync {
+    /// Returns the atmospheric density in kg/m³ at the given geodetic altitude in m.
+    fn density(&self, altitude: f64) -> f64;
+}
+
+/// A layer of an exponential atmosphere given by its base altitude in m, the density at the base
+/// altitude in kg/m³, and its scale height in m.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AtmosphereLayer {
+    pub base_altitude: f64,
+    pub base_density: f64,
+    pub scale_height: f64,
+}
+
+impl AtmosphereLayer {
+    pub const fn new(base_altitude: f64, base_density: f64, scale_height: f64) -> Self {
+        Self {
+            base_altitude,
+            base_density,
+            scale_height,
+        }
+    }
+}
+
+// Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., Table 8-4
+const EARTH_LAYERS: [AtmosphereLayer; 28] = [
+    AtmosphereLayer::new(0.0, 1.225, 7249.0),
+    AtmosphereLayer::new(25e3, 3.899e-2, 6349.0),
+    AtmosphereLayer::new(30e3, 1.774e-2, 6682.0),
+    AtmosphereLayer::new(40e3, 3.972e-3, 7554.0),
+    AtmosphereLayer::new(50e3, 1.057e-3, 8382.0),
+    AtmosphereLayer::new(60e3, 3.206e-4, 7714.0),
+    AtmosphereLayer::new(70e3, 8.770e-5, 6549.0),
+    AtmosphereLayer::new(80e3, 1.905e-5, 5799.0),
+    AtmosphereLayer::new(90e3, 3.396e-6, 5382.0),
+    AtmosphereLayer::new(100e3, 5.297e-7, 5877.0),
+    AtmosphereLayer::new(110e3, 9.661e-8, 7263.0),
+    AtmosphereLayer::new(120e3, 2.438e-8, 9473.0),
+    AtmosphereLayer::new(130e3, 8.484e-9, 12636.0),
+    AtmosphereLayer::new(140e3, 3.845e-9, 16149.0),
+    AtmosphereLayer::new(150e3, 2.070e-9, 22523.0),
+    AtmosphereLayer::new(180e3, 5.464e-10, 29740.0),
+    AtmosphereLayer::new(200e3, 2.789e-10, 37105.0),
+    AtmosphereLayer::new(250e3, 7.248e-11, 45546.0),
+    AtmosphereLayer::new(300e3, 2.418e-11, 53628.0),
+    AtmosphereLayer::new(350e3, 9.518e-12, 53298.0),
+    AtmosphereLayer::new(400e3, 3.725e-12, 58515.0),
+    AtmosphereLayer::new(450e3, 1.585e-12, 60828.0),
+    AtmosphereLayer::new(500e3, 6.967e-13, 63822.0),
+    AtmosphereLayer::new(600e3, 1.454e-13, 71835.0),
+    AtmosphereLayer::new(700e3, 3.614e-14, 88667.0),
+    AtmosphereLayer::new(800e3, 1.170e-14, 124640.0),
+    AtmosphereLayer::new(900e3, 5.245e-15, 181050.0),
+    AtmosphereLayer::new(1000e3, 3.019e-15, 268000.0),
+];
+
+/// Piecewise exponential atmosphere. The density is zero above the cutoff altitude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialAtmosphere {
+    layers: Vec<AtmosphereLayer>,
+    cutoff_altitude: f64,
+}
+
+impl ExponentialAtmosphere {
+    /// Creates an atmosphere from layers sorted by their base altitude. The density below the
+    /// lowest base altitude is extrapolated from the first layer.
+    pub fn new(layers: Vec<AtmosphereLayer>) -> Self {
+        Self {
+            layers,
+            cutoff_altitude: f64::INFINITY,
+        }
+    }
+
+    /// The exponential model of the Earth's atmosphere from Vallado with a cutoff at 1000 km.
+    pub fn earth() -> Self {
+        Self::new(EARTH_LAYERS.to_vec()).with_cutoff_altitude(1000e3)
+    }
+
+    /// Sets the altitude in m above which the density is zero.
+    pub fn with_cutoff_altitude(self, cutoff_altitude: f64) -> Self {
+        Self {
+            cutoff_altitude,
+            ..self
+        }
+    }
+
+    pub fn cutoff_altitude(&self) -> f64 {
+        self.cutoff_altitude
+    }
+}
+
+impl AtmosphereModel for ExponentialAtmosphere {
+    fn density(&self, altitude: f64) -> f64 {
+        if altitude > self.cutoff_altitude {
+            return 0.0;
+        }
+        let i = self
+            .layers
+            .partition_point(|layer| layer.base_altitude <= altitude)
+            .saturating_sub(1);
+        match self.layers.get(i) {
+            Some(layer) => {
+                layer.base_density * (-(altitude - layer.base_altitude) / layer.scale_height).exp()
+            }
+            None => 0.0,
+        }
+    }
+}
+
+/// Atmospheric drag on a spherical spacecraft ("cannonball" model).
+///
+/// The drag acceleration is computed from the velocity relative to the atmosphere, which
+/// co-rotates with the central body about its IAU pole. The density is evaluated at the geodetic
+/// altitude above the reference spheroid of the body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drag<O, A> {
+    body: O,
+    atmosphere: A,
+    drag_coefficient: f64,
+    area_to_mass_ratio: f64,
+}
+
+impl<O, A> Drag<O, A>
+where
+    O: Spheroid + RotationalElements,
+    A: AtmosphereModel,
+{
+    /// Creates a drag model from the dimensionless drag coefficient and the area-to-mass ratio of
+    /// the spacecraft in m²/kg.
+    pub fn new(body: O, atmosphere: A, drag_coefficient: f64, area_to_mass_ratio: f64) -> Self {
+        Self {
+            body,
+            atmosphere,
+            drag_coefficient,
+            area_to_mass_ratio,
+        }
+    }
+
+    pub fn atmosphere(&self) -> &A {
+        &self.atmosphere
+    }
+
+    /// Returns the geodetic altitude in m for a position in km relative to the body in the ICRF.
+    pub fn altitude(&self, position: DVec3, time: f64) -> f64 {
+        let pole = self.pole(time);
+        // The geodetic altitude does not depend on the longitude
+        let z = position.dot(pole);
+        let meridian = DVec3::new(
+            (position.length_squared() - z.powi(2)).max(0.0).sqrt(),
+            0.0,
+            z,
+        );
+        let (_, _, altitude) = geodetic_coordinates(meridian, &self.body);
+        altitude * 1e3
+    }
+
+    fn pole(&self, time: f64) -> DVec3 {
+        let (ra, dec, _) = self.body.rotational_elements(time);
+        DVec3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin())
+    }
+}
+
+impl<O, A> ForceModel for Drag<O, A>
+where
+    O: Spheroid + RotationalElements + Send + Sync,
+    A: AtmosphereModel,
+{
+    fn acceleration(
+        &self,
+        position: DVec3,
+        velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError> {
+        let density = self.atmosphere.density(self.altitude(position, time));
+        if density == 0.0 {
+            return Ok(DVec3::ZERO);
+        }
+        let (_, _, rotation_rate) = self.body.rotational_element_rates(time);
+        let angular_velocity = rotation_rate * self.pole(time);
+        let relative_velocity = velocity - angular_velocity.cross(position);
+        // kg/m³ * m²/kg * km²/s² = 10³ km/s²
+        Ok(-0.5e3
+            * self.drag_coefficient
+            * self.area_to_mass_ratio
+            * density
+            * relative_velocity.length()
+            * relative_velocity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use lox_bodies::Earth;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use crate::elements::Keplerian;
+    use crate::propagators::numerical::NumericalPropagator;
+    use crate::propagators::Propagator;
+
+    use super::*;
+
+    #[test]
+    fn test_exponential_atmosphere() {
+        let atmosphere = ExponentialAtmosphere::earth();
+        assert_float_eq!(atmosphere.density(0.0), 1.225, rel <= 1e-12);
+        assert_float_eq!(atmosphere.density(400e3), 3.725e-12, rel <= 1e-12);
+        assert_float_eq!(
+            atmosphere.density(420e3),
+            3.725e-12 * (-20.0 / 58.515f64).exp(),
+            rel <= 1e-12
+        );
+        assert!(atmosphere.density(450e3 - 1.0) > atmosphere.density(450e3 + 1.0));
+        assert!(atmosphere.density(1000e3) > 0.0);
+        assert_eq!(atmosphere.density(1000e3 + 1.0), 0.0);
+        assert_eq!(atmosphere.with_cutoff_altitude(500e3).density(600e3), 0.0);
+    }
+
+    #[test]
+    fn test_drag_geodetic_altitude() {
+        let drag = Drag::new(Earth, ExponentialAtmosphere::earth(), 2.2, 0.01);
+        let polar_radius = Earth.equatorial_radius() * (1.0 - Earth.flattening());
+        // At J2000 the IAU pole of the Earth is aligned with the z-axis
+        let position = DVec3::new(0.0, 0.0, polar_radius + 400.0);
+        assert_float_eq!(drag.altitude(position, 0.0), 400e3, abs <= 1e-3);
+        let position = DVec3::new(Earth.equatorial_radius() + 400.0, 0.0, 0.0);
+        assert_float_eq!(drag.altitude(position, 0.0), 400e3, abs <= 1e-3);
+    }
+
+    #[test]
+    fn test_drag_acceleration() {
+        let drag = Drag::new(Earth, ExponentialAtmosphere::earth(), 2.2, 0.01);
+        let r = Earth.equatorial_radius() + 400.0;
+        let position = DVec3::new(r, 0.0, 0.0);
+
+        // A spacecraft co-rotating with the atmosphere experiences no drag
+        let rotation_rate = Earth.rotational_element_rates(0.0).2;
+        let velocity = DVec3::new(0.0, rotation_rate * r, 0.0);
+        assert_close!(
+            drag.acceleration(position, velocity, 0.0).unwrap(),
+            DVec3::ZERO,
+            1e-20
+        );
+
+        let velocity = DVec3::new(0.0, 7.67, 0.0);
+        let relative_speed = 7.67 - rotation_rate * r;
+        let exp = -0.5e3 * 2.2 * 0.01 * 3.725e-12 * relative_speed.powi(2);
+        let act = drag.acceleration(position, velocity, 0.0).unwrap();
+        assert_float_eq!(act.y, exp, rel <= 1e-6);
+        assert_float_eq!(act.x, 0.0, abs <= 1e-20);
+
+        // No drag above the cutoff altitude
+        let position = DVec3::new(Earth.equatorial_radius() + 1200.0, 0.0, 0.0);
+        assert_eq!(
+            drag.acceleration(position, velocity, 0.0).unwrap(),
+            DVec3::ZERO
+        );
+    }
+
+    #[test]
+    fn test_drag_orbit_decay() {
+        let s0 = Keplerian::builder(Time::j2000(Tdb), Earth)
+            .with_semi_major_axis(Earth.equatorial_radius() + 300.0)
+            .with_eccentricity(1e-3)
+            .with_inclination(51.6f64.to_radians())
+            .build()
+            .unwrap()
+            .to_cartesian();
+        let time = s0.time() + TimeDelta::from_days(1.0).unwrap();
+        let s1 = NumericalPropagator::new(s0)
+            .with_force_model(Drag::new(Earth, ExponentialAtmosphere::earth(), 2.2, 0.01))
+            .propagate(time)
+            .unwrap();
+        let decay = s0.to_keplerian().semi_major_axis() - s1.to_keplerian().semi_major_axis();
+        assert!(decay > 0.0 && decay < 10.0);
+    }
+}
@@ -0,0 +1,275 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::Arc;
+
+use glam::DVec3;
+
+use lox_bodies::{Origin, Spheroid, Sun};
+use lox_ephem::Ephemeris;
+use lox_math::constants::f64::physics::{ASTRONOMICAL_UNIT, SOLAR_CONSTANT, SPEED_OF_LIGHT};
+
+use crate::analysis::ShadowGeometry;
+use crate::propagators::numerical::{ephemeris_position, ForceModel, ForceModelError};
+
+/// Solar radiation pressure on a spherical spacecraft ("cannonball" model).
+///
+/// The acceleration points away from the Sun and scales with the inverse square of the distance to
+/// the Sun. It is reduced by the visible fraction of the solar disk, which is computed with the
+/// conical shadow model of the central body that is also used for eclipse detection. If the
+/// position of the Sun cannot be obtained from the shared ephemeris, a
+/// [ForceModelError::EphemerisError] is returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Srp<O, E> {
+    body: O,
+    ephemeris: Arc<E>,
+    reflectivity_coefficient: f64,
+    area_to_mass_ratio: f64,
+}
+
+impl<O, E> Srp<O, E>
+where
+    O: Origin + Spheroid,
+    E: Ephemeris,
+{
+    /// Creates a solar radiation pressure model for a spacecraft orbiting `body` from the
+    /// dimensionless reflectivity coefficient and the area-to-mass ratio in m²/kg.
+    pub fn new(
+        body: O,
+        ephemeris: Arc<E>,
+        reflectivity_coefficient: f64,
+        area_to_mass_ratio: f64,
+    ) -> Self {
+        Self {
+            body,
+            ephemeris,
+            reflectivity_coefficient,
+            area_to_mass_ratio,
+        }
+    }
+
+    /// Returns the position of the Sun relative to the central body in km.
+    pub fn sun_position(&self, time: f64) -> Result<DVec3, E::Error> {
+        ephemeris_position(self.ephemeris.as_ref(), time, self.body.id(), Sun.id())
+    }
+
+    /// Returns the visible fraction of the solar disk at `position` in km relative to the central
+    /// body.
+    pub fn illumination(&self, position: DVec3, sun: DVec3) -> f64 {
+        if self.body.id() == Sun.id() {
+            return 1.0;
+        }
+        ShadowGeometry::new(
+            sun - position,
+            -position,
+            Sun.equatorial_radius(),
+            self.body.equatorial_radius(),
+        )
+        .illumination()
+    }
+}
+
+impl<O, E> ForceModel for Srp<O, E>
+where
+    O: Origin + Spheroid + Send + Sync,
+    E: Ephemeris + Send + Sync,
+{
+    fn acceleration(
+        &self,
+        position: DVec3,
+        _velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError> {
+        let sun = self
+            .sun_position(time)
+            .map_err(|err| ForceModelError::EphemerisError(err.to_string()))?;
+        let illumination = self.illumination(position, sun);
+        if illumination == 0.0 {
+            return Ok(DVec3::ZERO);
+        }
+        let sun_sc = position - sun;
+        let distance = sun_sc.length();
+        // Radiation pressure at 1 AU in N/m² with the speed of light converted to m/s
+        let pressure = SOLAR_CONSTANT / (SPEED_OF_LIGHT * 1e3);
+        // N/m² * m²/kg = m/s² = 10⁻³ km/s²
+        Ok(1e-3
+            * illumination
+            * pressure
+            * self.reflectivity_coefficient
+            * self.area_to_mass_ratio
+            * (ASTRONOMICAL_UNIT / distance).powi(2)
+            * sun_sc
+            / distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use std::path::PathBuf;
+
+    use lox_bodies::Earth;
+    use lox_ephem::spk::parser::parse_daf_spk;
+    use lox_ephem::EphemerisId;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use crate::elements::Keplerian;
+    use crate::propagators::numerical::{NumericalPropagator, NumericalPropagatorError};
+    use crate::propagators::Propagator;
+
+    use super::*;
+
+    /// The Sun is fixed on the positive x-axis of the Earth.
+    #[derive(Debug, Clone, PartialEq)]
+    struct MockEphemeris;
+
+    impl MockEphemeris {
+        fn position(id: EphemerisId) -> DVec3 {
+            match id.0 {
+                10 => DVec3::new(ASTRONOMICAL_UNIT, 0.0, 0.0),
+                _ => DVec3::ZERO,
+            }
+        }
+    }
+
+    impl Ephemeris for MockEphemeris {
+        type Error = std::convert::Infallible;
+
+        fn position(
+            &self,
+            _epoch: f64,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            let r = Self::position(target) - Self::position(origin);
+            Ok((r.x, r.y, r.z))
+        }
+
+        fn velocity(
+            &self,
+            _epoch: f64,
+            _origin: EphemerisId,
+            _target: EphemerisId,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            Ok((0.0, 0.0, 0.0))
+        }
+
+        fn state(
+            &self,
+            epoch: f64,
+            origin: EphemerisId,
+            target: EphemerisId,
+        ) -> Result<((f64, f64, f64), (f64, f64, f64)), Self::Error> {
+            Ok((
+                Ephemeris::position(self, epoch, origin, target)?,
+                self.velocity(epoch, origin, target)?,
+            ))
+        }
+    }
+
+    const RADIUS: f64 = 7000.0;
+
+    #[test]
+    fn test_srp_sunlit() {
+        let srp = Srp::new(Earth, Arc::new(MockEphemeris), 1.5, 0.02);
+        let position = DVec3::new(0.0, RADIUS, 0.0);
+        let act = srp.acceleration(position, DVec3::ZERO, 0.0).unwrap();
+        let distance = (ASTRONOMICAL_UNIT.powi(2) + RADIUS.powi(2)).sqrt();
+        let magnitude =
+            1e-3 * 1361.0 / 299792458.0 * 1.5 * 0.02 * (ASTRONOMICAL_UNIT / distance).powi(2);
+        assert_float_eq!(act.length(), magnitude, rel <= 1e-12);
+        assert!(act.x < 0.0);
+        assert_close!(
+            act.normalize(),
+            (position - srp.sun_position(0.0).unwrap()).normalize()
+        );
+    }
+
+    #[test]
+    fn test_srp_umbra() {
+        let srp = Srp::new(Earth, Arc::new(MockEphemeris), 1.5, 0.02);
+        let position = DVec3::new(-RADIUS, 0.0, 0.0);
+        assert_eq!(
+            srp.illumination(position, srp.sun_position(0.0).unwrap()),
+            0.0
+        );
+        assert_eq!(
+            srp.acceleration(position, DVec3::ZERO, 0.0).unwrap(),
+            DVec3::ZERO
+        );
+    }
+
+    #[test]
+    fn test_srp_penumbra() {
+        let srp = Srp::new(Earth, Arc::new(MockEphemeris), 1.5, 0.02);
+        let sun = srp.sun_position(0.0).unwrap();
+        let sunlit = srp
+            .acceleration(DVec3::new(0.0, RADIUS, 0.0), DVec3::ZERO, 0.0)
+            .unwrap()
+            .length();
+
+        // The acceleration decreases continuously while the spacecraft crosses the terminator at
+        // an orbital angle of about 114°
+        let mut previous = sunlit;
+        let mut penumbra = false;
+        for i in 0..=3000 {
+            let angle = (113.0 + 3.0 * i as f64 / 3000.0).to_radians();
+            let position = RADIUS * DVec3::new(angle.cos(), angle.sin(), 0.0);
+            let illumination = srp.illumination(position, sun);
+            let acceleration = srp
+                .acceleration(position, DVec3::ZERO, 0.0)
+                .unwrap()
+                .length();
+            assert!(acceleration <= previous * (1.0 + 1e-6));
+            assert!(previous - acceleration < 5e-3 * sunlit);
+            penumbra |= illumination > 0.0 && illumination < 1.0;
+            previous = acceleration;
+        }
+        assert!(penumbra);
+        assert_eq!(previous, 0.0);
+    }
+
+    #[test]
+    fn test_srp_ephemeris_error() {
+        let path = PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")));
+        let contents = std::fs::read(path.join("de440s.bsp")).unwrap();
+        let srp = Srp::new(
+            Earth,
+            Arc::new(parse_daf_spk(&contents).unwrap()),
+            1.5,
+            0.02,
+        );
+        // The ephemeris ends in 2150
+        let epoch = 300.0 * 365.25 * 86400.0;
+        let position = DVec3::new(0.0, RADIUS, 0.0);
+        assert!(matches!(
+            srp.acceleration(position, DVec3::ZERO, epoch),
+            Err(ForceModelError::EphemerisError(_))
+        ));
+
+        let time = Time::j2000(Tdb) + TimeDelta::from_decimal_seconds(epoch).unwrap();
+        let s0 = Keplerian::builder(time, Earth)
+            .with_semi_major_axis(RADIUS)
+            .build()
+            .unwrap()
+            .to_cartesian();
+        let propagator = NumericalPropagator::new(s0).with_force_model(srp);
+        let t1 = time + TimeDelta::from_decimal_seconds(60.0).unwrap();
+        assert!(matches!(
+            propagator.propagate(t1),
+            Err(NumericalPropagatorError::ForceModelError(
+                ForceModelError::EphemerisError(_)
+            ))
+        ));
+    }
+}
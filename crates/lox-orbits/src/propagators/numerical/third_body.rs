@@ -0,0 +1,206 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::Arc;
+
+use glam::DVec3;
+
+use lox_bodies::{Origin, PointMass};
+use lox_ephem::Ephemeris;
+
+use crate::propagators::numerical::{
+    ephemeris_position, third_body_acceleration, ForceModel, ForceModelError,
+};
+
+/// The gravitational perturbation of a third body, e.g. the Sun or the Moon, whose position
+/// relative to the central body is obtained from an ephemeris.
+///
+/// The ephemeris is shared, so that the same ephemeris can be used for several third bodies. If
+/// the position of the third body cannot be obtained from the ephemeris, a
+/// [ForceModelError::EphemerisError] is returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThirdBody<O, B, E> {
+    central_body: O,
+    body: B,
+    ephemeris: Arc<E>,
+}
+
+impl<O, B, E> ThirdBody<O, B, E>
+where
+    O: Origin,
+    B: Origin + PointMass,
+    E: Ephemeris,
+{
+    pub fn new(central_body: O, body: B, ephemeris: Arc<E>) -> Self {
+        Self {
+            central_body,
+            body,
+            ephemeris,
+        }
+    }
+
+    /// Returns the position of the third body relative to the central body in km.
+    pub fn position(&self, time: f64) -> Result<DVec3, E::Error> {
+        ephemeris_position(
+            self.ephemeris.as_ref(),
+            time,
+            self.central_body.id(),
+            self.body.id(),
+        )
+    }
+}
+
+impl<O, B, E> ForceModel for ThirdBody<O, B, E>
+where
+    O: Origin + Send + Sync,
+    B: Origin + PointMass + Send + Sync,
+    E: Ephemeris + Send + Sync,
+{
+    fn acceleration(
+        &self,
+        position: DVec3,
+        _velocity: DVec3,
+        time: f64,
+    ) -> Result<DVec3, ForceModelError> {
+        let body = self
+            .position(time)
+            .map_err(|err| ForceModelError::EphemerisError(err.to_string()))?;
+        Ok(third_body_acceleration(
+            self.body.gravitational_parameter(),
+            position,
+            body,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::OnceLock;
+
+    use float_eq::assert_float_eq;
+
+    use lox_bodies::{Earth, Moon, Sun};
+    use lox_ephem::spk::parser::{parse_daf_spk, Spk};
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::julian_dates::JulianDate;
+    use lox_time::time_scales::Tdb;
+    use lox_time::Time;
+
+    use crate::elements::Keplerian;
+    use crate::propagators::numerical::{NumericalPropagator, NumericalPropagatorError};
+    use crate::propagators::Propagator;
+
+    use super::*;
+
+    fn ephemeris() -> Arc<Spk> {
+        static EPHEMERIS: OnceLock<Arc<Spk>> = OnceLock::new();
+        EPHEMERIS
+            .get_or_init(|| {
+                let path = PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")));
+                let contents = std::fs::read(path.join("de440s.bsp")).unwrap();
+                Arc::new(parse_daf_spk(&contents).unwrap())
+            })
+            .clone()
+    }
+
+    #[test]
+    fn test_third_body_acceleration() {
+        let moon = ThirdBody::new(Earth, Moon, ephemeris());
+        let time = 0.0;
+        let r_moon = moon.position(time).unwrap();
+        let position = DVec3::new(42164.0, 0.0, 0.0);
+        let d = r_moon - position;
+        let mu = Moon.gravitational_parameter();
+        let exp = mu * (d / d.length().powi(3) - r_moon / r_moon.length().powi(3));
+        let act = moon.acceleration(position, DVec3::ZERO, time).unwrap();
+        assert_close!(act, exp, 1e-9 * exp.length());
+
+        // No perturbation at the centre of the central body
+        assert_close!(
+            moon.acceleration(DVec3::ZERO, DVec3::ZERO, time).unwrap(),
+            DVec3::ZERO,
+            1e-25
+        );
+    }
+
+    #[test]
+    fn test_third_body_acceleration_near_central_body() {
+        // The naive formula loses all significant digits for a spacecraft very close to the
+        // centre, whereas the tidal acceleration is linear in the position
+        let sun = ThirdBody::new(Earth, Sun, ephemeris());
+        let r_sun = sun.position(0.0).unwrap();
+        let u = r_sun.normalize();
+        let mu = Sun.gravitational_parameter();
+        let position = 1e-3 * u;
+        let exp = 2.0 * mu * 1e-3 / r_sun.length().powi(3);
+        let act = sun.acceleration(position, DVec3::ZERO, 0.0).unwrap();
+        assert_float_eq!(act.dot(u), exp, rel <= 1e-6);
+    }
+
+    #[test]
+    fn test_third_body_geo_inclination_drift() {
+        let ephemeris = ephemeris();
+        let s0 = Keplerian::builder(Time::j2000(Tdb), Earth)
+            .with_semi_major_axis(42164.0)
+            .with_eccentricity(0.0)
+            .with_inclination(0.0)
+            .build()
+            .unwrap()
+            .to_cartesian();
+        let propagator = NumericalPropagator::new(s0)
+            .with_force_model(ThirdBody::new(Earth, Sun, ephemeris.clone()))
+            .with_force_model(ThirdBody::new(Earth, Moon, ephemeris));
+        let days = 60.0;
+        let s1 = propagator
+            .propagate(s0.time() + TimeDelta::from_days(days).unwrap())
+            .unwrap();
+
+        // Luni-solar perturbations tilt an equatorial geostationary orbit by 0.75-0.95°/year
+        // towards a node close to 90°
+        let k1 = s1.to_keplerian();
+        let rate = k1.inclination().to_degrees() / days * 365.25;
+        assert!((0.6..1.1).contains(&rate), "rate = {rate}");
+        let node = k1.longitude_of_ascending_node().to_degrees();
+        assert!((60.0..120.0).contains(&node), "node = {node}");
+    }
+
+    #[test]
+    fn test_third_body_ephemeris_error() {
+        // The ephemeris ends in 2150
+        let time = Time::j2000(Tdb) + TimeDelta::from_days(300.0 * 365.25).unwrap();
+        let moon = ThirdBody::new(Earth, Moon, ephemeris());
+        let epoch = time.seconds_since_j2000();
+        assert!(matches!(
+            moon.acceleration(DVec3::new(42164.0, 0.0, 0.0), DVec3::ZERO, epoch),
+            Err(ForceModelError::EphemerisError(_))
+        ));
+
+        let s0 = Keplerian::builder(time, Earth)
+            .with_semi_major_axis(42164.0)
+            .build()
+            .unwrap()
+            .to_cartesian();
+        let propagator = NumericalPropagator::new(s0).with_force_model(moon);
+        let t1 = time + TimeDelta::from_decimal_seconds(60.0).unwrap();
+        assert!(matches!(
+            propagator.propagate(t1),
+            Err(NumericalPropagatorError::ForceModelError(
+                ForceModelError::EphemerisError(_)
+            ))
+        ));
+        assert!(matches!(
+            propagator.propagate_with_stm(t1),
+            Err(NumericalPropagatorError::ForceModelError(
+                ForceModelError::EphemerisError(_)
+            ))
+        ));
+    }
+}
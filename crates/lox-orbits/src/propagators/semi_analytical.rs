@@ -9,17 +9,24 @@
 use thiserror::Error;
 
 use lox_bodies::{DynOrigin, Origin, PointMass, TryPointMass};
+use lox_math::math::mod_two_pi;
+use lox_time::deltas::TimeDelta;
 use lox_time::TimeLike;
 
+use crate::anomalies::{
+    eccentric_to_mean, hyperbolic_to_mean, true_to_eccentric, true_to_hyperbolic,
+};
 use crate::frames::{CoordinateSystem, DynFrame, Icrf, ReferenceFrame};
 use crate::propagators::{stumpff, Propagator};
-use crate::states::{DynState, State};
+use crate::states::{rv_to_keplerian, DynState, State};
 use crate::trajectories::TrajectoryError;
 
 #[derive(Debug, Error, Eq, PartialEq)]
 pub enum ValladoError {
     #[error("did not converge")]
     NotConverged,
+    #[error("the true anomaly is not reached on the remaining escape trajectory")]
+    UnreachableTrueAnomaly,
     #[error(transparent)]
     TrajectoryError(#[from] TrajectoryError),
 }
@@ -101,6 +108,54 @@ where
     }
 }
 
+impl<T, O, R> Vallado<T, O, R>
+where
+    T: TimeLike + Clone,
+    O: TryPointMass + Clone,
+    R: ReferenceFrame + Clone,
+{
+    /// Propagates the initial state to the next time at which the orbit reaches `true_anomaly`,
+    /// e.g. `π` for the next apoapsis. The time of the returned state is the arrival time.
+    ///
+    /// For elliptic orbits the arrival time is within one orbital period after the initial state.
+    /// Hyperbolic orbits only pass each true anomaly once, and [ValladoError::UnreachableTrueAnomaly]
+    /// is returned if `true_anomaly` lies behind the initial state or beyond the asymptote.
+    ///
+    /// The true anomaly follows the conventions of [State::to_keplerian], i.e. it is measured from
+    /// the ascending node for circular orbits and from the x-axis for circular equatorial orbits.
+    pub fn propagate_to_true_anomaly(
+        &self,
+        true_anomaly: f64,
+    ) -> Result<State<T, O, R>, ValladoError> {
+        let mu = self.gravitational_parameter();
+        let elements = rv_to_keplerian(
+            self.initial_state.position(),
+            self.initial_state.velocity(),
+            mu,
+        );
+        let a = elements.semi_major_axis;
+        let e = elements.eccentricity;
+        let dt = if e < 1.0 {
+            let mean_motion = (mu / a.powi(3)).sqrt();
+            let m0 = eccentric_to_mean(true_to_eccentric(elements.true_anomaly, e), e);
+            let m1 = eccentric_to_mean(true_to_eccentric(true_anomaly, e), e);
+            mod_two_pi(m1 - m0) / mean_motion
+        } else {
+            let asymptote = (-1.0 / e).acos();
+            if true_anomaly.abs() >= asymptote || true_anomaly < elements.true_anomaly {
+                return Err(ValladoError::UnreachableTrueAnomaly);
+            }
+            let mean_motion = (mu / (-a).powi(3)).sqrt();
+            let m0 = hyperbolic_to_mean(true_to_hyperbolic(elements.true_anomaly, e), e);
+            let m1 = hyperbolic_to_mean(true_to_hyperbolic(true_anomaly, e), e);
+            (m1 - m0) / mean_motion
+        };
+        let dt = TimeDelta::from_decimal_seconds(dt)
+            .map_err(|_| ValladoError::UnreachableTrueAnomaly)?;
+        self.propagate(self.initial_state.time() + dt)
+    }
+}
+
 impl<T, O, R> Propagator<T, O, R> for Vallado<T, O, R>
 where
     T: TimeLike + Clone,
@@ -174,7 +229,7 @@ where
 mod tests {
     use float_eq::assert_float_eq;
 
-    use lox_bodies::Earth;
+    use lox_bodies::{Earth, Mars};
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
     use lox_time::deltas::TimeDelta;
@@ -184,6 +239,10 @@ mod tests {
 
     use crate::elements::Keplerian;
 
+    use std::f64::consts::{PI, TAU};
+
+    use glam::DVec3;
+
     use super::*;
 
     #[test]
@@ -269,4 +328,132 @@ mod tests {
         assert_float_eq!(k1.argument_of_periapsis(), periapsis_arg, rel <= 1e-8);
         assert_float_eq!(k1.true_anomaly(), true_anomaly, rel <= 1e-8);
     }
+
+    #[test]
+    fn test_vallado_propagate_mars_centered() {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_tdb();
+        let radius = 4000.0;
+        let speed = (Mars.gravitational_parameter() / radius).sqrt();
+        let s0 = State::new(
+            time,
+            DVec3::new(radius, 0.0, 0.0),
+            DVec3::new(0.0, speed, 0.0),
+            Mars,
+            Icrf,
+        );
+        let mars_period = TAU * (radius.powi(3) / Mars.gravitational_parameter()).sqrt();
+        let earth_period = TAU * (radius.powi(3) / Earth.gravitational_parameter()).sqrt();
+        assert_float_eq!(
+            s0.to_keplerian().orbital_period().to_decimal_seconds(),
+            mars_period,
+            rel <= 1e-10
+        );
+
+        let propagator = Vallado::new(s0);
+        let s1 = propagator
+            .propagate(time + TimeDelta::from_decimal_seconds(mars_period).unwrap())
+            .expect("propagator should converge");
+        assert_eq!(s1.origin(), Mars);
+        assert_float_eq!(s1.position().x, radius, rel <= 1e-8);
+        assert_float_eq!(s1.position().y, 0.0, abs <= 1e-4);
+
+        let s2 = propagator
+            .propagate(time + TimeDelta::from_decimal_seconds(earth_period).unwrap())
+            .expect("propagator should converge");
+        assert!(s2.position().distance(s0.position()) > 1000.0);
+    }
+
+    #[test]
+    fn test_vallado_propagate_mars_centered_dynamic() {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_tdb();
+        let radius = 4000.0;
+        let mu = Mars.gravitational_parameter();
+        let speed = (mu / radius).sqrt();
+        let s0 = State::new(
+            time,
+            DVec3::new(radius, 0.0, 0.0),
+            DVec3::new(0.0, speed, 0.0),
+            DynOrigin::Mars,
+            DynFrame::Icrf,
+        );
+        let period = TAU * (radius.powi(3) / mu).sqrt();
+
+        let propagator = Vallado::with_dynamic(s0).unwrap();
+        let s1 = propagator
+            .propagate(time + TimeDelta::from_decimal_seconds(period).unwrap())
+            .expect("propagator should converge");
+        assert_eq!(s1.origin(), DynOrigin::Mars);
+        assert_eq!(s1.reference_frame(), DynFrame::Icrf);
+        assert_float_eq!(s1.position().x, radius, rel <= 1e-8);
+        assert_float_eq!(s1.position().y, 0.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn test_vallado_propagate_to_true_anomaly_apoapsis() {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_tdb();
+        let semi_major = 24464.560;
+        let eccentricity = 0.7311;
+        let k0 = Keplerian::new(
+            time,
+            Earth,
+            semi_major,
+            eccentricity,
+            0.122138,
+            1.00681,
+            3.10686,
+            0.44369564302687126,
+        );
+        let propagator = Vallado::new(k0.to_cartesian());
+
+        let apoapsis = propagator
+            .propagate_to_true_anomaly(PI)
+            .expect("propagator should converge");
+        let r_apo = semi_major * (1.0 + eccentricity);
+        assert_float_eq!(apoapsis.position().length(), r_apo, rel <= 1e-8);
+        assert!(apoapsis.time() > time);
+        assert!(apoapsis.time() < time + k0.orbital_period());
+
+        // The radius is at its maximum at apoapsis
+        for dt in [-60, 60] {
+            let neighbour = propagator
+                .propagate(apoapsis.time() + TimeDelta::from_seconds(dt))
+                .expect("propagator should converge");
+            assert!(neighbour.position().length() < apoapsis.position().length());
+        }
+
+        // The true anomaly behind the initial state is reached on the next revolution
+        let periapsis = propagator
+            .propagate_to_true_anomaly(0.0)
+            .expect("propagator should converge");
+        assert_float_eq!(
+            periapsis.position().length(),
+            semi_major * (1.0 - eccentricity),
+            rel <= 1e-8
+        );
+        assert!(periapsis.time() > apoapsis.time());
+        assert!(periapsis.time() < time + k0.orbital_period());
+    }
+
+    #[test]
+    fn test_vallado_propagate_to_true_anomaly_hyperbolic() {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_tdb();
+        let k0 = Keplerian::new(time, Earth, -20000.0, 1.5, 0.3, 1.0, 0.5, -0.4);
+        let propagator = Vallado::new(k0.to_cartesian());
+
+        let s1 = propagator
+            .propagate_to_true_anomaly(1.2)
+            .expect("propagator should converge");
+        assert!(s1.time() > time);
+        assert_float_eq!(s1.to_keplerian().true_anomaly(), 1.2, rel <= 1e-8);
+
+        assert_eq!(
+            propagator.propagate_to_true_anomaly(-1.0),
+            Err(ValladoError::UnreachableTrueAnomaly)
+        );
+        // Beyond the asymptote at acos(-1/e) ≈ 2.30 rad
+        assert_eq!(
+            propagator.propagate_to_true_anomaly(2.5),
+            Err(ValladoError::UnreachableTrueAnomaly)
+        );
+    }
 }
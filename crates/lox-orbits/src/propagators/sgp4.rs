@@ -6,12 +6,14 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use chrono::{NaiveDate, NaiveTime};
 use glam::DVec3;
 pub use sgp4::Elements;
-use sgp4::{Constants, ElementsError, MinutesSinceEpoch};
+use sgp4::{Classification, Constants, ElementsError, MinutesSinceEpoch};
 use thiserror::Error;
 
 use lox_bodies::Earth;
+use lox_io::tle::Tle;
 use lox_math::constants::f64::time::SECONDS_PER_MINUTE;
 use lox_time::deltas::TimeDelta;
 use lox_time::time_scales::Tai;
@@ -32,6 +34,16 @@ pub enum Sgp4Error {
     Sgp4(#[from] sgp4::Error),
 }
 
+#[derive(Debug, Clone, Error)]
+pub enum Sgp4InitError {
+    #[error("invalid TLE epoch: year {year}, day {day}")]
+    InvalidEpoch { year: i32, day: f64 },
+    #[error("unknown TLE classification '{0}'")]
+    InvalidClassification(char),
+    #[error(transparent)]
+    Elements(#[from] ElementsError),
+}
+
 pub struct Sgp4 {
     constants: Constants,
     time: Time<Tai>,
@@ -45,6 +57,15 @@ impl Sgp4 {
         Ok(Self { constants, time })
     }
 
+    /// Initialises the propagator from a two-line element set.
+    ///
+    /// Whether the near-Earth (SGP4) or deep-space (SDP4) model is used is determined from the
+    /// orbital period of the element set.
+    pub fn from_tle(tle: &Tle) -> Result<Self, Sgp4InitError> {
+        let elements = elements_from_tle(tle)?;
+        Ok(Self::new(elements)?)
+    }
+
     pub fn time(&self) -> Time<Tai> {
         self.time
     }
@@ -62,6 +83,56 @@ impl Propagator<Time<Tai>, Earth, Icrf> for Sgp4 {
     }
 }
 
+fn elements_from_tle(tle: &Tle) -> Result<Elements, Sgp4InitError> {
+    let invalid_epoch = || Sgp4InitError::InvalidEpoch {
+        year: tle.epoch_year,
+        day: tle.epoch_day,
+    };
+    // The day of the year is one-based, which also guarantees a non-negative day fraction
+    if !tle.epoch_day.is_finite() || tle.epoch_day < 1.0 {
+        return Err(invalid_epoch());
+    }
+    let day_of_year = tle.epoch_day.floor();
+    let mut nanoseconds = ((tle.epoch_day - day_of_year) * 86400e9).round() as u64;
+    let mut date =
+        NaiveDate::from_yo_opt(tle.epoch_year, day_of_year as u32).ok_or_else(invalid_epoch)?;
+    // A day fraction just below one may round up to midnight of the following day
+    if nanoseconds == 86_400_000_000_000 {
+        date = date.succ_opt().ok_or_else(invalid_epoch)?;
+        nanoseconds = 0;
+    }
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanoseconds / 1_000_000_000) as u32,
+        (nanoseconds % 1_000_000_000) as u32,
+    )
+    .ok_or_else(invalid_epoch)?;
+    let classification = match tle.classification {
+        'U' => Classification::Unclassified,
+        'C' => Classification::Classified,
+        'S' => Classification::Secret,
+        c => return Err(Sgp4InitError::InvalidClassification(c)),
+    };
+    Ok(Elements {
+        object_name: tle.object_name.clone(),
+        international_designator: Some(tle.international_designator.clone()),
+        norad_id: tle.norad_cat_id as u64,
+        classification,
+        datetime: date.and_time(time),
+        mean_motion_dot: tle.mean_motion_dot,
+        mean_motion_ddot: tle.mean_motion_ddot,
+        drag_term: tle.bstar,
+        element_set_number: tle.element_set_no as u64,
+        inclination: tle.inclination,
+        right_ascension: tle.ra_of_asc_node,
+        eccentricity: tle.eccentricity,
+        argument_of_perigee: tle.arg_of_pericenter,
+        mean_anomaly: tle.mean_anomaly,
+        mean_motion: tle.mean_motion,
+        revolution_number: tle.rev_at_epoch as u64,
+        ephemeris_type: tle.ephemeris_type,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
@@ -87,4 +158,91 @@ mod tests {
             rel <= 1e-4
         );
     }
+
+    // Test case 00005 from Vallado et al., "Revisiting Spacetrack Report #3", AIAA 2006-6753
+    //
+    // 1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+    // 2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667
+    fn vanguard_1() -> Tle {
+        Tle {
+            object_name: None,
+            norad_cat_id: 5,
+            classification: 'U',
+            international_designator: "58002B".to_string(),
+            epoch_year: 2000,
+            epoch_day: 179.78495062,
+            mean_motion_dot: 0.00000023,
+            mean_motion_ddot: 0.0,
+            bstar: 0.28098e-4,
+            ephemeris_type: 0,
+            element_set_no: 475,
+            inclination: 34.2682,
+            ra_of_asc_node: 348.7242,
+            eccentricity: 0.1859667,
+            arg_of_pericenter: 331.7664,
+            mean_anomaly: 19.3264,
+            mean_motion: 10.82419157,
+            rev_at_epoch: 41366,
+        }
+    }
+
+    #[test]
+    fn test_sgp4_from_tle() {
+        let sgp4 = Sgp4::from_tle(&vanguard_1()).unwrap();
+        let elements = Elements::from_tle(
+            None,
+            "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753".as_bytes(),
+            "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667".as_bytes(),
+        )
+        .unwrap();
+        let exp = Sgp4::new(elements).unwrap();
+        assert_eq!(sgp4.time(), exp.time());
+
+        // The reference vectors were generated with the WGS72 geopotential while `Sgp4` uses
+        // WGS84, which results in differences of a few tens of metres.
+        let s0 = sgp4.propagate(sgp4.time()).unwrap();
+        let r0 = DVec3::new(7022.46529266, -1400.08296755, 0.03995155);
+        let v0 = DVec3::new(1.893841015, 6.405893759, 4.534807250);
+        assert!((s0.position() - r0).length() < 0.05);
+        assert!((s0.velocity() - v0).length() < 1e-4);
+
+        for minutes in [360.0, 720.0, 1440.0] {
+            let t = sgp4.time() + TimeDelta::from_minutes(minutes).unwrap();
+            let act = sgp4.propagate(t).unwrap();
+            let exp = exp.propagate(t).unwrap();
+            assert_eq!(act.position(), exp.position());
+            assert_eq!(act.velocity(), exp.velocity());
+        }
+    }
+
+    #[test]
+    fn test_sgp4_from_tle_invalid_epoch() {
+        let mut tle = vanguard_1();
+        tle.epoch_day = 367.5;
+        assert!(matches!(
+            Sgp4::from_tle(&tle),
+            Err(Sgp4InitError::InvalidEpoch { year: 2000, .. })
+        ));
+        for day in [-0.5, 0.5, f64::NAN] {
+            tle.epoch_day = day;
+            assert!(matches!(
+                Sgp4::from_tle(&tle),
+                Err(Sgp4InitError::InvalidEpoch { year: 2000, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sgp4_from_tle_epoch_rounding() {
+        let midnight = |year, month, day| {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_time(NaiveTime::MIN)
+        };
+        let mut tle = vanguard_1();
+        // The day fraction of the largest double below two rounds up to a full day
+        tle.epoch_day = 2.0 - f64::EPSILON;
+        let elements = elements_from_tle(&tle).unwrap();
+        assert_eq!(elements.datetime, midnight(2000, 1, 2));
+    }
 }
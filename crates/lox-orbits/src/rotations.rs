@@ -8,6 +8,7 @@ pub fn rotation_matrix_derivative(m: DMat3, v: DVec3) -> DMat3 {
     -s * m
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Rotation {
     m: DMat3,
     dm: DMat3,
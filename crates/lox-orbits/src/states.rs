@@ -12,22 +12,40 @@ use lox_bodies::{
     UndefinedOriginPropertyError,
 };
 use lox_ephem::{path_from_ids, Ephemeris};
+use lox_io::ndm::common::UnsupportedUnitsError;
+use lox_io::ndm::opm::OpmType;
 use lox_math::glam::Azimuth;
+use lox_math::linear_algebra::dmat6::DMat6;
 use lox_math::math::{mod_two_pi, normalize_two_pi};
 use lox_math::roots::{BracketError, FindRoot, Secant};
-use lox_time::{julian_dates::JulianDate, time_scales::Tdb, transformations::TryToScale, TimeLike};
+use lox_time::{
+    julian_dates::JulianDate,
+    time_scales::{Tai, Tdb, TimeScale},
+    transformations::{ToTdb, TryToScale},
+    Time, TimeLike,
+};
 use std::f64::consts::{PI, TAU};
 use std::ops::Sub;
 use thiserror::Error;
 
 use crate::anomalies::{eccentric_to_true, hyperbolic_to_true};
 use crate::elements::{is_circular, is_equatorial, DynKeplerian, Keplerian, KeplerianElements};
+use crate::frames::iau::IcrfToBodyFixedError;
+use crate::frames::iers::icrf_to_cirf;
 use crate::frames::{
     BodyFixed, CoordinateSystem, DynFrame, FrameTransformationProvider, Icrf, ReferenceFrame,
     TryToFrame,
 };
 use crate::ground::{DynGroundLocation, GroundLocation};
-
+use crate::rotations::Rotation;
+use crate::trajectories::{ndm_epoch, ndm_frame};
+
+/// A Cartesian state relative to an explicit central `origin`.
+///
+/// The frame only defines the orientation of the axes, i.e. a `State` in the
+/// [Icrf] frame with a Mars origin is a Mars-centered inertial state.
+/// Conversions to orbital elements and the propagators use the gravitational
+/// parameter of the origin.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct State<T: TimeLike, O: Origin, R: ReferenceFrame> {
     time: T,
@@ -87,6 +105,13 @@ fn rotation_lvlh(position: DVec3, velocity: DVec3) -> DMat3 {
     DMat3::from_cols(x, y, z)
 }
 
+fn rotation_rtn(position: DVec3, velocity: DVec3) -> DMat3 {
+    let r = position.normalize();
+    let n = position.cross(velocity).normalize();
+    let t = n.cross(r);
+    DMat3::from_cols(r, t, n)
+}
+
 impl<T, O> State<T, O, Icrf>
 where
     T: TimeLike,
@@ -95,6 +120,71 @@ where
     pub fn rotation_lvlh(&self) -> DMat3 {
         rotation_lvlh(self.position(), self.velocity())
     }
+
+    /// Returns the rotation matrix from the radial/transverse/normal (RTN) frame of the state to
+    /// the ICRF. The RTN frame is also known as the RIC or RSW frame.
+    pub fn rotation_rtn(&self) -> DMat3 {
+        rotation_rtn(self.position(), self.velocity())
+    }
+}
+
+/// Returns the position and velocity of `deputy` relative to `chief` in the rotating RTN frame of
+/// `chief`.
+///
+/// The relative velocity is the velocity observed in the rotating frame, i.e. it is corrected for
+/// the angular velocity `h / r²` of the frame.
+pub fn relative_state_rtn<T, O>(
+    chief: &State<T, O, Icrf>,
+    deputy: &State<T, O, Icrf>,
+) -> (DVec3, DVec3)
+where
+    T: TimeLike,
+    O: Origin,
+{
+    let (r, v) = (chief.position(), chief.velocity());
+    let omega = r.cross(v) / r.length_squared();
+    let to_rtn = rotation_rtn(r, v).transpose();
+    let dr = deputy.position() - r;
+    let dv = deputy.velocity() - v;
+    (to_rtn * dr, to_rtn * (dv - omega.cross(dr)))
+}
+
+// Returns the transformation of a state from the rotating RTN frame to the inertial frame
+fn rtn_to_inertial(position: DVec3, velocity: DVec3) -> DMat6 {
+    let rot = rotation_rtn(position, velocity);
+    let omega = position.cross(velocity) / position.length_squared();
+    let rot_dot = DMat3::from_cols(
+        omega.cross(rot.x_axis),
+        omega.cross(rot.y_axis),
+        omega.cross(rot.z_axis),
+    );
+    DMat6::from_blocks(rot, DMat3::ZERO, rot_dot, rot)
+}
+
+/// Rotates a 6x6 position-velocity covariance given in the RTN frame of `reference` into the
+/// inertial frame, i.e. `C_inertial = T C_rtn T^T`. The velocity block accounts for the rotation
+/// of the RTN frame with the angular velocity `h / r²`.
+pub fn covariance_rtn_to_inertial<T, O>(reference: &State<T, O, Icrf>, covariance: &DMat6) -> DMat6
+where
+    T: TimeLike,
+    O: Origin,
+{
+    let t = rtn_to_inertial(reference.position(), reference.velocity());
+    t * *covariance * t.transpose()
+}
+
+/// Rotates a 6x6 position-velocity covariance given in the inertial frame into the RTN frame of
+/// `reference`. This is the inverse of [covariance_rtn_to_inertial].
+pub fn covariance_inertial_to_rtn<T, O>(reference: &State<T, O, Icrf>, covariance: &DMat6) -> DMat6
+where
+    T: TimeLike,
+    O: Origin,
+{
+    let t = rtn_to_inertial(reference.position(), reference.velocity());
+    let rot_t = t.block(0, 0).transpose();
+    // The inverse of the block-triangular transformation `[[R, 0], [Ṙ, R]]`
+    let t_inv = DMat6::from_blocks(rot_t, DMat3::ZERO, -rot_t * t.block(1, 0) * rot_t, rot_t);
+    t_inv * *covariance * t_inv.transpose()
 }
 
 impl<T> DynState<T>
@@ -208,7 +298,7 @@ where
     }
 }
 
-fn eccentricity_vector(r: DVec3, v: DVec3, mu: f64) -> DVec3 {
+pub(crate) fn eccentricity_vector(r: DVec3, v: DVec3, mu: f64) -> DVec3 {
     let rm = r.length();
     let v2 = v.dot(v);
     let rv = r.dot(v);
@@ -247,7 +337,7 @@ where
         let target_id = target.id();
         let path = path_from_ids(origin_id.0, target_id.0);
         for (origin, target) in path.into_iter().tuple_windows() {
-            let (p, v) = ephemeris.state(epoch, origin, target)?;
+            let (p, v) = ephemeris.state(epoch, origin.into(), target.into())?;
             let p: DVec3 = p.into();
             let v: DVec3 = v.into();
             pos_eph += p;
@@ -278,7 +368,7 @@ where
         let target_id = target.id();
         let path = path_from_ids(origin_id.0, target_id.0);
         for (origin, target) in path.into_iter().tuple_windows() {
-            let (p, v) = ephemeris.state(epoch, origin, target)?;
+            let (p, v) = ephemeris.state(epoch, origin.into(), target.into())?;
             let p: DVec3 = p.into();
             let v: DVec3 = v.into();
             pos_eph += p;
@@ -290,6 +380,29 @@ where
     }
 }
 
+impl<T, O> State<Time<T>, O, Icrf>
+where
+    T: TimeScale + Clone,
+    Time<T>: ToTdb,
+    O: Origin,
+{
+    /// Returns the state of `target` relative to `origin` at `time` from `ephemeris`.
+    ///
+    /// The ephemeris is evaluated at the epoch of `time` in TDB seconds since J2000, while the
+    /// returned state keeps `time` in its original time scale. The position and velocity are in
+    /// km and km/s as tabulated by SPK ephemerides.
+    pub fn from_ephemeris<B: Origin, E: Ephemeris>(
+        ephemeris: &E,
+        time: Time<T>,
+        origin: O,
+        target: B,
+    ) -> Result<Self, E::Error> {
+        let epoch = time.to_tdb().seconds_since_j2000();
+        let (p, v) = ephemeris.states_at(epoch, origin.id().into(), &[target.id().into()])?[0];
+        Ok(State::new(time, p.into(), v.into(), origin, Icrf))
+    }
+}
+
 impl<T, O, R, U> TryToFrame<BodyFixed<R>, U> for State<T, O, Icrf>
 where
     T: TryToScale<Tdb, U> + TimeLike + Clone,
@@ -332,6 +445,44 @@ where
     }
 }
 
+impl<T, P> TryToFrame<DynFrame, P> for DynState<T>
+where
+    T: TryToScale<Tdb, P> + TimeLike + Clone,
+    P: FrameTransformationProvider,
+{
+    type Output = DynState<T>;
+    type Error = IcrfToBodyFixedError;
+
+    fn try_to_frame(&self, frame: DynFrame, provider: &P) -> Result<Self::Output, Self::Error> {
+        self.try_to_frame_with(frame, provider, &mut icrf_to_cirf)
+    }
+}
+
+impl<T> DynState<T>
+where
+    T: TimeLike + Clone,
+{
+    /// Like [TryToFrame::try_to_frame] but obtains the precession-nutation rotation from
+    /// `precession_nutation`, see [DynFrame::try_rotation_with].
+    pub(crate) fn try_to_frame_with<P, F>(
+        &self,
+        frame: DynFrame,
+        provider: &P,
+        precession_nutation: &mut F,
+    ) -> Result<Self, IcrfToBodyFixedError>
+    where
+        T: TryToScale<Tdb, P>,
+        P: FrameTransformationProvider,
+        F: FnMut(f64) -> Rotation,
+    {
+        let rot =
+            self.frame
+                .try_rotation_with(&frame, self.time(), provider, precession_nutation)?;
+        let (pos, vel) = rot.rotate_state(self.position, self.velocity);
+        Ok(State::new(self.time(), pos, vel, self.origin, frame))
+    }
+}
+
 pub(crate) fn rv_to_keplerian(r: DVec3, v: DVec3, mu: f64) -> KeplerianElements {
     let rm = r.length();
     let vm = v.length();
@@ -441,18 +592,64 @@ where
     }
 }
 
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum OpmError {
+    #[error("unsupported time system `{0}`")]
+    UnsupportedTimeSystem(String),
+    #[error("unsupported reference frame `{0}`")]
+    UnsupportedFrame(String),
+    #[error("unsupported center `{0}`")]
+    UnsupportedCenter(String),
+    #[error("invalid epoch `{0}`")]
+    InvalidEpoch(String),
+    #[error(transparent)]
+    UnsupportedUnits(#[from] UnsupportedUnitsError),
+}
+
+impl TryFrom<&OpmType> for DynState<Time<Tai>> {
+    type Error = OpmError;
+
+    /// Creates a state from the state vector of an OPM.
+    ///
+    /// The epoch is converted to TAI. Keplerian elements, covariance, and maneuvers are not
+    /// part of the state and are ignored.
+    fn try_from(opm: &OpmType) -> Result<Self, Self::Error> {
+        let metadata = &opm.body.segment.metadata;
+        let state_vector = &opm.body.segment.data.state_vector;
+        let origin = DynOrigin::from_name(&metadata.center_name)
+            .map_err(|_| OpmError::UnsupportedCenter(metadata.center_name.clone()))?;
+        let frame = ndm_frame(&metadata.ref_frame)
+            .ok_or_else(|| OpmError::UnsupportedFrame(metadata.ref_frame.clone()))?;
+        let time = ndm_epoch(
+            &metadata.time_system,
+            &state_vector.epoch.0,
+            OpmError::UnsupportedTimeSystem,
+            OpmError::InvalidEpoch,
+        )?;
+        Ok(State::new(
+            time,
+            DVec3::from_array(state_vector.position_km()?),
+            DVec3::from_array(state_vector.velocity_km_per_s()?),
+            origin,
+            frame,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{path::PathBuf, sync::OnceLock};
 
     use float_eq::assert_float_eq;
 
-    use lox_bodies::{Earth, Jupiter, Venus};
+    use lox_bodies::{Earth, Jupiter, Moon, SolarSystemBarycenter, Venus};
     use lox_ephem::spk::parser::{parse_daf_spk, Spk};
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
     use lox_time::{time, time_scales::Tdb, transformations::ToTai, utc::Utc, Time};
 
+    use lox_io::ndm::kvn::KvnDeserializer;
+
     use crate::frames::NoOpFrameTransformationProvider;
 
     use super::*;
@@ -539,6 +736,150 @@ mod tests {
         PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")))
     }
 
+    fn circular_state(radius: f64, angle: f64) -> State<Time<Tdb>, Earth, Icrf> {
+        let speed = (Earth.gravitational_parameter() / radius).sqrt();
+        State::new(
+            Time::j2000(Tdb),
+            radius * DVec3::new(angle.cos(), angle.sin(), 0.0),
+            speed * DVec3::new(-angle.sin(), angle.cos(), 0.0),
+            Earth,
+            Icrf,
+        )
+    }
+
+    #[test]
+    fn test_rotation_rtn() {
+        let chief = circular_state(7000.0, 0.3);
+        let rot = chief.rotation_rtn();
+        assert_close!(
+            rot.transpose() * chief.position(),
+            DVec3::new(7000.0, 0.0, 0.0)
+        );
+        assert_close!(rot.z_axis, DVec3::Z);
+        assert!(rot
+            .mul_mat3(&rot.transpose())
+            .abs_diff_eq(DMat3::IDENTITY, 1e-15));
+    }
+
+    #[test]
+    fn test_relative_state_rtn() {
+        let chief = circular_state(7000.0, 0.3);
+        let (dr, dv) = relative_state_rtn(&chief, &chief);
+        assert_eq!(dr, DVec3::ZERO);
+        assert_eq!(dv, DVec3::ZERO);
+
+        // A deputy trailing on the same circular orbit is at rest in the rotating frame
+        let deputy = circular_state(7000.0, 0.3 - 1e-3);
+        let (dr, dv) = relative_state_rtn(&chief, &deputy);
+        assert_float_eq!(dr.y, -7000.0 * 1e-3, rel <= 1e-6);
+        assert_float_eq!(dr.x, -7000.0 * (1.0 - 1e-3f64.cos()), rel <= 1e-6);
+        assert_float_eq!(dr.z, 0.0, abs <= 1e-12);
+        assert_close!(dv, DVec3::ZERO, 1e-12);
+
+        // A deputy with a radial offset moving with the angular velocity of the chief is at rest
+        // as well, while its inertial velocity differs from the chief's
+        let n = (Earth.gravitational_parameter() / 7000.0f64.powi(3)).sqrt();
+        let r = chief.position().normalize();
+        let deputy = State::new(
+            chief.time(),
+            chief.position() + r,
+            chief.velocity() * 7001.0 / 7000.0,
+            Earth,
+            Icrf,
+        );
+        let (dr, dv) = relative_state_rtn(&chief, &deputy);
+        assert_close!(dr, DVec3::new(1.0, 0.0, 0.0), 1e-9);
+        assert_close!(dv, DVec3::ZERO, 1e-12);
+        assert_float_eq!(
+            (deputy.velocity() - chief.velocity()).length(),
+            n,
+            rel <= 1e-9
+        );
+
+        // Out-of-plane motion
+        let deputy = State::new(
+            chief.time(),
+            chief.position(),
+            chief.velocity() + DVec3::new(0.0, 0.0, 1e-3),
+            Earth,
+            Icrf,
+        );
+        let (dr, dv) = relative_state_rtn(&chief, &deputy);
+        assert_eq!(dr, DVec3::ZERO);
+        assert_close!(dv, DVec3::new(0.0, 0.0, 1e-3), 1e-15);
+    }
+
+    fn covariance() -> DMat6 {
+        // A symmetric positive-definite matrix with correlated position and velocity errors
+        let mut rows = [[0.0; 6]; 6];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let scale = if i < 3 { 1e-1 } else { 1e-4 } * if j < 3 { 1e-1 } else { 1e-4 };
+                *value = scale
+                    * if i == j {
+                        10.0
+                    } else {
+                        1.0 / (1.0 + (i + j) as f64)
+                    };
+            }
+        }
+        DMat6::from_rows(rows)
+    }
+
+    #[test]
+    fn test_covariance_rtn_round_trip() {
+        let reference = circular_state(7000.0, 0.3);
+        let rtn = covariance();
+        let inertial = covariance_rtn_to_inertial(&reference, &rtn);
+        let act = covariance_inertial_to_rtn(&reference, &inertial);
+        assert!(act.max_abs_diff(&rtn) < 1e-15 * 1e-1);
+        assert!(inertial.max_abs_diff(&inertial.transpose()) < 1e-18);
+        assert!(inertial.max_abs_diff(&rtn) > 1e-3);
+    }
+
+    #[test]
+    fn test_covariance_rtn_to_inertial() {
+        let reference = circular_state(7000.0, 0.3);
+        let mut rtn = DMat6::ZERO;
+        // Radial position uncertainty only
+        rtn[(0, 0)] = 1.0;
+        let inertial = covariance_rtn_to_inertial(&reference, &rtn);
+        let r = reference.position().normalize();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_float_eq!(inertial[(i, j)], r[i] * r[j], abs <= 1e-15);
+            }
+        }
+
+        // The position block is rotated by an orthogonal matrix, which preserves the trace
+        let rot = reference.rotation_rtn();
+        assert!(rot
+            .mul_mat3(&rot.transpose())
+            .abs_diff_eq(DMat3::IDENTITY, 1e-15));
+        let full = covariance();
+        let trace = |m: &DMat6| (0..3).map(|i| m[(i, i)]).sum::<f64>();
+        assert_float_eq!(
+            trace(&covariance_rtn_to_inertial(&reference, &full)),
+            trace(&full),
+            rel <= 1e-14
+        );
+
+        // A radial position error appears as a transverse velocity error in the inertial frame
+        // due to the rotation of the frame
+        let n = (Earth.gravitational_parameter() / 7000.0f64.powi(3)).sqrt();
+        let t = reference.rotation_rtn().y_axis;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_float_eq!(inertial[(3 + i, j)], n * t[i] * r[j], abs <= 1e-15);
+                assert_float_eq!(
+                    inertial[(3 + i, 3 + j)],
+                    n.powi(2) * t[i] * t[j],
+                    abs <= 1e-15
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_state_to_origin() {
         let r_venus = DVec3::new(
@@ -572,4 +913,151 @@ mod tests {
         static EPHEMERIS: OnceLock<Spk> = OnceLock::new();
         EPHEMERIS.get_or_init(|| parse_daf_spk(&contents).unwrap())
     }
+
+    #[test]
+    fn test_state_from_ephemeris() {
+        let tai = time!(Tai, 2024, 3, 1, 12).unwrap();
+        let epoch = tai.to_tdb().seconds_since_j2000();
+        let (p0, v0) = ephemeris().state(epoch, 0.into(), 3.into()).unwrap();
+        let (p1, v1) = ephemeris().state(epoch, 3.into(), 301.into()).unwrap();
+        let r_moon = DVec3::from(p0) + DVec3::from(p1);
+        let v_moon = DVec3::from(v0) + DVec3::from(v1);
+
+        let moon = State::from_ephemeris(ephemeris(), tai, SolarSystemBarycenter, Moon).unwrap();
+
+        assert_eq!(moon.time(), tai);
+        assert_eq!(moon.origin(), SolarSystemBarycenter);
+        assert_eq!(moon.position(), r_moon);
+        assert_eq!(moon.velocity(), v_moon);
+
+        // The ephemeris is not evaluated at TAI seconds since J2000
+        let (p, _) = ephemeris()
+            .state(tai.seconds_since_j2000(), 0.into(), 3.into())
+            .unwrap();
+        assert!((DVec3::from(p0) - DVec3::from(p)).length() > 100.0);
+
+        let earth = State::from_ephemeris(ephemeris(), tai, Moon, Earth).unwrap();
+        let moon_earth = State::from_ephemeris(ephemeris(), tai, Earth, Moon).unwrap();
+        assert_close!(earth.position(), -moon_earth.position(), 0.0, 1e-9);
+        assert_close!(earth.velocity(), -moon_earth.velocity(), 0.0, 1e-12);
+    }
+
+    const OPM: &str = "CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+EPOCH = 2021-06-03T00:00:00.000
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]
+SEMI_MAJOR_AXIS = 41399.5123 [km]
+ECCENTRICITY = 0.020842611
+INCLINATION = 0.117746 [deg]
+RA_OF_ASC_NODE = 17.604721 [deg]
+ARG_OF_PERICENTER = 218.242943 [deg]
+TRUE_ANOMALY = 41.922339 [deg]
+GM = 398600.4415 [km**3/s**2]
+MAN_EPOCH_IGNITION = 2021-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -0.02325700 [km/s]
+MAN_DV_2 = 0.01683160 [km/s]
+MAN_DV_3 = -0.00893444 [km/s]
+MAN_EPOCH_IGNITION = 2021-06-05T18:59:21.0
+MAN_DURATION = 0.00 [s]
+MAN_DELTA_MASS = -1.469 [kg]
+MAN_REF_FRAME = RTN
+MAN_DV_1 = 0.00101500 [km/s]
+MAN_DV_2 = -0.00187300 [km/s]
+MAN_DV_3 = 0.00000000 [km/s]";
+
+    #[test]
+    fn test_state_from_opm() {
+        let opm: OpmType = KvnDeserializer::from_kvn_str(OPM).unwrap();
+        assert_eq!(opm.body.segment.data.maneuver_parameters_list.len(), 2);
+        let state = DynState::try_from(&opm).unwrap();
+        let time = Utc::from_iso("2021-06-03T00:00:00.000").unwrap().to_tai();
+        assert_eq!(state.time(), time);
+        assert_eq!(state.origin(), DynOrigin::Earth);
+        assert_eq!(state.reference_frame(), DynFrame::Icrf);
+        assert_eq!(
+            state.position(),
+            DVec3::new(6655.9942, -40218.5751, -82.9177)
+        );
+        assert_eq!(
+            state.velocity(),
+            DVec3::new(3.11548208, 0.47042605, -0.00101495)
+        );
+    }
+
+    #[test]
+    fn test_state_from_opm_metres() {
+        let mut opm: OpmType = KvnDeserializer::from_kvn_str(OPM).unwrap();
+        let state_vector = &mut opm.body.segment.data.state_vector;
+        state_vector.x.base *= 1e3;
+        state_vector.x.units = Some(lox_io::ndm::common::PositionUnits("m".to_string()));
+        let state = DynState::try_from(&opm).unwrap();
+        assert_float_eq!(state.position().x, 6655.9942, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_state_from_opm_time_systems() {
+        let opm: OpmType = KvnDeserializer::from_kvn_str(OPM).unwrap();
+        let utc = DynState::try_from(&opm).unwrap();
+        for (time_system, offset) in [("TAI", 37.0), ("tai", 37.0), ("TT", 37.0 + 32.184)] {
+            let mut other = opm.clone();
+            other.body.segment.metadata.time_system = time_system.to_string();
+            let state = DynState::try_from(&other).unwrap();
+            let delta = (utc.time() - state.time()).to_decimal_seconds();
+            assert_float_eq!(delta, offset, abs <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_state_from_opm_errors() {
+        let opm: OpmType = KvnDeserializer::from_kvn_str(OPM).unwrap();
+
+        let mut tod = opm.clone();
+        tod.body.segment.metadata.ref_frame = "TOD".to_string();
+        assert_eq!(
+            DynState::try_from(&tod),
+            Err(OpmError::UnsupportedFrame("TOD".to_string()))
+        );
+
+        let mut center = opm.clone();
+        center.body.segment.metadata.center_name = "DEATH STAR".to_string();
+        assert_eq!(
+            DynState::try_from(&center),
+            Err(OpmError::UnsupportedCenter("DEATH STAR".to_string()))
+        );
+
+        let mut gmst = opm.clone();
+        gmst.body.segment.metadata.time_system = "GMST".to_string();
+        assert_eq!(
+            DynState::try_from(&gmst),
+            Err(OpmError::UnsupportedTimeSystem("GMST".to_string()))
+        );
+
+        let mut ut1 = opm.clone();
+        ut1.body.segment.metadata.time_system = "UT1".to_string();
+        assert_eq!(
+            DynState::try_from(&ut1),
+            Err(OpmError::UnsupportedTimeSystem("UT1".to_string()))
+        );
+
+        let mut epoch = opm;
+        epoch.body.segment.data.state_vector.epoch.0 = "yesterday".to_string();
+        assert_eq!(
+            DynState::try_from(&epoch),
+            Err(OpmError::InvalidEpoch("yesterday".to_string()))
+        );
+    }
 }
@@ -4,19 +4,28 @@ use std::sync::Arc;
 use csv::Error;
 use glam::DVec3;
 use lox_ephem::Ephemeris;
+use lox_io::horizons::HorizonsVectorTable;
+use lox_io::ndm::common::UnsupportedUnitsError;
+use lox_io::ndm::oem::OemType;
 use thiserror::Error;
 
 use lox_bodies::{DynOrigin, Origin, RotationalElements};
+use lox_math::constants::f64::time::SECONDS_PER_JULIAN_CENTURY;
+use lox_math::interpolation::{HermiteInterpolator, LagrangeInterpolator, OutOfRangeError};
 use lox_math::roots::Brent;
 use lox_math::series::{Series, SeriesError};
-use lox_time::time_scales::{Tai, Tdb};
-use lox_time::transformations::TryToScale;
+use lox_time::julian_dates::Epoch;
+use lox_time::ndm::{oem_epochs, parse_ndm_epoch, NdmEpochError};
+use lox_time::time_scales::{DynTimeScale, Tai, Tcb, Tcg, Tdb, Tt};
+use lox_time::transformations::{ToTai, TryToScale};
 use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
 use lox_time::utc::Utc;
-use lox_time::{deltas::TimeDelta, Time, TimeLike};
+use lox_time::{deltas::TimeDelta, DynTime, Time, TimeLike};
 
-use crate::events::{find_events, find_windows, Event, Window};
+use crate::events::{find_events, find_windows, with_grazing_extrema, Event, Window};
+use crate::frames::iers::icrf_to_cirf;
 use crate::frames::{BodyFixed, DynFrame, FrameTransformationProvider, Icrf, TryToFrame};
+use crate::rotations::Rotation;
 use crate::{
     frames::{CoordinateSystem, ReferenceFrame},
     states::State,
@@ -187,6 +196,33 @@ where
         .unwrap_or_default()
     }
 
+    /// Finds the zero crossings of `func` along the trajectory and refines them to within
+    /// `tolerance` seconds. The refinement also stops once `func` is within `tolerance` of zero.
+    ///
+    /// `func` is sampled at the time steps of the trajectory. Between consecutive samples with the
+    /// same sign, the extremum of `func` is located with a golden-section search, so that pairs of
+    /// closely spaced crossings such as grazing passes are detected as well. This assumes that
+    /// `func` has at most one extremum between two samples, i.e. the sampling of the trajectory
+    /// must resolve the oscillations of `func`.
+    pub fn find_events_with_tolerance<F: Fn(State<T, O, R>) -> f64>(
+        &self,
+        func: F,
+        tolerance: f64,
+    ) -> Vec<Event<T>> {
+        let root_finder = Brent::default().with_tolerances(tolerance, 2.0 * f64::EPSILON);
+        let func = |t| {
+            func(State::new(
+                self.start_time() + TimeDelta::from_decimal_seconds(t).unwrap(),
+                self.position(t),
+                self.velocity(t),
+                self.origin(),
+                self.reference_frame(),
+            ))
+        };
+        let steps = with_grazing_extrema(func, self.t.as_ref());
+        find_events(func, self.start_time(), &steps, root_finder).unwrap_or_default()
+    }
+
     pub fn find_windows<F: Fn(State<T, O, R>) -> f64>(&self, func: F) -> Vec<Window<T>> {
         let root_finder = Brent::default();
         find_windows(
@@ -302,6 +338,313 @@ where
     }
 }
 
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum HorizonsTrajectoryError {
+    #[error("Horizons header does not declare the {0}")]
+    MissingMetadata(&'static str),
+    #[error("unsupported Horizons reference frame `{0}`")]
+    UnsupportedFrame(String),
+    #[error("unsupported Horizons center body `{name}` ({id})")]
+    UnsupportedCenter { name: String, id: i32 },
+    #[error("unsupported Horizons output units `{0}`, expected `KM-S`")]
+    UnsupportedUnits(String),
+    #[error("invalid Julian date {0}")]
+    InvalidJulianDate(f64),
+    #[error(transparent)]
+    TrajectoryError(#[from] TrajectoryError),
+}
+
+fn horizons_frame(name: &str) -> Option<DynFrame> {
+    match name {
+        "ICRF" | "ICRF/J2000.0" => Some(DynFrame::Icrf),
+        "Ecliptic of J2000.0" => Some(DynFrame::EclipticJ2000),
+        _ => None,
+    }
+}
+
+impl Trajectory<Time<Tdb>, DynOrigin, DynFrame> {
+    /// Creates a trajectory from a JPL Horizons vector table.
+    ///
+    /// The origin and frame are taken from the `Center body name` and `Reference frame` entries of
+    /// the table header. Only the ICRF and the ecliptic of J2000 are supported as frames and the
+    /// table must be in `KM-S` units. The Julian dates of the table are interpreted as TDB.
+    pub fn from_horizons(table: &HorizonsVectorTable) -> Result<Self, HorizonsTrajectoryError> {
+        let frame = table
+            .reference_frame()
+            .ok_or(HorizonsTrajectoryError::MissingMetadata("reference frame"))?;
+        let frame = horizons_frame(frame)
+            .ok_or_else(|| HorizonsTrajectoryError::UnsupportedFrame(frame.to_string()))?;
+        let center = table
+            .center()
+            .ok_or(HorizonsTrajectoryError::MissingMetadata("center body"))?;
+        let origin = DynOrigin::try_from(center.id).map_err(|_| {
+            HorizonsTrajectoryError::UnsupportedCenter {
+                name: center.name.clone(),
+                id: center.id,
+            }
+        })?;
+        let units = table
+            .output_units()
+            .ok_or(HorizonsTrajectoryError::MissingMetadata("output units"))?;
+        if units != "KM-S" {
+            return Err(HorizonsTrajectoryError::UnsupportedUnits(units.to_string()));
+        }
+
+        let states = table
+            .states()
+            .iter()
+            .map(|state| {
+                let time = Time::from_julian_date(Tdb, state.julian_date, Epoch::JulianDate)
+                    .map_err(|_| HorizonsTrajectoryError::InvalidJulianDate(state.julian_date))?;
+                Ok(State::new(
+                    time,
+                    DVec3::from_array(state.position),
+                    DVec3::from_array(state.velocity),
+                    origin,
+                    frame,
+                ))
+            })
+            .collect::<Result<Vec<_>, HorizonsTrajectoryError>>()?;
+        Ok(Trajectory::new(&states)?)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum EphemerisTrajectoryError {
+    #[error("the ephemeris does not contain any segments")]
+    NoSegments,
+    #[error("unsupported time system `{0}`")]
+    UnsupportedTimeSystem(String),
+    #[error("unsupported reference frame `{0}`")]
+    UnsupportedFrame(String),
+    #[error("unsupported center `{0}`")]
+    UnsupportedCenter(String),
+    #[error("unsupported interpolation method `{0}`")]
+    UnsupportedInterpolation(String),
+    #[error("all segments must share the same center and reference frame")]
+    InconsistentSegments,
+    #[error("invalid epoch `{0}`")]
+    InvalidEpoch(String),
+    #[error(transparent)]
+    UnsupportedUnits(#[from] UnsupportedUnitsError),
+    #[error(transparent)]
+    SeriesError(#[from] SeriesError),
+    #[error("{0} is not covered by the ephemeris")]
+    OutOfRange(Time<Tai>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum StateInterpolator {
+    Hermite(HermiteInterpolator),
+    Lagrange(LagrangeInterpolator),
+}
+
+impl StateInterpolator {
+    fn times(&self) -> &[f64] {
+        match self {
+            StateInterpolator::Hermite(hermite) => hermite.times(),
+            StateInterpolator::Lagrange(lagrange) => lagrange.times(),
+        }
+    }
+
+    fn interpolate(&self, t: f64) -> Result<(DVec3, DVec3), OutOfRangeError> {
+        match self {
+            StateInterpolator::Hermite(hermite) => hermite.interpolate(t),
+            StateInterpolator::Lagrange(lagrange) => lagrange.interpolate(t),
+        }
+    }
+}
+
+/// A trajectory defined by the state vectors of an ephemeris such as a CCSDS OEM.
+///
+/// The ephemeris may consist of several segments which are interpolated separately. Queries
+/// between segments fail with [EphemerisTrajectoryError::OutOfRange].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EphemerisTrajectory {
+    epoch: Time<Tai>,
+    origin: DynOrigin,
+    frame: DynFrame,
+    segments: Vec<StateInterpolator>,
+}
+
+impl EphemerisTrajectory {
+    pub fn origin(&self) -> DynOrigin {
+        self.origin
+    }
+
+    pub fn reference_frame(&self) -> DynFrame {
+        self.frame
+    }
+
+    pub fn start_time(&self) -> Time<Tai> {
+        self.epoch
+    }
+
+    pub fn end_time(&self) -> Time<Tai> {
+        let times = self.segments.last().unwrap().times();
+        self.epoch + TimeDelta::from_decimal_seconds(times[times.len() - 1]).unwrap()
+    }
+
+    pub fn interpolate_at(
+        &self,
+        time: Time<Tai>,
+    ) -> Result<State<Time<Tai>, DynOrigin, DynFrame>, EphemerisTrajectoryError> {
+        let t = (time - self.epoch).to_decimal_seconds();
+        let (position, velocity) = self
+            .segments
+            .iter()
+            .find_map(|segment| segment.interpolate(t).ok())
+            .ok_or(EphemerisTrajectoryError::OutOfRange(time))?;
+        Ok(State::new(
+            time,
+            position,
+            velocity,
+            self.origin,
+            self.frame,
+        ))
+    }
+}
+
+/// Maps the reference frame name of a CCSDS navigation data message to a frame.
+pub(crate) fn ndm_frame(name: &str) -> Option<DynFrame> {
+    match name {
+        // The frame bias between EME2000 and the ICRF is neglected
+        "ICRF" | "EME2000" | "J2000" => Some(DynFrame::Icrf),
+        _ => name.parse().ok(),
+    }
+}
+
+/// Converts an epoch of a CCSDS navigation data message to TAI. Returns `None` for UT1 epochs,
+/// which cannot be converted without Earth orientation parameters.
+fn ndm_time_to_tai(time: DynTime) -> Option<Time<Tai>> {
+    match time.scale() {
+        DynTimeScale::Tai => Some(time.with_scale(Tai)),
+        DynTimeScale::Tt => Some(time.with_scale(Tt).to_tai()),
+        DynTimeScale::Tdb => Some(time.with_scale(Tdb).to_tai()),
+        DynTimeScale::Tcb => Some(time.with_scale(Tcb).to_tai()),
+        DynTimeScale::Tcg => Some(time.with_scale(Tcg).to_tai()),
+        DynTimeScale::Ut1 => None,
+    }
+}
+
+/// Parses an epoch of a CCSDS navigation data message in the given time system and converts it
+/// to TAI. The `unsupported` and `invalid` constructors build the caller's error for an unknown
+/// time system and an unparseable epoch respectively.
+pub(crate) fn ndm_epoch<E>(
+    time_system: &str,
+    epoch: &str,
+    unsupported: fn(String) -> E,
+    invalid: fn(String) -> E,
+) -> Result<Time<Tai>, E> {
+    let time = parse_ndm_epoch(time_system, epoch).map_err(|err| match err {
+        NdmEpochError::UnsupportedTimeSystem(time_system) => unsupported(time_system),
+        NdmEpochError::InvalidEpoch(epoch) => invalid(epoch),
+    })?;
+    ndm_time_to_tai(time).ok_or_else(|| unsupported(time_system.to_string()))
+}
+
+fn oem_epoch(time_system: &str, epoch: &str) -> Result<Time<Tai>, EphemerisTrajectoryError> {
+    ndm_epoch(
+        time_system,
+        epoch,
+        EphemerisTrajectoryError::UnsupportedTimeSystem,
+        EphemerisTrajectoryError::InvalidEpoch,
+    )
+}
+
+impl From<NdmEpochError> for EphemerisTrajectoryError {
+    fn from(err: NdmEpochError) -> Self {
+        match err {
+            NdmEpochError::UnsupportedTimeSystem(time_system) => {
+                EphemerisTrajectoryError::UnsupportedTimeSystem(time_system)
+            }
+            NdmEpochError::InvalidEpoch(epoch) => EphemerisTrajectoryError::InvalidEpoch(epoch),
+        }
+    }
+}
+
+impl TryFrom<&OemType> for EphemerisTrajectory {
+    type Error = EphemerisTrajectoryError;
+
+    /// Creates a trajectory from the state vectors of all segments of an OEM.
+    ///
+    /// The epochs are converted to TAI. Each segment is interpolated with the method and degree
+    /// declared in its metadata. Hermite interpolation of degree 3 is used if no method is
+    /// declared.
+    fn try_from(oem: &OemType) -> Result<Self, Self::Error> {
+        let first = oem
+            .body
+            .segment_list
+            .first()
+            .ok_or(EphemerisTrajectoryError::NoSegments)?;
+        let center = &first.metadata.center_name;
+        let origin = DynOrigin::from_name(center)
+            .map_err(|_| EphemerisTrajectoryError::UnsupportedCenter(center.clone()))?;
+        let frame_name = &first.metadata.ref_frame;
+        let frame = ndm_frame(frame_name)
+            .ok_or_else(|| EphemerisTrajectoryError::UnsupportedFrame(frame_name.clone()))?;
+        let epoch = match first.data.state_vector_list.first() {
+            Some(state) => oem_epoch(&first.metadata.time_system, &state.epoch.0)?,
+            None => {
+                return Err(EphemerisTrajectoryError::SeriesError(
+                    SeriesError::InsufficientPoints(0, 2),
+                ))
+            }
+        };
+
+        let mut segments = Vec::with_capacity(oem.body.segment_list.len());
+        for segment in &oem.body.segment_list {
+            let metadata = &segment.metadata;
+            if &metadata.center_name != center || &metadata.ref_frame != frame_name {
+                return Err(EphemerisTrajectoryError::InconsistentSegments);
+            }
+            let mut times = vec![];
+            let mut positions = vec![];
+            let mut velocities = vec![];
+            let epochs = oem_epochs(segment)?;
+            for (time, state) in epochs.into_iter().zip(&segment.data.state_vector_list) {
+                let time = ndm_time_to_tai(time).ok_or_else(|| {
+                    EphemerisTrajectoryError::UnsupportedTimeSystem(metadata.time_system.clone())
+                })?;
+                times.push((time - epoch).to_decimal_seconds());
+                positions.push(DVec3::from_array(state.position_km()?));
+                velocities.push(DVec3::from_array(state.velocity_km_per_s()?));
+            }
+            let degree = metadata.interpolation_degree.map(|degree| degree as usize);
+            let method = metadata.interpolation.as_deref().map(str::to_uppercase);
+            let interpolator = match method.as_deref() {
+                None | Some("HERMITE") => StateInterpolator::Hermite(
+                    HermiteInterpolator::new(times, positions, velocities)?
+                        .with_degree(degree.unwrap_or(3)),
+                ),
+                Some("LAGRANGE") => {
+                    let lagrange = LagrangeInterpolator::new(times, positions, velocities)?;
+                    StateInterpolator::Lagrange(match degree {
+                        Some(degree) => lagrange.with_degree(degree),
+                        None => lagrange,
+                    })
+                }
+                Some("LINEAR") => StateInterpolator::Lagrange(LagrangeInterpolator::new(
+                    times, positions, velocities,
+                )?),
+                Some(_) => {
+                    return Err(EphemerisTrajectoryError::UnsupportedInterpolation(
+                        metadata.interpolation.clone().unwrap(),
+                    ))
+                }
+            };
+            segments.push(interpolator);
+        }
+
+        Ok(Self {
+            epoch,
+            origin,
+            frame,
+            segments,
+        })
+    }
+}
+
 impl<T, O, R> CoordinateSystem<R> for Trajectory<T, O, R>
 where
     T: TimeLike,
@@ -342,3 +685,376 @@ where
         Ok(Trajectory::new(&states)?)
     }
 }
+
+/// Trajectory samples whose epochs are at most this many seconds apart share the
+/// precession-nutation matrix when transformed with [TryToFrame]. The matrix changes by less
+/// than 1e-11 rad/s, so the resulting error is below 1e-9 rad.
+pub const PRECESSION_NUTATION_CACHE_INTERVAL: f64 = 60.0;
+
+impl<T> DynTrajectory<T>
+where
+    T: TimeLike + Clone,
+{
+    /// Transforms all states of the trajectory into `frame` and preserves the sample times.
+    ///
+    /// The precession-nutation matrix is computed once and reused for all subsequent samples
+    /// whose epochs are at most `cache_interval` seconds away from the epoch for which it was
+    /// computed. A `cache_interval` of zero is equivalent to transforming each state on its own.
+    pub fn try_to_frame_cached<P>(
+        &self,
+        frame: DynFrame,
+        provider: &P,
+        cache_interval: f64,
+    ) -> Result<Self, TrajectoryTransformationError>
+    where
+        T: TryToScale<Tdb, P>,
+        P: FrameTransformationProvider,
+    {
+        let mut cache: Option<(f64, Rotation)> = None;
+        let mut precession_nutation = |centuries: f64| {
+            if let Some((epoch, rotation)) = cache {
+                if ((centuries - epoch) * SECONDS_PER_JULIAN_CENTURY).abs() <= cache_interval {
+                    return rotation;
+                }
+            }
+            let rotation = icrf_to_cirf(centuries);
+            cache = Some((centuries, rotation));
+            rotation
+        };
+        let mut states = Vec::with_capacity(self.states.len());
+        for state in &self.states {
+            let state = state
+                .try_to_frame_with(frame, provider, &mut precession_nutation)
+                .map_err(|e| {
+                    TrajectoryTransformationError::StateTransformationError(e.to_string())
+                })?;
+            states.push(state);
+        }
+        Ok(Trajectory::new(&states)?)
+    }
+}
+
+impl<T, P> TryToFrame<DynFrame, P> for DynTrajectory<T>
+where
+    T: TryToScale<Tdb, P> + TimeLike + Clone,
+    P: FrameTransformationProvider,
+{
+    type Output = DynTrajectory<T>;
+    type Error = TrajectoryTransformationError;
+
+    /// Transforms all states of the trajectory into `frame` reusing the precession-nutation
+    /// matrix within [PRECESSION_NUTATION_CACHE_INTERVAL], see
+    /// [DynTrajectory::try_to_frame_cached].
+    fn try_to_frame(&self, frame: DynFrame, provider: &P) -> Result<Self::Output, Self::Error> {
+        self.try_to_frame_cached(frame, provider, PRECESSION_NUTATION_CACHE_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use lox_bodies::PointMass;
+    use lox_io::ndm::common::{PositionUnits, VelocityUnits};
+    use lox_io::ndm::kvn::KvnDeserializer;
+
+    use crate::events::ZeroCrossing;
+    use crate::frames::NoOpFrameTransformationProvider;
+    use crate::states::DynState;
+
+    use super::*;
+
+    const RADIUS: f64 = 7000.0;
+
+    fn circular_state(t: f64) -> (DVec3, DVec3) {
+        let n = (lox_bodies::Earth.gravitational_parameter() / RADIUS.powi(3)).sqrt();
+        let (sin, cos) = (n * t).sin_cos();
+        (
+            DVec3::new(RADIUS * cos, RADIUS * sin, 0.0),
+            DVec3::new(-RADIUS * n * sin, RADIUS * n * cos, 0.0),
+        )
+    }
+
+    fn oem_segment(seconds: &[i64], interpolation: &str) -> String {
+        let epoch = |s: &i64| {
+            format!(
+                "2024-01-01T{:02}:{:02}:{:02}.000",
+                s / 3600,
+                s / 60 % 60,
+                s % 60
+            )
+        };
+        let mut kvn = format!(
+            "META_START
+OBJECT_NAME = SATELLITE
+OBJECT_ID = 2024-001A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = UTC
+START_TIME = {}
+STOP_TIME = {}
+{interpolation}META_STOP
+
+",
+            epoch(seconds.first().unwrap()),
+            epoch(seconds.last().unwrap()),
+        );
+        for s in seconds {
+            let (r, v) = circular_state(*s as f64);
+            kvn.push_str(&format!(
+                "{} {:.9} {:.9} {:.9} {:.12} {:.12} {:.12}\n",
+                epoch(s),
+                r.x,
+                r.y,
+                r.z,
+                v.x,
+                v.y,
+                v.z
+            ));
+        }
+        kvn.push('\n');
+        kvn
+    }
+
+    fn oem() -> OemType {
+        let first: Vec<i64> = (0..=10).map(|i| 60 * i).collect();
+        let second: Vec<i64> = (20..=30).map(|i| 60 * i).collect();
+        let kvn = format!(
+            "CCSDS_OEM_VERS = 2.0
+CREATION_DATE = 2024-01-02T00:00:00
+ORIGINATOR = LOX
+{}{}",
+            oem_segment(
+                &first,
+                "INTERPOLATION = HERMITE\nINTERPOLATION_DEGREE = 5\n"
+            ),
+            oem_segment(
+                &second,
+                "INTERPOLATION = LAGRANGE\nINTERPOLATION_DEGREE = 7\n"
+            ),
+        );
+        KvnDeserializer::from_kvn_str(&kvn).unwrap()
+    }
+
+    #[test]
+    fn test_ephemeris_trajectory_from_oem() {
+        let trajectory = EphemerisTrajectory::try_from(&oem()).unwrap();
+        assert_eq!(trajectory.origin(), DynOrigin::Earth);
+        assert_eq!(trajectory.reference_frame(), DynFrame::Icrf);
+        let epoch = Utc::from_iso("2024-01-01T00:00:00.000").unwrap().to_tai();
+        assert_eq!(trajectory.start_time(), epoch);
+        assert_eq!(trajectory.end_time(), epoch + TimeDelta::from_seconds(1800));
+        for t in [0.0, 123.4, 599.9, 600.0, 1200.0, 1500.5, 1800.0] {
+            let time = epoch + TimeDelta::from_decimal_seconds(t).unwrap();
+            let state = trajectory.interpolate_at(time).unwrap();
+            let (r, v) = circular_state(t);
+            assert_eq!(state.time(), time);
+            assert!((state.position() - r).length() < 1e-6);
+            assert!((state.velocity() - v).length() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_trajectory_mixed_units() {
+        let exp = EphemerisTrajectory::try_from(&oem()).unwrap();
+        let mut oem = oem();
+        for (i, state) in oem.body.segment_list[0]
+            .data
+            .state_vector_list
+            .iter_mut()
+            .enumerate()
+        {
+            let (position, velocity) = match i % 3 {
+                0 => (&mut state.x, &mut state.x_dot),
+                1 => (&mut state.y, &mut state.y_dot),
+                _ => (&mut state.z, &mut state.z_dot),
+            };
+            position.units = Some(PositionUnits("km".to_string()));
+            velocity.base *= 1e3;
+            velocity.units = Some(VelocityUnits("m/s".to_string()));
+        }
+        let act = EphemerisTrajectory::try_from(&oem).unwrap();
+        let time = act.start_time() + TimeDelta::from_decimal_seconds(123.4).unwrap();
+        let act = act.interpolate_at(time).unwrap();
+        let exp = exp.interpolate_at(time).unwrap();
+        assert!((act.position() - exp.position()).length() < 1e-12);
+        assert!((act.velocity() - exp.velocity()).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_ephemeris_trajectory_gap() {
+        let trajectory = EphemerisTrajectory::try_from(&oem()).unwrap();
+        let epoch = trajectory.start_time();
+        for t in [900, -1, 1801] {
+            let time = epoch + TimeDelta::from_seconds(t);
+            assert_eq!(
+                trajectory.interpolate_at(time),
+                Err(EphemerisTrajectoryError::OutOfRange(time))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_trajectory_unsupported_metadata() {
+        let mut oem = oem();
+        oem.body.segment_list[1].metadata.center_name = "MOON".to_string();
+        assert_eq!(
+            EphemerisTrajectory::try_from(&oem),
+            Err(EphemerisTrajectoryError::InconsistentSegments)
+        );
+        oem.body.segment_list[0].metadata.time_system = "GMST".to_string();
+        oem.body.segment_list[1].metadata.center_name = "EARTH".to_string();
+        assert_eq!(
+            EphemerisTrajectory::try_from(&oem),
+            Err(EphemerisTrajectoryError::UnsupportedTimeSystem(
+                "GMST".to_string()
+            ))
+        );
+        oem.body.segment_list.clear();
+        assert_eq!(
+            EphemerisTrajectory::try_from(&oem),
+            Err(EphemerisTrajectoryError::NoSegments)
+        );
+    }
+
+    fn data_dir() -> PathBuf {
+        PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")))
+    }
+
+    fn horizons_table(name: &str) -> HorizonsVectorTable {
+        HorizonsVectorTable::from_path(data_dir().join(name)).unwrap()
+    }
+
+    #[test]
+    fn test_trajectory_from_horizons_icrf() {
+        let table = horizons_table("horizons_mars_vectors.txt");
+        let trajectory = Trajectory::from_horizons(&table).unwrap();
+        assert_eq!(trajectory.origin(), DynOrigin::Sun);
+        assert_eq!(trajectory.reference_frame(), DynFrame::Icrf);
+        assert_eq!(trajectory.states().len(), 4);
+        assert_eq!(
+            trajectory.start_time(),
+            Time::from_julian_date(Tdb, 2460310.5, Epoch::JulianDate).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_trajectory_from_horizons_ecliptic() {
+        let icrf = Trajectory::from_horizons(&horizons_table("horizons_mars_vectors.txt")).unwrap();
+        let ecliptic =
+            Trajectory::from_horizons(&horizons_table("horizons_mars_vectors_ecliptic.txt"))
+                .unwrap();
+        assert_eq!(ecliptic.origin(), DynOrigin::Sun);
+        assert_eq!(ecliptic.reference_frame(), DynFrame::EclipticJ2000);
+
+        for (exp, act) in icrf.states().iter().zip(ecliptic.states()) {
+            let act = act
+                .try_to_frame(DynFrame::Icrf, &NoOpFrameTransformationProvider)
+                .unwrap();
+            assert_eq!(act.reference_frame(), DynFrame::Icrf);
+            assert_eq!(act.time(), exp.time());
+            assert_close!(act.position(), exp.position(), 1e-12);
+            assert_close!(act.velocity(), exp.velocity(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_trajectory_from_horizons_unsupported_frame() {
+        let contents = std::fs::read_to_string(data_dir().join("horizons_mars_vectors.txt"))
+            .unwrap()
+            .replace("Reference frame : ICRF", "Reference frame : FK4/B1950.0");
+        let table = HorizonsVectorTable::from_string(&contents).unwrap();
+        assert_eq!(
+            Trajectory::from_horizons(&table).unwrap_err(),
+            HorizonsTrajectoryError::UnsupportedFrame("FK4/B1950.0".to_string())
+        );
+    }
+
+    fn icrf_trajectory() -> DynTrajectory<Time<Tai>> {
+        let epoch = Utc::from_iso("2024-01-01T00:00:00.000").unwrap().to_tai();
+        let states: Vec<DynState<Time<Tai>>> = (0..=180)
+            .map(|i| {
+                let t = 10.0 * i as f64;
+                let (r, v) = circular_state(t);
+                let time = epoch + TimeDelta::from_decimal_seconds(t).unwrap();
+                State::new(time, r, v, DynOrigin::Earth, DynFrame::Icrf)
+            })
+            .collect();
+        Trajectory::new(&states).unwrap()
+    }
+
+    #[test]
+    fn test_dyn_trajectory_to_frame() {
+        let provider = &NoOpFrameTransformationProvider;
+        let trajectory = icrf_trajectory();
+
+        let exact = trajectory
+            .try_to_frame_cached(DynFrame::Itrf, provider, 0.0)
+            .unwrap();
+        let cached = trajectory.try_to_frame(DynFrame::Itrf, provider).unwrap();
+        assert_eq!(cached.reference_frame(), DynFrame::Itrf);
+        assert_eq!(cached.times(), trajectory.times());
+        for ((state, exact), cached) in trajectory
+            .states()
+            .iter()
+            .zip(exact.states())
+            .zip(cached.states())
+        {
+            assert_eq!(exact, state.try_to_frame(DynFrame::Itrf, provider).unwrap());
+            // The cached precession-nutation matrix is accurate to 1e-9 rad
+            assert!((cached.position() - exact.position()).length() < RADIUS * 1e-9);
+            assert!((cached.velocity() - exact.velocity()).length() < 1e-8);
+        }
+
+        // The transformed trajectory remains queryable
+        let time = trajectory.start_time() + TimeDelta::from_decimal_seconds(905.0).unwrap();
+        let state = trajectory.interpolate_at(time);
+        let exp = state.try_to_frame(DynFrame::Itrf, provider).unwrap();
+        let act = cached.interpolate_at(time);
+        assert_eq!(act.time(), time);
+        assert!((act.position() - exp.position()).length() < 1e-6);
+
+        let back = cached.try_to_frame(DynFrame::Icrf, provider).unwrap();
+        for (act, exp) in back.states().iter().zip(trajectory.states()) {
+            assert!((act.position() - exp.position()).length() < 1e-8);
+            assert!((act.velocity() - exp.velocity()).length() < 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_find_events_with_tolerance() {
+        let trajectory = icrf_trajectory();
+        let start = trajectory.start_time();
+        let seconds = |state: State<Time<Tai>, DynOrigin, DynFrame>| {
+            (state.time() - start).to_decimal_seconds()
+        };
+        let assert_event = |event: &Event<Time<Tai>>, crossing: ZeroCrossing, t: f64| {
+            assert_eq!(event.crossing(), crossing);
+            let dt = (*event.time() - start).to_decimal_seconds();
+            assert!((dt - t).abs() < 1e-9, "{dt} != {t}");
+        };
+
+        // Positive between 452 s and 458 s, i.e. between two samples of the trajectory
+        let bump = |state| 1.0 - ((seconds(state) - 455.0) / 3.0).powi(2);
+        assert!(trajectory.find_events(bump).is_empty());
+        let events = trajectory.find_events_with_tolerance(bump, 1e-10);
+        assert_eq!(events.len(), 2);
+        assert_event(&events[0], ZeroCrossing::Up, 452.0);
+        assert_event(&events[1], ZeroCrossing::Down, 458.0);
+
+        // Negative between 1202 s and 1208 s
+        let dip = |state| ((seconds(state) - 1205.0) / 3.0).powi(2) - 1.0;
+        let events = trajectory.find_events_with_tolerance(dip, 1e-10);
+        assert_eq!(events.len(), 2);
+        assert_event(&events[0], ZeroCrossing::Down, 1202.0);
+        assert_event(&events[1], ZeroCrossing::Up, 1208.0);
+
+        // Regular crossings are found as well
+        let y = |state: State<Time<Tai>, DynOrigin, DynFrame>| state.position().y;
+        let events = trajectory.find_events_with_tolerance(y, 1e-6);
+        assert_eq!(events, trajectory.find_events(y));
+    }
+}
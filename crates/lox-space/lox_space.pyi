@@ -30,6 +30,7 @@ class Origin:
     def name(self) -> str: ...
     def gravitational_parameter(self) -> float: ...
     def mean_radius(self) -> float: ...
+    def volumetric_radius(self) -> float: ...
     def radii(self) -> tuple[float, float, float]: ...
     def equatorial_radius(self) -> float: ...
     def polar_radius(self) -> float: ...
@@ -203,6 +204,7 @@ class Time:
     def from_seconds(cls, scale: Scale, seconds: int, subsecond: float) -> Self: ...
     def seconds(self) -> int: ...
     def subsecond(self) -> float: ...
+    def to_iso(self) -> str: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __add__(self, other: TimeDelta) -> Self: ...
@@ -32,7 +32,9 @@ use crate::julian_dates::{Epoch, JulianDate, Unit};
 
 fn iso_regex() -> &'static Regex {
     static ISO: OnceLock<Regex> = OnceLock::new();
-    ISO.get_or_init(|| Regex::new(r"(?<year>-?\d{4,})-(?<month>\d{2})-(?<day>\d{2})").unwrap())
+    ISO.get_or_init(|| {
+        Regex::new(r"^(?<year>-?\d{4,})-(?:(?<month>\d{2})-(?<day>\d{2})|(?<doy>\d{3}))$").unwrap()
+    })
 }
 
 /// Error type returned when attempting to construct a [Date] from invalid inputs.
@@ -44,6 +46,8 @@ pub enum DateError {
     InvalidIsoString(String),
     #[error("day of year cannot be 366 for a non-leap year")]
     NonLeapYear,
+    #[error("day of year must be in the range [1..366] but was {0}")]
+    InvalidDayOfYear(u16),
 }
 
 /// The calendars supported by Lox.
@@ -65,7 +69,11 @@ pub struct Date {
 
 impl Display for Date {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+        if self.year < 0 {
+            write!(f, "-{:04}-{:02}-{:02}", -self.year, self.month, self.day)
+        } else {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        }
     }
 }
 
@@ -159,12 +167,15 @@ impl Date {
         }
     }
 
-    /// Constructs a new [Date] from an ISO 8601 string.
+    /// Constructs a new [Date] from an ISO 8601 string in either calendar (`YYYY-MM-DD`) or
+    /// ordinal (`YYYY-DDD`) form.
     ///
     /// # Errors
     ///
     /// - [DateError::InvalidIsoString] if the input string does not contain a valid ISO 8601 date.
     /// - [DateError::InvalidDate] if the date parsed from the ISO 8601 string is invalid.
+    /// - [DateError::InvalidDayOfYear] or [DateError::NonLeapYear] if the day of year of an
+    ///   ordinal date is invalid.
     pub fn from_iso(iso: &str) -> Result<Self, DateError> {
         let caps = iso_regex()
             .captures(iso)
@@ -172,6 +183,13 @@ impl Date {
         let year: i64 = caps["year"]
             .parse()
             .map_err(|_| DateError::InvalidIsoString(iso.to_owned()))?;
+        if let Some(day_of_year) = caps.name("doy") {
+            let day_of_year = day_of_year
+                .as_str()
+                .parse()
+                .map_err(|_| DateError::InvalidIsoString(iso.to_owned()))?;
+            return Date::from_day_of_year(year, day_of_year);
+        }
         let month = caps["month"]
             .parse()
             .map_err(|_| DateError::InvalidIsoString(iso.to_owned()))?;
@@ -227,8 +245,12 @@ impl Date {
     ///
     /// # Errors
     ///
+    /// - [DateError::InvalidDayOfYear] if the input day number is not in the range `1..=366`.
     /// - [DateError::NonLeapYear] if the input day number is 366 and the year is not a leap year.
     pub fn from_day_of_year(year: i64, day_of_year: u16) -> Result<Self, DateError> {
+        if !(1..=366).contains(&day_of_year) {
+            return Err(DateError::InvalidDayOfYear(day_of_year));
+        }
         let calendar = calendar(year, 1, 1);
         let leap = is_leap_year(calendar, year);
         let month = find_month(day_of_year, leap);
@@ -384,6 +406,12 @@ pub trait CalendarDate {
     }
 }
 
+impl CalendarDate for Date {
+    fn date(&self) -> Date {
+        *self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use lox_math::constants::f64::time::DAYS_PER_JULIAN_CENTURY;
@@ -414,6 +442,25 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[rstest]
+    #[case(Date::new(2000, 1, 1).unwrap(), "2000-01-01")]
+    #[case(Date::new(10, 2, 3).unwrap(), "0010-02-03")]
+    #[case(Date::new(-1, 12, 31).unwrap(), "-0001-12-31")]
+    fn test_date_display(#[case] date: Date, #[case] expected: &str) {
+        assert_eq!(date.to_string(), expected);
+        assert_eq!(Date::from_iso(expected).unwrap(), date);
+    }
+
+    #[rstest]
+    #[case("2000-01-011")]
+    #[case("x2000-01-01")]
+    #[case("2000-01-01T")]
+    fn test_date_iso_invalid(#[case] str: &str) {
+        let actual = Date::from_iso(str);
+        let expected = Err(DateError::InvalidIsoString(str.to_string()));
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_date_from_day_of_year() {
         let date = Date::from_day_of_year(2000, 366).unwrap();
@@ -427,6 +474,35 @@ mod tests {
         let actual = Date::from_day_of_year(2001, 366);
         let expected = Err(DateError::NonLeapYear);
         assert_eq!(actual, expected);
+        let actual = Date::from_day_of_year(2000, 0);
+        let expected = Err(DateError::InvalidDayOfYear(0));
+        assert_eq!(actual, expected);
+        let actual = Date::from_day_of_year(2000, 367);
+        let expected = Err(DateError::InvalidDayOfYear(367));
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(2000, 366)]
+    #[case(2001, 365)]
+    #[case(1900, 365)]
+    fn test_date_day_of_year_round_trip(#[case] year: i64, #[case] days: u16) {
+        for day_of_year in 1..=days {
+            let date = Date::from_day_of_year(year, day_of_year).unwrap();
+            assert_eq!(date.year(), year);
+            assert_eq!(date.day_of_year(), day_of_year);
+            assert_eq!(Date::new(year, date.month(), date.day()), Ok(date));
+        }
+    }
+
+    #[rstest]
+    #[case("2004-281", Ok(Date::new(2004, 10, 7).unwrap()))]
+    #[case("2000-366", Ok(Date::new(2000, 12, 31).unwrap()))]
+    #[case("2001-366", Err(DateError::NonLeapYear))]
+    #[case("2001-000", Err(DateError::InvalidDayOfYear(0)))]
+    #[case("2001-28", Err(DateError::InvalidIsoString("2001-28".to_string())))]
+    fn test_date_iso_ordinal(#[case] iso: &str, #[case] expected: Result<Date, DateError>) {
+        assert_eq!(Date::from_iso(iso), expected);
     }
 
     #[test]
@@ -18,8 +18,10 @@
 
 use std::fmt::Display;
 use std::ops::{Add, Neg, RangeInclusive, Sub};
+use std::sync::OnceLock;
 
 use num::ToPrimitive;
+use regex::Regex;
 use thiserror::Error;
 
 use lox_math::constants::f64::time::{
@@ -60,6 +62,28 @@ impl PartialEq for TimeDeltaError {
     }
 }
 
+/// Error type returned when attempting to parse a [TimeDelta] from an ISO 8601 duration.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum IsoDurationError {
+    #[error("invalid ISO 8601 duration `{0}`")]
+    InvalidIsoString(String),
+    #[error("ISO 8601 duration `{0}` cannot be represented as a `TimeDelta`")]
+    OutOfRange(String),
+}
+
+fn iso_duration_regex() -> &'static Regex {
+    static ISO: OnceLock<Regex> = OnceLock::new();
+    ISO.get_or_init(|| {
+        Regex::new(
+            r"^(?<sign>[-+])?P(?:(?<weeks>\d+)W)?(?:(?<days>\d+)D)?(?:T(?:(?<hours>\d+)H)?(?:(?<minutes>\d+)M)?(?:(?<seconds>\d+)(?:[.,](?<fraction>\d+))?S)?)?$",
+        )
+        .unwrap()
+    })
+}
+
+/// The number of fractional digits of a [Subsecond] with femtosecond resolution.
+const FRACTION_DIGITS: usize = 15;
+
 /// A signed, continuous time difference supporting femtosecond precision.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct TimeDelta {
@@ -280,6 +304,109 @@ impl TimeDelta {
     pub fn range(range: RangeInclusive<i64>) -> TimeDeltaRange {
         range.into()
     }
+
+    /// Parses a [TimeDelta] from an ISO 8601 duration such as `PT1H30M`, `P1DT6H`, or
+    /// `-PT0.5S`.
+    ///
+    /// Weeks, days, hours, minutes, and seconds are supported, where a day is exactly 86400
+    /// seconds. Year and month designators are rejected because their length is ambiguous.
+    /// Fractional seconds are parsed directly into the [Subsecond] and truncated to femtoseconds.
+    ///
+    /// # Errors
+    ///
+    /// - [IsoDurationError::InvalidIsoString] if `iso` is not a supported ISO 8601 duration.
+    /// - [IsoDurationError::OutOfRange] if the number of seconds overflows an `i64`.
+    pub fn from_iso_duration(iso: &str) -> Result<Self, IsoDurationError> {
+        let invalid = || IsoDurationError::InvalidIsoString(iso.to_owned());
+        let caps = iso_duration_regex().captures(iso).ok_or_else(invalid)?;
+        let has_component = ["weeks", "days", "hours", "minutes", "seconds"]
+            .iter()
+            .any(|name| caps.name(name).is_some());
+        if !has_component || iso.ends_with('T') {
+            return Err(invalid());
+        }
+
+        let mut seconds: i64 = 0;
+        for (name, factor) in [
+            ("weeks", 7 * 86400),
+            ("days", 86400),
+            ("hours", 3600),
+            ("minutes", 60),
+            ("seconds", 1),
+        ] {
+            if let Some(value) = caps.name(name) {
+                seconds = value
+                    .as_str()
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|value| value.checked_mul(factor))
+                    .and_then(|value| value.checked_add(seconds))
+                    .ok_or_else(|| IsoDurationError::OutOfRange(iso.to_owned()))?;
+            }
+        }
+
+        let subsecond = match caps.name("fraction") {
+            Some(fraction) => {
+                let digits = &fraction.as_str()[..fraction.len().min(FRACTION_DIGITS)];
+                let femtoseconds: f64 = format!("{:0<width$}", digits, width = FRACTION_DIGITS)
+                    .parse()
+                    .map_err(|_| invalid())?;
+                Subsecond(femtoseconds / 1e15)
+            }
+            None => Subsecond::default(),
+        };
+
+        let delta = Self::new(seconds, subsecond);
+        match caps.name("sign").map(|sign| sign.as_str()) {
+            Some("-") => Ok(-delta),
+            _ => Ok(delta),
+        }
+    }
+
+    /// Formats the [TimeDelta] as an ISO 8601 duration, e.g. `P1DT6H` or `-PT0.5S`.
+    ///
+    /// Days are the largest designator used and components that are zero are omitted. Fractional
+    /// seconds are written with up to femtosecond resolution, so the result can be parsed back
+    /// with [TimeDelta::from_iso_duration].
+    pub fn to_iso_duration(&self) -> String {
+        let (sign, delta) = if self.is_negative() {
+            ("-", -*self)
+        } else {
+            ("", *self)
+        };
+        let mut seconds = delta.seconds;
+        let mut femtoseconds = (delta.subsecond.0 * 1e15).round() as i64;
+        if femtoseconds >= 1_000_000_000_000_000 {
+            seconds += 1;
+            femtoseconds -= 1_000_000_000_000_000;
+        }
+
+        let days = seconds / 86400;
+        let hours = seconds % 86400 / 3600;
+        let minutes = seconds % 3600 / 60;
+        let seconds = seconds % 60;
+
+        let mut iso = format!("{}P", sign);
+        if days > 0 {
+            iso.push_str(&format!("{}D", days));
+        }
+        if hours > 0 || minutes > 0 || seconds > 0 || femtoseconds > 0 || days == 0 {
+            iso.push('T');
+        }
+        if hours > 0 {
+            iso.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            iso.push_str(&format!("{}M", minutes));
+        }
+        if femtoseconds > 0 {
+            let fraction = format!("{:015}", femtoseconds);
+            iso.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds > 0 || (days == 0 && hours == 0 && minutes == 0) {
+            iso.push_str(&format!("{}S", seconds));
+        }
+        iso
+    }
 }
 
 impl Display for TimeDelta {
@@ -616,4 +743,71 @@ mod tests {
         let delta: TimeDelta = 4i64.into();
         assert_eq!(delta.seconds, 4);
     }
+
+    #[rstest]
+    #[case("PT1H30M", TimeDelta::from_seconds(5400))]
+    #[case("P1DT6H", TimeDelta::from_seconds(108000))]
+    #[case("P2W", TimeDelta::from_seconds(1209600))]
+    #[case("P1D", TimeDelta::from_seconds(86400))]
+    #[case("PT0S", TimeDelta::default())]
+    #[case("+PT45S", TimeDelta::from_seconds(45))]
+    #[case("PT1.5S", TimeDelta::new(1, Subsecond(0.5)))]
+    #[case("PT0,25S", TimeDelta::new(0, Subsecond(0.25)))]
+    #[case(
+        "PT0.123456789012345S",
+        TimeDelta::new(0, Subsecond(0.123456789012345))
+    )]
+    #[case(
+        "PT0.1234567890123456789S",
+        TimeDelta::new(0, Subsecond(0.123456789012345))
+    )]
+    #[case("-PT1H", TimeDelta::from_seconds(-3600))]
+    #[case("-PT0.25S", TimeDelta::new(-1, Subsecond(0.75)))]
+    fn test_delta_from_iso_duration(#[case] iso: &str, #[case] expected: TimeDelta) {
+        assert_eq!(TimeDelta::from_iso_duration(iso), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("P")]
+    #[case("PT")]
+    #[case("P1DT")]
+    #[case("P1Y")]
+    #[case("P1M")]
+    #[case("PT1.5M")]
+    #[case("T1H")]
+    #[case("PT1H30M ")]
+    fn test_delta_from_iso_duration_invalid(#[case] iso: &str) {
+        assert_eq!(
+            TimeDelta::from_iso_duration(iso),
+            Err(IsoDurationError::InvalidIsoString(iso.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_delta_from_iso_duration_out_of_range() {
+        let iso = "P9223372036854775807D";
+        assert_eq!(
+            TimeDelta::from_iso_duration(iso),
+            Err(IsoDurationError::OutOfRange(iso.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case(TimeDelta::from_seconds(5400), "PT1H30M")]
+    #[case(TimeDelta::from_seconds(108000), "P1DT6H")]
+    #[case(TimeDelta::from_seconds(86400), "P1D")]
+    #[case(TimeDelta::default(), "PT0S")]
+    #[case(TimeDelta::from_seconds(90061), "P1DT1H1M1S")]
+    #[case(TimeDelta::new(1, Subsecond(0.5)), "PT1.5S")]
+    #[case(
+        TimeDelta::new(86400, Subsecond(0.000000000000001)),
+        "P1DT0.000000000000001S"
+    )]
+    #[case(TimeDelta::from_seconds(-3600), "-PT1H")]
+    #[case(TimeDelta::new(-1, Subsecond(0.75)), "-PT0.25S")]
+    fn test_delta_to_iso_duration(#[case] delta: TimeDelta, #[case] expected: &str) {
+        assert_eq!(delta.to_iso_duration(), expected);
+        assert_eq!(TimeDelta::from_iso_duration(expected), Ok(delta));
+    }
 }
@@ -0,0 +1,360 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `intervals` exposes [Interval], a span of time between two instants in the same
+    [TimeScale], together with the set operations required to combine intervals such as
+    visibility windows, eclipses or manoeuvres.
+*/
+
+use crate::deltas::TimeDelta;
+use crate::time_scales::TimeScale;
+use crate::Time;
+
+/// A half-open interval of time `[start, end)`.
+///
+/// `start` is contained in the interval while `end` is not, so that adjacent intervals sharing a
+/// boundary do not overlap. An interval whose `end` is not after its `start` is empty, i.e. it
+/// contains no instants and has a duration of zero. Since empty intervals may have arbitrary
+/// boundaries, use [Interval::is_empty] rather than equality to check for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<T: TimeScale> {
+    start: Time<T>,
+    end: Time<T>,
+}
+
+impl<T: TimeScale + Copy + Ord> Interval<T> {
+    pub fn new(start: Time<T>, end: Time<T>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> Time<T> {
+        self.start
+    }
+
+    pub fn end(&self) -> Time<T> {
+        self.end
+    }
+
+    /// Returns `true` if the interval contains no instants.
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Returns the length of the interval, which is zero for empty intervals.
+    pub fn duration(&self) -> TimeDelta {
+        if self.is_empty() {
+            return TimeDelta::default();
+        }
+        self.end - self.start
+    }
+
+    /// Returns `true` if `start <= time < end`.
+    pub fn contains(&self, time: Time<T>) -> bool {
+        self.start <= time && time < self.end
+    }
+
+    /// Returns `true` if the interval shares at least one instant with `other`.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Returns the instants contained in both intervals, which is empty if the intervals do not
+    /// overlap.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Returns the instants contained in either interval in chronological order.
+    ///
+    /// Overlapping and adjacent intervals are merged into a single interval, while disjoint
+    /// intervals are returned separately. Empty intervals are discarded, so the result contains
+    /// between zero and two intervals.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => vec![],
+            (true, false) => vec![*other],
+            (false, true) => vec![*self],
+            (false, false) => {
+                let (first, second) = if self.start <= other.start {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                if second.start <= first.end {
+                    vec![Self::new(first.start, first.end.max(second.end))]
+                } else {
+                    vec![*first, *second]
+                }
+            }
+        }
+    }
+
+    /// Splits the interval into `[start, time)` and `[time, end)`.
+    ///
+    /// Returns `None` unless `time` lies strictly within the interval, i.e. if either part would
+    /// be empty.
+    pub fn split_at(&self, time: Time<T>) -> Option<(Self, Self)> {
+        if self.start < time && time < self.end {
+            Some((Self::new(self.start, time), Self::new(time, self.end)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Sorts `intervals` chronologically and merges intervals that overlap or are separated by a gap
+/// of at most `tolerance`, in place.
+///
+/// With a zero `tolerance`, only overlapping and adjacent intervals are merged. Empty intervals
+/// are removed. The result is a sorted list of disjoint, non-empty intervals.
+pub fn coalesce<T: TimeScale + Copy + Ord>(intervals: &mut Vec<Interval<T>>, tolerance: TimeDelta) {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_unstable_by_key(|interval| interval.start);
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+    for &interval in intervals.iter() {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end + tolerance => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => merged.push(interval),
+        }
+    }
+    *intervals = merged;
+}
+
+/// Returns the instants contained in at least one interval of every set in `sets`, e.g. the
+/// windows during which several constraints are satisfied at the same time.
+///
+/// The sets do not need to be sorted or disjoint. The result is a sorted list of disjoint,
+/// non-empty intervals, which is empty if `sets` is empty.
+pub fn intersect_all<T: TimeScale + Copy + Ord>(sets: &[Vec<Interval<T>>]) -> Vec<Interval<T>> {
+    let Some((first, rest)) = sets.split_first() else {
+        return vec![];
+    };
+    let mut result = first.clone();
+    coalesce(&mut result, TimeDelta::default());
+    for set in rest {
+        let mut set = set.clone();
+        coalesce(&mut set, TimeDelta::default());
+        result = intersect_sorted(&result, &set);
+    }
+    result
+}
+
+/// Intersects two sorted lists of disjoint intervals in linear time.
+fn intersect_sorted<T: TimeScale + Copy + Ord>(
+    a: &[Interval<T>],
+    b: &[Interval<T>],
+) -> Vec<Interval<T>> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let intersection = a[i].intersection(&b[j]);
+        if !intersection.is_empty() {
+            result.push(intersection);
+        }
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use crate::subsecond::Subsecond;
+    use crate::time_scales::Tai;
+
+    use super::*;
+
+    fn tai(seconds: i64) -> Time<Tai> {
+        Time::new(Tai, seconds, Subsecond::default())
+    }
+
+    fn interval(start: i64, end: i64) -> Interval<Tai> {
+        Interval::new(tai(start), tai(end))
+    }
+
+    #[rstest]
+    #[case::before(-1, false)]
+    #[case::start(0, true)]
+    #[case::inside(5, true)]
+    #[case::end(10, false)]
+    #[case::after(11, false)]
+    fn test_interval_contains(#[case] seconds: i64, #[case] expected: bool) {
+        assert_eq!(interval(0, 10).contains(tai(seconds)), expected);
+    }
+
+    #[rstest]
+    #[case::regular(interval(0, 10), false, TimeDelta::from_seconds(10))]
+    #[case::degenerate(interval(10, 10), true, TimeDelta::default())]
+    #[case::reversed(interval(10, 0), true, TimeDelta::default())]
+    fn test_interval_is_empty(
+        #[case] interval: Interval<Tai>,
+        #[case] is_empty: bool,
+        #[case] duration: TimeDelta,
+    ) {
+        assert_eq!(interval.is_empty(), is_empty);
+        assert_eq!(interval.duration(), duration);
+        assert_eq!(interval.contains(interval.start()), !is_empty);
+    }
+
+    #[rstest]
+    #[case::overlapping(interval(0, 10), interval(5, 15), Some(interval(5, 10)))]
+    #[case::contained(interval(0, 10), interval(2, 4), Some(interval(2, 4)))]
+    #[case::adjacent(interval(0, 10), interval(10, 20), None)]
+    #[case::disjoint(interval(0, 10), interval(15, 20), None)]
+    #[case::empty(interval(0, 10), interval(5, 5), None)]
+    fn test_interval_intersection(
+        #[case] a: Interval<Tai>,
+        #[case] b: Interval<Tai>,
+        #[case] expected: Option<Interval<Tai>>,
+    ) {
+        for actual in [a.intersection(&b), b.intersection(&a)] {
+            match expected {
+                Some(expected) => assert_eq!(actual, expected),
+                None => assert!(actual.is_empty()),
+            }
+        }
+        assert_eq!(a.overlaps(&b), expected.is_some());
+    }
+
+    #[rstest]
+    #[case::overlapping(interval(0, 10), interval(5, 15), vec![interval(0, 15)])]
+    #[case::contained(interval(0, 10), interval(2, 4), vec![interval(0, 10)])]
+    #[case::adjacent(interval(0, 10), interval(10, 20), vec![interval(0, 20)])]
+    #[case::disjoint(interval(0, 10), interval(15, 20), vec![interval(0, 10), interval(15, 20)])]
+    #[case::one_empty(interval(0, 10), interval(15, 15), vec![interval(0, 10)])]
+    #[case::both_empty(interval(10, 0), interval(15, 15), vec![])]
+    fn test_interval_union(
+        #[case] a: Interval<Tai>,
+        #[case] b: Interval<Tai>,
+        #[case] expected: Vec<Interval<Tai>>,
+    ) {
+        assert_eq!(a.union(&b), expected);
+        assert_eq!(b.union(&a), expected);
+    }
+
+    #[rstest]
+    #[case::inside(5, Some((interval(0, 5), interval(5, 10))))]
+    #[case::start(0, None)]
+    #[case::end(10, None)]
+    #[case::outside(15, None)]
+    fn test_interval_split_at(
+        #[case] seconds: i64,
+        #[case] expected: Option<(Interval<Tai>, Interval<Tai>)>,
+    ) {
+        let actual = interval(0, 10).split_at(tai(seconds));
+        assert_eq!(actual, expected);
+        if let Some((a, b)) = actual {
+            assert_eq!(a.duration() + b.duration(), TimeDelta::from_seconds(10));
+            assert_eq!(a.union(&b), vec![interval(0, 10)]);
+        }
+    }
+
+    #[rstest]
+    #[case::unsorted(
+        vec![interval(20, 30), interval(0, 10)],
+        TimeDelta::default(),
+        vec![interval(0, 10), interval(20, 30)],
+    )]
+    #[case::overlapping(
+        vec![interval(5, 15), interval(0, 10), interval(12, 13)],
+        TimeDelta::default(),
+        vec![interval(0, 15)],
+    )]
+    #[case::adjacent(
+        vec![interval(10, 20), interval(0, 10)],
+        TimeDelta::default(),
+        vec![interval(0, 20)],
+    )]
+    #[case::within_tolerance(
+        vec![interval(0, 10), interval(12, 20), interval(25, 30)],
+        TimeDelta::from_seconds(2),
+        vec![interval(0, 20), interval(25, 30)],
+    )]
+    #[case::empty(
+        vec![interval(0, 10), interval(15, 15), interval(40, 30)],
+        TimeDelta::from_seconds(10),
+        vec![interval(0, 10)],
+    )]
+    #[case::none(vec![], TimeDelta::default(), vec![])]
+    fn test_coalesce(
+        #[case] mut intervals: Vec<Interval<Tai>>,
+        #[case] tolerance: TimeDelta,
+        #[case] expected: Vec<Interval<Tai>>,
+    ) {
+        coalesce(&mut intervals, tolerance);
+        assert_eq!(intervals, expected);
+    }
+
+    #[test]
+    fn test_intersect_all() {
+        let sunlit = vec![interval(0, 50), interval(80, 150)];
+        let in_view = vec![interval(120, 200), interval(10, 30), interval(40, 90)];
+        let above_elevation = vec![interval(20, 100), interval(130, 140)];
+        let actual = intersect_all(&[sunlit, in_view, above_elevation]);
+        let expected = vec![
+            interval(20, 30),
+            interval(40, 50),
+            interval(80, 90),
+            interval(130, 140),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_intersect_all_empty() {
+        assert!(intersect_all::<Tai>(&[]).is_empty());
+        assert!(intersect_all(&[vec![interval(0, 10)], vec![]]).is_empty());
+        assert_eq!(
+            intersect_all(&[vec![interval(10, 20), interval(0, 10)]]),
+            vec![interval(0, 20)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_interval_self_intersection(
+            start in -1_000_000i64..1_000_000,
+            end in -1_000_000i64..1_000_000,
+        ) {
+            let interval = interval(start, end);
+            prop_assert_eq!(interval.intersection(&interval), interval);
+            let union = interval.union(&interval);
+            if interval.is_empty() {
+                prop_assert!(union.is_empty());
+            } else {
+                prop_assert_eq!(union, vec![interval]);
+            }
+        }
+
+        #[test]
+        fn prop_coalesce_sorted_and_disjoint(
+            bounds in prop::collection::vec((-1000i64..1000, -1000i64..1000), 0..50),
+        ) {
+            let mut intervals: Vec<Interval<Tai>> =
+                bounds.iter().map(|&(start, end)| interval(start, end)).collect();
+            let original = intervals.clone();
+            coalesce(&mut intervals, TimeDelta::default());
+            for pair in intervals.windows(2) {
+                prop_assert!(pair[0].end() < pair[1].start());
+            }
+            for &(start, _) in &bounds {
+                let covered = original.iter().any(|i| i.contains(tai(start)));
+                prop_assert_eq!(intervals.iter().any(|i| i.contains(tai(start))), covered);
+            }
+        }
+    }
+}
@@ -50,7 +50,7 @@ use constants::julian_dates::{
 use lox_math::constants::f64::time;
 use lox_math::types::units::Days;
 use time_of_day::{CivilTime, TimeOfDay, TimeOfDayError};
-use time_scales::{Tai, Tcb, Tcg, Tdb, Tt, Ut1};
+use time_scales::{DynTimeScale, Tai, Tcb, Tcg, Tdb, Tt, Ut1};
 
 use crate::calendar_dates::{CalendarDate, Date};
 use crate::deltas::{TimeDelta, ToDelta};
@@ -61,7 +61,9 @@ use crate::time_scales::TimeScale;
 pub mod calendar_dates;
 pub mod constants;
 pub mod deltas;
+pub mod intervals;
 pub mod julian_dates;
+pub mod ndm;
 pub mod prelude;
 #[cfg(feature = "python")]
 pub mod python;
@@ -128,6 +130,9 @@ pub struct Time<T: TimeScale> {
     subsecond: Subsecond,
 }
 
+/// A [Time] whose [TimeScale] is only known at runtime.
+pub type DynTime = Time<DynTimeScale>;
+
 impl<T: TimeScale> Time<T> {
     /// Instantiates a [Time] in the given [TimeScale] from the count of seconds since J2000, subdivided
     /// into integral seconds and [Subsecond].
@@ -162,12 +167,19 @@ impl<T: TimeScale> Time<T> {
         Ok(Self::new(scale, seconds, time.subsecond()))
     }
 
-    /// Instantiates a [Time] in the given [TimeScale] from an ISO 8601 string.
+    /// Instantiates a [Time] in the given [TimeScale] from an ISO 8601 string of the form
+    /// `YYYY-MM-DDTHH:MM:SS[.f] [SCALE]`, where the fractional seconds and the scale abbreviation
+    /// are optional.
     ///
     /// # Errors
     ///
-    /// * Returns `TimeError::InvalidIsoString` if `iso` is not a valid ISO 8601 timestamp.
+    /// * Returns `TimeError::InvalidIsoString` if `iso` is not a valid ISO 8601 timestamp or the
+    ///   scale abbreviation does not match `scale`.
+    /// * Returns `TimeError::DateError` if the date component is malformed or out of range.
+    /// * Returns `TimeError::TimeError` if the time component is malformed or out of range.
+    /// * Returns `TimeError::LeapSecondOutsideUtc` if the seconds component is 60.
     pub fn from_iso(scale: T, iso: &str) -> Result<Self, TimeError> {
+        let iso = iso.trim();
         let Some((date, time_and_scale)) = iso.split_once('T') else {
             return Err(TimeError::InvalidIsoString(iso.to_owned()));
         };
@@ -187,6 +199,16 @@ impl<T: TimeScale> Time<T> {
         Self::from_date_and_time(scale, date, time)
     }
 
+    /// Returns the ISO 8601 representation of the [Time] including the scale abbreviation, e.g.
+    /// `2000-01-01T12:00:00.000000000000000 TAI`.
+    ///
+    /// Unlike the [Display] implementation, which defaults to millisecond precision, the fractional
+    /// seconds are written with femtosecond precision so that the result can be parsed back with
+    /// [Time::from_iso] without loss.
+    pub fn to_iso(&self) -> String {
+        format!("{:.15}", self)
+    }
+
     /// Instantiates a [Time] in the given [TimeScale] and a [TimeDelta] relative to J2000.
     pub fn from_delta(scale: T, delta: TimeDelta) -> Self {
         Self {
@@ -254,10 +276,43 @@ impl<T: TimeScale> Time<T> {
         Ok(Self::new(scale, seconds, subsecond))
     }
 
+    /// Given a two-part Julian date, instantiates a [Time] in the specified [TimeScale].
+    ///
+    /// Splitting the date, e.g. into integral and fractional days, preserves sub-second precision.
     pub fn from_two_part_julian_date(scale: T, jd1: Days, jd2: Days) -> Result<Self, TimeError> {
+        Self::from_two_part_days(scale, jd1, jd2, SECONDS_BETWEEN_JD_AND_J2000)
+    }
+
+    /// Given a Modified Julian Date, instantiates a [Time] in the specified [TimeScale].
+    ///
+    /// The date is interpreted in the given time scale, e.g. the MJDs of Earth orientation
+    /// parameters are UTC dates.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::JulianDateOutOfRange` if `mjd` is NaN or ±infinity.
+    pub fn from_modified_julian_date(scale: T, mjd: Days) -> Result<Self, TimeError> {
+        Self::from_julian_date(scale, mjd, Epoch::ModifiedJulianDate)
+    }
+
+    /// Given a two-part Modified Julian Date, instantiates a [Time] in the specified [TimeScale].
+    pub fn from_two_part_modified_julian_date(
+        scale: T,
+        mjd1: Days,
+        mjd2: Days,
+    ) -> Result<Self, TimeError> {
+        Self::from_two_part_days(scale, mjd1, mjd2, SECONDS_BETWEEN_MJD_AND_J2000)
+    }
+
+    fn from_two_part_days(
+        scale: T,
+        jd1: Days,
+        jd2: Days,
+        epoch_offset: i64,
+    ) -> Result<Self, TimeError> {
         let seconds1 = jd1 * time::SECONDS_PER_DAY;
         let seconds2 = jd2 * time::SECONDS_PER_DAY;
-        let seconds = seconds1.trunc() + seconds2.trunc() - SECONDS_BETWEEN_JD_AND_J2000 as f64;
+        let seconds = seconds1.trunc() + seconds2.trunc() - epoch_offset as f64;
         if !(i64::MIN as f64..=i64::MAX as f64).contains(&seconds) {
             return Err(TimeError::JulianDateOutOfRange(JulianDateOutOfRange(
                 seconds,
@@ -344,6 +399,37 @@ impl<T: TimeScale> Time<T> {
     pub fn subsecond(&self) -> f64 {
         self.subsecond.into()
     }
+
+    /// Returns the Modified Julian Date in the time scale of `self`, i.e. the number of days since
+    /// 1858-11-17T00:00:00.
+    pub fn modified_julian_date(&self) -> Days {
+        self.julian_date(Epoch::ModifiedJulianDate, Unit::Days)
+    }
+
+    /// Returns the Modified Julian Date as the integral number of days and the fraction of the
+    /// current day, which retains the full sub-second precision of `self`.
+    pub fn two_part_modified_julian_date(&self) -> (Days, Days) {
+        self.two_part_days(SECONDS_BETWEEN_MJD_AND_J2000)
+    }
+
+    fn two_part_days(&self, epoch_offset: i64) -> (Days, Days) {
+        let seconds = self.seconds + epoch_offset;
+        let days = seconds.div_euclid(constants::i64::SECONDS_PER_DAY);
+        let seconds_of_day = seconds.rem_euclid(constants::i64::SECONDS_PER_DAY);
+        let fraction = (seconds_of_day as f64 + self.subsecond.0) / time::SECONDS_PER_DAY;
+        (days as f64, fraction)
+    }
+
+    /// Returns the number of whole nanoseconds since the last whole second.
+    pub fn subsecond_nanos(&self) -> u32 {
+        ((self.subsecond.0 * 1e9).trunc() as u32).min(999_999_999)
+    }
+
+    /// Returns the number of whole seconds since J2000 and the number of whole nanoseconds since
+    /// the last whole second, e.g. for interoperability with [std::time::Duration].
+    pub fn seconds_and_nanos(&self) -> (i64, u32) {
+        (self.seconds, self.subsecond_nanos())
+    }
 }
 
 impl<T: TimeScale> IsClose for Time<T> {
@@ -384,6 +470,12 @@ impl<T: TimeScale> JulianDate for Time<T> {
             Unit::Centuries => decimal_seconds / time::SECONDS_PER_JULIAN_CENTURY,
         }
     }
+
+    /// Returns the integral number of days since the Julian epoch and the fraction of the current
+    /// day, which retains the full sub-second precision of `self`.
+    fn two_part_julian_date(&self) -> (f64, f64) {
+        self.two_part_days(SECONDS_BETWEEN_JD_AND_J2000)
+    }
 }
 
 impl<T: TimeScale> Display for Time<T> {
@@ -669,6 +761,16 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_time_seconds_and_nanos() {
+        let time = Time::new(Tai, -1234567890, Subsecond(0.9876543219));
+        assert_eq!(time.subsecond_nanos(), 987654321);
+        assert_eq!(time.seconds_and_nanos(), (-1234567890, 987654321));
+        let time = Time::new(Tai, 42, Subsecond(0.9999999999999));
+        assert_eq!(time.seconds_and_nanos(), (42, 999999999));
+        assert_eq!(Time::j2000(Tai).seconds_and_nanos(), (0, 0));
+    }
+
     #[rstest]
     #[case(Epoch::JulianDate, -SECONDS_BETWEEN_JD_AND_J2000)]
     #[case(Epoch::ModifiedJulianDate, -SECONDS_BETWEEN_MJD_AND_J2000)]
@@ -693,6 +795,71 @@ mod tests {
         assert_close!(t0, t1);
     }
 
+    #[test]
+    fn test_time_two_part_julian_date_precision() {
+        // 2024-07-11T08:02:14.000000001 TAI
+        let t0 = time!(Tai, 2024, 7, 11, 8, 2, 14.0).unwrap()
+            + TimeDelta::from_decimal_seconds(1e-9).unwrap();
+        let (jd1, jd2) = t0.two_part_julian_date();
+        assert_eq!(jd1, 2460502.0);
+        assert_float_eq!(
+            jd2,
+            (20.0 * 3600.0 + 2.0 * 60.0 + 14.0 + 1e-9) / 86400.0,
+            abs <= 1e-17
+        );
+        let t1 = Time::from_two_part_julian_date(Tai, jd1, jd2).unwrap();
+        assert_float_eq!(t1.subsecond(), 1e-9, abs <= 1e-11);
+    }
+
+    #[rstest]
+    #[case::year_1000(time!(Tai, 1000, 3, 1, 23, 59, 59.0).unwrap())]
+    #[case::year_1858(Time::mjd0(Tai))]
+    #[case::year_2024(time!(Tai, 2024, 7, 11, 8, 2, 14.0).unwrap())]
+    #[case::year_3000(time!(Tai, 3000, 12, 31, 11, 59, 59.0).unwrap())]
+    fn test_time_two_part_julian_date_round_trip(#[case] time: Time<Tai>) {
+        let t0 = time + TimeDelta::from_decimal_seconds(0.123456789).unwrap();
+        let (jd1, jd2) = t0.two_part_julian_date();
+        assert_eq!(jd1.fract(), 0.0);
+        assert!((0.0..1.0).contains(&jd2));
+        let t1 = Time::from_two_part_julian_date(Tai, jd1, jd2).unwrap();
+        // The fraction of the day is limited by the resolution of an `f64` close to one day, i.e.
+        // about 10 picoseconds.
+        assert_float_eq!((t1 - t0).to_decimal_seconds(), 0.0, abs <= 2e-11);
+        assert_eq!(t1.two_part_julian_date(), (jd1, jd2));
+    }
+
+    #[test]
+    fn test_time_modified_julian_date() {
+        let t0 = time!(Tai, 2024, 7, 11, 8, 2, 14.5).unwrap();
+        let seconds_of_day = 8.0 * 3600.0 + 2.0 * 60.0 + 14.5;
+        assert_float_eq!(
+            t0.modified_julian_date(),
+            60502.0 + seconds_of_day / 86400.0,
+            rel <= 1e-15
+        );
+        let (mjd1, mjd2) = t0.two_part_modified_julian_date();
+        assert_eq!(mjd1, 60502.0);
+        assert_eq!(mjd2, seconds_of_day / 86400.0);
+        let t1 = Time::from_two_part_modified_julian_date(Tai, mjd1, mjd2).unwrap();
+        assert_close!(t0, t1);
+        let t2 = Time::from_modified_julian_date(Tai, t0.modified_julian_date()).unwrap();
+        assert_close!(t0, t2);
+
+        // Consistent with the MJD-keyed Earth orientation parameters
+        let mjd = 41684.0;
+        let t3 = Time::from_modified_julian_date(Tai, mjd).unwrap();
+        assert_eq!(
+            t3.seconds(),
+            41684 * SECONDS_PER_DAY - SECONDS_BETWEEN_MJD_AND_J2000
+        );
+        assert_eq!(t3.two_part_modified_julian_date(), (mjd, 0.0));
+
+        // Before the epoch the fraction of the day stays positive
+        let t4 = Time::mjd0(Tai) - TimeDelta::from_hours(6.0).unwrap();
+        assert_eq!(t4.two_part_modified_julian_date(), (-1.0, 0.75));
+        assert_eq!(t4.modified_julian_date(), -0.25);
+    }
+
     #[rstest]
     #[case(i64::MAX as f64, 1.0)]
     #[case(i64::MIN as f64, -1.0)]
@@ -758,10 +925,29 @@ mod tests {
     #[rstest]
     #[case("2000-01-01T00:00:00", Ok(time!(Tai, 2000, 1, 1).unwrap()))]
     #[case("2000-01-01T00:00:00 TAI", Ok(time!(Tai, 2000, 1, 1).unwrap()))]
+    #[case("2004-281T17:26:06 TAI", Ok(time!(Tai, 2004, 10, 7, 17, 26, 6.0).unwrap()))]
     #[case("2000-1-01T00:00:00", Err(TimeError::DateError(DateError::InvalidIsoString("2000-1-01".to_string()))))]
     #[case("2000-01-01T0:00:00", Err(TimeError::TimeError(TimeOfDayError::InvalidIsoString("0:00:00".to_string()))))]
     #[case("2000-01-01-00:00:00", Err(TimeError::InvalidIsoString("2000-01-01-00:00:00".to_string())))]
     #[case("2000-01-01T00:00:00 UTC", Err(TimeError::InvalidIsoString("2000-01-01T00:00:00 UTC".to_string())))]
+    #[case(
+        "2000-13-01T00:00:00",
+        Err(TimeError::DateError(DateError::InvalidDate(2000, 13, 1)))
+    )]
+    #[case(
+        "2000-01-01T24:00:00",
+        Err(TimeError::TimeError(TimeOfDayError::InvalidHour(24)))
+    )]
+    #[case(
+        "2000-01-01T00:60:00",
+        Err(TimeError::TimeError(TimeOfDayError::InvalidMinute(60)))
+    )]
+    #[case(
+        "2000-01-01T00:00:61",
+        Err(TimeError::TimeError(TimeOfDayError::InvalidSecond(61)))
+    )]
+    #[case("2000-01-01T23:59:60", Err(TimeError::LeapSecondOutsideUtc))]
+    #[case("2000-01-01T00:00:000", Err(TimeError::TimeError(TimeOfDayError::InvalidIsoString("00:00:000".to_string()))))]
     fn test_time_from_str_tai(#[case] iso: &str, #[case] expected: Result<Time<Tai>, TimeError>) {
         let actual: Result<Time<Tai>, TimeError> = iso.parse();
         assert_eq!(actual, expected)
@@ -838,6 +1024,17 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[rstest]
+    #[case("2000-01-01T12:00:00.000000000000000 TAI")]
+    #[case("2024-03-05T01:02:03.123456789012345 TAI")]
+    #[case("1969-07-20T20:17:40.000000000000001 TAI")]
+    #[case("-0001-12-31T23:59:59.999999999999000 TAI")]
+    fn test_time_iso_round_trip(#[case] iso: &str) {
+        let time = Time::from_iso(Tai, iso).unwrap();
+        assert_eq!(time.to_iso(), iso);
+        assert_eq!(Time::from_iso(Tai, &time.to_iso()).unwrap(), time);
+    }
+
     #[test]
     fn test_time_j2000() {
         let actual = Time::j2000(Tai);
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module `ndm` converts the epochs of CCSDS Navigation Data Messages (NDM) into [DynTime]s
+//! according to their declared `TIME_SYSTEM`.
+
+use lox_io::ndm::oem::OemSegment;
+use thiserror::Error;
+
+use crate::time_scales::DynTimeScale;
+use crate::transformations::ToTai;
+use crate::utc::Utc;
+use crate::{DynTime, Time};
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum NdmEpochError {
+    #[error("unsupported time system `{0}`")]
+    UnsupportedTimeSystem(String),
+    #[error("invalid epoch `{0}`")]
+    InvalidEpoch(String),
+}
+
+/// Parses an NDM `epoch` in the given `time_system`.
+///
+/// As a discontinuous time scale, UTC is not represented by [DynTimeScale]. UTC epochs are
+/// therefore converted to TAI using the built-in leap seconds table. Epochs in all other time
+/// systems retain their declared time scale.
+///
+/// # Errors
+///
+/// * Returns [NdmEpochError::UnsupportedTimeSystem] if `time_system` is neither UTC nor a known
+///   [DynTimeScale].
+/// * Returns [NdmEpochError::InvalidEpoch] if `epoch` is not a valid ISO 8601 string.
+pub fn parse_ndm_epoch(time_system: &str, epoch: &str) -> Result<DynTime, NdmEpochError> {
+    if time_system.eq_ignore_ascii_case("UTC") {
+        let utc =
+            Utc::from_iso(epoch).map_err(|_| NdmEpochError::InvalidEpoch(epoch.to_string()))?;
+        return Ok(utc.to_tai().with_scale(DynTimeScale::Tai));
+    }
+    let scale: DynTimeScale = time_system
+        .parse()
+        .map_err(|_| NdmEpochError::UnsupportedTimeSystem(time_system.to_string()))?;
+    Time::from_iso(scale, epoch).map_err(|_| NdmEpochError::InvalidEpoch(epoch.to_string()))
+}
+
+/// Parses the epochs of all state vectors of an OEM `segment` in the `TIME_SYSTEM` declared by
+/// its metadata.
+pub fn oem_epochs(segment: &OemSegment) -> Result<Vec<DynTime>, NdmEpochError> {
+    let time_system = &segment.metadata.time_system;
+    segment
+        .data
+        .state_vector_list
+        .iter()
+        .map(|state| parse_ndm_epoch(time_system, &state.epoch.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use lox_io::ndm::kvn::KvnDeserializer;
+    use lox_io::ndm::oem::OemType;
+    use rstest::rstest;
+
+    use crate::time;
+    use crate::time_scales::Tai;
+
+    use super::*;
+
+    #[rstest]
+    #[case("TAI", DynTimeScale::Tai)]
+    #[case("tt", DynTimeScale::Tt)]
+    #[case("TDB", DynTimeScale::Tdb)]
+    #[case("TCB", DynTimeScale::Tcb)]
+    #[case("TCG", DynTimeScale::Tcg)]
+    #[case("UT1", DynTimeScale::Ut1)]
+    fn test_parse_ndm_epoch(#[case] time_system: &str, #[case] scale: DynTimeScale) {
+        let actual = parse_ndm_epoch(time_system, "2024-01-01T12:00:00.000").unwrap();
+        let expected = time!(scale, 2024, 1, 1, 12).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_ndm_epoch_utc() {
+        let actual = parse_ndm_epoch("UTC", "2016-12-31T23:59:60.000").unwrap();
+        let expected = time!(Tai, 2017, 1, 1, 0, 0, 36.0).unwrap();
+        assert_eq!(actual, expected.with_scale(DynTimeScale::Tai));
+    }
+
+    #[test]
+    fn test_parse_ndm_epoch_errors() {
+        assert_eq!(
+            parse_ndm_epoch("GMST", "2024-01-01T12:00:00.000"),
+            Err(NdmEpochError::UnsupportedTimeSystem("GMST".to_string()))
+        );
+        assert_eq!(
+            parse_ndm_epoch("TAI", "yesterday"),
+            Err(NdmEpochError::InvalidEpoch("yesterday".to_string()))
+        );
+        assert_eq!(
+            parse_ndm_epoch("TAI", "2024-01-01T23:59:60.000"),
+            Err(NdmEpochError::InvalidEpoch(
+                "2024-01-01T23:59:60.000".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_oem_epochs() {
+        let oem: OemType = KvnDeserializer::from_kvn_str(
+            "CCSDS_OEM_VERS = 2.0
+CREATION_DATE = 2024-01-02T00:00:00
+ORIGINATOR = LOX
+META_START
+OBJECT_NAME = SATELLITE
+OBJECT_ID = 2024-001A
+CENTER_NAME = EARTH
+REF_FRAME = EME2000
+TIME_SYSTEM = TT
+START_TIME = 2024-01-01T00:00:00.000
+STOP_TIME = 2024-01-01T00:01:00.000
+META_STOP
+
+2024-01-01T00:00:00.000 7000.0 0.0 0.0 0.0 7.5 0.0
+2024-01-01T00:01:00.000 6996.0 450.0 0.0 -0.5 7.5 0.0
+
+",
+        )
+        .unwrap();
+        let epochs = oem_epochs(&oem.body.segment_list[0]).unwrap();
+        assert_eq!(
+            epochs,
+            vec![
+                time!(DynTimeScale::Tt, 2024, 1, 1).unwrap(),
+                time!(DynTimeScale::Tt, 2024, 1, 1, 0, 1).unwrap(),
+            ]
+        );
+    }
+}
@@ -174,6 +174,10 @@ impl PyTime {
         self.0.subsecond()
     }
 
+    pub fn to_iso(&self) -> String {
+        self.0.to_iso()
+    }
+
     #[classattr]
     const __hash__: Option<PyObject> = None;
 
@@ -521,6 +525,9 @@ mod tests {
             "Time(\"TAI\", 2000, 1, 1, 0, 0, 12.123456789123)"
         );
         assert_eq!(time.__str__(), "2000-01-01T00:00:12.123 TAI");
+        assert!(time
+            .to_iso()
+            .starts_with("2000-01-01T00:00:12.123456789123"));
         assert_eq!(time.scale(), "TAI".to_string());
         assert_eq!(time.year(), 2000);
         assert_eq!(time.month(), 1);
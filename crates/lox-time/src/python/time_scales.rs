@@ -6,7 +6,7 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::prelude::{Tai, Tcb, Tcg, Tdb, TimeScale, Tt, Ut1};
+use crate::prelude::{DynTimeScale, Tai, Tcb, Tcg, Tdb, TimeScale, Tt, UnknownTimeScaleError, Ut1};
 use pyo3::exceptions::PyValueError;
 use pyo3::PyErr;
 use std::fmt::{Display, Formatter};
@@ -22,22 +22,30 @@ pub enum PyTimeScale {
     Ut1,
 }
 
+impl From<UnknownTimeScaleError> for PyErr {
+    fn from(value: UnknownTimeScaleError) -> Self {
+        PyValueError::new_err(value.to_string())
+    }
+}
+
+impl From<DynTimeScale> for PyTimeScale {
+    fn from(scale: DynTimeScale) -> Self {
+        match scale {
+            DynTimeScale::Tai => PyTimeScale::Tai,
+            DynTimeScale::Tcb => PyTimeScale::Tcb,
+            DynTimeScale::Tcg => PyTimeScale::Tcg,
+            DynTimeScale::Tdb => PyTimeScale::Tdb,
+            DynTimeScale::Tt => PyTimeScale::Tt,
+            DynTimeScale::Ut1 => PyTimeScale::Ut1,
+        }
+    }
+}
+
 impl FromStr for PyTimeScale {
     type Err = PyErr;
 
     fn from_str(name: &str) -> Result<Self, Self::Err> {
-        match name {
-            "TAI" => Ok(PyTimeScale::Tai),
-            "TCB" => Ok(PyTimeScale::Tcb),
-            "TCG" => Ok(PyTimeScale::Tcg),
-            "TDB" => Ok(PyTimeScale::Tdb),
-            "TT" => Ok(PyTimeScale::Tt),
-            "UT1" => Ok(PyTimeScale::Ut1),
-            _ => Err(PyValueError::new_err(format!(
-                "invalid time scale: {}",
-                name
-            ))),
-        }
+        Ok(name.parse::<DynTimeScale>()?.into())
     }
 }
 
@@ -6,9 +6,16 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::iter::FusedIterator;
 use std::ops::RangeInclusive;
 
+use num::ToPrimitive;
+use thiserror::Error;
+
 use crate::deltas::TimeDelta;
+use crate::subsecond::Subsecond;
+use crate::time_scales::TimeScale;
+use crate::Time;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TimeDeltaRange {
@@ -86,10 +93,121 @@ impl Iterator for TimeDeltaRange {
     }
 }
 
+/// Error type returned when attempting to construct a [TimeRange] with a zero-length step.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("the step of a time range must not be zero")]
+pub struct ZeroStepError;
+
+/// An iterator over the instants from `start` to `stop` (inclusive) separated by a fixed `step`.
+///
+/// The `n`-th element is computed as `start + n * step` rather than by repeated addition, so
+/// rounding errors do not accumulate over long ranges. A negative `step` produces a descending
+/// range. If `step` does not evenly divide the span, the last element is the final instant before
+/// `stop`. A range whose `step` points away from `stop` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange<T: TimeScale> {
+    start: Time<T>,
+    step: TimeDelta,
+    len: usize,
+    index: usize,
+}
+
+impl<T: TimeScale + Copy> TimeRange<T> {
+    pub fn new(start: Time<T>, stop: Time<T>, step: TimeDelta) -> Result<Self, ZeroStepError> {
+        if step.is_zero() {
+            return Err(ZeroStepError);
+        }
+        let len = range_len(start, stop, step);
+        Ok(Self {
+            start,
+            step,
+            len,
+            index: 0,
+        })
+    }
+
+    pub fn start(&self) -> Time<T> {
+        self.start
+    }
+
+    pub fn step(&self) -> TimeDelta {
+        self.step
+    }
+
+    fn nth_time(&self, n: usize) -> Time<T> {
+        self.start + multiply(self.step, n as i64)
+    }
+}
+
+/// Computes `delta * n` exactly in the integral part and with a single rounding in the fractional
+/// part.
+fn multiply(delta: TimeDelta, n: i64) -> TimeDelta {
+    let subsecond = delta.subsecond.0 * n as f64;
+    let carry = subsecond.floor();
+    TimeDelta {
+        seconds: delta.seconds * n + carry.to_i64().unwrap(),
+        subsecond: Subsecond(subsecond - carry),
+    }
+}
+
+fn range_len<T: TimeScale + Copy>(start: Time<T>, stop: Time<T>, step: TimeDelta) -> usize {
+    let within = |t: Time<T>| {
+        let remaining = stop - t;
+        remaining.is_zero() || remaining.is_positive() == step.is_positive()
+    };
+    if !within(start) {
+        return 0;
+    }
+    // Estimate the number of steps in floating point and correct the estimate with exact
+    // comparisons.
+    let span = (stop - start).to_decimal_seconds();
+    let mut n = (span / step.to_decimal_seconds())
+        .floor()
+        .to_i64()
+        .unwrap_or(0)
+        .max(0);
+    while n > 0 && !within(start + multiply(step, n)) {
+        n -= 1;
+    }
+    while within(start + multiply(step, n + 1)) {
+        n += 1;
+    }
+    n as usize + 1
+}
+
+impl<T: TimeScale + Copy> Iterator for TimeRange<T> {
+    type Item = Time<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let time = self.nth_time(self.index);
+        self.index += 1;
+        Some(time)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl<T: TimeScale + Copy> ExactSizeIterator for TimeRange<T> {}
+
+impl<T: TimeScale + Copy> FusedIterator for TimeRange<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::time_scales::Tai;
+
     #[test]
     fn test_time_delta_range() {
         let range = TimeDeltaRange::new(TimeDelta::from_seconds(0), TimeDelta::from_seconds(10));
@@ -99,4 +217,66 @@ mod tests {
         assert_eq!(values[1], TimeDelta::from_seconds(1));
         assert_eq!(values[10], TimeDelta::from_seconds(10));
     }
+
+    #[test]
+    fn test_time_range() {
+        let start = Time::new(Tai, 0, Subsecond::default());
+        let stop = start + TimeDelta::from_seconds(10);
+        let range = TimeRange::new(start, stop, TimeDelta::from_seconds(2)).unwrap();
+        assert_eq!(range.len(), 6);
+        let values: Vec<Time<Tai>> = range.collect();
+        assert_eq!(values[0], start);
+        assert_eq!(values[1], start + TimeDelta::from_seconds(2));
+        assert_eq!(values[5], stop);
+    }
+
+    #[test]
+    fn test_time_range_uneven_step() {
+        let start = Time::new(Tai, 0, Subsecond::default());
+        let stop = start + TimeDelta::from_seconds(10);
+        let values: Vec<Time<Tai>> = TimeRange::new(start, stop, TimeDelta::from_seconds(3))
+            .unwrap()
+            .collect();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[3], start + TimeDelta::from_seconds(9));
+    }
+
+    #[test]
+    fn test_time_range_descending() {
+        let start = Time::new(Tai, 10, Subsecond::default());
+        let stop = Time::new(Tai, 0, Subsecond::default());
+        let values: Vec<Time<Tai>> = TimeRange::new(start, stop, -TimeDelta::from_seconds(3))
+            .unwrap()
+            .collect();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[0], start);
+        assert_eq!(values[3], Time::new(Tai, 1, Subsecond::default()));
+    }
+
+    #[test]
+    fn test_time_range_wrong_direction() {
+        let start = Time::new(Tai, 0, Subsecond::default());
+        let stop = start + TimeDelta::from_seconds(10);
+        let mut range = TimeRange::new(start, stop, -TimeDelta::from_seconds(1)).unwrap();
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn test_time_range_zero_step() {
+        let start = Time::new(Tai, 0, Subsecond::default());
+        let range = TimeRange::new(start, start, TimeDelta::default());
+        assert_eq!(range, Err(ZeroStepError));
+    }
+
+    #[test]
+    fn test_time_range_no_drift() {
+        let start = Time::new(Tai, 0, Subsecond::default());
+        let stop = start + TimeDelta::from_seconds(10000);
+        let step = TimeDelta::from_decimal_seconds(0.1).unwrap();
+        let mut range = TimeRange::new(start, stop, step).unwrap();
+        assert_eq!(range.len(), 100001);
+        assert_eq!(range.nth(100000), Some(stop));
+        assert_eq!(range.next(), None);
+    }
 }
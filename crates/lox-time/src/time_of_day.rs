@@ -30,7 +30,8 @@ use crate::{
 fn iso_regex() -> &'static Regex {
     static ISO: OnceLock<Regex> = OnceLock::new();
     ISO.get_or_init(|| {
-        Regex::new(r"(?<hour>\d{2}):(?<minute>\d{2}):(?<second>\d{2})(?<subsecond>\.\d+)?").unwrap()
+        Regex::new(r"^(?<hour>\d{2}):(?<minute>\d{2}):(?<second>\d{2})(?<subsecond>\.\d+)?$")
+            .unwrap()
     })
 }
 
@@ -343,6 +344,9 @@ mod tests {
     #[case("2:13:14.123", Err(TimeOfDayError::InvalidIsoString("2:13:14.123".to_string())))]
     #[case("12:3:14.123", Err(TimeOfDayError::InvalidIsoString("12:3:14.123".to_string())))]
     #[case("12:13:4.123", Err(TimeOfDayError::InvalidIsoString("12:13:4.123".to_string())))]
+    #[case("12:13:145", Err(TimeOfDayError::InvalidIsoString("12:13:145".to_string())))]
+    #[case("12:13:14.", Err(TimeOfDayError::InvalidIsoString("12:13:14.".to_string())))]
+    #[case("12:13:14xyz", Err(TimeOfDayError::InvalidIsoString("12:13:14xyz".to_string())))]
     fn test_time_of_day_from_string(
         #[case] iso: &str,
         #[case] expected: Result<TimeOfDay, TimeOfDayError>,
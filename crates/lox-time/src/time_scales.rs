@@ -16,6 +16,11 @@
     exclusively as an IO format.
 */
 
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use thiserror::Error;
+
 /// Marker trait denoting a continuous astronomical time scale.
 pub trait TimeScale {
     fn abbreviation(&self) -> &'static str;
@@ -100,6 +105,72 @@ impl TimeScale for Ut1 {
     }
 }
 
+/// Runtime representation of the continuous time scales supported by Lox.
+///
+/// [DynTimeScale] implements [FromStr] and [Display] and is the canonical mapping between time
+/// scale abbreviations and time scales, e.g. for the `TIME_SYSTEM` of CCSDS navigation data
+/// messages.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum DynTimeScale {
+    Tai,
+    Tcb,
+    Tcg,
+    Tdb,
+    Tt,
+    Ut1,
+}
+
+impl TimeScale for DynTimeScale {
+    fn abbreviation(&self) -> &'static str {
+        match self {
+            DynTimeScale::Tai => Tai.abbreviation(),
+            DynTimeScale::Tcb => Tcb.abbreviation(),
+            DynTimeScale::Tcg => Tcg.abbreviation(),
+            DynTimeScale::Tdb => Tdb.abbreviation(),
+            DynTimeScale::Tt => Tt.abbreviation(),
+            DynTimeScale::Ut1 => Ut1.abbreviation(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            DynTimeScale::Tai => Tai.name(),
+            DynTimeScale::Tcb => Tcb.name(),
+            DynTimeScale::Tcg => Tcg.name(),
+            DynTimeScale::Tdb => Tdb.name(),
+            DynTimeScale::Tt => Tt.name(),
+            DynTimeScale::Ut1 => Ut1.name(),
+        }
+    }
+}
+
+impl Display for DynTimeScale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("invalid time scale: {0}, expected one of TAI, TCB, TCG, TDB, TT or UT1")]
+pub struct UnknownTimeScaleError(String);
+
+impl FromStr for DynTimeScale {
+    type Err = UnknownTimeScaleError;
+
+    /// Parses a time scale from its abbreviation, ignoring case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_uppercase().as_str() {
+            "TAI" => Ok(DynTimeScale::Tai),
+            "TCB" => Ok(DynTimeScale::Tcb),
+            "TCG" => Ok(DynTimeScale::Tcg),
+            "TDB" => Ok(DynTimeScale::Tdb),
+            "TT" => Ok(DynTimeScale::Tt),
+            "UT1" => Ok(DynTimeScale::Ut1),
+            _ => Err(UnknownTimeScaleError(name.to_owned())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +191,39 @@ mod tests {
         assert_eq!(scale.abbreviation(), abbreviation);
         assert_eq!(scale.name(), name);
     }
+
+    #[rstest]
+    #[case("TAI", DynTimeScale::Tai)]
+    #[case("tcb", DynTimeScale::Tcb)]
+    #[case("Tcg", DynTimeScale::Tcg)]
+    #[case("TDB", DynTimeScale::Tdb)]
+    #[case("tt", DynTimeScale::Tt)]
+    #[case("UT1", DynTimeScale::Ut1)]
+    fn test_dyn_time_scale_from_str(#[case] name: &str, #[case] expected: DynTimeScale) {
+        let scale: DynTimeScale = name.parse().unwrap();
+        assert_eq!(scale, expected);
+        assert_eq!(scale.to_string(), name.to_uppercase());
+        assert_eq!(scale.to_string().parse(), Ok(scale));
+    }
+
+    #[test]
+    fn test_dyn_time_scale_from_str_unknown() {
+        let err = "UTC".parse::<DynTimeScale>().unwrap_err();
+        assert_eq!(err, UnknownTimeScaleError("UTC".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "invalid time scale: UTC, expected one of TAI, TCB, TCG, TDB, TT or UT1"
+        );
+    }
+
+    #[rstest]
+    #[case(DynTimeScale::Tai, Tai.name())]
+    #[case(DynTimeScale::Tcb, Tcb.name())]
+    #[case(DynTimeScale::Tcg, Tcg.name())]
+    #[case(DynTimeScale::Tdb, Tdb.name())]
+    #[case(DynTimeScale::Tt, Tt.name())]
+    #[case(DynTimeScale::Ut1, Ut1.name())]
+    fn test_dyn_time_scale_name(#[case] scale: DynTimeScale, #[case] name: &str) {
+        assert_eq!(scale.name(), name);
+    }
 }
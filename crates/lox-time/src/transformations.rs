@@ -499,6 +499,64 @@ impl<T: DeltaUt1TaiProvider> TryToScale<Tdb, T> for Time<Ut1> {
     }
 }
 
+// Elapsed time conversions
+
+/// Implemented by [TimeScale]s whose mean rate relative to [Tt] is constant.
+///
+/// [Ut1] is excluded because its rate follows the irregular rotation of the Earth.
+pub trait MeanRate: TimeScale {
+    /// The fractional rate of [Tt] with respect to this scale, i.e. `dTT/dX - 1`.
+    fn rate_relative_to_tt(&self) -> f64;
+}
+
+impl MeanRate for Tai {
+    fn rate_relative_to_tt(&self) -> f64 {
+        0.0
+    }
+}
+
+impl MeanRate for Tt {
+    fn rate_relative_to_tt(&self) -> f64 {
+        0.0
+    }
+}
+
+/// [Tdb] is defined to match [Tt] on average, the difference between the two is purely periodic.
+impl MeanRate for Tdb {
+    fn rate_relative_to_tt(&self) -> f64 {
+        0.0
+    }
+}
+
+impl MeanRate for Tcg {
+    fn rate_relative_to_tt(&self) -> f64 {
+        -LG
+    }
+}
+
+impl MeanRate for Tcb {
+    fn rate_relative_to_tt(&self) -> f64 {
+        -LB
+    }
+}
+
+/// `ToScaleDelta` converts an elapsed interval measured in one [TimeScale] into the equivalent
+/// interval in another by applying the defining rates L_G and L_B.
+///
+/// Unlike [ToScale], which transforms an instant, this conversion only accounts for the different
+/// rates at which the scales tick and ignores constant and periodic offsets.
+pub trait ToScaleDelta<T: MeanRate>: MeanRate {
+    fn to_scale_delta(&self, delta: TimeDelta, scale: T) -> TimeDelta {
+        let source = self.rate_relative_to_tt();
+        let target = scale.rate_relative_to_tt();
+        // Only the small correction is scaled to avoid losing the precision of `delta`.
+        let factor = (source - target) / (1.0 + target);
+        delta + delta.scale(factor)
+    }
+}
+
+impl<T: MeanRate, U: MeanRate> ToScaleDelta<T> for U {}
+
 /// Implementers of `LeapSecondsProvider` provide the offset between TAI and UTC in leap seconds at
 /// an instant in either time scale.
 pub trait LeapSecondsProvider: OffsetProvider {
@@ -738,4 +796,50 @@ mod tests {
         let tt = tdb.to_tt();
         assert_eq!(expected, tt);
     }
+
+    #[test]
+    fn test_to_scale_delta_tcb_tt() {
+        let one_year = TimeDelta::from_julian_years(1.0).unwrap();
+        let tt = Tcb.to_scale_delta(one_year, Tt);
+        let diff = (one_year - tt).to_decimal_seconds();
+        assert_float_eq!(diff, LB * 31557600.0, rel <= 1e-9);
+        assert!(diff > 0.48 && diff < 0.49);
+
+        let tcb = Tt.to_scale_delta(tt, Tcb);
+        assert_float_eq!(
+            tcb.to_decimal_seconds(),
+            one_year.to_decimal_seconds(),
+            abs <= 1e-9
+        );
+
+        // Agrees with the difference of two transformed instants up to the periodic TDB terms.
+        let t0 = time!(Tcb, 2024, 1, 1).unwrap();
+        let t1 = t0 + one_year;
+        let elapsed = (t1.to_tt() - t0.to_tt()).to_decimal_seconds();
+        assert_float_eq!(elapsed, tt.to_decimal_seconds(), abs <= 2e-3);
+    }
+
+    #[test]
+    fn test_to_scale_delta_tcg_tt() {
+        let one_day = TimeDelta::from_days(1.0).unwrap();
+        let tt = Tcg.to_scale_delta(one_day, Tt);
+        assert_float_eq!(
+            (one_day - tt).to_decimal_seconds(),
+            LG * 86400.0,
+            rel <= 1e-9
+        );
+        let tcg = Tt.to_scale_delta(tt, Tcg);
+        assert_float_eq!(
+            tcg.to_decimal_seconds(),
+            one_day.to_decimal_seconds(),
+            abs <= 1e-12
+        );
+    }
+
+    #[test]
+    fn test_to_scale_delta_same_rate() {
+        let delta = TimeDelta::from_decimal_seconds(123.456).unwrap();
+        assert_eq!(Tai.to_scale_delta(delta, Tt), delta);
+        assert_eq!(Tt.to_scale_delta(delta, Tdb), delta);
+    }
 }
@@ -52,6 +52,8 @@ pub enum DeltaUt1TaiError {
     Csv(#[from] ParseFinalsCsvError),
     #[error(transparent)]
     Series(#[from] SeriesError),
+    #[error("no leap seconds available for EOP record on {0}")]
+    MissingLeapSeconds(Date),
 }
 
 /// Error type indicating that an input date to [DeltaUt1Tai] was outside the range of available
@@ -96,12 +98,30 @@ impl DeltaUt1Tai {
     /// # Errors
     ///
     /// - [DeltaUt1TaiError::Csv] if the CSV file could not be parsed.
-    /// - [DeltaUt1TaiError::Series] if construction of a cubic spline from the input series fails.
+    /// - Any error returned by [DeltaUt1Tai::from_eop].
     pub fn new<P: AsRef<Path>>(
         path: P,
         ls: &impl LeapSecondsProvider,
     ) -> Result<Self, DeltaUt1TaiError> {
         let eop = EarthOrientationParams::parse_finals_csv(path)?;
+        Self::from_eop(&eop, ls)
+    }
+
+    /// Instantiates a new [DeltaUt1Tai] provider from previously loaded
+    /// [EarthOrientationParams] and a [LeapSecondsProvider].
+    ///
+    /// UT1-UTC from the EOP data is combined with TAI-UTC from `ls` to tabulate UT1-TAI, so `ls`
+    /// must provide leap second data for the full range of the EOP data.
+    ///
+    /// # Errors
+    ///
+    /// - [DeltaUt1TaiError::MissingLeapSeconds] if `ls` provides no leap second data for an EOP
+    ///   record.
+    /// - [DeltaUt1TaiError::Series] if construction of a cubic spline from the input series fails.
+    pub fn from_eop(
+        eop: &EarthOrientationParams,
+        ls: &impl LeapSecondsProvider,
+    ) -> Result<Self, DeltaUt1TaiError> {
         let deltas: Vec<TimeDelta> = eop
             .mjd()
             .iter()
@@ -111,15 +131,17 @@ impl DeltaUt1Tai {
                 )
             })
             .collect();
-        let delta_ut1_tai: Vec<f64> = zip(deltas.clone(), eop.delta_ut1_utc().iter())
-            .map(|(delta, &delta_ut1_utc)| {
+        let delta_ut1_tai: Vec<f64> = zip(deltas.iter(), eop.delta_ut1_utc().iter())
+            .map(|(&delta, &delta_ut1_utc)| {
                 let utc = Utc::from_delta(delta);
-                let delta_utc_tai = ls.delta_utc_tai(utc).unwrap();
+                let delta_utc_tai = ls
+                    .delta_utc_tai(utc)
+                    .ok_or(DeltaUt1TaiError::MissingLeapSeconds(utc.date()))?;
                 let delta_ut1_tai =
                     TimeDelta::from_decimal_seconds(delta_ut1_utc).unwrap() + delta_utc_tai;
-                delta_ut1_tai.to_decimal_seconds()
+                Ok(delta_ut1_tai.to_decimal_seconds())
             })
-            .collect();
+            .collect::<Result<_, DeltaUt1TaiError>>()?;
         let seconds: Vec<f64> = deltas.iter().map(|dt| dt.to_decimal_seconds()).collect();
         let series = Series::with_cubic_spline(seconds, delta_ut1_tai)?;
         Ok(Self(series))
@@ -166,6 +188,8 @@ mod tests {
     use super::*;
     use crate::subsecond::Subsecond;
     use crate::time;
+    use crate::time_scales::{Tdb, Tt};
+    use crate::transformations::{ToTai, TryToScale};
     use crate::utc::leap_seconds::BuiltinLeapSeconds;
     use float_eq::assert_float_eq;
     use rstest::rstest;
@@ -305,6 +329,56 @@ mod tests {
         assert_float_eq!(actual, -expected, rel <= 1e-8);
     }
 
+    #[test]
+    fn test_delta_ut1_tai_from_eop() {
+        let eop = EarthOrientationParams::parse_finals_csv(format!(
+            "{}/../../data/finals2000A.all.csv",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        let provider = DeltaUt1Tai::from_eop(&eop, &BuiltinLeapSeconds).unwrap();
+        assert_eq!(&provider, delta_ut1_tai());
+    }
+
+    #[test]
+    fn test_delta_ut1_tai_from_eop_missing_leap_seconds() {
+        let eop = EarthOrientationParams::new(
+            vec![41316, 41317, 41318, 41319],
+            vec![0.0; 4],
+            vec![0.0; 4],
+            vec![0.0; 4],
+        )
+        .unwrap();
+        let actual = DeltaUt1Tai::from_eop(&eop, &BuiltinLeapSeconds);
+        assert!(matches!(
+            actual,
+            Err(DeltaUt1TaiError::MissingLeapSeconds(date)) if date == Date::new(1971, 12, 31).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_ut1_round_trip_via_tt() {
+        let provider = delta_ut1_tai();
+        let tt = time!(Tt, 2024, 5, 17, 12, 13, 14.0).unwrap();
+        let ut1 = tt.try_to_scale(Ut1, provider).unwrap();
+        let expected = tt.to_tai().try_to_scale(Ut1, provider).unwrap();
+        assert_eq!(ut1, expected);
+        let actual = ut1.try_to_scale(Tt, provider).unwrap();
+        assert_float_eq!((actual - tt).to_decimal_seconds(), 0.0, abs <= 1e-9);
+    }
+
+    #[test]
+    fn test_ut1_out_of_eop_range() {
+        let provider = delta_ut1_tai();
+        let tt = time!(Tt, 2025, 3, 20, 12).unwrap();
+        let err = tt.try_to_scale(Ut1, provider).unwrap_err();
+        assert_eq!(err.req_date, Date::new(2025, 3, 20).unwrap());
+        assert_eq!(err.max_date, Date::new(2025, 3, 15).unwrap());
+        let ut1 = time!(Ut1, 2025, 3, 20, 12).unwrap();
+        let err = ut1.try_to_scale(Tdb, provider).unwrap_err();
+        assert_eq!(err.req_date, Date::new(2025, 3, 20).unwrap());
+    }
+
     fn delta_ut1_tai() -> &'static DeltaUt1Tai {
         static PROVIDER: OnceLock<DeltaUt1Tai> = OnceLock::new();
         PROVIDER.get_or_init(|| {
@@ -21,12 +21,16 @@ use num::ToPrimitive;
 use thiserror::Error;
 
 use crate::calendar_dates::{CalendarDate, Date, DateError};
+use crate::constants::julian_dates::SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000;
 use crate::deltas::{TimeDelta, ToDelta};
 use crate::julian_dates::JulianDate;
 use crate::time_of_day::{CivilTime, TimeOfDay, TimeOfDayError};
-use crate::transformations::LeapSecondsProvider;
+use crate::time_scales::Tai;
+use crate::transformations::{LeapSecondsProvider, ToTai};
+use crate::Time;
 
 use self::leap_seconds::BuiltinLeapSeconds;
+use self::transformations::ToUtc;
 
 pub mod leap_seconds;
 pub mod transformations;
@@ -40,10 +44,14 @@ pub enum UtcError {
     TimeError(#[from] TimeOfDayError),
     #[error("no leap second on {0}")]
     NonLeapSecondDate(Date),
+    #[error("leap seconds can only occur at 23:59:60 but got {0}")]
+    InvalidLeapSecondTime(TimeOfDay),
     #[error("UTC is not defined for dates before 1960-01-01")]
     UtcUndefined,
     #[error("invalid ISO string `{0}`")]
     InvalidIsoString(String),
+    #[error("`{0}` cannot be represented as Unix time")]
+    InvalidUnixTime(String),
 }
 
 /// Coordinated Universal Time.
@@ -60,6 +68,8 @@ impl Utc {
     /// # Errors
     ///
     /// - [UtcError::UtcUndefined] if the date is before 1960-01-01.
+    /// - [UtcError::InvalidLeapSecondTime] if `time.seconds` is 60 seconds and the time is not
+    ///   within the last minute of the day.
     /// - [UtcError::NonLeapSecondDate] if `time.seconds` is 60 seconds and the date is not a leap
     ///   second date.
     pub fn new(
@@ -70,8 +80,13 @@ impl Utc {
         if date.year() < 1960 {
             return Err(UtcError::UtcUndefined);
         }
-        if time.second() == 60 && !provider.is_leap_second_date(date) {
-            return Err(UtcError::NonLeapSecondDate(date));
+        if time.second() == 60 {
+            if time.hour() != 23 || time.minute() != 59 {
+                return Err(UtcError::InvalidLeapSecondTime(time));
+            }
+            if !provider.is_leap_second_date(date) {
+                return Err(UtcError::NonLeapSecondDate(date));
+            }
         }
         Ok(Self { date, time })
     }
@@ -90,13 +105,16 @@ impl Utc {
     /// - [UtcError::DateError] if the date component of the string is invalid.
     /// - [UtcError::TimeError] if the time component of the string is invalid.
     /// - [UtcError::UtcUndefined] if the date is before 1960-01-01.
+    /// - [UtcError::InvalidLeapSecondTime] if the time component is 60 seconds and not within the
+    ///   last minute of the day.
     /// - [UtcError::NonLeapSecondDate] if the time component is 60 seconds and the date is not a
     ///   leap second date.
     pub fn from_iso_with_provider<T: LeapSecondsProvider>(
         iso: &str,
         provider: &T,
     ) -> Result<Self, UtcError> {
-        let _ = iso.strip_suffix('Z');
+        let iso = iso.trim();
+        let iso = iso.strip_suffix('Z').unwrap_or(iso);
 
         let Some((date, time_and_scale)) = iso.split_once('T') else {
             return Err(UtcError::InvalidIsoString(iso.to_owned()));
@@ -132,6 +150,57 @@ impl Utc {
             TimeOfDay::from_seconds_since_j2000(delta.seconds).with_subsecond(delta.subsecond);
         Self { date, time }
     }
+
+    /// Constructs a new [Utc] instance from the number of seconds since the Unix epoch
+    /// 1970-01-01T00:00:00 UTC.
+    ///
+    /// Like Unix time itself, the conversion does not count leap seconds, i.e. every day is
+    /// 86400 seconds long.
+    ///
+    /// # Errors
+    ///
+    /// - [UtcError::InvalidUnixTime] if `seconds` is NaN or ±infinity.
+    /// - [UtcError::UtcUndefined] if the resulting date is before 1960-01-01.
+    pub fn from_unix_seconds(seconds: f64) -> Result<Self, UtcError> {
+        let delta = TimeDelta::from_decimal_seconds(seconds)
+            .map_err(|_| UtcError::InvalidUnixTime(seconds.to_string()))?;
+        let utc =
+            Self::from_delta(delta - TimeDelta::from_seconds(SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000));
+        if utc.year() < 1960 {
+            return Err(UtcError::UtcUndefined);
+        }
+        Ok(utc)
+    }
+
+    /// Returns the number of seconds since the Unix epoch 1970-01-01T00:00:00 UTC.
+    ///
+    /// Leap seconds are not counted. A leap second, e.g. 2016-12-31T23:59:60 UTC, therefore maps to
+    /// the same Unix time as the start of the following day.
+    pub fn to_unix_seconds(&self) -> f64 {
+        (self.to_delta() + TimeDelta::from_seconds(SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000))
+            .to_decimal_seconds()
+    }
+}
+
+impl Time<Tai> {
+    /// Returns the [Time] in TAI at the given number of seconds since the Unix epoch
+    /// 1970-01-01T00:00:00 UTC.
+    ///
+    /// Unix time follows UTC, so the difference TAI-UTC which was valid at the given instant is
+    /// applied, see [Utc::from_unix_seconds].
+    pub fn from_unix_seconds(seconds: f64) -> Result<Self, UtcError> {
+        Ok(Utc::from_unix_seconds(seconds)?.to_tai())
+    }
+
+    /// Returns the number of seconds since the Unix epoch 1970-01-01T00:00:00 UTC, removing the
+    /// difference TAI-UTC which was valid at this instant.
+    ///
+    /// # Errors
+    ///
+    /// - [UtcError::UtcUndefined] if the instant is before 1960-01-01 UTC.
+    pub fn to_unix_seconds(&self) -> Result<f64, UtcError> {
+        Ok(self.to_utc()?.to_unix_seconds())
+    }
 }
 
 impl ToDelta for Utc {
@@ -291,6 +360,49 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[rstest]
+    #[case(0.0, utc!(1970, 1, 1))]
+    #[case(1e8, utc!(1973, 3, 3, 9, 46, 40.0))]
+    #[case(1_700_000_000.25, utc!(2023, 11, 14, 22, 13, 20.25))]
+    #[case(-86400.5, utc!(1969, 12, 30, 23, 59, 59.5))]
+    fn test_utc_unix_seconds(#[case] seconds: f64, #[case] expected: Result<Utc, UtcError>) {
+        let expected = expected.unwrap();
+        assert_eq!(Utc::from_unix_seconds(seconds), Ok(expected));
+        assert_eq!(expected.to_unix_seconds(), seconds);
+    }
+
+    #[test]
+    fn test_utc_unix_seconds_leap_second() {
+        let leap_second = utc!(2016, 12, 31, 23, 59, 60.0).unwrap();
+        let next_day = utc!(2017, 1, 1).unwrap();
+        assert_eq!(leap_second.to_unix_seconds(), next_day.to_unix_seconds());
+        assert_eq!(leap_second.to_unix_seconds(), 1_483_228_800.0);
+    }
+
+    #[test]
+    fn test_utc_unix_seconds_invalid() {
+        assert_eq!(
+            Utc::from_unix_seconds(f64::NAN),
+            Err(UtcError::InvalidUnixTime("NaN".to_string()))
+        );
+        assert_eq!(
+            Utc::from_unix_seconds(-400_000_000.0),
+            Err(UtcError::UtcUndefined)
+        );
+    }
+
+    #[rstest]
+    // TAI-UTC was 12 s from 1973-01-01 and 37 s from 2017-01-01
+    #[case(1e8, 1e8 + 12.0)]
+    #[case(1_700_000_000.25, 1_700_000_000.25 + 37.0)]
+    fn test_tai_unix_seconds(#[case] unix_seconds: f64, #[case] tai_seconds: f64) {
+        let tai = Time::from_unix_seconds(unix_seconds).unwrap();
+        let exp = TimeDelta::from_decimal_seconds(tai_seconds).unwrap()
+            - TimeDelta::from_seconds(SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000);
+        assert_eq!(tai.to_delta(), exp);
+        assert_eq!(tai.to_unix_seconds(), Ok(unix_seconds));
+    }
+
     #[test]
     fn test_utc_non_leap_second_date() {
         let actual = Utc::builder()
@@ -301,6 +413,18 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn test_utc_invalid_leap_second_time() {
+        let actual = Utc::builder()
+            .with_ymd(2016, 12, 31)
+            .with_hms(12, 0, 60.0)
+            .build();
+        let expected = Err(UtcError::InvalidLeapSecondTime(
+            TimeOfDay::new(12, 0, 60).unwrap(),
+        ));
+        assert_eq!(actual, expected)
+    }
+
     #[test]
     fn test_utc_undefined() {
         let actual = Utc::builder().with_ymd(1959, 12, 31).build();
@@ -326,6 +450,10 @@ mod tests {
     #[case("2000-01-01T0:00:00", Err(UtcError::TimeError(TimeOfDayError::InvalidIsoString("0:00:00".to_string()))))]
     #[case("2000-01-01-00:00:00", Err(UtcError::InvalidIsoString("2000-01-01-00:00:00".to_string())))]
     #[case("2000-01-01T00:00:00 TAI", Err(UtcError::InvalidIsoString("2000-01-01T00:00:00 TAI".to_string())))]
+    #[case("2016-12-31T23:59:60", Ok(utc!(2016, 12, 31, 23, 59, 60.0).unwrap()))]
+    #[case("2016-12-31T23:59:60.5 UTC", Ok(utc!(2016, 12, 31, 23, 59, 60.5).unwrap()))]
+    #[case("2017-12-31T23:59:60", Err(UtcError::NonLeapSecondDate(Date::new(2017, 12, 31).unwrap())))]
+    #[case("2016-12-31T23:58:60", Err(UtcError::InvalidLeapSecondTime(TimeOfDay::new(23, 58, 60).unwrap())))]
     fn test_utc_from_str(#[case] iso: &str, #[case] expected: Result<Utc, UtcError>) {
         let actual: Result<Utc, UtcError> = iso.parse();
         assert_eq!(actual, expected)
@@ -12,7 +12,12 @@
     applications.
 
     `leap_seconds` additionally exposes the lower-level [LeapSecondsKernel] for working directly
-    with [NAIF Leap Seconds Kernel][LSK] data.
+    with [NAIF Leap Seconds Kernel][LSK] data, as well as [leap_seconds_at] and
+    [leap_second_dates] for querying the built-in table directly.
+
+    To pick up leap seconds announced after the release of `lox-time`, load the current IERS
+    table at runtime with [IersLeapSeconds] and pass it to the `*_with_provider` APIs. All other
+    UTC conversions use [BuiltinLeapSeconds].
 
     [LSK]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/time.html#The%20Leapseconds%20Kernel%20LSK
 */
@@ -84,6 +89,38 @@ impl LeapSecondsProvider for BuiltinLeapSeconds {
     }
 }
 
+/// Error returned when querying the offset between TAI and UTC before 1972-01-01.
+///
+/// Before 1972, UTC was kept close to UT1 by fractional offsets and changes of the length of the
+/// UTC second ("rubber seconds") rather than by leap seconds, which Lox does not support.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("TAI-UTC is not defined by leap seconds before 1972-01-01, but got {0}")]
+pub struct UtcBeforeLeapSecondsError(Utc);
+
+/// Returns the offset TAI-UTC in seconds at `utc` from the built-in leap seconds table.
+///
+/// During a leap second, i.e. at 23:59:60, the offset before the leap second applies.
+///
+/// # Errors
+///
+/// Returns [UtcBeforeLeapSecondsError] if `utc` is before 1972-01-01.
+pub fn leap_seconds_at(utc: &Utc) -> Result<i64, UtcBeforeLeapSecondsError> {
+    BuiltinLeapSeconds
+        .delta_utc_tai(*utc)
+        .map(|delta| -delta.seconds)
+        .ok_or(UtcBeforeLeapSecondsError(*utc))
+}
+
+/// Returns the dates at the end of which a leap second was inserted, according to the built-in
+/// leap seconds table.
+///
+/// The initial offset of 10 seconds on 1972-01-01 is not a leap second and is not included.
+pub fn leap_second_dates() -> Vec<Date> {
+    leap_second_day_numbers(&LEAP_SECOND_EPOCHS_UTC)
+        .map(Date::from_days_since_j2000)
+        .collect()
+}
+
 /// Error type related to parsing leap seconds data from a NAIF Leap Seconds Kernel.
 #[derive(Debug, Error)]
 pub enum LeapSecondsKernelError {
@@ -192,6 +229,222 @@ impl LeapSecondsProvider for LeapSecondsKernel {
     }
 }
 
+const MODIFIED_JULIAN_DAY_J2000: i64 = 51544;
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Error type related to parsing leap seconds data in the IERS `Leap_Second.dat` format.
+#[derive(Debug, Error)]
+pub enum IersLeapSecondsError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("invalid leap seconds entry in line {0}: `{1}`")]
+    InvalidEntry(usize, String),
+    #[error("invalid expiration date in line {0}: `{1}`")]
+    InvalidExpirationDate(usize, String),
+    #[error(transparent)]
+    DateError(#[from] DateError),
+    #[error("MJD {0} does not match the date {1}")]
+    DateMismatch(f64, Date),
+    #[error("no leap seconds found")]
+    NoLeapSeconds,
+    #[error("expected the table to start on 1972-01-01 with TAI-UTC = 10 s but found {0} with TAI-UTC = {1} s")]
+    InvalidFirstEntry(Date, i64),
+    #[error("expected TAI-UTC to increase by one second on {0} but found {1} s after {2} s")]
+    InvalidLeapSecond(Date, i64, i64),
+    #[error("entries must be in chronological order, but {0} follows {1}")]
+    NotChronological(Date, Date),
+}
+
+/// In-memory representation of the IERS leap seconds table in the [`Leap_Second.dat`][LSD]
+/// format.
+///
+/// Use `IersLeapSeconds` to pick up newly announced leap seconds at runtime without waiting for a
+/// `lox-time` release. The table is validated on load: it must start on 1972-01-01 with
+/// TAI-UTC = 10 s, be in chronological order and increase TAI-UTC by one second per entry.
+///
+/// [BuiltinLeapSeconds] remains the default. To use a loaded table, pass it explicitly to the
+/// provider-based APIs, e.g. [Utc::from_iso_with_provider] or
+/// [ToUtc::to_utc_with_provider](crate::utc::transformations::ToUtc::to_utc_with_provider).
+///
+/// [LSD]: https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat
+#[derive(Debug)]
+pub struct IersLeapSeconds {
+    epochs_utc: Vec<i64>,
+    epochs_tai: Vec<i64>,
+    leap_seconds: Vec<i64>,
+    expiration_date: Option<Date>,
+}
+
+impl IersLeapSeconds {
+    /// Parse an IERS leap seconds table in the `Leap_Second.dat` format from a string.
+    ///
+    /// # Errors
+    ///
+    /// - [IersLeapSecondsError::InvalidEntry] if a non-comment line is not of the form
+    ///   `MJD day month year TAI-UTC`.
+    /// - [IersLeapSecondsError::InvalidExpirationDate] if the `File expires on` comment can't be
+    ///   parsed.
+    /// - [IersLeapSecondsError::DateError] if an entry contains an invalid date.
+    /// - [IersLeapSecondsError::DateMismatch] if the MJD of an entry does not match its date.
+    /// - [IersLeapSecondsError::NoLeapSeconds] if the table contains no entries.
+    /// - [IersLeapSecondsError::InvalidFirstEntry] if the table does not start on 1972-01-01 with
+    ///   TAI-UTC = 10 s.
+    /// - [IersLeapSecondsError::NotChronological] if the entries are not in chronological order.
+    /// - [IersLeapSecondsError::InvalidLeapSecond] if TAI-UTC does not increase by one second
+    ///   between consecutive entries.
+    pub fn from_string(table: impl AsRef<str>) -> Result<Self, IersLeapSecondsError> {
+        let mut epochs_utc: Vec<i64> = Vec::new();
+        let mut epochs_tai: Vec<i64> = Vec::new();
+        let mut leap_seconds: Vec<i64> = Vec::new();
+        let mut expiration_date: Option<Date> = None;
+        let mut previous: Option<(Date, i64)> = None;
+        for (idx, line) in table.as_ref().lines().enumerate() {
+            let line_number = idx + 1;
+            let line = line.trim();
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(date) = comment.trim().strip_prefix("File expires on") {
+                    let date = parse_expiration_date(date).ok_or_else(|| {
+                        IersLeapSecondsError::InvalidExpirationDate(line_number, line.to_string())
+                    })?;
+                    expiration_date = Some(date);
+                }
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let (mjd, date, ls) = parse_entry(line).ok_or_else(|| {
+                IersLeapSecondsError::InvalidEntry(line_number, line.to_string())
+            })??;
+            let day_number = date.j2000_day_number();
+            if mjd != (day_number + MODIFIED_JULIAN_DAY_J2000) as f64 {
+                return Err(IersLeapSecondsError::DateMismatch(mjd, date));
+            }
+            match previous {
+                None if date != Date::new(1972, 1, 1)? || ls != 10 => {
+                    return Err(IersLeapSecondsError::InvalidFirstEntry(date, ls));
+                }
+                Some((previous_date, _)) if date <= previous_date => {
+                    return Err(IersLeapSecondsError::NotChronological(date, previous_date));
+                }
+                Some((_, previous_ls)) if ls != previous_ls + 1 => {
+                    return Err(IersLeapSecondsError::InvalidLeapSecond(
+                        date,
+                        ls,
+                        previous_ls,
+                    ));
+                }
+                _ => {}
+            }
+            previous = Some((date, ls));
+            let epoch = day_number * SECONDS_PER_DAY - SECONDS_PER_HALF_DAY;
+            epochs_utc.push(epoch);
+            epochs_tai.push(epoch + ls - 1);
+            leap_seconds.push(ls);
+        }
+        if leap_seconds.is_empty() {
+            return Err(IersLeapSecondsError::NoLeapSeconds);
+        }
+        Ok(Self {
+            epochs_utc,
+            epochs_tai,
+            leap_seconds,
+            expiration_date,
+        })
+    }
+
+    /// Parse an IERS leap seconds table in the `Leap_Second.dat` format located at `path`.
+    ///
+    /// # Errors
+    ///
+    /// - [IersLeapSecondsError::Io] if the file at `path` can't be read.
+    /// - Any error returned by [IersLeapSeconds::from_string] if the table is invalid.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, IersLeapSecondsError> {
+        let path = path.as_ref();
+        let table = read_to_string(path)?;
+        Self::from_string(table)
+    }
+
+    /// Returns the date until which the table is guaranteed to be complete, if the file declares
+    /// one.
+    ///
+    /// Leap seconds announced after this date are not known to the table, i.e. UTC conversions
+    /// beyond it may be off by an integer number of seconds.
+    pub fn expiration_date(&self) -> Option<Date> {
+        self.expiration_date
+    }
+
+    /// Returns the dates at the end of which a leap second was inserted according to the table.
+    pub fn leap_second_dates(&self) -> Vec<Date> {
+        leap_second_day_numbers(&self.epochs_utc)
+            .map(Date::from_days_since_j2000)
+            .collect()
+    }
+}
+
+impl OffsetProvider for IersLeapSeconds {
+    type Error = Infallible;
+}
+
+impl LeapSecondsProvider for IersLeapSeconds {
+    fn delta_tai_utc(&self, tai: Time<Tai>) -> Option<TimeDelta> {
+        find_leap_seconds_tai(&self.epochs_tai, &self.leap_seconds, tai)
+    }
+
+    fn delta_utc_tai(&self, utc: Utc) -> Option<TimeDelta> {
+        find_leap_seconds_utc(&self.epochs_utc, &self.leap_seconds, utc)
+    }
+
+    fn is_leap_second_date(&self, date: Date) -> bool {
+        is_leap_second_date(&self.epochs_utc, date)
+    }
+
+    fn is_leap_second(&self, tai: Time<Tai>) -> bool {
+        is_leap_second(&self.epochs_tai, tai)
+    }
+}
+
+/// Parses a `MJD day month year TAI-UTC` entry, returning `None` if the line is malformed.
+fn parse_entry(line: &str) -> Option<Result<(f64, Date, i64), DateError>> {
+    let mut fields = line.split_whitespace();
+    let mjd: f64 = fields.next()?.parse().ok()?;
+    let day: u8 = fields.next()?.parse().ok()?;
+    let month: u8 = fields.next()?.parse().ok()?;
+    let year: i64 = fields.next()?.parse().ok()?;
+    let ls: i64 = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Some(Date::new(year, month, day).map(|date| (mjd, date, ls)))
+}
+
+/// Parses dates of the form `28 June 2024`.
+fn parse_expiration_date(date: &str) -> Option<Date> {
+    let mut fields = date.split_whitespace();
+    let day: u8 = fields.next()?.parse().ok()?;
+    let month = fields.next()?.to_lowercase();
+    let month = MONTH_NAMES.iter().position(|&name| name == month)? as u8 + 1;
+    let year: i64 = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Date::new(year, month, day).ok()
+}
+
 fn find_leap_seconds(epochs: &[i64], leap_seconds: &[i64], seconds: i64) -> Option<TimeDelta> {
     if seconds < epochs[0] {
         return None;
@@ -218,17 +471,23 @@ fn find_leap_seconds_utc(epochs: &[i64], leap_seconds: &[i64], utc: Utc) -> Opti
     })
 }
 
-fn is_leap_second_date(epochs: &[i64], date: Date) -> bool {
-    let epochs: Vec<i64> = epochs
+/// Returns the J2000 day numbers of the days at the end of which a leap second was inserted,
+/// skipping the introduction of leap seconds on 1972-01-01.
+fn leap_second_day_numbers(epochs: &[i64]) -> impl Iterator<Item = i64> + '_ {
+    // The epochs lie within the first minute of the day after the leap second, i.e. half a day
+    // before the corresponding J2000 day number.
+    epochs[1..]
         .iter()
-        .map(|&epoch| epoch / SECONDS_PER_DAY)
-        .collect();
+        .map(|&epoch| epoch.div_euclid(SECONDS_PER_DAY))
+}
+
+fn is_leap_second_date(epochs: &[i64], date: Date) -> bool {
     let day_number = date.j2000_day_number();
-    epochs.binary_search(&day_number).is_ok()
+    leap_second_day_numbers(epochs).any(|leap_second_day| leap_second_day == day_number)
 }
 
 fn is_leap_second(epochs: &[i64], tai: Time<Tai>) -> bool {
-    epochs.binary_search(&tai.seconds).is_ok()
+    epochs[1..].binary_search(&tai.seconds).is_ok()
 }
 
 #[cfg(test)]
@@ -240,7 +499,7 @@ mod tests {
     use crate::deltas::TimeDelta;
     use crate::time;
     use crate::time_scales::Tai;
-    use crate::transformations::LeapSecondsProvider;
+    use crate::transformations::{LeapSecondsProvider, TryToScale};
     use crate::utc;
     use crate::utc::Utc;
     use crate::Time;
@@ -296,6 +555,12 @@ mod tests {
     #[case(&BuiltinLeapSeconds, Date::new(2016, 12, 31).unwrap(), true)]
     #[case(kernel(), Date::new(2000, 12, 31).unwrap(), false)]
     #[case(kernel(), Date::new(2016, 12, 31).unwrap(), true)]
+    #[case(&BuiltinLeapSeconds, Date::new(1998, 12, 31).unwrap(), true)]
+    #[case(&BuiltinLeapSeconds, Date::new(1999, 1, 1).unwrap(), false)]
+    #[case(&BuiltinLeapSeconds, Date::new(1971, 12, 31).unwrap(), false)]
+    #[case(kernel(), Date::new(1998, 12, 31).unwrap(), true)]
+    #[case(kernel(), Date::new(1999, 1, 1).unwrap(), false)]
+    #[case(kernel(), Date::new(1971, 12, 31).unwrap(), false)]
     fn test_is_leap_second_date(
         #[case] provider: &impl LeapSecondsProvider,
         #[case] date: Date,
@@ -310,6 +575,9 @@ mod tests {
     #[case(&BuiltinLeapSeconds, time!(Tai, 2017, 1, 1, 0, 0, 36.0).unwrap(), true)]
     #[case(kernel(), time!(Tai, 2017, 1, 1, 0, 0, 35.0).unwrap(), false)]
     #[case(kernel(), time!(Tai, 2017, 1, 1, 0, 0, 36.0).unwrap(), true)]
+    #[case(&BuiltinLeapSeconds, time!(Tai, 1999, 1, 1, 0, 0, 31.0).unwrap(), true)]
+    #[case(&BuiltinLeapSeconds, time!(Tai, 1972, 1, 1, 0, 0, 9.0).unwrap(), false)]
+    #[case(kernel(), time!(Tai, 1972, 1, 1, 0, 0, 9.0).unwrap(), false)]
     fn test_is_leap_second(
         #[case] provider: &impl LeapSecondsProvider,
         #[case] tai: Time<Tai>,
@@ -319,6 +587,39 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[rstest]
+    #[case::new_year_1972(utc!(1972, 1, 1).unwrap(), Ok(10))]
+    #[case::new_year_1999(utc!(1999, 1, 1).unwrap(), Ok(32))]
+    #[case::leap_second_2016(utc!(2016, 12, 31, 23, 59, 60.0).unwrap(), Ok(36))]
+    #[case::new_year_2017(utc!(2017, 1, 1).unwrap(), Ok(37))]
+    #[case::before_leap_seconds(
+        utc!(1971, 12, 31, 23, 59, 59.0).unwrap(),
+        Err(UtcBeforeLeapSecondsError(utc!(1971, 12, 31, 23, 59, 59.0).unwrap())),
+    )]
+    fn test_leap_seconds_at(
+        #[case] utc: Utc,
+        #[case] expected: Result<i64, UtcBeforeLeapSecondsError>,
+    ) {
+        assert_eq!(leap_seconds_at(&utc), expected);
+    }
+
+    #[test]
+    fn test_leap_second_dates() {
+        let dates = leap_second_dates();
+        assert_eq!(dates.len(), 27);
+        assert_eq!(dates[0], Date::new(1972, 6, 30).unwrap());
+        assert_eq!(dates[1], Date::new(1972, 12, 31).unwrap());
+        assert_eq!(dates[26], Date::new(2016, 12, 31).unwrap());
+        for date in dates {
+            assert!(BuiltinLeapSeconds.is_leap_second_date(date));
+            let leap_second = Utc::builder()
+                .with_ymd(date.year(), date.month(), date.day())
+                .with_hms(23, 59, 60.0)
+                .build();
+            assert!(leap_second.is_ok(), "{date:?}");
+        }
+    }
+
     #[test]
     fn test_leap_seconds_kernel() {
         let lsk = kernel();
@@ -365,8 +666,120 @@ DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
 
 \\begintext";
 
+    #[test]
+    fn test_iers_leap_seconds() {
+        let table = iers_table();
+        assert_eq!(table.leap_seconds, &LEAP_SECONDS);
+        assert_eq!(table.epochs_utc, &LEAP_SECOND_EPOCHS_UTC);
+        assert_eq!(table.epochs_tai, &LEAP_SECOND_EPOCHS_TAI);
+        assert_eq!(
+            table.expiration_date(),
+            Some(Date::new(2024, 12, 28).unwrap())
+        );
+        assert_eq!(table.leap_second_dates(), leap_second_dates());
+    }
+
+    #[test]
+    fn test_iers_leap_seconds_new_leap_second() {
+        let table = format!("{IERS_TABLE}    61406.0    1  1 2027       38\n");
+        let table = IersLeapSeconds::from_string(table).unwrap();
+        assert_eq!(
+            table.expiration_date(),
+            Some(Date::new(2024, 12, 28).unwrap())
+        );
+
+        let iso = "2026-12-31T23:59:60";
+        assert!(Utc::from_iso(iso).is_err());
+        let utc = Utc::from_iso_with_provider(iso, &table).unwrap();
+        let tai = utc.try_to_scale(Tai, &table).unwrap();
+        assert_eq!(tai, time!(Tai, 2027, 1, 1, 0, 0, 37.0).unwrap());
+        assert_eq!(
+            table.delta_utc_tai(utc!(2027, 1, 1).unwrap()),
+            Some(TimeDelta::from_seconds(-38))
+        );
+    }
+
+    #[rstest]
+    #[case::empty("# No entries\n", "no leap seconds found")]
+    #[case::invalid_entry(
+        "    41317.0    1  1 1972\n",
+        "invalid leap seconds entry in line 1: `41317.0    1  1 1972`"
+    )]
+    #[case::invalid_date("    41317.0    1  13 1972       10\n", "invalid date `1972-13-1`")]
+    #[case::date_mismatch(
+        "    41318.0    1  1 1972       10\n",
+        "MJD 41318 does not match the date 1972-01-01"
+    )]
+    #[case::invalid_first_entry(
+        "    41499.0    1  7 1972       11\n",
+        "expected the table to start on 1972-01-01 with TAI-UTC = 10 s but found 1972-07-01 with TAI-UTC = 11 s"
+    )]
+    #[case::not_chronological(
+        "    41317.0    1  1 1972       10\n    41317.0    1  1 1972       11\n",
+        "entries must be in chronological order, but 1972-01-01 follows 1972-01-01"
+    )]
+    #[case::invalid_leap_second(
+        "    41317.0    1  1 1972       10\n    41683.0    1  1 1973       12\n",
+        "expected TAI-UTC to increase by one second on 1973-01-01 but found 12 s after 10 s"
+    )]
+    #[case::invalid_expiration_date(
+        "#  File expires on 31 Juno 2024\n",
+        "invalid expiration date in line 1: `#  File expires on 31 Juno 2024`"
+    )]
+    fn test_iers_leap_seconds_invalid(#[case] table: &str, #[case] expected: &str) {
+        let err = IersLeapSeconds::from_string(table).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
     fn kernel() -> &'static LeapSecondsKernel {
         static LSK: OnceLock<LeapSecondsKernel> = OnceLock::new();
         LSK.get_or_init(|| LeapSecondsKernel::from_string(KERNEL).expect("file should be parsable"))
     }
+
+    const IERS_TABLE: &str = "#  Value of TAI-UTC in second valid beetween the initial value until
+#  the epoch given on the next line. The last line reads that NO
+#  leap second was introduced since the corresponding date
+#
+#  File expires on 28 December 2024
+#
+#    MJD        Date        TAI-UTC (s)
+#           day month year
+#    ---    --------------   ------
+#
+    41317.0    1  1 1972       10
+    41499.0    1  7 1972       11
+    41683.0    1  1 1973       12
+    42048.0    1  1 1974       13
+    42413.0    1  1 1975       14
+    42778.0    1  1 1976       15
+    43144.0    1  1 1977       16
+    43509.0    1  1 1978       17
+    43874.0    1  1 1979       18
+    44239.0    1  1 1980       19
+    44786.0    1  7 1981       20
+    45151.0    1  7 1982       21
+    45516.0    1  7 1983       22
+    46247.0    1  7 1985       23
+    47161.0    1  1 1988       24
+    47892.0    1  1 1990       25
+    48257.0    1  1 1991       26
+    48804.0    1  7 1992       27
+    49169.0    1  7 1993       28
+    49534.0    1  7 1994       29
+    50083.0    1  1 1996       30
+    50630.0    1  7 1997       31
+    51179.0    1  1 1999       32
+    53736.0    1  1 2006       33
+    54832.0    1  1 2009       34
+    56109.0    1  7 2012       35
+    57204.0    1  7 2015       36
+    57754.0    1  1 2017       37
+";
+
+    fn iers_table() -> &'static IersLeapSeconds {
+        static TABLE: OnceLock<IersLeapSeconds> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            IersLeapSeconds::from_string(IERS_TABLE).expect("table should be parsable")
+        })
+    }
 }
@@ -234,6 +234,29 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_utc_leap_second_round_trip() {
+        let before = utc!(2016, 12, 31, 23, 59, 59.0).unwrap();
+        let leap = utc!(2016, 12, 31, 23, 59, 60.5).unwrap();
+        let after = utc!(2017, 1, 1).unwrap();
+
+        let tai = leap.to_tai();
+        assert_eq!(tai, time!(Tai, 2017, 1, 1, 0, 0, 36.5).unwrap());
+        assert_eq!(tai.to_utc().unwrap(), leap);
+
+        // TAI-UTC increments from 36 to 37 seconds across the leap second.
+        let provider = &BuiltinLeapSeconds;
+        assert_eq!(
+            provider.delta_tai_utc(before.to_tai()),
+            Some(TimeDelta::from_seconds(36))
+        );
+        assert_eq!(
+            provider.delta_tai_utc(after.to_tai()),
+            Some(TimeDelta::from_seconds(37))
+        );
+        assert_eq!(after.to_tai() - before.to_tai(), TimeDelta::from_seconds(2));
+    }
+
     #[test]
     fn test_all_scales_to_utc() {
         let tai = time!(Tai, 2024, 5, 17, 12, 13, 14.0).unwrap();
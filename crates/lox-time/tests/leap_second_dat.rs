@@ -0,0 +1,24 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use lox_time::calendar_dates::Date;
+use lox_time::utc::leap_seconds::{leap_second_dates, IersLeapSeconds};
+
+#[test]
+fn test_iers_leap_seconds_from_file() {
+    let table = IersLeapSeconds::from_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../data/Leap_Second.dat"
+    ))
+    .unwrap();
+    assert_eq!(
+        table.expiration_date(),
+        Some(Date::new(2024, 12, 28).unwrap())
+    );
+    assert_eq!(table.leap_second_dates(), leap_second_dates());
+}
@@ -9,10 +9,12 @@
 use std::path::PathBuf;
 
 use crate::origins::generate_bodies;
+use crate::xy06::generate_xy06;
 use lox_io::spice::Kernel;
 
 mod common;
 mod origins;
+mod xy06;
 
 fn crates_dir() -> PathBuf {
     PathBuf::from(format!("{}/../../crates", env!("CARGO_MANIFEST_DIR")))
@@ -23,6 +25,13 @@ pub fn main() {
         .expect("parsing should succeed");
     let gm = Kernel::from_string(include_str!("../../../data/gm_de440.tpc"))
         .expect("parsing should succeed");
+    let zonal = Kernel::from_string(include_str!("../../../data/zonal_harmonics.tpc"))
+        .expect("parsing should succeed");
     let bodies_target_dir = crates_dir().join("lox-bodies/src/");
-    generate_bodies(&bodies_target_dir, &pck, &gm);
+    generate_bodies(&bodies_target_dir, &pck, &gm, &zonal);
+
+    let xy06 = Kernel::from_string(include_str!("../../../data/xy06.tpc"))
+        .expect("parsing should succeed");
+    let xy06_target_dir = crates_dir().join("lox-earth/src/cip/xy06/");
+    generate_xy06(&xy06_target_dir, &xy06);
 }
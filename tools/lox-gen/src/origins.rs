@@ -988,20 +988,98 @@ fn get_array_as_radians(kernel: &Kernel, key: &str) -> Option<Vec<f64>> {
         .map(|array| array.iter().map(|v| v.to_radians()).collect())
 }
 
-fn unpair(vec: &[f64]) -> (Vec<f64>, Vec<f64>) {
-    let mut a: Vec<f64> = Vec::with_capacity(vec.len() / 2);
-    let mut b: Vec<f64> = Vec::with_capacity(vec.len() / 2);
-    for (i, coefficient) in vec.iter().enumerate() {
-        if i % 2 == 0 {
-            a.push(*coefficient);
-        } else {
-            b.push(*coefficient);
-        }
+const DISTANCE_UNITS: [&str; 2] = ["KM", "KILOMETERS"];
+const GM_UNITS: [&str; 2] = ["KM^3/S^2", "KM3/S2"];
+
+/// Panics if the kernel declares units for `key` which differ from the `expected` ones.
+/// The generated constants are emitted verbatim and must therefore follow the km-based SPICE
+/// conventions.
+fn assert_units(kernel: &Kernel, key: &str, expected: &[&str]) {
+    if let Some(units) = kernel.get_units(key) {
+        assert!(
+            expected.iter().any(|e| units.eq_ignore_ascii_case(e)),
+            "`{key}` is declared in '{units}' but one of {expected:?} was expected"
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ShapeProvenance {
+    /// Triaxial radii from `BODY<id>_RADII`.
+    Pck,
+    /// Spherical approximation derived from the mean radius.
+    MeanRadius,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Shape {
+    radii: Vec<f64>,
+    provenance: ShapeProvenance,
+}
+
+/// Returns the shape of the body with the given NAIF `id`. Triaxial radii from the PCK take
+/// precedence. If the PCK does not provide them but a mean radius is known, the body is
+/// approximated as a sphere, i.e. equatorial and polar radii both equal the mean radius and the
+/// flattening is zero.
+fn get_shape(pck: &Kernel, id: i32, mean_radius: Option<f64>) -> Option<Shape> {
+    let key = format!("BODY{id}_RADII");
+    if let Some(radii) = pck.get_double_array(&key) {
+        assert_units(pck, &key, &DISTANCE_UNITS);
+        return Some(Shape {
+            radii: radii.clone(),
+            provenance: ShapeProvenance::Pck,
+        });
+    }
+    mean_radius.map(|mean_radius| Shape {
+        radii: vec![mean_radius; 3],
+        provenance: ShapeProvenance::MeanRadius,
+    })
+}
+
+/// Returns the radius of the sphere with the same volume as the triaxial ellipsoid.
+fn volumetric_radius(radii: &[f64]) -> f64 {
+    (radii[0] * radii[1] * radii[2]).cbrt()
+}
+
+/// Returns the coefficients of the trigonometric terms of the rotational element `key` of the body
+/// with the NAIF `id` and the constant and linear coefficients of the phase angles which they
+/// reference, all in radians.
+///
+/// Satellites reference the angles of their planetary system, see [Kernel::get_nut_prec_angles].
+fn get_nut_prec_terms(pck: &Kernel, id: i32, key: &str) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let Some(c) = get_array_as_radians(pck, key) else {
+        return Default::default();
+    };
+    let angles = pck.get_nut_prec_angles(id).unwrap_or_default();
+    assert!(
+        c.len() <= angles.len(),
+        "`{key}` has {} coefficients but only {} nutation-precession angles are defined",
+        c.len(),
+        angles.len()
+    );
+    let angles = &angles[0..c.len()];
+    assert!(
+        angles.iter().all(|angle| angle.len() == 2),
+        "the nutation-precession angles referenced by `{key}` must be linear in time"
+    );
+    let theta0 = angles.iter().map(|angle| angle[0].to_radians()).collect();
+    let theta1 = angles.iter().map(|angle| angle[1].to_radians()).collect();
+    (c, theta0, theta1)
+}
+
+/// Returns the unnormalized zonal harmonic coefficients J2, J3, and J4 of the body with the given
+/// NAIF `id` if all of them are available.
+fn get_zonal_harmonics(kernel: &Kernel, id: i32) -> Option<[f64; 3]> {
+    let mut coefficients = [0.0; 3];
+    for (degree, coefficient) in (2..=4).zip(coefficients.iter_mut()) {
+        *coefficient = *kernel
+            .get_double_array(&format!("BODY{id}_J{degree}"))?
+            .first()?;
     }
-    (a, b)
+    Some(coefficients)
 }
 
-pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
+pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel, zonal: &Kernel) {
     let mut code = quote! {
         use crate::DynOrigin;
         use crate::Elements;
@@ -1020,7 +1098,11 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
         use crate::TryRotationalElements;
         use crate::TrySpheroid;
         use crate::TryTriaxialEllipsoid;
+        use crate::TryVolumetricRadius;
+        use crate::TryZonalHarmonics;
         use crate::UndefinedOriginPropertyError;
+        use crate::VolumetricRadius;
+        use crate::ZonalHarmonics;
         use std::fmt::Display;
         use std::fmt::Formatter;
     };
@@ -1028,6 +1110,10 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
     let mut point_mass_match_arms = quote! {};
     let mut mean_radius_match_arms = quote! {};
     let mut ellipsoid_match_arms = quote! {};
+    let mut volumetric_radius_match_arms = quote! {};
+    let mut j2_match_arms = quote! {};
+    let mut j3_match_arms = quote! {};
+    let mut j4_match_arms = quote! {};
 
     let mut rotational_elements_match_arms = quote! {};
     let mut rotational_element_rates_match_arms = quote! {};
@@ -1070,8 +1156,9 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
             format!("BODY{id}_GM")
         };
 
-        if let Some(gm) = gm.get_double_array(&key) {
-            let gm = gm.first().unwrap();
+        if let Some(gm_array) = gm.get_double_array(&key) {
+            assert_units(gm, &key, &GM_UNITS);
+            let gm = gm_array.first().unwrap();
             code.extend(quote! {
                 impl PointMass for #ident {
                     fn gravitational_parameter(&self) -> f64 {
@@ -1105,10 +1192,17 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
         }
 
         // TriaxialEllipsoid / Spheroid
-        let key = format!("BODY{id}_RADII");
-
-        if let Some(radii) = pck.get_double_array(&key) {
+        if let Some(shape) = get_shape(pck, id, mean_radius) {
+            let radii = &shape.radii;
+            let doc = match shape.provenance {
+                ShapeProvenance::Pck => quote! {},
+                ShapeProvenance::MeanRadius => quote! {
+                    /// The PCK does not provide triaxial radii for this body. It is approximated
+                    /// as a sphere with all radii equal to its mean radius.
+                },
+            };
             code.extend(quote! {
+                #doc
                 impl TriaxialEllipsoid for #ident {
                     fn radii(&self) -> Radii {
                         (#(#radii),*)
@@ -1124,9 +1218,51 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
 
             ellipsoid_match_arms.extend(quote! {
                 DynOrigin::#ident => Ok((#(#radii),*)),
+            });
+
+            let volumetric_radius = volumetric_radius(radii);
+            code.extend(quote! {
+                impl VolumetricRadius for #ident {
+                    fn volumetric_radius(&self) -> f64 {
+                        #volumetric_radius
+                    }
+                }
+            });
+
+            volumetric_radius_match_arms.extend(quote! {
+                DynOrigin::#ident => Ok(#volumetric_radius),
             })
         }
 
+        // ZonalHarmonics
+        if let Some([j2, j3, j4]) = get_zonal_harmonics(zonal, id) {
+            code.extend(quote! {
+                impl ZonalHarmonics for #ident {
+                    fn j2(&self) -> f64 {
+                        #j2
+                    }
+
+                    fn j3(&self) -> f64 {
+                        #j3
+                    }
+
+                    fn j4(&self) -> f64 {
+                        #j4
+                    }
+                }
+            });
+
+            j2_match_arms.extend(quote! {
+                DynOrigin::#ident => Ok(#j2),
+            });
+            j3_match_arms.extend(quote! {
+                DynOrigin::#ident => Ok(#j3),
+            });
+            j4_match_arms.extend(quote! {
+                DynOrigin::#ident => Ok(#j4),
+            });
+        }
+
         let ra_key = format!("BODY{id}_POLE_RA");
         let dec_key = format!("BODY{id}_POLE_DEC");
         let pm_key = format!("BODY{id}_PM");
@@ -1134,26 +1270,16 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
         let nut_prec_dec_key = format!("BODY{id}_NUT_PREC_DEC");
         let nut_prec_pm_key = format!("BODY{id}_NUT_PREC_PM");
 
-        let nut_prec_id = id / 100;
-        let nut_prec_key = format!("BODY{}_NUT_PREC_ANGLES", nut_prec_id);
-
         if let (Some(ra), Some(dec), Some(pm)) = (
             get_array_as_radians(pck, &ra_key),
             get_array_as_radians(pck, &dec_key),
             get_array_as_radians(pck, &pm_key),
         ) {
-            let (theta0, theta1): (Vec<f64>, Vec<f64>) = get_array_as_radians(pck, &nut_prec_key)
-                .as_ref()
-                .map(|nut_prec| unpair(nut_prec))
-                .unwrap_or_default();
-
             let ra1 = ra[0];
             let ra2 = ra[1];
             let ra3 = ra.get(2).copied().unwrap_or_default();
-            let nut_prec_ra = get_array_as_radians(pck, &nut_prec_ra_key).unwrap_or_default();
+            let (nut_prec_ra, theta0_ra, theta1_ra) = get_nut_prec_terms(pck, id, &nut_prec_ra_key);
             let n = nut_prec_ra.len();
-            let theta0_ra = &theta0[0..n];
-            let theta1_ra = &theta1[0..n];
 
             let ra_const_ident = format_ident!("RIGHT_ASCENSION_{}", ident_upper);
             let ra_const = quote! {
@@ -1177,10 +1303,9 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
             let dec1 = dec[0];
             let dec2 = dec[1];
             let dec3 = dec.get(2).copied().unwrap_or_default();
-            let nut_prec_dec = get_array_as_radians(pck, &nut_prec_dec_key).unwrap_or_default();
+            let (nut_prec_dec, theta0_dec, theta1_dec) =
+                get_nut_prec_terms(pck, id, &nut_prec_dec_key);
             let n = nut_prec_dec.len();
-            let theta0_dec = &theta0[0..n];
-            let theta1_dec = &theta1[0..n];
 
             let dec_const_ident = format_ident!("DECLINATION_{}", ident_upper);
             let dec_const = quote! {
@@ -1204,10 +1329,8 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
             let pm1 = pm[0];
             let pm2 = pm[1];
             let pm3 = pm.get(2).copied().unwrap_or_default();
-            let nut_prec_pm = get_array_as_radians(pck, &nut_prec_pm_key).unwrap_or_default();
+            let (nut_prec_pm, theta0_pm, theta1_pm) = get_nut_prec_terms(pck, id, &nut_prec_pm_key);
             let n = nut_prec_pm.len();
-            let theta0_pm = &theta0[0..n];
-            let theta1_pm = &theta1[0..n];
 
             let pm_const_ident = format_ident!("ROTATION_{}", ident_upper);
             let pm_const = quote! {
@@ -1294,6 +1417,56 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
             }
         }
         impl TrySpheroid for DynOrigin {}
+        impl TryVolumetricRadius for DynOrigin {
+            fn try_volumetric_radius(&self) -> Result<f64, UndefinedOriginPropertyError> {
+                match self {
+                    #volumetric_radius_match_arms
+                    _ => Err(
+                        UndefinedOriginPropertyError {
+                            origin: self.to_string(),
+                            prop: "volumetric radius".to_string(),
+                        }
+                    ),
+                }
+            }
+        }
+        impl TryZonalHarmonics for DynOrigin {
+            fn try_j2(&self) -> Result<f64, UndefinedOriginPropertyError> {
+                match self {
+                    #j2_match_arms
+                    _ => Err(
+                        UndefinedOriginPropertyError {
+                            origin: self.to_string(),
+                            prop: "J2".to_string(),
+                        }
+                    ),
+                }
+            }
+
+            fn try_j3(&self) -> Result<f64, UndefinedOriginPropertyError> {
+                match self {
+                    #j3_match_arms
+                    _ => Err(
+                        UndefinedOriginPropertyError {
+                            origin: self.to_string(),
+                            prop: "J3".to_string(),
+                        }
+                    ),
+                }
+            }
+
+            fn try_j4(&self) -> Result<f64, UndefinedOriginPropertyError> {
+                match self {
+                    #j4_match_arms
+                    _ => Err(
+                        UndefinedOriginPropertyError {
+                            origin: self.to_string(),
+                            prop: "J4".to_string(),
+                        }
+                    ),
+                }
+            }
+        }
         impl TryRotationalElements for DynOrigin {
             fn try_rotational_elements(&self, t: f64)
                 -> Result<Elements, UndefinedOriginPropertyError> {
@@ -1327,3 +1500,129 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
 
     write_file(path, "generated.rs", code)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KERNEL: &str = "KPL/PCK
+\\begindata
+BODY399_RADII       = ( 6378136.6 6378136.6 6356751.9 )
+BODY399_RADII_UNITS = 'METERS'
+BODY499_RADII       = ( 3396.19 3396.19 3376.20 )
+BODY599_RADII       = ( 71492 71492 66854 )
+BODY599_RADII_UNITS = 'KILOMETERS'
+\\begintext
+";
+
+    #[test]
+    fn test_assert_units() {
+        let kernel = Kernel::from_string(KERNEL).expect("kernel should be parsable");
+        assert_units(&kernel, "BODY499_RADII", &DISTANCE_UNITS);
+        assert_units(&kernel, "BODY599_RADII", &DISTANCE_UNITS);
+    }
+
+    #[test]
+    #[should_panic(expected = "`BODY399_RADII` is declared in 'METERS'")]
+    fn test_assert_units_mismatch() {
+        let kernel = Kernel::from_string(KERNEL).expect("kernel should be parsable");
+        assert_units(&kernel, "BODY399_RADII", &DISTANCE_UNITS);
+    }
+
+    #[test]
+    fn test_get_shape_from_pck() {
+        let kernel = Kernel::from_string(KERNEL).expect("kernel should be parsable");
+        let shape = get_shape(&kernel, 499, Some(3389.5)).unwrap();
+        assert_eq!(shape.provenance, ShapeProvenance::Pck);
+        assert_eq!(shape.radii, vec![3396.19, 3396.19, 3376.20]);
+    }
+
+    #[test]
+    fn test_get_shape_mean_radius_fallback() {
+        let kernel = Kernel::from_string(KERNEL).expect("kernel should be parsable");
+        let shape = get_shape(&kernel, 902, Some(19.0)).unwrap();
+        assert_eq!(shape.provenance, ShapeProvenance::MeanRadius);
+        let (equatorial, polar) = (shape.radii[0], shape.radii[2]);
+        assert_eq!(equatorial, 19.0);
+        assert_eq!(polar, 19.0);
+        assert_eq!((equatorial - polar) / equatorial, 0.0);
+    }
+
+    #[test]
+    fn test_get_shape_undefined() {
+        let kernel = Kernel::from_string(KERNEL).expect("kernel should be parsable");
+        assert_eq!(get_shape(&kernel, 902, None), None);
+    }
+
+    #[test]
+    fn test_volumetric_radius() {
+        assert_eq!(volumetric_radius(&[1737.4, 1737.4, 1737.4]), 1737.4);
+        let act = volumetric_radius(&[71492.0, 71492.0, 66854.0]);
+        assert!((act - 69911.0).abs() < 1.0);
+        let exp = 71492.0f64 * 71492.0 * 66854.0;
+        assert!((act.powi(3) - exp).abs() / exp < 1e-15);
+    }
+
+    #[test]
+    fn test_get_nut_prec_terms() {
+        let pck = Kernel::from_string(include_str!("../../../data/pck00011_n0066.tpc"))
+            .expect("kernel should be parsable");
+
+        // Io's prime meridian references the angles J3 and J4 of the Jovian system
+        let (c, theta0, theta1) = get_nut_prec_terms(&pck, 501, "BODY501_NUT_PREC_PM");
+        let exp_c: Vec<f64> = [0.0, 0.0, -0.085, -0.022]
+            .iter()
+            .map(|c: &f64| c.to_radians())
+            .collect();
+        let exp_theta0: Vec<f64> = [73.32, 24.62, 283.90, 355.80]
+            .iter()
+            .map(|theta: &f64| theta.to_radians())
+            .collect();
+        let exp_theta1: Vec<f64> = [91472.9, 45137.2, 4850.7, 1191.3]
+            .iter()
+            .map(|theta: &f64| theta.to_radians())
+            .collect();
+        assert_eq!(c, exp_c);
+        assert_eq!(theta0, exp_theta0);
+        assert_eq!(theta1, exp_theta1);
+
+        let (c, theta0, theta1) = get_nut_prec_terms(&pck, 399, "BODY399_NUT_PREC_PM");
+        assert!(c.is_empty() && theta0.is_empty() && theta1.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "`BODY501_NUT_PREC_PM` has 3 coefficients")]
+    fn test_get_nut_prec_terms_missing_angles() {
+        let pck = Kernel::from_string(
+            "KPL/PCK
+\\begindata
+BODY5_NUT_PREC_ANGLES = ( 73.32 91472.9 24.62 45137.2 )
+BODY501_NUT_PREC_PM = ( 0. 0. -0.085 )
+\\begintext
+",
+        )
+        .unwrap();
+        get_nut_prec_terms(&pck, 501, "BODY501_NUT_PREC_PM");
+    }
+
+    #[test]
+    fn test_get_zonal_harmonics() {
+        let kernel = Kernel::from_string(
+            "KPL/PCK
+\\begindata
+BODY399_J2 = ( 1.0826D-3 )
+BODY399_J3 = ( -2.5D-6 )
+BODY399_J4 = ( -1.6D-6 )
+BODY499_J2 = ( 1.96D-3 )
+\\begintext
+",
+        )
+        .unwrap();
+        assert_eq!(
+            get_zonal_harmonics(&kernel, 399),
+            Some([1.0826e-3, -2.5e-6, -1.6e-6])
+        );
+        assert_eq!(get_zonal_harmonics(&kernel, 499), None);
+        assert_eq!(get_zonal_harmonics(&kernel, 599), None);
+    }
+}
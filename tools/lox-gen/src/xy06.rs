@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+use crate::common::write_file;
+use lox_io::spice::Kernel;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use std::path::Path;
+
+const N_LUNI_SOLAR_ARGS: usize = 5;
+const N_PLANETARY_ARGS: usize = 14;
+const N_POWERS_OF_T: usize = 6;
+
+fn get_array<'a>(kernel: &'a Kernel, key: &str) -> &'a [f64] {
+    kernel
+        .get_double_array(key)
+        .unwrap_or_else(|| panic!("`{key}` should be present"))
+}
+
+fn floats(values: &[f64]) -> Vec<Literal> {
+    values.iter().copied().map(Literal::f64_unsuffixed).collect()
+}
+
+fn indices(values: &[f64]) -> Vec<Literal> {
+    values
+        .iter()
+        .map(|&v| {
+            assert!(
+                v >= 0.0 && v.fract() == 0.0,
+                "index `{v}` should be a non-negative integer"
+            );
+            Literal::usize_unsuffixed(v as usize)
+        })
+        .collect()
+}
+
+fn frequency_lists(values: &[f64], n_args: usize) -> Vec<TokenStream> {
+    assert_eq!(
+        values.len() % n_args,
+        0,
+        "frequency multipliers should come in groups of {n_args}"
+    );
+    values
+        .chunks(n_args)
+        .map(|list| {
+            let list = floats(list);
+            quote! { [#(#list),*] }
+        })
+        .collect()
+}
+
+pub fn generate_xy06(path: &Path, kernel: &Kernel) {
+    let polynomial_x = get_array(kernel, "XY06_POLYNOMIAL_X");
+    let polynomial_y = get_array(kernel, "XY06_POLYNOMIAL_Y");
+    assert_eq!(polynomial_x.len(), N_POWERS_OF_T);
+    assert_eq!(polynomial_y.len(), N_POWERS_OF_T);
+
+    let luni_solar = get_array(kernel, "XY06_LUNI_SOLAR_FREQUENCIES");
+    let planetary = get_array(kernel, "XY06_PLANETARY_FREQUENCIES");
+    let amplitude_indices = get_array(kernel, "XY06_AMPLITUDE_INDICES");
+    let amplitudes = get_array(kernel, "XY06_AMPLITUDES");
+    let usage_xy = get_array(kernel, "XY06_USAGE_XY");
+    let usage_sin_cos = get_array(kernel, "XY06_USAGE_SIN_COS");
+    let usage_power_of_t = get_array(kernel, "XY06_USAGE_POWER_OF_T");
+
+    let luni_solar = frequency_lists(luni_solar, N_LUNI_SOLAR_ARGS);
+    let planetary = frequency_lists(planetary, N_PLANETARY_ARGS);
+    assert_eq!(
+        amplitude_indices.len(),
+        luni_solar.len() + planetary.len(),
+        "there should be one amplitude index per frequency"
+    );
+    assert_eq!(usage_xy.len(), usage_sin_cos.len());
+    assert_eq!(usage_xy.len(), usage_power_of_t.len());
+
+    let n_powers_of_t = Literal::usize_unsuffixed(N_POWERS_OF_T);
+    let n_luni_solar_args = Literal::usize_unsuffixed(N_LUNI_SOLAR_ARGS);
+    let n_planetary_args = Literal::usize_unsuffixed(N_PLANETARY_ARGS);
+    let n_luni_solar = Literal::usize_unsuffixed(luni_solar.len());
+    let n_planetary = Literal::usize_unsuffixed(planetary.len());
+    let n_indices = Literal::usize_unsuffixed(amplitude_indices.len());
+    let n_amplitudes = Literal::usize_unsuffixed(amplitudes.len());
+    let n_usage = Literal::usize_unsuffixed(usage_xy.len());
+
+    // The generated smoke test asserts the leading coefficients of each table.
+    let first_amplitudes = floats(&amplitudes[..3]);
+    let first_luni_solar = &luni_solar[0];
+    let first_planetary = &planetary[0];
+    let first_x = Literal::f64_unsuffixed(polynomial_x[1]);
+    let first_y = Literal::f64_unsuffixed(polynomial_y[2]);
+
+    let polynomial_x = floats(polynomial_x);
+    let polynomial_y = floats(polynomial_y);
+    let amplitude_indices = indices(amplitude_indices);
+    let amplitudes = floats(amplitudes);
+    let usage_xy = indices(usage_xy);
+    let usage_sin_cos = indices(usage_sin_cos);
+    let usage_power_of_t = indices(usage_power_of_t);
+
+    let code = quote! {
+        use lox_math::types::units::Microarcseconds;
+
+        /// Polynomial coefficients of X in arcseconds.
+        pub(crate) const POLYNOMIAL_X: [f64; #n_powers_of_t] = [#(#polynomial_x),*];
+
+        /// Polynomial coefficients of Y in arcseconds.
+        pub(crate) const POLYNOMIAL_Y: [f64; #n_powers_of_t] = [#(#polynomial_y),*];
+
+        pub(crate) type LuniSolarFrequencyList = [f64; #n_luni_solar_args];
+
+        pub(crate) const N_LUNI_SOLAR_FREQUENCY_LISTS: usize = #n_luni_solar;
+
+        pub(crate) static LUNI_SOLAR_FREQUENCY_LISTS:
+            [LuniSolarFrequencyList; N_LUNI_SOLAR_FREQUENCY_LISTS] = [#(#luni_solar),*];
+
+        pub(crate) type PlanetaryFrequencyList = [f64; #n_planetary_args];
+
+        pub(crate) static PLANETARY_FREQUENCY_LISTS: [PlanetaryFrequencyList; #n_planetary] =
+            [#(#planetary),*];
+
+        /// Indices into [AMPLITUDES].
+        pub(crate) static AMPLITUDE_INDICES: [usize; #n_indices] =
+            [#(#amplitude_indices),*];
+
+        #[allow(clippy::approx_constant)]
+        pub(crate) static AMPLITUDES: [Microarcseconds; #n_amplitudes] = [#(#amplitudes),*];
+
+        pub(crate) const USAGE_XY: [usize; #n_usage] = [#(#usage_xy),*];
+
+        pub(crate) const USAGE_SIN_COS: [usize; #n_usage] = [#(#usage_sin_cos),*];
+
+        pub(crate) const USAGE_POWER_OF_T: [usize; #n_usage] = [#(#usage_power_of_t),*];
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn test_xy06_coefficients() {
+                assert_eq!(POLYNOMIAL_X[1], #first_x);
+                assert_eq!(POLYNOMIAL_Y[2], #first_y);
+                assert_eq!(LUNI_SOLAR_FREQUENCY_LISTS[0], #first_luni_solar);
+                assert_eq!(PLANETARY_FREQUENCY_LISTS[0], #first_planetary);
+                assert_eq!(AMPLITUDES[..3], [#(#first_amplitudes),*]);
+                assert_eq!(AMPLITUDE_INDICES[0], 1);
+            }
+        }
+    };
+
+    write_file(path, "generated.rs", code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequency_lists() {
+        let lists = frequency_lists(&[0.0, 1.0, -2.0, 3.0], 2);
+        let lists: Vec<String> = lists.iter().map(|l| l.to_string()).collect();
+        assert_eq!(lists, vec!["[0.0 , 1.0]", "[- 2.0 , 3.0]"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "frequency multipliers should come in groups of 5")]
+    fn test_frequency_lists_incomplete() {
+        frequency_lists(&[0.0, 1.0, -2.0], 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "index `1.5` should be a non-negative integer")]
+    fn test_indices_not_integer() {
+        indices(&[1.0, 1.5]);
+    }
+}